@@ -1,10 +1,15 @@
 use rash_spec::types::common::{Framework, Language};
 
 use crate::context::EmitContext;
+use crate::generator::{normalize_filename, normalize_identifier};
 use crate::traits::{CtxAccessPattern, FrameworkAdapter, LanguageEmitter};
 use rash_ir::expr::ExprIR;
 use rash_ir::types::{HandlerIR, MiddlewareIR, ProjectIR, RouteIR};
 
+use super::axum::{
+    app_error_file, functions_mod_decl, provides_hint, rust_state_get, state_file, state_keys,
+    state_mod_decl, state_type,
+};
 use super::convert_colon_params_to_braces;
 
 /// Actix-web framework adapter for Rust.
@@ -50,19 +55,25 @@ impl FrameworkAdapter for ActixAdapter {
         let mut lines = Vec::new();
 
         // Actix uses extractors as function params
-        let params = if handler.params.is_empty() {
-            String::new()
-        } else {
-            handler
-                .params
-                .iter()
-                .map(|p| format!("{}: {}", p.name, emitter.emit_type(&p.type_ir)))
-                .collect::<Vec<_>>()
-                .join(", ")
-        };
-
+        let mut params: Vec<String> = handler
+            .params
+            .iter()
+            .map(|p| format!("{}: {}", p.name, emitter.emit_type(&p.type_ir)))
+            .collect();
+        // Middleware inserts provided values as request extensions
+        params.extend(state_keys(handler).into_iter().map(|key| {
+            format!(
+                "{}: actix_web::web::ReqData<crate::state::{}>",
+                key,
+                state_type(&key)
+            )
+        }));
+        let params = params.join(", ");
+
+        ctx.add_import("HttpResponse", "actix_web::HttpResponse");
+        ctx.add_import("AppError", "crate::error::AppError");
         lines.push(format!(
-            "pub {}fn {}({}) -> impl Responder {{",
+            "pub {}fn {}({}) -> Result<HttpResponse, AppError> {{",
             async_kw, handler.name, params
         ));
 
@@ -87,9 +98,10 @@ impl FrameworkAdapter for ActixAdapter {
         format!(
             r#"use actix_web::{{web, App, HttpServer}};
 
-mod handlers;
+mod error;
+{functions_mod}mod handlers;
 mod routes;
-
+{state_mod}
 #[actix_web::main]
 async fn main() -> std::io::Result<()> {{
     let port = std::env::var("PORT")
@@ -108,6 +120,8 @@ async fn main() -> std::io::Result<()> {{
     .await
 }}
 "#,
+            functions_mod = functions_mod_decl(project),
+            state_mod = state_mod_decl(project),
             port = port
         )
     }
@@ -140,17 +154,23 @@ argon2 = "0.5"
             name = name
         );
         files.push(("Cargo.toml".to_string(), cargo_toml));
+        files.push(app_error_file(Framework::Actix, project));
+        files.extend(state_file(project));
 
         files
     }
 
     fn emit_domain_expr(
         &self,
-        _expr: &ExprIR,
+        expr: &ExprIR,
         _emitter: &dyn LanguageEmitter,
         _ctx: &mut EmitContext,
     ) -> Option<String> {
-        None
+        rust_state_get(expr)
+    }
+
+    fn handlers_return_error(&self) -> bool {
+        true
     }
 
     fn emit_middleware_def(
@@ -159,13 +179,16 @@ argon2 = "0.5"
         _emitter: &dyn LanguageEmitter,
         _ctx: &mut EmitContext,
     ) -> String {
+        if !mw.compose.is_empty() {
+            return emit_composed(mw);
+        }
         format!(
             r#"use actix_web::dev::{{ServiceRequest, ServiceResponse, Transform, Service}};
 use std::future::{{Ready, ready}};
 
 pub struct {name};
 
-// TODO: implement {mw_type} middleware
+// TODO: implement {mw_type} middleware{provides}
 impl<S, B> Transform<S, ServiceRequest> for {name}
 where
     S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = actix_web::Error>,
@@ -185,7 +208,8 @@ pub struct {name}Middleware<S> {{
     service: S,
 }}"#,
             name = mw.name,
-            mw_type = mw.middleware_type
+            mw_type = mw.middleware_type,
+            provides = provides_hint(mw)
         )
     }
 
@@ -219,6 +243,38 @@ pub struct {name}Middleware<S> {{
     }
 }
 
+/// A composed middleware wraps its steps so that the first one runs
+/// outermost. Actix always stops at the first step that responds, whatever
+/// `shortCircuit` says.
+fn emit_composed(mw: &MiddlewareIR) -> String {
+    // The last `.wrap` runs first, so steps are wrapped in reverse
+    let wraps: String = mw
+        .compose
+        .iter()
+        .rev()
+        .map(|step| {
+            format!(
+                "\n            .wrap($crate::middleware::{}::{})",
+                normalize_filename(step),
+                normalize_identifier(step)
+            )
+        })
+        .collect();
+    format!(
+        r#"/// Runs {steps} in order, stopping at the first that responds.
+/// Apply it to an `App` or `Scope` with `{name}!(app)`.
+#[macro_export]
+macro_rules! {name} {{
+    ($target:expr) => {{
+        $target{wraps}
+    }};
+}}"#,
+        steps = mw.compose.join(", "),
+        name = mw.name,
+        wraps = wraps
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -244,6 +300,7 @@ mod tests {
                         summary: None,
                         handler_ref: "list_users".to_string(),
                         middleware: vec![],
+                        provides: IndexMap::new(),
                         request: RequestIR {
                             query_schema: None,
                             body_schema: None,
@@ -274,6 +331,7 @@ mod tests {
             models: vec![],
             middleware: vec![],
             handlers: vec![],
+            functions: vec![],
         };
 
         let mut ctx = EmitContext::new(IndentStyle::Spaces(4));
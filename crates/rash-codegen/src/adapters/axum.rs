@@ -0,0 +1,1400 @@
+use indexmap::IndexMap;
+use rash_spec::types::common::{Framework, Language};
+
+use crate::context::{EmitContext, SchemaRef};
+use crate::error::CodegenError;
+use crate::generator::{normalize_filename, normalize_identifier};
+use crate::traits::{CtxAccessPattern, FrameworkAdapter, LanguageEmitter};
+use rash_ir::convert::convert_json_schema;
+use rash_ir::expr::{ExprIR, HttpRespondIR, TypeIR};
+use rash_ir::statement::StatementIR;
+use rash_ir::types::{HandlerIR, MiddlewareIR, ProjectIR, RouteIR};
+
+use super::{collect_ctx_paths, convert_colon_params_to_braces};
+use crate::emitters::rust_lang::json_has_kind;
+
+/// Axum framework adapter for Rust.
+pub struct AxumAdapter;
+
+impl FrameworkAdapter for AxumAdapter {
+    fn framework(&self) -> Framework {
+        Framework::Axum
+    }
+
+    fn compatible_language(&self) -> Language {
+        Language::Rust
+    }
+
+    fn emit_route_registration(
+        &self,
+        route: &RouteIR,
+        _emitter: &dyn LanguageEmitter,
+        ctx: &mut EmitContext,
+    ) -> String {
+        let mut lines = Vec::new();
+        for (method, endpoint) in &route.methods {
+            let method_lower = format!("{:?}", method).to_lowercase();
+            let handler_id = normalize_identifier(&endpoint.handler_ref);
+            let handler_file = normalize_filename(&endpoint.handler_ref);
+
+            ctx.add_import(
+                method_lower.clone(),
+                format!("axum::routing::{}", method_lower),
+            );
+            ctx.add_import(
+                handler_id.clone(),
+                format!("crate::handlers::{}::{}", handler_file, handler_id),
+            );
+
+            let mut method_router = format!("{}({})", method_lower, handler_id);
+
+            // ServiceBuilder runs layers top-to-bottom, preserving spec order
+            if !endpoint.middleware.is_empty() {
+                ctx.add_import("ServiceBuilder", "tower::ServiceBuilder");
+                let layers: Vec<String> = endpoint
+                    .middleware
+                    .iter()
+                    .map(|mw| self.emit_middleware_apply(mw, ctx))
+                    .collect();
+                method_router = format!(
+                    "{}.layer(ServiceBuilder::new(){})",
+                    method_router,
+                    layers.join("")
+                );
+            }
+
+            lines.push(format!(".route(\"{}\", {})", route.path, method_router));
+        }
+        lines.join("\n")
+    }
+
+    fn emit_middleware_apply(&self, mw_ref: &str, ctx: &mut EmitContext) -> String {
+        let mw_id = normalize_identifier(mw_ref);
+        let mw_file = normalize_filename(mw_ref);
+        ctx.add_import("from_fn", "axum::middleware::from_fn");
+        ctx.add_import(
+            mw_id.clone(),
+            format!("crate::middleware::{}::{}", mw_file, mw_id),
+        );
+        format!(".layer(from_fn({}))", mw_id)
+    }
+
+    fn emit_handler(
+        &self,
+        handler: &HandlerIR,
+        emitter: &dyn LanguageEmitter,
+        ctx: &mut EmitContext,
+    ) -> String {
+        ctx.add_import("IntoResponse", "axum::response::IntoResponse");
+        ctx.add_import("Response", "axum::response::Response");
+        ctx.add_import("StatusCode", "axum::http::StatusCode");
+        ctx.add_import("Json", "axum::Json");
+        ctx.add_import("AppError", "crate::error::AppError");
+
+        // Only request the extractors the body actually reads: `Path` rejects
+        // routes without params and `Json` rejects bodiless requests.
+        let mut ctx_paths = Vec::new();
+        if let Ok(body) = serde_json::to_value(&handler.body) {
+            collect_ctx_paths(&body, &mut ctx_paths);
+        }
+        let uses = |root: &str| {
+            ctx_paths
+                .iter()
+                .any(|p| p == root || p.starts_with(&format!("{}.", root)))
+        };
+
+        let mut params = Vec::new();
+        if uses_database(handler) {
+            ctx.add_import("State", "axum::extract::State");
+            ctx.add_import("DatabaseConnection", "sea_orm::DatabaseConnection");
+            params.push("State(db): State<DatabaseConnection>".to_string());
+        }
+        // Middleware inserts provided values as request extensions
+        for key in state_keys(handler) {
+            ctx.add_import("Extension", "axum::Extension");
+            params.push(format!(
+                "Extension({}): Extension<crate::state::{}>",
+                key,
+                state_type(&key)
+            ));
+        }
+        // Extractors are typed from the endpoint's path and schemas; handlers
+        // without a route fall back to untyped maps
+        let request = ctx.request().cloned().unwrap_or_default();
+        let mut lines = Vec::new();
+        if uses("params") {
+            ctx.add_import("Path", "axum::extract::Path");
+            if request.path_params.is_empty() {
+                ctx.add_import("HashMap", "std::collections::HashMap");
+                params.push("Path(params): Path<HashMap<String, String>>".to_string());
+            } else {
+                let params_type = format!("{}Params", state_type(&handler.name));
+                lines.push("#[derive(Debug, serde::Deserialize)]".to_string());
+                lines.push(format!("pub struct {} {{", params_type));
+                for name in &request.path_params {
+                    lines.push(format!("    pub {}: String,", name));
+                }
+                lines.push("}".to_string());
+                lines.push(String::new());
+                params.push(format!("Path(params): Path<{}>", params_type));
+            }
+        }
+        if uses("query") {
+            ctx.add_import("Query", "axum::extract::Query");
+            match &request.query_schema {
+                Some(schema) => {
+                    import_schema(schema, ctx);
+                    params.push(format!("Query(query): Query<{}>", schema.name));
+                }
+                None => {
+                    ctx.add_import("HashMap", "std::collections::HashMap");
+                    params.push("Query(query): Query<HashMap<String, String>>".to_string());
+                }
+            }
+        }
+        if uses("headers") {
+            ctx.add_import("HeaderMap", "axum::http::HeaderMap");
+            params.push("headers: HeaderMap".to_string());
+        }
+        // `Json` consumes the request body, so it must be the last extractor
+        if uses("body") {
+            match &request.body_schema {
+                Some(schema) => {
+                    import_schema(schema, ctx);
+                    params.push(format!("Json(body): Json<{}>", schema.name));
+                }
+                None => params.push("Json(body): Json<serde_json::Value>".to_string()),
+            }
+        }
+
+        // Axum only calls async functions as handlers, middleware handlers included
+        lines.push(format!(
+            "pub async fn {}({}) -> Result<Response, AppError> {{",
+            handler.name,
+            params.join(", ")
+        ));
+
+        let mut body = handler.body.clone();
+        respond_on_return(&mut body);
+        ctx.push_indent();
+        for stmt in &body {
+            lines.push(emitter.emit_statement(stmt, ctx));
+        }
+        ctx.pop_indent();
+
+        lines.push("}".to_string());
+        lines.join("\n")
+    }
+
+    fn emit_entrypoint(&self, project: &ProjectIR, ctx: &mut EmitContext) -> String {
+        let port = project
+            .config
+            .get("server")
+            .and_then(|s| s.get("port"))
+            .and_then(|p| p.as_u64())
+            .unwrap_or(3000);
+
+        let name = project
+            .config
+            .get("name")
+            .and_then(|n| n.as_str())
+            .unwrap_or("rash-app");
+
+        let base_path = project
+            .config
+            .get("server")
+            .and_then(|s| s.get("basePath"))
+            .and_then(|b| b.as_str())
+            .filter(|b| !b.is_empty() && *b != "/");
+
+        let mount = match base_path {
+            Some(base) => format!(".nest(\"{}\", routes::router())", base),
+            None => ".merge(routes::router())".to_string(),
+        };
+
+        // Global middleware is layered here rather than by the generator:
+        // axum layers only wrap routes registered before them.
+        let global_refs: Vec<String> = project
+            .config
+            .get("middleware")
+            .and_then(|m| m.get("global"))
+            .and_then(|g| g.as_array())
+            .map(|arr| {
+                arr.iter()
+                    .filter_map(|v| v.get("ref").and_then(|r| r.as_str()))
+                    .map(|s| s.to_string())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let mut mw_uses = Vec::new();
+        let mut mw_layers = String::new();
+        for mw_ref in &global_refs {
+            mw_uses.push(format!(
+                "use middleware::{}::{};\n",
+                normalize_filename(mw_ref),
+                normalize_identifier(mw_ref)
+            ));
+            mw_layers.push_str(&format!(
+                "\n        {}",
+                self.emit_middleware_apply(mw_ref, ctx)
+            ));
+        }
+        // Route-level imports are not needed in the entrypoint
+        ctx.take_imports();
+
+        // Handlers reach the database through `State`
+        let connect = if project.models.is_empty() {
+            "    let db = DatabaseConnection::Disconnected;\n".to_string()
+        } else {
+            r#"    let database_url = std::env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    let db = sea_orm::Database::connect(&database_url)
+        .await
+        .expect("failed to connect to the database");
+"#
+            .to_string()
+        };
+
+        // Middleware handlers find the connection in the request extensions
+        let db_extension = if project.middleware.iter().any(|mw| mw.handler_ref.is_some()) {
+            "\n        .layer(axum::Extension(db.clone()))"
+        } else {
+            ""
+        };
+
+        let from_fn_use = if global_refs.is_empty() {
+            ""
+        } else {
+            "use axum::middleware::from_fn;\n"
+        };
+
+        format!(
+            r#"#![allow(dead_code, non_snake_case, unused_imports)]
+
+{from_fn_use}use axum::Router;
+use sea_orm::DatabaseConnection;
+
+mod error;
+{functions_mod}mod handlers;
+mod middleware;
+mod models;
+mod schemas;
+#[path = "routes/index.rs"]
+mod routes;
+{state_mod}
+{mw_uses}
+#[tokio::main]
+async fn main() {{
+    let port = std::env::var("PORT")
+        .unwrap_or_else(|_| "{port}".to_string())
+        .parse::<u16>()
+        .expect("PORT must be a number");
+
+{connect}
+    let app = Router::new()
+        {mount}{mw_layers}{db_extension}
+        .with_state(db);
+
+    let listener = tokio::net::TcpListener::bind(("0.0.0.0", port))
+        .await
+        .expect("failed to bind port");
+
+    println!("{name} running on port {{}}", port);
+
+    axum::serve(listener, app).await.expect("server error");
+}}
+"#,
+            from_fn_use = from_fn_use,
+            functions_mod = functions_mod_decl(project),
+            state_mod = state_mod_decl(project),
+            mw_uses = mw_uses.concat(),
+            port = port,
+            connect = connect,
+            mount = mount,
+            mw_layers = mw_layers,
+            db_extension = db_extension,
+            name = name
+        )
+    }
+
+    fn emit_project_config(&self, project: &ProjectIR) -> Vec<(String, String)> {
+        let mut files = Vec::new();
+
+        let name = project
+            .config
+            .get("name")
+            .and_then(|n| n.as_str())
+            .unwrap_or("rash-app");
+
+        let cargo_toml = format!(
+            r#"[package]
+name = "{name}"
+version = "0.1.0"
+edition = "2021"
+
+[[bin]]
+name = "{name}"
+path = "src/index.rs"
+
+[dependencies]
+axum = "0.8"
+tokio = {{ version = "1", features = ["full"] }}
+tower = "0.5"
+tower-http = {{ version = "0.6", features = ["cors", "trace"] }}
+serde = {{ version = "1", features = ["derive"] }}
+serde_json = "1"
+chrono = {{ version = "0.4", features = ["serde"] }}
+sea-orm = {{ version = "1", features = ["sqlx-postgres", "runtime-tokio-rustls"] }}
+jsonwebtoken = "9"
+argon2 = "0.5"
+"#,
+            name = name
+        );
+        files.push(("Cargo.toml".to_string(), cargo_toml));
+
+        files.push(app_error_file(Framework::Axum, project));
+        files.extend(rust_module_files(project));
+        files.extend(state_file(project));
+
+        files
+    }
+
+    fn emit_domain_expr(
+        &self,
+        expr: &ExprIR,
+        emitter: &dyn LanguageEmitter,
+        ctx: &mut EmitContext,
+    ) -> Option<String> {
+        match expr {
+            ExprIR::HttpRespond(r) => {
+                let status = axum_status_code(r.status);
+                Some(match r.body.as_deref() {
+                    // A bare literal has no Rust type to infer, so it's written as JSON
+                    Some(ExprIR::Literal { value }) => format!(
+                        "({}, Json(serde_json::json!({}))).into_response()",
+                        status, value
+                    ),
+                    Some(b) => {
+                        let body = emitter.emit_expression(b, ctx);
+                        format!("({}, Json({})).into_response()", status, body)
+                    }
+                    None => format!("{}.into_response()", status),
+                })
+            }
+            ExprIR::CtxGet { path } => {
+                let request = ctx.request();
+                let typed_params = request.is_some_and(|r| !r.path_params.is_empty());
+                let typed_query = request.is_some_and(|r| r.query_schema.is_some());
+                let typed_body = request.is_some_and(|r| r.body_schema.is_some());
+                let parts: Vec<&str> = path.splitn(2, '.').collect();
+                Some(match parts.as_slice() {
+                    ["params", rest] if typed_params => format!("params.{}.clone()", rest),
+                    ["params", rest] => {
+                        format!("params.get(\"{}\").cloned().unwrap_or_default()", rest)
+                    }
+                    ["query", rest] if typed_query => format!("query.{}.clone()", rest),
+                    ["query", rest] => format!("query.get(\"{}\").cloned()", rest),
+                    ["headers", rest] => format!(
+                        "headers.get(\"{}\").and_then(|v| v.to_str().ok()).unwrap_or_default().to_string()",
+                        rest
+                    ),
+                    // A typed body is read as JSON where it's used whole, e.g. as ORM input
+                    ["body"] if typed_body => "serde_json::json!(body)".to_string(),
+                    ["body"] => "body".to_string(),
+                    ["body", rest] if typed_body => match rest.split_once('.') {
+                        Some((field, nested)) => {
+                            let index: String =
+                                nested.split('.').map(|f| format!("[\"{}\"]", f)).collect();
+                            format!("serde_json::json!(body.{}){}.clone()", field, index)
+                        }
+                        None => format!("body.{}.clone()", rest),
+                    },
+                    ["body", rest] => format!("body[\"{}\"].clone()", rest),
+                    ["state", _] => rust_state_get(expr)?,
+                    _ => path.replace('.', "_"),
+                })
+            }
+            _ => None,
+        }
+    }
+
+    fn handlers_return_error(&self) -> bool {
+        true
+    }
+
+    fn emit_middleware_def(
+        &self,
+        mw: &MiddlewareIR,
+        _emitter: &dyn LanguageEmitter,
+        _ctx: &mut EmitContext,
+    ) -> String {
+        if !mw.compose.is_empty() {
+            return emit_composed(mw);
+        }
+        match &mw.handler_ref {
+            Some(handler_ref) => emit_handler_middleware(mw, handler_ref),
+            None => format!(
+                r#"use axum::extract::Request;
+use axum::middleware::Next;
+use axum::response::Response;
+
+// `{name}` has no handler, so requests pass through unchanged
+pub async fn {name}(req: Request, next: Next) -> Response {{
+    next.run(req).await
+}}"#,
+                name = mw.name
+            ),
+        }
+    }
+
+    fn check_middleware(&self, mw: &MiddlewareIR) -> Result<(), CodegenError> {
+        // Handlers run before the request goes on; there's no hook for
+        // rewriting responses or catching errors yet
+        match mw.middleware_type.as_str() {
+            "request" | "composed" => Ok(()),
+            other => Err(CodegenError::Other(format!(
+                "Axum can't generate `{}` middleware `{}`",
+                other, mw.name
+            ))),
+        }
+    }
+
+    fn ctx_access_pattern(&self) -> CtxAccessPattern {
+        CtxAccessPattern::Extractors
+    }
+
+    fn wrap_route_file(&self, imports: &str, route_blocks: &str, _ctx: &mut EmitContext) -> String {
+        let mut lines = Vec::new();
+        lines.push("use axum::Router;".to_string());
+        lines.push("use sea_orm::DatabaseConnection;".to_string());
+        if !imports.is_empty() {
+            lines.push(imports.to_string());
+        }
+        lines.push(String::new());
+        lines.push("pub fn router() -> Router<DatabaseConnection> {".to_string());
+        lines.push("    Router::new()".to_string());
+        for line in route_blocks.lines() {
+            if !line.is_empty() {
+                lines.push(format!("        {}", line));
+            }
+        }
+        lines.push("}".to_string());
+        lines.join("\n")
+    }
+
+    fn normalize_path(&self, path: &str) -> String {
+        // Axum 0.8 uses {param} instead of :param
+        convert_colon_params_to_braces(path)
+    }
+}
+
+/// A composed middleware layers its steps around the chain, stopping at the first that responds.
+/// Run the middleware's handler on the incoming request. A response other
+/// than 2xx is sent back as is; otherwise the value the handler returned is
+/// handed to downstream handlers as the middleware's `provides`.
+fn emit_handler_middleware(mw: &MiddlewareIR, handler_ref: &str) -> String {
+    let handler_id = normalize_identifier(handler_ref);
+    // A single provided value is the whole result; several are its fields
+    let provides: String = mw
+        .provides
+        .keys()
+        .map(|key| {
+            let value = if mw.provides.len() == 1 {
+                "provided".to_string()
+            } else {
+                format!("provided[\"{}\"].clone()", key)
+            };
+            format!(
+                "    req.extensions_mut().insert(crate::state::{}(\n        serde_json::from_value({}).unwrap_or_default(),\n    ));\n",
+                state_type(key),
+                value
+            )
+        })
+        .collect();
+    let read_provided = if provides.is_empty() {
+        String::new()
+    } else {
+        r#"    let provided: serde_json::Value = match to_bytes(response.into_body(), usize::MAX).await {
+        Ok(bytes) => serde_json::from_slice(&bytes).unwrap_or_default(),
+        Err(_) => serde_json::Value::Null,
+    };
+"#
+        .to_string()
+    };
+    let mut_req = if provides.is_empty() { "" } else { "mut " };
+    format!(
+        r#"use axum::body::{{to_bytes, Body}};
+use axum::extract::Request;
+use axum::handler::Handler;
+use axum::middleware::Next;
+use axum::response::{{IntoResponse, Response}};
+use sea_orm::DatabaseConnection;
+
+use crate::error::AppError;
+use crate::handlers::{handler_file}::{handler_id};
+
+// Runs `{handler_ref}` before the request goes on
+pub async fn {name}(req: Request, next: Next) -> Response {{
+    let db = req
+        .extensions()
+        .get::<DatabaseConnection>()
+        .cloned()
+        .unwrap_or(DatabaseConnection::Disconnected);
+    let (parts, body) = req.into_parts();
+    let bytes = match to_bytes(body, usize::MAX).await {{
+        Ok(bytes) => bytes,
+        Err(err) => return AppError::new(400, err.to_string()).into_response(),
+    }};
+    let check = Request::from_parts(parts.clone(), Body::from(bytes.clone()));
+    let response = Handler::call({handler_id}, check, db).await;
+    if !response.status().is_success() {{
+        return response;
+    }}
+{read_provided}    let {mut_req}req = Request::from_parts(parts, Body::from(bytes));
+{provides}    next.run(req).await
+}}"#,
+        handler_file = normalize_filename(handler_ref),
+        handler_id = handler_id,
+        handler_ref = handler_ref,
+        name = mw.name,
+        read_provided = read_provided,
+        mut_req = mut_req,
+        provides = provides
+    )
+}
+
+fn emit_composed(mw: &MiddlewareIR) -> String {
+    let uses: String = mw
+        .compose
+        .iter()
+        .map(|step| {
+            format!(
+                "use crate::middleware::{}::{};\n",
+                normalize_filename(step),
+                normalize_identifier(step)
+            )
+        })
+        .collect();
+    let layers: String = mw
+        .compose
+        .iter()
+        .map(|step| format!("\n        .layer(from_fn({}))", normalize_identifier(step)))
+        .collect();
+    format!(
+        r#"use axum::extract::Request;
+use axum::middleware::{{from_fn, Next}};
+use axum::response::Response;
+use tower::{{service_fn, ServiceBuilder, ServiceExt}};
+{uses}
+// Runs {steps} in order, stopping at the first that responds
+pub async fn {name}(req: Request, next: Next) -> Response {{
+    let chain = ServiceBuilder::new(){layers}
+        .service(service_fn(move |req: Request| {{
+            let next = next.clone();
+            async move {{ Ok::<_, std::convert::Infallible>(next.run(req).await) }}
+        }}));
+    match chain.oneshot(req).await {{
+        Ok(response) => response,
+        Err(never) => match never {{}},
+    }}
+}}"#,
+        uses = uses,
+        steps = mw.compose.join(", "),
+        name = mw.name,
+        layers = layers
+    )
+}
+
+/// `mod functions;` when the generator emits the shared user function module.
+pub(crate) fn functions_mod_decl(project: &ProjectIR) -> &'static str {
+    if project.functions.is_empty() {
+        ""
+    } else {
+        "mod functions;\n"
+    }
+}
+
+/// `mod state;` when middleware provides values to handlers.
+pub(crate) fn state_mod_decl(project: &ProjectIR) -> &'static str {
+    if project.middleware.iter().all(|mw| mw.provides.is_empty()) {
+        ""
+    } else {
+        "mod state;\n"
+    }
+}
+
+/// `src/state.rs`: one request extension type per middleware-provided value,
+/// so values of the same Rust type don't overwrite each other.
+pub(crate) fn state_file(project: &ProjectIR) -> Option<(String, String)> {
+    let mut provided = IndexMap::new();
+    for mw in &project.middleware {
+        for (key, schema) in &mw.provides {
+            provided.insert(key.clone(), (mw.name.clone(), convert_json_schema(schema)));
+        }
+    }
+    if provided.is_empty() {
+        return None;
+    }
+    let types: Vec<String> = provided
+        .iter()
+        .map(|(key, (mw_name, type_ir))| {
+            let inner = match type_ir {
+                TypeIR::String => "String",
+                TypeIR::Number => "f64",
+                TypeIR::Boolean => "bool",
+                _ => "serde_json::Value",
+            };
+            format!(
+                "/// `{}` provided by the `{}` middleware.\n#[derive(Clone, Debug)]\npub struct {}(pub {});",
+                key,
+                mw_name,
+                state_type(key),
+                inner
+            )
+        })
+        .collect();
+    Some(("src/state.rs".to_string(), format!("{}\n", types.join("\n\n"))))
+}
+
+/// Import a request schema from the schema file that defines it.
+fn import_schema(schema: &SchemaRef, ctx: &mut EmitContext) {
+    ctx.add_import(
+        schema.name.clone(),
+        format!("crate::schemas::{}::{}", schema.module, schema.name),
+    );
+}
+
+/// Extension type carrying the provided value `key`.
+pub(crate) fn state_type(key: &str) -> String {
+    let mut chars = key.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+        None => String::new(),
+    }
+}
+
+/// Keys of the `state.<key>` values a handler reads, in first-read order.
+pub(crate) fn state_keys(handler: &HandlerIR) -> Vec<String> {
+    let mut paths = Vec::new();
+    if let Ok(body) = serde_json::to_value(&handler.body) {
+        collect_ctx_paths(&body, &mut paths);
+    }
+    let mut keys: Vec<String> = Vec::new();
+    for path in &paths {
+        let key = path.strip_prefix("state.").and_then(|rest| rest.split('.').next());
+        if let Some(key) = key.filter(|k| !keys.iter().any(|seen| seen == k)) {
+            keys.push(key.to_string());
+        }
+    }
+    keys
+}
+
+/// Read a provided value, `state.<key>[.<field>...]`, from the extension
+/// bound to `key`.
+pub(crate) fn rust_state_get(expr: &ExprIR) -> Option<String> {
+    let ExprIR::CtxGet { path } = expr else {
+        return None;
+    };
+    let mut fields = path.strip_prefix("state.")?.split('.');
+    let key = fields.next()?;
+    let index: String = fields.map(|f| format!("[\"{}\"]", f)).collect();
+    Some(format!("{}.0{}.clone()", key, index))
+}
+
+/// Comment lines pointing a middleware stub at the extensions it provides.
+pub(crate) fn provides_hint(mw: &MiddlewareIR) -> String {
+    mw.provides
+        .keys()
+        .map(|key| {
+            format!(
+                "\n// Provides `{}`: req.extensions_mut().insert(crate::state::{}(..))",
+                key,
+                state_type(key)
+            )
+        })
+        .collect()
+}
+
+/// Whether a handler body talks to the database and so needs the connection.
+fn uses_database(handler: &HandlerIR) -> bool {
+    serde_json::to_value(&handler.body)
+        .map(|body| {
+            ["DbQuery", "DbMutate", "Transaction"]
+                .iter()
+                .any(|kind| json_has_kind(&body, kind))
+        })
+        .unwrap_or(false)
+}
+
+/// Handlers return a `Response`, so returning a plain value responds with it
+/// as JSON (or `204 No Content` for a bare `return` / `return null`).
+fn respond_on_return(stmts: &mut [StatementIR]) {
+    for stmt in stmts {
+        match stmt {
+            StatementIR::Return { value } => {
+                let respond = match value.take() {
+                    Some(respond @ ExprIR::HttpRespond(_)) => respond,
+                    Some(ExprIR::Literal { value }) if value.is_null() => {
+                        ExprIR::HttpRespond(HttpRespondIR {
+                            status: 204,
+                            headers: None,
+                            body: None,
+                        })
+                    }
+                    Some(body) => ExprIR::HttpRespond(HttpRespondIR {
+                        status: 200,
+                        headers: None,
+                        body: Some(Box::new(body)),
+                    }),
+                    None => ExprIR::HttpRespond(HttpRespondIR {
+                        status: 204,
+                        headers: None,
+                        body: None,
+                    }),
+                };
+                *value = Some(respond);
+            }
+            StatementIR::If { then_, else_, .. } => {
+                respond_on_return(then_);
+                if let Some(else_) = else_ {
+                    respond_on_return(else_);
+                }
+            }
+            StatementIR::For { body, .. }
+            | StatementIR::While { body, .. }
+            | StatementIR::Transaction { body, .. }
+            | StatementIR::Component { body, .. } => respond_on_return(body),
+            StatementIR::Match { arms, .. } => {
+                for arm in arms {
+                    respond_on_return(&mut arm.body);
+                }
+            }
+            StatementIR::TryCatch {
+                try_,
+                catch_,
+                finally_,
+            } => {
+                respond_on_return(try_);
+                respond_on_return(&mut catch_.body);
+                if let Some(finally_) = finally_ {
+                    respond_on_return(finally_);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// `mod.rs` trees for the per-item Rust files emitted by the generator.
+pub(crate) fn rust_module_files(project: &ProjectIR) -> Vec<(String, String)> {
+    let mut files = Vec::new();
+
+    let handler_mods: Vec<String> = project
+        .handlers
+        .iter()
+        .map(|h| normalize_filename(&h.name))
+        .collect();
+    let middleware_mods: Vec<String> = project
+        .middleware
+        .iter()
+        .map(|m| normalize_filename(&m.name))
+        .collect();
+    let model_mods: Vec<String> = project
+        .models
+        .iter()
+        .map(|m| m.name.to_lowercase())
+        .collect();
+    let schema_mods: Vec<String> = project
+        .schemas
+        .iter()
+        .map(|s| s.name.to_lowercase())
+        .collect();
+
+    for (dir, mods) in [
+        ("handlers", handler_mods),
+        ("middleware", middleware_mods),
+        ("models", model_mods),
+        ("schemas", schema_mods),
+    ] {
+        let content: String = mods.iter().map(|m| format!("pub mod {};\n", m)).collect();
+        files.push((format!("src/{}/mod.rs", dir), content));
+    }
+
+    files
+}
+
+/// `src/error.rs`: the `AppError` that lowered `throw` statements produce,
+/// with the framework's response conversion, and the `Finally` drop guard
+/// used for `finally` blocks. `?` on SeaORM and `jsonwebtoken` calls converts
+/// through `From` impls that are only emitted when the project makes those calls.
+pub(crate) fn app_error_file(framework: Framework, project: &ProjectIR) -> (String, String) {
+    let response_impl = match framework {
+        Framework::Actix => {
+            r#"impl actix_web::ResponseError for AppError {
+    fn status_code(&self) -> actix_web::http::StatusCode {
+        actix_web::http::StatusCode::from_u16(self.status)
+            .unwrap_or(actix_web::http::StatusCode::INTERNAL_SERVER_ERROR)
+    }
+
+    fn error_response(&self) -> actix_web::HttpResponse {
+        actix_web::HttpResponse::build(self.status_code()).json(&self.body)
+    }
+}"#
+        }
+        Framework::Rocket => {
+            r#"impl<'r> rocket::response::Responder<'r, 'static> for AppError {
+    fn respond_to(self, req: &'r rocket::Request<'_>) -> rocket::response::Result<'static> {
+        let status = rocket::http::Status::from_code(self.status)
+            .unwrap_or(rocket::http::Status::InternalServerError);
+        let body = (status, rocket::serde::json::Json(self.body));
+        rocket::response::Responder::respond_to(body, req)
+    }
+}"#
+        }
+        _ => {
+            r#"impl axum::response::IntoResponse for AppError {
+    fn into_response(self) -> axum::response::Response {
+        let status = axum::http::StatusCode::from_u16(self.status)
+            .unwrap_or(axum::http::StatusCode::INTERNAL_SERVER_ERROR);
+        (status, axum::Json(self.body)).into_response()
+    }
+}"#
+        }
+    };
+
+    let orm = project
+        .config
+        .get("database")
+        .and_then(|d| d.get("orm"))
+        .and_then(|o| o.as_str());
+    let orm_impl = if orm == Some("seaorm")
+        || project_uses(project, &["DbQuery", "DbMutate", "Transaction"])
+    {
+        r#"impl From<sea_orm::DbErr> for AppError {
+    fn from(err: sea_orm::DbErr) -> Self {
+        Self::new(500, err.to_string())
+    }
+}
+
+"#
+    } else {
+        ""
+    };
+    let auth_impl = if project_uses(project, &["SignToken", "VerifyToken"]) {
+        r#"impl From<jsonwebtoken::errors::Error> for AppError {
+    fn from(err: jsonwebtoken::errors::Error) -> Self {
+        Self::new(401, err.to_string())
+    }
+}
+
+"#
+    } else {
+        ""
+    };
+
+    let content = format!(
+        r#"use serde_json::{{json, Value}};
+
+/// Error raised by `throw` in handler code.
+#[derive(Debug)]
+pub struct AppError {{
+    pub status: u16,
+    pub body: Value,
+}}
+
+impl AppError {{
+    pub fn new(status: u16, message: impl Into<String>) -> Self {{
+        Self {{
+            status,
+            body: json!({{ "error": message.into() }}),
+        }}
+    }}
+}}
+
+impl std::fmt::Display for AppError {{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {{
+        write!(f, "{{}} {{}}", self.status, self.body)
+    }}
+}}
+
+impl std::error::Error for AppError {{}}
+
+impl From<&str> for AppError {{
+    fn from(message: &str) -> Self {{
+        Self::new(500, message)
+    }}
+}}
+
+impl From<String> for AppError {{
+    fn from(message: String) -> Self {{
+        Self::new(500, message)
+    }}
+}}
+
+/// Thrown objects may carry their own `status`.
+impl From<Value> for AppError {{
+    fn from(body: Value) -> Self {{
+        let status = body
+            .get("status")
+            .and_then(|s| s.as_u64())
+            .and_then(|s| u16::try_from(s).ok())
+            .unwrap_or(500);
+        Self {{ status, body }}
+    }}
+}}
+
+{orm_impl}{auth_impl}{response_impl}
+
+/// Runs the wrapped closure when dropped, so `finally` blocks execute on every exit path.
+pub struct Finally<F: FnOnce()>(Option<F>);
+
+impl<F: FnOnce()> Finally<F> {{
+    pub fn new(f: F) -> Self {{
+        Self(Some(f))
+    }}
+}}
+
+impl<F: FnOnce()> Drop for Finally<F> {{
+    fn drop(&mut self) {{
+        if let Some(f) = self.0.take() {{
+            f();
+        }}
+    }}
+}}
+"#,
+        orm_impl = orm_impl,
+        auth_impl = auth_impl,
+        response_impl = response_impl
+    );
+    ("src/error.rs".to_string(), content)
+}
+
+/// Whether any handler or shared function contains one of the IR node `kinds`.
+fn project_uses(project: &ProjectIR, kinds: &[&str]) -> bool {
+    let bodies = [
+        serde_json::to_value(&project.handlers),
+        serde_json::to_value(&project.functions),
+    ];
+    bodies
+        .iter()
+        .flatten()
+        .any(|body| kinds.iter().any(|kind| json_has_kind(body, kind)))
+}
+
+/// Map an HTTP status to the `axum::http::StatusCode` constant expression.
+fn axum_status_code(status: u16) -> String {
+    let name = match status {
+        200 => "OK",
+        201 => "CREATED",
+        202 => "ACCEPTED",
+        204 => "NO_CONTENT",
+        400 => "BAD_REQUEST",
+        401 => "UNAUTHORIZED",
+        403 => "FORBIDDEN",
+        404 => "NOT_FOUND",
+        409 => "CONFLICT",
+        422 => "UNPROCESSABLE_ENTITY",
+        500 => "INTERNAL_SERVER_ERROR",
+        _ => return format!("StatusCode::from_u16({}).unwrap()", status),
+    };
+    format!("StatusCode::{}", name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::rc::Rc;
+    use crate::context::{HandlerRequest, IndentStyle};
+    use indexmap::IndexMap;
+    use rash_ir::expr::TypeIR;
+    use rash_ir::statement::StatementIR;
+    use rash_ir::types::{EndpointIR, RequestIR};
+    use rash_spec::types::common::{HttpMethod, Tier};
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_emit_route_registration() {
+        let adapter = AxumAdapter;
+        let emitter = crate::emitters::rust_lang::RustEmitter;
+        let mut ctx = EmitContext::new(IndentStyle::Spaces(4));
+
+        let route = RouteIR {
+            path: "/api/users/{id}".to_string(),
+            methods: {
+                let mut m = IndexMap::new();
+                m.insert(
+                    HttpMethod::Get,
+                    EndpointIR {
+                        operation_id: "getUser".to_string(),
+                        summary: None,
+                        handler_ref: "users.getUser".to_string(),
+                        middleware: vec!["auth".to_string()],
+                        provides: IndexMap::new(),
+                        request: RequestIR {
+                            query_schema: None,
+                            body_schema: None,
+                            content_type: None,
+                        },
+                        response: IndexMap::new(),
+                    },
+                );
+                m
+            },
+            tags: vec![],
+        };
+
+        let code = adapter.emit_route_registration(&route, &emitter, &mut ctx);
+        assert!(code.contains(
+            ".route(\"/api/users/{id}\", get(usersGetUser).layer(ServiceBuilder::new().layer(from_fn(auth))))"
+        ));
+
+        let imports = ctx.take_imports();
+        assert!(imports
+            .iter()
+            .any(|i| i.from == "crate::handlers::users_getUser::usersGetUser"));
+        assert!(imports.iter().any(|i| i.from == "axum::routing::get"));
+    }
+
+    #[test]
+    fn test_emit_handler_extractors() {
+        let adapter = AxumAdapter;
+        let emitter = crate::emitters::rust_lang::RustEmitter;
+        let mut ctx = EmitContext::new(IndentStyle::Spaces(4)).with_adapter(Rc::new(AxumAdapter));
+
+        let handler = HandlerIR {
+            name: "getUser".to_string(),
+            is_async: true,
+            params: vec![],
+            return_type: TypeIR::Any,
+            body: vec![StatementIR::Let {
+                name: "id".to_string(),
+                type_: None,
+                value: ExprIR::CtxGet {
+                    path: "params.id".to_string(),
+                },
+            }],
+            max_tier: Tier::Domain,
+            bridge_languages: HashSet::new(),
+        };
+
+        let code = adapter.emit_handler(&handler, &emitter, &mut ctx);
+        assert!(code.contains(
+            "pub async fn getUser(Path(params): Path<HashMap<String, String>>) -> Result<Response, AppError> {"
+        ));
+        assert!(!code.contains("Json(body)"));
+        // Each imported name is registered under its own key
+        let imports: Vec<(&str, &str)> = ctx
+            .imports()
+            .iter()
+            .map(|i| (i.names.as_str(), i.from.as_str()))
+            .collect();
+        assert!(imports.contains(&("IntoResponse", "axum::response::IntoResponse")));
+        assert!(imports.contains(&("Response", "axum::response::Response")));
+    }
+
+    #[test]
+    fn test_emit_handler_typed_extractors() {
+        let adapter = AxumAdapter;
+        let emitter = crate::emitters::rust_lang::RustEmitter;
+        let schema = |name: &str| SchemaRef {
+            module: "user".to_string(),
+            name: name.to_string(),
+        };
+        let mut ctx = EmitContext::new(IndentStyle::Spaces(4))
+            .with_adapter(Rc::new(AxumAdapter))
+            .with_request(HandlerRequest {
+                path_params: vec!["id".to_string()],
+                query_schema: Some(schema("ListUsersQuery")),
+                body_schema: Some(schema("CreateUserBody")),
+            });
+        let get = |path: &str| ExprIR::CtxGet {
+            path: path.to_string(),
+        };
+        let let_ = |name: &str, path: &str| StatementIR::Let {
+            name: name.to_string(),
+            type_: None,
+            value: get(path),
+        };
+
+        let handler = HandlerIR {
+            name: "updateUser".to_string(),
+            is_async: true,
+            params: vec![],
+            return_type: TypeIR::Any,
+            body: vec![
+                let_("id", "params.id"),
+                let_("page", "query.page"),
+                let_("email", "body.email"),
+                let_("data", "body"),
+            ],
+            max_tier: Tier::Domain,
+            bridge_languages: HashSet::new(),
+        };
+
+        let code = adapter.emit_handler(&handler, &emitter, &mut ctx);
+        assert!(code.contains(
+            "#[derive(Debug, serde::Deserialize)]\npub struct UpdateUserParams {\n    pub id: String,\n}"
+        ));
+        assert!(code.contains(
+            "pub async fn updateUser(Path(params): Path<UpdateUserParams>, Query(query): Query<ListUsersQuery>, Json(body): Json<CreateUserBody>) -> Result<Response, AppError> {"
+        ));
+        assert!(code.contains("let id = params.id.clone();"));
+        assert!(code.contains("let page = query.page.clone();"));
+        assert!(code.contains("let email = body.email.clone();"));
+        assert!(code.contains("let data = serde_json::json!(body);"));
+        let imports: Vec<&str> = ctx.imports().iter().map(|i| i.from.as_str()).collect();
+        assert!(imports.contains(&"crate::schemas::user::ListUsersQuery"));
+        assert!(imports.contains(&"crate::schemas::user::CreateUserBody"));
+        assert!(!imports.contains(&"std::collections::HashMap"));
+    }
+
+    #[test]
+    fn test_emit_handler_db_state_and_plain_return() {
+        let adapter = AxumAdapter;
+        let emitter = crate::emitters::rust_lang::RustEmitter;
+        let mut ctx = EmitContext::new(IndentStyle::Spaces(4)).with_adapter(Rc::new(AxumAdapter));
+
+        let handler = HandlerIR {
+            name: "listUsers".to_string(),
+            is_async: true,
+            params: vec![],
+            return_type: TypeIR::Any,
+            body: vec![
+                StatementIR::Let {
+                    name: "users".to_string(),
+                    type_: None,
+                    value: ExprIR::Await {
+                        expr: Box::new(ExprIR::DbQuery(rash_ir::expr::DbQueryIR {
+                            model: "User".to_string(),
+                            operation: "findMany".to_string(),
+                            r#where: None,
+                            order_by: vec![],
+                            skip: None,
+                            take: None,
+                            select: None,
+                            include: vec![],
+                        })),
+                    },
+                },
+                StatementIR::If {
+                    condition: ExprIR::ident("empty"),
+                    then_: vec![StatementIR::Return { value: None }],
+                    else_: None,
+                },
+                StatementIR::Return {
+                    value: Some(ExprIR::ident("users")),
+                },
+            ],
+            max_tier: Tier::Domain,
+            bridge_languages: HashSet::new(),
+        };
+
+        let code = adapter.emit_handler(&handler, &emitter, &mut ctx);
+        assert!(code.contains(
+            "pub async fn listUsers(State(db): State<DatabaseConnection>) -> Result<Response, AppError> {"
+        ));
+        assert!(code.contains("let users = User::find().all(&db).await?;"));
+        assert!(code.contains("return Ok(StatusCode::NO_CONTENT.into_response());"));
+        assert!(code.contains("return Ok((StatusCode::OK, Json(users)).into_response());"));
+    }
+
+    #[test]
+    fn test_emit_entrypoint() {
+        let adapter = AxumAdapter;
+        let project = ProjectIR {
+            config: serde_json::json!({
+                "name": "test-app",
+                "server": { "port": 9090, "basePath": "/api" },
+                "middleware": { "global": [{ "ref": "cors" }] }
+            }),
+            routes: vec![],
+            schemas: vec![],
+            models: vec![],
+            middleware: vec![],
+            handlers: vec![],
+            functions: vec![],
+        };
+
+        let mut ctx = EmitContext::new(IndentStyle::Spaces(4));
+        let code = adapter.emit_entrypoint(&project, &mut ctx);
+        assert!(code.contains("axum::serve"));
+        assert!(code.contains("9090"));
+        assert!(code.contains(".nest(\"/api\", routes::router())"));
+        assert!(code.contains(".layer(from_fn(cors))"));
+        // No middleware handler needs the connection
+        assert!(!code.contains("Extension(db.clone())"));
+    }
+
+    fn middleware(name: &str, middleware_type: &str, handler_ref: Option<&str>) -> MiddlewareIR {
+        MiddlewareIR {
+            name: name.to_string(),
+            middleware_type: middleware_type.to_string(),
+            handler_ref: handler_ref.map(String::from),
+            provides: IndexMap::new(),
+            compose: vec![],
+            short_circuit: false,
+        }
+    }
+
+    #[test]
+    fn test_middleware_runs_its_handler() {
+        let adapter = AxumAdapter;
+        let emitter = crate::emitters::rust_lang::RustEmitter;
+        let mut ctx = EmitContext::new(IndentStyle::Spaces(4));
+        let mut auth = middleware("auth", "request", Some("auth.verifyToken"));
+        auth.provides
+            .insert("user".to_string(), serde_json::json!({ "type": "object" }));
+
+        let code = adapter.emit_middleware_def(&auth, &emitter, &mut ctx);
+        assert!(code.contains("use crate::handlers::auth_verifyToken::authVerifyToken;"));
+        assert!(code.contains("let response = Handler::call(authVerifyToken, check, db).await;"));
+        assert!(code.contains("if !response.status().is_success() {\n        return response;"));
+        assert!(code.contains(
+            "req.extensions_mut().insert(crate::state::User(\n        serde_json::from_value(provided).unwrap_or_default(),"
+        ));
+        assert!(code.contains("next.run(req).await"));
+        assert!(!code.contains("TODO"));
+
+        // Several provided values are read from the fields of the result
+        auth.provides
+            .insert("tenantId".to_string(), serde_json::json!({ "type": "string" }));
+        let code = adapter.emit_middleware_def(&auth, &emitter, &mut ctx);
+        assert!(code.contains("crate::state::User(\n        serde_json::from_value(provided[\"user\"].clone())"));
+        assert!(code.contains("crate::state::TenantId(\n        serde_json::from_value(provided[\"tenantId\"].clone())"));
+
+        // The entrypoint hands the connection to middleware handlers
+        let project = ProjectIR {
+            config: serde_json::json!({ "name": "test-app" }),
+            routes: vec![],
+            schemas: vec![],
+            models: vec![],
+            middleware: vec![auth],
+            handlers: vec![],
+            functions: vec![],
+        };
+        let code = adapter.emit_entrypoint(&project, &mut ctx);
+        assert!(code.contains(".layer(axum::Extension(db.clone()))\n        .with_state(db);"));
+    }
+
+    #[test]
+    fn test_middleware_without_handler_passes_through() {
+        let adapter = AxumAdapter;
+        let emitter = crate::emitters::rust_lang::RustEmitter;
+        let mut ctx = EmitContext::new(IndentStyle::Spaces(4));
+
+        let code = adapter.emit_middleware_def(
+            &middleware("validate", "request", None),
+            &emitter,
+            &mut ctx,
+        );
+        assert!(code.contains("pub async fn validate(req: Request, next: Next) -> Response {\n    next.run(req).await\n}"));
+        assert!(!code.contains("TODO"));
+    }
+
+    #[test]
+    fn test_check_middleware_rejects_unsupported_types() {
+        let adapter = AxumAdapter;
+        assert!(adapter
+            .check_middleware(&middleware("auth", "request", Some("auth.verifyToken")))
+            .is_ok());
+        let err = adapter
+            .check_middleware(&middleware("audit", "response", Some("audit.log")))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "codegen error: Axum can't generate `response` middleware `audit`"
+        );
+        assert!(adapter
+            .check_middleware(&middleware("recover", "error", None))
+            .is_err());
+    }
+
+    #[test]
+    fn test_project_config_emits_app_error() {
+        let adapter = AxumAdapter;
+        let project = ProjectIR {
+            config: serde_json::json!({ "name": "test-app" }),
+            routes: vec![],
+            schemas: vec![],
+            models: vec![],
+            middleware: vec![],
+            handlers: vec![],
+            functions: vec![],
+        };
+
+        let files = adapter.emit_project_config(&project);
+        let (_, error_rs) = files
+            .iter()
+            .find(|(path, _)| path == "src/error.rs")
+            .expect("src/error.rs");
+        assert!(error_rs.contains("pub struct AppError"));
+        assert!(error_rs.contains("impl axum::response::IntoResponse for AppError"));
+        assert!(error_rs.contains("impl<F: FnOnce()> Drop for Finally<F>"));
+        // No database or token calls, so no conversions from their errors
+        assert!(!error_rs.contains("sea_orm"));
+        assert!(!error_rs.contains("jsonwebtoken"));
+
+        let mut ctx = EmitContext::new(IndentStyle::Spaces(4));
+        assert!(adapter.emit_entrypoint(&project, &mut ctx).contains("mod error;"));
+    }
+
+    #[test]
+    fn test_app_error_converts_orm_and_auth_errors_when_used() {
+        let verify = HandlerIR {
+            name: "verifyToken".to_string(),
+            is_async: true,
+            params: vec![],
+            return_type: TypeIR::Any,
+            body: vec![StatementIR::Return {
+                value: Some(ExprIR::VerifyToken {
+                    token: Box::new(ExprIR::ident("token")),
+                }),
+            }],
+            max_tier: Tier::Domain,
+            bridge_languages: HashSet::new(),
+        };
+        let mut project = ProjectIR {
+            config: serde_json::json!({ "name": "test-app", "database": { "orm": "seaorm" } }),
+            routes: vec![],
+            schemas: vec![],
+            models: vec![],
+            middleware: vec![],
+            handlers: vec![],
+            functions: vec![],
+        };
+
+        let (_, error_rs) = app_error_file(Framework::Axum, &project);
+        assert!(error_rs.contains("impl From<sea_orm::DbErr> for AppError"));
+        assert!(!error_rs.contains("jsonwebtoken"));
+
+        project.config = serde_json::json!({ "name": "test-app" });
+        project.handlers.push(verify);
+        let (_, error_rs) = app_error_file(Framework::Actix, &project);
+        assert!(!error_rs.contains("sea_orm"));
+        assert!(error_rs.contains("impl From<jsonwebtoken::errors::Error> for AppError"));
+    }
+
+    #[test]
+    fn test_emit_axum_respond_and_ctx_get() {
+        let emitter = crate::emitters::rust_lang::RustEmitter;
+        let mut ctx = EmitContext::new(IndentStyle::Spaces(4)).with_adapter(Rc::new(AxumAdapter));
+
+        let respond = ExprIR::HttpRespond(rash_ir::expr::HttpRespondIR {
+            status: 404,
+            headers: None,
+            body: Some(Box::new(ExprIR::ident("err"))),
+        });
+        assert_eq!(
+            emitter.emit_expression(&respond, &mut ctx),
+            "(StatusCode::NOT_FOUND, Json(err)).into_response()"
+        );
+
+        let param = ExprIR::CtxGet {
+            path: "params.id".to_string(),
+        };
+        assert_eq!(
+            emitter.emit_expression(&param, &mut ctx),
+            "params.get(\"id\").cloned().unwrap_or_default()"
+        );
+    }
+}
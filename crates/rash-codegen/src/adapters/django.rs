@@ -0,0 +1,580 @@
+use rash_spec::types::common::{Framework, Language};
+
+use super::flask::{hmu_import, import_composed_steps, python_hmu_client, python_state_get};
+use super::{
+    config_base_path, config_global_middleware, convert_colon_params_to_angle_brackets, hmu_enabled,
+    is_handler_module,
+};
+use crate::context::EmitContext;
+use crate::generator::{normalize_filename, normalize_identifier};
+use crate::traits::{CtxAccessPattern, FrameworkAdapter, LanguageEmitter};
+use rash_ir::expr::ExprIR;
+use rash_ir::types::{HandlerIR, MiddlewareIR, ProjectIR, RouteIR};
+
+/// Label of the generated Django app that owns views, URLs and models.
+pub(crate) const DJANGO_APP_LABEL: &str = "api";
+
+/// Django framework adapter for Python.
+pub struct DjangoAdapter;
+
+impl FrameworkAdapter for DjangoAdapter {
+    fn framework(&self) -> Framework {
+        Framework::Django
+    }
+
+    fn compatible_language(&self) -> Language {
+        Language::Python
+    }
+
+    fn emit_route_registration(
+        &self,
+        route: &RouteIR,
+        _emitter: &dyn LanguageEmitter,
+        ctx: &mut EmitContext,
+    ) -> String {
+        // One class-based view per path; methods are dispatched by the view itself
+        let view = view_class_name(&route.path);
+        ctx.add_import(view.clone(), format!("{}.views", DJANGO_APP_LABEL));
+        format!(
+            "path(\"{}\", {}.as_view()),",
+            route.path.trim_start_matches('/'),
+            view
+        )
+    }
+
+    fn emit_middleware_apply(&self, mw_ref: &str, _ctx: &mut EmitContext) -> String {
+        normalize_identifier(mw_ref)
+    }
+
+    fn emit_handler(
+        &self,
+        handler: &HandlerIR,
+        emitter: &dyn LanguageEmitter,
+        ctx: &mut EmitContext,
+    ) -> String {
+        let mut lines = Vec::new();
+
+        // Always async: Django requires every method of an async view to be async
+        lines.push(format!("async def {}(request, **params):", handler.name));
+
+        ctx.push_indent();
+        if handler.body.is_empty() {
+            lines.push(format!("{}pass", ctx.indent()));
+        } else {
+            for stmt in &handler.body {
+                lines.push(emitter.emit_statement(stmt, ctx));
+            }
+        }
+        ctx.pop_indent();
+
+        lines.join("\n")
+    }
+
+    fn emit_entrypoint(&self, project: &ProjectIR, _ctx: &mut EmitContext) -> String {
+        let port = project
+            .config
+            .get("server")
+            .and_then(|s| s.get("port"))
+            .and_then(|p| p.as_u64())
+            .unwrap_or(8000);
+
+        // The autoreloader would run a second copy of the HMU client
+        let noreload = if hmu_enabled(&project.config) {
+            ", \"--noreload\""
+        } else {
+            ""
+        };
+
+        format!(
+            r#"import os
+import sys
+{hmu}
+
+def main():
+    os.environ.setdefault("DJANGO_SETTINGS_MODULE", "settings")
+    from django.core.management import execute_from_command_line
+
+    args = sys.argv if len(sys.argv) > 1 else [sys.argv[0], "runserver", "0.0.0.0:{port}"{noreload}]
+    execute_from_command_line(args)
+
+
+if __name__ == "__main__":
+    main()
+"#,
+            hmu = hmu_import(project),
+            port = port,
+            noreload = noreload
+        )
+    }
+
+    fn emit_project_config(&self, project: &ProjectIR) -> Vec<(String, String)> {
+        let mut files = Vec::new();
+
+        let name = project
+            .config
+            .get("name")
+            .and_then(|n| n.as_str())
+            .unwrap_or("rash-app");
+
+        files.push(("src/settings.py".to_string(), generate_settings(project)));
+
+        let prefix = config_base_path(&project.config)
+            .map(|base| format!("{}/", base.trim_matches('/')))
+            .unwrap_or_default();
+        files.push((
+            "src/urls.py".to_string(),
+            format!(
+                "from django.urls import include, path\n\nurlpatterns = [\n    path(\"{}\", include(\"{}.urls\")),\n]\n",
+                prefix, DJANGO_APP_LABEL
+            ),
+        ));
+
+        files.push((
+            format!("src/{}/__init__.py", DJANGO_APP_LABEL),
+            String::new(),
+        ));
+        files.push((
+            format!("src/{}/apps.py", DJANGO_APP_LABEL),
+            format!(
+                "from django.apps import AppConfig\n\n\nclass ApiConfig(AppConfig):\n    name = \"{}\"\n    default_auto_field = \"django.db.models.BigAutoField\"\n",
+                DJANGO_APP_LABEL
+            ),
+        ));
+
+        // Models live in src/models/ with `app_label` set; re-export them so
+        // Django discovers them through the app's models module.
+        let model_imports: String = project
+            .models
+            .iter()
+            .map(|m| {
+                format!(
+                    "from models.{} import {}  # noqa: F401\n",
+                    m.name.to_lowercase(),
+                    m.name
+                )
+            })
+            .collect();
+        files.push((format!("src/{}/models.py", DJANGO_APP_LABEL), model_imports));
+
+        files.push((
+            format!("src/{}/views.py", DJANGO_APP_LABEL),
+            generate_views(project),
+        ));
+
+        let pyproject = format!(
+            r#"[project]
+name = "{name}"
+version = "0.1.0"
+requires-python = ">=3.11"
+dependencies = [
+    "django>=5.0",
+    "psycopg[binary]>=3.1",
+    "passlib[bcrypt]>=1.7.0",
+    "python-jose[cryptography]>=3.3.0",
+]
+
+[build-system]
+requires = ["hatchling"]
+build-backend = "hatchling.backends"
+"#,
+            name = name
+        );
+        files.push(("pyproject.toml".to_string(), pyproject));
+
+        let requirements = "\
+django>=5.0
+psycopg[binary]>=3.1
+passlib[bcrypt]>=1.7.0
+python-jose[cryptography]>=3.3.0
+";
+        files.push(("requirements.txt".to_string(), requirements.to_string()));
+
+        files
+    }
+
+    fn emit_domain_expr(
+        &self,
+        expr: &ExprIR,
+        emitter: &dyn LanguageEmitter,
+        ctx: &mut EmitContext,
+    ) -> Option<String> {
+        match expr {
+            ExprIR::HttpRespond(r) => Some(match &r.body {
+                Some(body) => {
+                    ctx.add_import("JsonResponse", "django.http");
+                    format!(
+                        "JsonResponse({}, status={}, safe=False)",
+                        emitter.emit_expression(body, ctx),
+                        r.status
+                    )
+                }
+                None => {
+                    ctx.add_import("HttpResponse", "django.http");
+                    format!("HttpResponse(status={})", r.status)
+                }
+            }),
+            ExprIR::CtxGet { path } => {
+                let parts: Vec<&str> = path.splitn(2, '.').collect();
+                Some(match parts.as_slice() {
+                    ["params", rest] => format!("params[\"{}\"]", rest),
+                    ["query", rest] => format!("request.GET.get(\"{}\")", rest),
+                    ["body"] => {
+                        ctx.add_import("loads", "json");
+                        "loads(request.body)".to_string()
+                    }
+                    ["body", rest] => {
+                        ctx.add_import("loads", "json");
+                        format!("loads(request.body)[\"{}\"]", rest)
+                    }
+                    ["headers", rest] => format!("request.headers.get(\"{}\")", rest),
+                    // Middleware sets provided values as request attributes
+                    ["state", _] => python_state_get("request", expr)?,
+                    _ => format!("request.{}", path),
+                })
+            }
+            _ => None,
+        }
+    }
+
+    fn emit_middleware_def(
+        &self,
+        mw: &MiddlewareIR,
+        _emitter: &dyn LanguageEmitter,
+        ctx: &mut EmitContext,
+    ) -> String {
+        if !mw.compose.is_empty() {
+            return emit_composed(mw, ctx);
+        }
+        // Returning a response short-circuits the view
+        format!(
+            "async def {}(request):\n    # TODO: implement {} middleware\n    return None",
+            mw.name, mw.middleware_type
+        )
+    }
+
+    fn emit_hmu_client(&self, _project: &ProjectIR) -> Option<(String, String)> {
+        Some(python_hmu_client())
+    }
+
+    fn hot_swappable(&self, path: &str) -> bool {
+        is_handler_module(path, "py")
+    }
+
+    fn ctx_access_pattern(&self) -> CtxAccessPattern {
+        CtxAccessPattern::SingleContext
+    }
+
+    fn wrap_route_file(&self, imports: &str, route_blocks: &str, _ctx: &mut EmitContext) -> String {
+        let mut lines = vec!["from django.urls import path".to_string()];
+        if !imports.is_empty() {
+            lines.push(imports.to_string());
+        }
+        lines.push(String::new());
+        lines.push("urlpatterns = [".to_string());
+        for line in route_blocks.lines() {
+            if !line.is_empty() {
+                lines.push(format!("    {}", line));
+            }
+        }
+        lines.push("]".to_string());
+        lines.join("\n")
+    }
+
+    fn route_file_path(&self, _ext: &str) -> String {
+        format!("src/{}/urls.py", DJANGO_APP_LABEL)
+    }
+
+    fn normalize_path(&self, path: &str) -> String {
+        // Django uses <param> instead of :param
+        convert_colon_params_to_angle_brackets(path)
+    }
+}
+
+/// Class-based view name for a route path (`/v1/users/:id` → `V1UsersIdView`).
+fn view_class_name(path: &str) -> String {
+    let mut name = String::new();
+    for word in path.split(|c: char| !c.is_ascii_alphanumeric()) {
+        let mut chars = word.chars();
+        if let Some(first) = chars.next() {
+            name.push(first.to_ascii_uppercase());
+            name.push_str(chars.as_str());
+        }
+    }
+    if name.is_empty() {
+        name.push_str("Root");
+    }
+    name.push_str("View");
+    name
+}
+
+/// Generate the app's `views.py`: one class-based view per route path, each
+/// method running global and route middleware before delegating to its handler.
+fn generate_views(project: &ProjectIR) -> String {
+    let global_refs = config_global_middleware(&project.config);
+
+    let mut imports: Vec<String> = Vec::new();
+    let mut push_import = |line: String| {
+        if !imports.contains(&line) {
+            imports.push(line);
+        }
+    };
+    let mut classes = Vec::new();
+    for route in &project.routes {
+        let mut methods = Vec::new();
+        for (method, endpoint) in &route.methods {
+            let handler_id = normalize_identifier(&endpoint.handler_ref);
+            push_import(format!(
+                "from handlers.{} import {}",
+                normalize_filename(&endpoint.handler_ref),
+                handler_id
+            ));
+            let mw_ids: Vec<String> = endpoint
+                .middleware
+                .iter()
+                .map(|mw| {
+                    push_import(format!(
+                        "from middleware.{} import {}",
+                        normalize_filename(mw),
+                        normalize_identifier(mw)
+                    ));
+                    normalize_identifier(mw)
+                })
+                .collect();
+            methods.push(format!(
+                "    async def {}(self, request, **params):\n        return await run(request, [{}], {}, params)",
+                format!("{:?}", method).to_lowercase(),
+                mw_ids.join(", "),
+                handler_id
+            ));
+        }
+        classes.push(format!(
+            "@method_decorator(csrf_exempt, name=\"dispatch\")\nclass {}(View):\n{}",
+            view_class_name(&route.path),
+            methods.join("\n\n")
+        ));
+    }
+    for mw_ref in &global_refs {
+        push_import(format!(
+            "from middleware.{} import {}",
+            normalize_filename(mw_ref),
+            normalize_identifier(mw_ref)
+        ));
+    }
+
+    let global_ids: Vec<String> = global_refs
+        .iter()
+        .map(|r| normalize_identifier(r))
+        .collect();
+
+    let mut out = String::from(
+        "from django.utils.decorators import method_decorator\n\
+         from django.views import View\n\
+         from django.views.decorators.csrf import csrf_exempt\n",
+    );
+    if !imports.is_empty() {
+        out.push('\n');
+        out.push_str(&imports.join("\n"));
+        out.push('\n');
+    }
+    out.push_str(&format!(
+        "\nGLOBAL_MIDDLEWARE = [{}]\n\n\n{}\n",
+        global_ids.join(", "),
+        RUN_HELPER
+    ));
+    for class in classes {
+        out.push_str("\n\n");
+        out.push_str(&class);
+        out.push('\n');
+    }
+    out
+}
+
+const RUN_HELPER: &str = r#"async def run(request, middleware, handler, params):
+    for mw in [*GLOBAL_MIDDLEWARE, *middleware]:
+        response = await mw(request)
+        if response is not None:
+            return response
+    return await handler(request, **params)"#;
+
+/// Generate `settings.py` for the configured database.
+fn generate_settings(project: &ProjectIR) -> String {
+    let engine = match project
+        .config
+        .get("database")
+        .and_then(|d| d.get("type"))
+        .and_then(|t| t.as_str())
+    {
+        Some("mysql") => "django.db.backends.mysql",
+        Some("sqlite") => "django.db.backends.sqlite3",
+        _ => "django.db.backends.postgresql",
+    };
+
+    format!(
+        r#"import os
+
+SECRET_KEY = os.environ.get("SECRET_KEY", "change-me")
+DEBUG = os.environ.get("DEBUG", "false").lower() == "true"
+ALLOWED_HOSTS = ["*"]
+
+INSTALLED_APPS = [
+    "django.contrib.contenttypes",
+    "{app}.apps.ApiConfig",
+]
+
+MIDDLEWARE = [
+    "django.middleware.common.CommonMiddleware",
+]
+
+ROOT_URLCONF = "urls"
+
+DATABASES = {{
+    "default": {{
+        "ENGINE": "{engine}",
+        "NAME": os.environ.get("DB_NAME", ""),
+        "USER": os.environ.get("DB_USER", ""),
+        "PASSWORD": os.environ.get("DB_PASSWORD", ""),
+        "HOST": os.environ.get("DB_HOST", "localhost"),
+        "PORT": os.environ.get("DB_PORT", ""),
+    }}
+}}
+
+USE_TZ = True
+"#,
+        app = DJANGO_APP_LABEL,
+        engine = engine
+    )
+}
+
+/// A composed middleware awaits each step in turn; a step that returns a
+/// response has responded.
+fn emit_composed(mw: &MiddlewareIR, ctx: &mut EmitContext) -> String {
+    let steps = import_composed_steps(mw, ctx);
+    let body = if mw.short_circuit {
+        "    for step in steps:\n        result = await step(request)\n        # Stop at the first step that responds\n        if result is not None:\n            return result\n    return None"
+    } else {
+        "    response = None\n    for step in steps:\n        result = await step(request)\n        if response is None:\n            response = result\n    return response"
+    };
+    format!(
+        "async def {}(request):\n    steps = ({},)\n{}",
+        mw.name,
+        steps.join(", "),
+        body
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context::IndentStyle;
+    use indexmap::IndexMap;
+    use rash_ir::types::{EndpointIR, RequestIR};
+    use rash_spec::types::common::HttpMethod;
+
+    fn users_route() -> RouteIR {
+        let endpoint = |operation_id: &str, handler_ref: &str, middleware: &[&str]| EndpointIR {
+            operation_id: operation_id.to_string(),
+            summary: None,
+            handler_ref: handler_ref.to_string(),
+            middleware: middleware.iter().map(|m| m.to_string()).collect(),
+            provides: IndexMap::new(),
+            request: RequestIR {
+                query_schema: None,
+                body_schema: None,
+                content_type: None,
+            },
+            response: IndexMap::new(),
+        };
+        RouteIR {
+            path: "/v1/users/:id".to_string(),
+            methods: {
+                let mut m = IndexMap::new();
+                m.insert(
+                    HttpMethod::Get,
+                    endpoint("getUser", "users.getUser", &["auth"]),
+                );
+                m.insert(
+                    HttpMethod::Delete,
+                    endpoint("deleteUser", "users.deleteUser", &[]),
+                );
+                m
+            },
+            tags: vec!["users".to_string()],
+        }
+    }
+
+    #[test]
+    fn test_emit_route_registration() {
+        let adapter = DjangoAdapter;
+        let emitter = crate::emitters::python::PythonEmitter;
+        let mut ctx = EmitContext::new(IndentStyle::Spaces(4));
+
+        let mut route = users_route();
+        route.path = adapter.normalize_path(&route.path);
+
+        let code = adapter.emit_route_registration(&route, &emitter, &mut ctx);
+        assert_eq!(code, "path(\"v1/users/<id>\", V1UsersIdView.as_view()),");
+        assert!(emitter
+            .emit_imports(&mut ctx)
+            .contains("from api.views import V1UsersIdView"));
+    }
+
+    #[test]
+    fn test_generate_views() {
+        let project = ProjectIR {
+            config: serde_json::json!({
+                "name": "test-app",
+                "middleware": { "global": [{ "ref": "cors" }] }
+            }),
+            routes: vec![users_route()],
+            schemas: vec![],
+            models: vec![],
+            middleware: vec![],
+            handlers: vec![],
+            functions: vec![],
+        };
+
+        let views = generate_views(&project);
+        assert!(views.contains("GLOBAL_MIDDLEWARE = [cors]"));
+        assert!(views.contains("class V1UsersIdView(View):"));
+        assert!(views.contains(
+            "    async def get(self, request, **params):\n        return await run(request, [auth], usersGetUser, params)"
+        ));
+        assert!(views.contains("return await run(request, [], usersDeleteUser, params)"));
+        assert!(views.contains("from handlers.users_getUser import usersGetUser"));
+        assert!(views.contains("from middleware.cors import cors"));
+    }
+
+    #[test]
+    fn test_emit_project_config() {
+        let adapter = DjangoAdapter;
+        let project = ProjectIR {
+            config: serde_json::json!({
+                "name": "test-app",
+                "server": { "basePath": "/api" },
+                "database": { "type": "postgresql", "orm": "django-orm" }
+            }),
+            routes: vec![],
+            schemas: vec![],
+            models: vec![],
+            middleware: vec![],
+            handlers: vec![],
+            functions: vec![],
+        };
+
+        let files: IndexMap<String, String> =
+            adapter.emit_project_config(&project).into_iter().collect();
+        assert!(files["src/urls.py"].contains("path(\"api/\", include(\"api.urls\"))"));
+        assert!(files["src/settings.py"].contains("\"api.apps.ApiConfig\""));
+        assert!(files["src/settings.py"].contains("django.db.backends.postgresql"));
+        assert!(files["src/api/apps.py"].contains("name = \"api\""));
+        assert!(files.contains_key("src/api/views.py"));
+        assert!(files["requirements.txt"].contains("django>=5.0"));
+    }
+
+    #[test]
+    fn test_view_class_name() {
+        assert_eq!(view_class_name("/v1/users/:id"), "V1UsersIdView");
+        assert_eq!(view_class_name("/v1/users/<id>"), "V1UsersIdView");
+        assert_eq!(view_class_name("/"), "RootView");
+    }
+}
@@ -0,0 +1,376 @@
+use rash_spec::types::common::{Framework, Language};
+
+use super::gin::{generate_go_mod, go_state_get};
+use super::{config_base_path, config_global_middleware};
+use crate::context::EmitContext;
+use crate::generator::normalize_identifier;
+use crate::traits::{CtxAccessPattern, FrameworkAdapter, LanguageEmitter};
+use rash_ir::expr::ExprIR;
+use rash_ir::statement::StatementIR;
+use rash_ir::types::{HandlerIR, MiddlewareIR, ProjectIR, RouteIR};
+
+const ECHO_IMPORT: &str = "github.com/labstack/echo/v4";
+
+/// Echo framework adapter for Go.
+pub struct EchoAdapter;
+
+impl FrameworkAdapter for EchoAdapter {
+    fn framework(&self) -> Framework {
+        Framework::Echo
+    }
+
+    fn compatible_language(&self) -> Language {
+        Language::Go
+    }
+
+    fn emit_route_registration(
+        &self,
+        route: &RouteIR,
+        _emitter: &dyn LanguageEmitter,
+        _ctx: &mut EmitContext,
+    ) -> String {
+        let mut lines = Vec::new();
+        for (method, endpoint) in &route.methods {
+            let method_upper = format!("{:?}", method).to_uppercase();
+            // Echo takes route middleware after the handler
+            let mut args = vec![normalize_identifier(&endpoint.handler_ref)];
+            args.extend(
+                endpoint
+                    .middleware
+                    .iter()
+                    .map(|mw| normalize_identifier(mw)),
+            );
+
+            lines.push(format!(
+                "router.{}(\"{}\", {})",
+                method_upper,
+                route.path,
+                args.join(", ")
+            ));
+        }
+        lines.join("\n")
+    }
+
+    fn emit_middleware_apply(&self, mw_ref: &str, _ctx: &mut EmitContext) -> String {
+        format!("e.Use({})", normalize_identifier(mw_ref))
+    }
+
+    fn emit_handler(
+        &self,
+        handler: &HandlerIR,
+        emitter: &dyn LanguageEmitter,
+        ctx: &mut EmitContext,
+    ) -> String {
+        let mut lines = Vec::new();
+        ctx.add_import(ECHO_IMPORT, ECHO_IMPORT);
+
+        lines.push(format!("func {}(c echo.Context) error {{", handler.name));
+
+        ctx.push_indent();
+        for stmt in &handler.body {
+            lines.push(emitter.emit_statement(stmt, ctx));
+        }
+        if !matches!(handler.body.last(), Some(StatementIR::Return { .. })) {
+            lines.push(format!("{}return nil", ctx.indent()));
+        }
+        ctx.pop_indent();
+
+        lines.push("}".to_string());
+        lines.join("\n")
+    }
+
+    fn emit_entrypoint(&self, project: &ProjectIR, ctx: &mut EmitContext) -> String {
+        let port = project
+            .config
+            .get("server")
+            .and_then(|s| s.get("port"))
+            .and_then(|p| p.as_u64())
+            .unwrap_or(8080);
+
+        let name = project
+            .config
+            .get("name")
+            .and_then(|n| n.as_str())
+            .unwrap_or("rash-app");
+
+        let mw_uses: String = config_global_middleware(&project.config)
+            .iter()
+            .map(|mw_ref| format!("\t{}\n", self.emit_middleware_apply(mw_ref, ctx)))
+            .collect();
+
+        let base_path = config_base_path(&project.config).unwrap_or("");
+
+        format!(
+            r#"package main
+
+import (
+	"fmt"
+	"os"
+
+	"github.com/labstack/echo/v4"
+)
+
+func main() {{
+	e := echo.New()
+	e.HideBanner = true
+{mw_uses}
+	RegisterRoutes(e.Group("{base_path}"))
+
+	port := os.Getenv("PORT")
+	if port == "" {{
+		port = "{port}"
+	}}
+	fmt.Printf("{name} running on port %s\n", port)
+	e.Logger.Fatal(e.Start(":" + port))
+}}
+"#,
+            mw_uses = mw_uses,
+            base_path = base_path,
+            port = port,
+            name = name
+        )
+    }
+
+    fn emit_project_config(&self, project: &ProjectIR) -> Vec<(String, String)> {
+        vec![(
+            "go.mod".to_string(),
+            generate_go_mod(project, "github.com/labstack/echo/v4 v4.11.4"),
+        )]
+    }
+
+    fn emit_domain_expr(
+        &self,
+        expr: &ExprIR,
+        emitter: &dyn LanguageEmitter,
+        ctx: &mut EmitContext,
+    ) -> Option<String> {
+        match expr {
+            ExprIR::HttpRespond(r) => Some(match &r.body {
+                Some(body) => format!("c.JSON({}, {})", r.status, emitter.emit_expression(body, ctx)),
+                None => format!("c.NoContent({})", r.status),
+            }),
+            ExprIR::CtxGet { path } if path.starts_with("state.") => {
+                go_state_get("c.Get", expr, ctx)
+            }
+            ExprIR::CtxGet { path } => {
+                let parts: Vec<&str> = path.splitn(2, '.').collect();
+                Some(match parts[0] {
+                    "params" if parts.len() > 1 => format!("c.Param(\"{}\")", parts[1]),
+                    "query" if parts.len() > 1 => format!("c.QueryParam(\"{}\")", parts[1]),
+                    "body" => "c.Bind(&body)".to_string(),
+                    "headers" if parts.len() > 1 => {
+                        format!("c.Request().Header.Get(\"{}\")", parts[1])
+                    }
+                    _ => format!("c.Get(\"{}\")", path),
+                })
+            }
+            _ => None,
+        }
+    }
+
+    fn handlers_return_error(&self) -> bool {
+        true
+    }
+
+    fn emit_middleware_def(
+        &self,
+        mw: &MiddlewareIR,
+        _emitter: &dyn LanguageEmitter,
+        ctx: &mut EmitContext,
+    ) -> String {
+        ctx.add_import(ECHO_IMPORT, ECHO_IMPORT);
+        if !mw.compose.is_empty() {
+            return emit_composed(mw);
+        }
+        format!(
+            "func {}(next echo.HandlerFunc) echo.HandlerFunc {{\n\treturn func(c echo.Context) error {{\n\t\t// TODO: implement {} middleware\n\t\treturn next(c)\n\t}}\n}}",
+            mw.name, mw.middleware_type
+        )
+    }
+
+    fn ctx_access_pattern(&self) -> CtxAccessPattern {
+        CtxAccessPattern::SingleContext
+    }
+
+    fn wrap_route_file(&self, imports: &str, route_blocks: &str, _ctx: &mut EmitContext) -> String {
+        let mut lines = vec![
+            "package routes".to_string(),
+            String::new(),
+            "import (".to_string(),
+            format!("\t\"{}\"", ECHO_IMPORT),
+            ")".to_string(),
+        ];
+        if !imports.is_empty() {
+            lines.push(imports.to_string());
+        }
+        lines.push(String::new());
+        lines.push("func RegisterRoutes(router *echo.Group) {".to_string());
+        for line in route_blocks.lines() {
+            if !line.is_empty() {
+                lines.push(format!("\t{}", line));
+            }
+        }
+        lines.push("}".to_string());
+        lines.join("\n")
+    }
+}
+
+/// A composed middleware runs each step with a stand-in `next` that records
+/// whether the step passed the request on; a step that doesn't has responded.
+fn emit_composed(mw: &MiddlewareIR) -> String {
+    let steps: Vec<String> = mw.compose.iter().map(|s| normalize_identifier(s)).collect();
+    let stop = if mw.short_circuit {
+        "\n\t\t\t\t// Stop at the first step that responds\n\t\t\t\tbreak"
+    } else {
+        ""
+    };
+    format!(
+        r#"func {name}(next echo.HandlerFunc) echo.HandlerFunc {{
+	steps := []echo.MiddlewareFunc{{{steps}}}
+	return func(c echo.Context) error {{
+		responded := false
+		for _, step := range steps {{
+			passed := false
+			if err := step(func(echo.Context) error {{
+				passed = true
+				return nil
+			}})(c); err != nil {{
+				return err
+			}}
+			if !passed {{
+				responded = true{stop}
+			}}
+		}}
+		if responded {{
+			return nil
+		}}
+		return next(c)
+	}}
+}}"#,
+        name = mw.name,
+        steps = steps.join(", "),
+        stop = stop
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::rc::Rc;
+    use crate::context::IndentStyle;
+    use indexmap::IndexMap;
+    use rash_ir::expr::{HttpRespondIR, TypeIR};
+    use rash_ir::types::{EndpointIR, RequestIR};
+    use rash_spec::types::common::{HttpMethod, Tier};
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_emit_route_registration() {
+        let adapter = EchoAdapter;
+        let emitter = crate::emitters::go_lang::GoEmitter;
+        let mut ctx = EmitContext::new(IndentStyle::Tabs).with_adapter(Rc::new(EchoAdapter));
+
+        let route = RouteIR {
+            path: "/users/:id".to_string(),
+            methods: {
+                let mut m = IndexMap::new();
+                m.insert(
+                    HttpMethod::Get,
+                    EndpointIR {
+                        operation_id: "getUser".to_string(),
+                        summary: None,
+                        handler_ref: "users.getUser".to_string(),
+                        middleware: vec!["auth".to_string()],
+                        provides: IndexMap::new(),
+                        request: RequestIR {
+                            query_schema: None,
+                            body_schema: None,
+                            content_type: None,
+                        },
+                        response: IndexMap::new(),
+                    },
+                );
+                m
+            },
+            tags: vec![],
+        };
+
+        let code = adapter.emit_route_registration(&route, &emitter, &mut ctx);
+        assert_eq!(code, "router.GET(\"/users/:id\", usersGetUser, auth)");
+    }
+
+    #[test]
+    fn test_emit_handler_returns_error() {
+        let adapter = EchoAdapter;
+        let emitter = crate::emitters::go_lang::GoEmitter;
+        let mut ctx = EmitContext::new(IndentStyle::Tabs).with_adapter(Rc::new(EchoAdapter));
+
+        let handler = HandlerIR {
+            name: "usersDeleteUser".to_string(),
+            is_async: true,
+            params: vec![],
+            return_type: TypeIR::Any,
+            body: vec![StatementIR::Return {
+                value: Some(ExprIR::HttpRespond(HttpRespondIR {
+                    status: 204,
+                    headers: None,
+                    body: None,
+                })),
+            }],
+            max_tier: Tier::Domain,
+            bridge_languages: HashSet::new(),
+        };
+
+        let code = adapter.emit_handler(&handler, &emitter, &mut ctx);
+        assert!(code.starts_with("func usersDeleteUser(c echo.Context) error {"));
+        assert!(code.contains("\treturn c.NoContent(204)"));
+        assert!(!code.contains("return nil"));
+    }
+
+    #[test]
+    fn test_emit_entrypoint() {
+        let adapter = EchoAdapter;
+        let project = ProjectIR {
+            config: serde_json::json!({
+                "name": "test-app",
+                "server": { "port": 3000, "basePath": "/api" },
+                "middleware": { "global": [{ "ref": "cors" }] }
+            }),
+            routes: vec![],
+            schemas: vec![],
+            models: vec![],
+            middleware: vec![],
+            handlers: vec![],
+            functions: vec![],
+        };
+
+        let mut ctx = EmitContext::new(IndentStyle::Tabs);
+        let code = adapter.emit_entrypoint(&project, &mut ctx);
+        assert!(code.contains("e := echo.New()"));
+        assert!(code.contains("\te.Use(cors)\n"));
+        assert!(code.contains("RegisterRoutes(e.Group(\"/api\"))"));
+        assert!(code.contains("e.Start(\":\" + port)"));
+        assert!(code.contains("3000"));
+        assert!(code.contains("test-app"));
+    }
+
+    #[test]
+    fn test_emit_project_config_requires_echo() {
+        let adapter = EchoAdapter;
+        let project = ProjectIR {
+            config: serde_json::json!({ "name": "test-app" }),
+            routes: vec![],
+            schemas: vec![],
+            models: vec![],
+            middleware: vec![],
+            handlers: vec![],
+            functions: vec![],
+        };
+
+        let files = adapter.emit_project_config(&project);
+        let go_mod = &files.iter().find(|(p, _)| p == "go.mod").unwrap().1;
+        assert!(go_mod.starts_with("module test-app"));
+        assert!(go_mod.contains("github.com/labstack/echo/v4 v4.11.4"));
+        assert!(!go_mod.contains("gin-gonic"));
+    }
+}
@@ -0,0 +1,693 @@
+use rash_spec::types::common::{Framework, Language};
+
+use super::express::{
+    generate_prisma_schema, PRISMA_SCHEMA_PATH, hmu_import, hot_swappable_ts_handler, import_composed_steps, tsconfig_json, typescript_hmu_client,
+};
+use super::fastify::extract_path_params;
+use super::{config_base_path, config_global_middleware, is_handler_module};
+use crate::context::EmitContext;
+use crate::emitters::typescript::{emit_json_literal, schema_ref_name};
+use crate::generator::{normalize_filename, normalize_identifier};
+use crate::traits::{CtxAccessPattern, FrameworkAdapter, LanguageEmitter};
+use rash_ir::expr::ExprIR;
+use rash_ir::types::{EndpointIR, HandlerIR, MiddlewareIR, ProjectIR, RouteIR, SchemaIR};
+
+/// Elysia framework adapter for TypeScript (Bun).
+pub struct ElysiaAdapter;
+
+impl FrameworkAdapter for ElysiaAdapter {
+    fn framework(&self) -> Framework {
+        Framework::Elysia
+    }
+
+    fn compatible_language(&self) -> Language {
+        Language::Typescript
+    }
+
+    fn emit_route_registration(
+        &self,
+        route: &RouteIR,
+        _emitter: &dyn LanguageEmitter,
+        ctx: &mut EmitContext,
+    ) -> String {
+        let path_params = extract_path_params(&route.path);
+        let mut blocks = Vec::new();
+        for (method, endpoint) in &route.methods {
+            let method_lower = format!("{:?}", method).to_lowercase();
+            let handler_id = normalize_identifier(&endpoint.handler_ref);
+            let handler_file = normalize_filename(&endpoint.handler_ref);
+
+            ctx.add_import(
+                format!("{{ {} }}", handler_id),
+                format!("../handlers/{}", handler_file),
+            );
+
+            let opts = build_route_options(endpoint, &path_params, ctx);
+            if opts.is_empty() {
+                blocks.push(format!(
+                    ".{}(\"{}\", {})",
+                    method_lower, route.path, handler_id
+                ));
+            } else {
+                blocks.push(format!(
+                    ".{}(\"{}\", {}, {{\n{}\n}})",
+                    method_lower,
+                    route.path,
+                    handler_id,
+                    opts.join("\n")
+                ));
+            }
+        }
+        blocks.join("\n")
+    }
+
+    fn emit_middleware_apply(&self, mw_ref: &str, _ctx: &mut EmitContext) -> String {
+        let mw_id = normalize_identifier(mw_ref);
+        format!(".onBeforeHandle({{ as: \"global\" }}, {})", mw_id)
+    }
+
+    fn emit_handler(
+        &self,
+        handler: &HandlerIR,
+        emitter: &dyn LanguageEmitter,
+        ctx: &mut EmitContext,
+    ) -> String {
+        ctx.add_import("{ Context }", "elysia");
+
+        let async_kw = if handler.is_async { "async " } else { "" };
+        let mut lines = Vec::new();
+
+        // Request types are checked by the route's `t` schemas; derived values
+        // are merged onto the same context
+        lines.push(format!(
+            "export {}function {}(ctx: Context<any> & Record<string, any>) {{",
+            async_kw, handler.name
+        ));
+
+        ctx.push_indent();
+        for stmt in &handler.body {
+            lines.push(emitter.emit_statement(stmt, ctx));
+        }
+        ctx.pop_indent();
+
+        lines.push("}".to_string());
+        lines.join("\n")
+    }
+
+    fn emit_entrypoint(&self, project: &ProjectIR, ctx: &mut EmitContext) -> String {
+        let port = project
+            .config
+            .get("server")
+            .and_then(|s| s.get("port"))
+            .and_then(|p| p.as_u64())
+            .unwrap_or(3000);
+
+        let name = project
+            .config
+            .get("name")
+            .and_then(|n| n.as_str())
+            .unwrap_or("rash-app");
+
+        let app_init = match config_base_path(&project.config) {
+            Some(base) => format!("new Elysia({{ prefix: \"{}\" }})", base),
+            None => "new Elysia()".to_string(),
+        };
+
+        let mut imports = Vec::new();
+        let mut chain = Vec::new();
+
+        // Provided values are derived for every route; the middleware's own
+        // `beforeHandle` decides whether a request may proceed
+        for mw in project.middleware.iter().filter(|m| !m.provides.is_empty()) {
+            let derive_id = format!("{}Derive", normalize_identifier(&mw.name));
+            imports.push(format!(
+                "import {{ {} }} from \"./middleware/{}\";",
+                derive_id,
+                normalize_filename(&mw.name)
+            ));
+            chain.push(format!(".derive({{ as: \"global\" }}, {})", derive_id));
+        }
+
+        // Global middleware is chained here rather than by the generator so the
+        // app type stays inferred end to end
+        for mw_ref in config_global_middleware(&project.config) {
+            imports.push(format!(
+                "import {{ {} }} from \"./middleware/{}\";",
+                normalize_identifier(&mw_ref),
+                normalize_filename(&mw_ref)
+            ));
+            chain.push(self.emit_middleware_apply(&mw_ref, ctx));
+        }
+        imports.sort();
+        imports.dedup();
+        chain.push(".use(routes)".to_string());
+        chain.push(".listen(port)".to_string());
+
+        let imports: String = imports.iter().map(|i| format!("{}\n", i)).collect();
+        let chain: String = chain.iter().map(|c| format!("\n  {}", c)).collect();
+
+        format!(
+            r#"{hmu}import {{ Elysia }} from "elysia";
+{imports}import {{ routes }} from "./routes";
+
+const port = Number(process.env.PORT) || {port};
+
+const app = {app_init}{chain};
+
+console.log(`{name} running on port ${{port}}`);
+
+export type App = typeof app;
+"#,
+            hmu = hmu_import(project),
+            imports = imports,
+            port = port,
+            app_init = app_init,
+            chain = chain,
+            name = name
+        )
+    }
+
+    fn emit_project_config(&self, project: &ProjectIR) -> Vec<(String, String)> {
+        let mut files = Vec::new();
+
+        let name = project
+            .config
+            .get("name")
+            .and_then(|n| n.as_str())
+            .unwrap_or("rash-app");
+
+        // package.json
+        let package_json = serde_json::json!({
+            "name": name,
+            "version": "0.1.0",
+            "private": true,
+            "scripts": {
+                "dev": "bun run --watch src/index.ts",
+                "start": "bun run src/index.ts",
+                "db:generate": "prisma generate",
+                "db:push": "prisma db push"
+            },
+            "dependencies": {
+                "elysia": "^1.3.0",
+                "@prisma/client": "^5.0.0"
+            },
+            "devDependencies": {
+                "@types/bun": "^1.0.0",
+                "typescript": "^5.3.0",
+                "prisma": "^5.0.0"
+            }
+        });
+        files.push((
+            "package.json".to_string(),
+            serde_json::to_string_pretty(&package_json).unwrap(),
+        ));
+
+        // tsconfig.json
+        files.push((
+            "tsconfig.json".to_string(),
+            serde_json::to_string_pretty(&tsconfig_json()).unwrap(),
+        ));
+
+        // src/schemas/index.ts — lets routes import `t` schemas by definition name
+        let barrel: Vec<String> = project
+            .schemas
+            .iter()
+            .map(|s| format!("export * from \"./{}\";", s.name.to_lowercase()))
+            .collect();
+        files.push(("src/schemas/index.ts".to_string(), barrel.join("\n")));
+
+        // prisma/schema.prisma
+        let prisma_schema = generate_prisma_schema(project);
+        if !prisma_schema.is_empty() {
+            files.push((PRISMA_SCHEMA_PATH.to_string(), prisma_schema));
+        }
+
+        files
+    }
+
+    fn emit_domain_expr(
+        &self,
+        expr: &ExprIR,
+        emitter: &dyn LanguageEmitter,
+        ctx: &mut EmitContext,
+    ) -> Option<String> {
+        match expr {
+            ExprIR::HttpRespond(r) => Some(match &r.body {
+                Some(b) => format!("ctx.status({}, {})", r.status, emitter.emit_expression(b, ctx)),
+                None => format!("ctx.status({})", r.status),
+            }),
+            // Elysia exposes request parts and derived values on one context
+            ExprIR::CtxGet { path } => {
+                let parts: Vec<&str> = path.splitn(2, '.').collect();
+                Some(match parts.as_slice() {
+                    ["headers", rest] => format!("ctx.headers[\"{}\"]", rest),
+                    // Derived values are merged into the context
+                    ["state", rest] => format!("ctx.{}", rest),
+                    _ => format!("ctx.{}", path),
+                })
+            }
+            _ => None,
+        }
+    }
+
+    fn emit_schema_def(
+        &self,
+        schema: &SchemaIR,
+        _emitter: &dyn LanguageEmitter,
+        ctx: &mut EmitContext,
+    ) -> String {
+        emit_typebox_schema(schema, ctx)
+    }
+
+    fn emit_middleware_def(
+        &self,
+        mw: &MiddlewareIR,
+        _emitter: &dyn LanguageEmitter,
+        ctx: &mut EmitContext,
+    ) -> String {
+        ctx.add_import("{ Context }", "elysia");
+        if !mw.compose.is_empty() {
+            return emit_composed(mw, ctx);
+        }
+        let mut code = format!(
+            "export async function {}(ctx: Context<any> & Record<string, any>) {{\n  // TODO: implement {} middleware\n}}",
+            mw.name, mw.middleware_type
+        );
+
+        if !mw.provides.is_empty() {
+            let fields: Vec<String> = mw
+                .provides
+                .keys()
+                .map(|key| format!("{}: any", key))
+                .collect();
+            let values: Vec<String> = mw
+                .provides
+                .keys()
+                .map(|key| format!("{}: undefined", key))
+                .collect();
+            code.push_str(&format!(
+                "\n\n/** Values the `{name}` middleware provides, derived before each handler. */\nexport async function {name}Derive(ctx: Context<any>): Promise<{{ {fields} }}> {{\n  // TODO: derive provided values\n  return {{ {values} }};\n}}",
+                name = mw.name,
+                fields = fields.join("; "),
+                values = values.join(", ")
+            ));
+        }
+        code
+    }
+
+    fn orm_schema_files(&self) -> Vec<String> {
+        vec![PRISMA_SCHEMA_PATH.to_string()]
+    }
+
+    fn emit_hmu_client(&self, _project: &ProjectIR) -> Option<(String, String)> {
+        Some(typescript_hmu_client())
+    }
+
+    fn hot_swappable_handler(&self, code: String, handler_name: &str) -> String {
+        hot_swappable_ts_handler(code, handler_name)
+    }
+
+    fn hot_swappable(&self, path: &str) -> bool {
+        is_handler_module(path, "ts")
+    }
+
+    fn ctx_access_pattern(&self) -> CtxAccessPattern {
+        CtxAccessPattern::SingleContext
+    }
+
+    fn wrap_route_file(&self, imports: &str, route_blocks: &str, _ctx: &mut EmitContext) -> String {
+        let mut lines = Vec::new();
+        lines.push("import { Elysia, t } from \"elysia\";".to_string());
+        if !imports.is_empty() {
+            lines.push(imports.to_string());
+        }
+        lines.push(String::new());
+        lines.push("export const routes = new Elysia()".to_string());
+        for line in route_blocks.lines() {
+            if !line.is_empty() {
+                lines.push(format!("  {}", line));
+            }
+        }
+        if let Some(last) = lines.last_mut() {
+            last.push(';');
+        }
+        lines.join("\n")
+    }
+}
+
+/// A composed middleware runs each step as a before-handle hook would; a step
+/// that returns a value has responded with it.
+fn emit_composed(mw: &MiddlewareIR, ctx: &mut EmitContext) -> String {
+    let steps = import_composed_steps(mw, ctx);
+    let body = if mw.short_circuit {
+        "  for (const step of steps) {\n    const result = await step(ctx);\n    // Stop at the first step that responds\n    if (result !== undefined) return result;\n  }"
+    } else {
+        "  let response: unknown;\n  for (const step of steps) {\n    const result = await step(ctx);\n    if (result !== undefined) response ??= result;\n  }\n  return response;"
+    };
+    format!(
+        "const steps: Array<(ctx: any) => unknown> = [{}];\n\nexport async function {}(ctx: Context<any> & Record<string, any>) {{\n{}\n}}",
+        steps.join(", "),
+        mw.name,
+        body
+    )
+}
+
+/// Build the lines of a route's local hook object: `t` schemas for each
+/// request part and response status, then middleware as `beforeHandle`.
+fn build_route_options(
+    endpoint: &EndpointIR,
+    path_params: &[String],
+    ctx: &mut EmitContext,
+) -> Vec<String> {
+    let mut opts = Vec::new();
+    let schema_import = |name: &str, ctx: &mut EmitContext| {
+        ctx.add_import(format!("{{ {} }}", name), "../schemas");
+        name.to_string()
+    };
+
+    if !path_params.is_empty() {
+        let props: Vec<String> = path_params
+            .iter()
+            .map(|p| format!("{}: t.String()", p))
+            .collect();
+        opts.push(format!("  params: t.Object({{ {} }}),", props.join(", ")));
+    }
+    if let Some(query) = &endpoint.request.query_schema {
+        opts.push(format!("  query: {},", schema_import(query, ctx)));
+    }
+    if let Some(body) = &endpoint.request.body_schema {
+        opts.push(format!("  body: {},", schema_import(body, ctx)));
+    }
+
+    let responses: Vec<String> = endpoint
+        .response
+        .iter()
+        .filter_map(|(status, resp)| {
+            resp.schema_ref
+                .as_ref()
+                .map(|r| format!("{}: {}", status, schema_import(r, ctx)))
+        })
+        .collect();
+    if !responses.is_empty() {
+        opts.push(format!("  response: {{ {} }},", responses.join(", ")));
+    }
+
+    let before_handle: Vec<String> = endpoint
+        .middleware
+        .iter()
+        .map(|mw| {
+            let mw_id = normalize_identifier(mw);
+            ctx.add_import(
+                format!("{{ {} }}", mw_id),
+                format!("../middleware/{}", normalize_filename(mw)),
+            );
+            mw_id
+        })
+        .collect();
+    if !before_handle.is_empty() {
+        opts.push(format!("  beforeHandle: [{}],", before_handle.join(", ")));
+    }
+
+    opts
+}
+
+/// Emit schema definitions as TypeBox schemas (Elysia's `t` builder).
+fn emit_typebox_schema(schema: &SchemaIR, ctx: &mut EmitContext) -> String {
+    ctx.add_import("{ t }", "elysia");
+    let mut lines = Vec::new();
+    for (name, value) in &schema.definitions {
+        lines.push(format!(
+            "export const {} = {};",
+            name,
+            json_schema_to_typebox(value)
+        ));
+        lines.push(format!("export type {} = typeof {}.static;", name, name));
+        lines.push(String::new());
+    }
+    lines.join("\n")
+}
+
+/// Convert a JSON Schema value to a TypeBox schema expression string.
+fn json_schema_to_typebox(schema: &serde_json::Value) -> String {
+    let obj = match schema.as_object() {
+        Some(o) => o,
+        None => return "t.Any()".to_string(),
+    };
+
+    // Validation keywords carry over to TypeBox options unchanged
+    let options = |keys: &[&str]| {
+        let entries: Vec<String> = keys
+            .iter()
+            .filter_map(|k| obj.get(*k).map(|v| format!("{}: {}", k, emit_json_literal(v))))
+            .collect();
+        if entries.is_empty() {
+            String::new()
+        } else {
+            format!("{{ {} }}", entries.join(", "))
+        }
+    };
+
+    match obj.get("type").and_then(|t| t.as_str()) {
+        Some("string") => format!(
+            "t.String({})",
+            options(&["format", "minLength", "maxLength", "pattern"])
+        ),
+        Some("integer") => format!("t.Integer({})", options(&["minimum", "maximum"])),
+        Some("number") => format!("t.Number({})", options(&["minimum", "maximum"])),
+        Some("boolean") => "t.Boolean()".to_string(),
+        Some("null") => "t.Null()".to_string(),
+        Some("array") => {
+            let items = obj
+                .get("items")
+                .map(json_schema_to_typebox)
+                .unwrap_or_else(|| "t.Any()".to_string());
+            format!("t.Array({})", items)
+        }
+        Some("object") => {
+            let required: Vec<&str> = obj
+                .get("required")
+                .and_then(|r| r.as_array())
+                .map(|arr| arr.iter().filter_map(|v| v.as_str()).collect())
+                .unwrap_or_default();
+
+            match obj.get("properties").and_then(|p| p.as_object()) {
+                Some(props) => {
+                    let fields: Vec<String> = props
+                        .iter()
+                        .map(|(k, v)| {
+                            let field = json_schema_to_typebox(v);
+                            if required.contains(&k.as_str()) {
+                                format!("  {}: {}", k, field)
+                            } else {
+                                format!("  {}: t.Optional({})", k, field)
+                            }
+                        })
+                        .collect();
+                    format!("t.Object({{\n{}\n}})", fields.join(",\n"))
+                }
+                None => "t.Object({})".to_string(),
+            }
+        }
+        _ => {
+            if let Some(enum_vals) = obj.get("enum").and_then(|e| e.as_array()) {
+                let literals: Vec<String> = enum_vals
+                    .iter()
+                    .map(|v| format!("t.Literal({})", emit_json_literal(v)))
+                    .collect();
+                if literals.len() == 1 {
+                    literals[0].clone()
+                } else {
+                    format!("t.Union([{}])", literals.join(", "))
+                }
+            } else if let Some(name) = schema_ref_name(schema) {
+                name.to_string()
+            } else {
+                "t.Any()".to_string()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::rc::Rc;
+    use crate::context::IndentStyle;
+    use indexmap::IndexMap;
+    use rash_ir::expr::HttpRespondIR;
+    use rash_ir::types::{RequestIR, ResponseIR};
+    use rash_spec::types::common::HttpMethod;
+
+    #[test]
+    fn test_emit_route_registration() {
+        let adapter = ElysiaAdapter;
+        let emitter = crate::emitters::typescript::TypeScriptEmitter;
+        let mut ctx = EmitContext::new(IndentStyle::Spaces(2));
+
+        let route = RouteIR {
+            path: "/users/:id".to_string(),
+            methods: {
+                let mut m = IndexMap::new();
+                m.insert(
+                    HttpMethod::Put,
+                    EndpointIR {
+                        operation_id: "updateUser".to_string(),
+                        summary: None,
+                        handler_ref: "users.updateUser".to_string(),
+                        middleware: vec!["auth".to_string(), "validate".to_string()],
+                        provides: IndexMap::new(),
+                        request: RequestIR {
+                            query_schema: None,
+                            body_schema: Some("CreateUserBody".to_string()),
+                            content_type: None,
+                        },
+                        response: {
+                            let mut r = IndexMap::new();
+                            r.insert(
+                                200,
+                                ResponseIR {
+                                    description: None,
+                                    schema_ref: Some("UserResponse".to_string()),
+                                },
+                            );
+                            r
+                        },
+                    },
+                );
+                m
+            },
+            tags: vec![],
+        };
+
+        let code = adapter.emit_route_registration(&route, &emitter, &mut ctx);
+        assert_eq!(
+            code,
+            ".put(\"/users/:id\", usersUpdateUser, {\n  params: t.Object({ id: t.String() }),\n  body: CreateUserBody,\n  response: { 200: UserResponse },\n  beforeHandle: [auth, validate],\n})"
+        );
+
+        let imports = ctx.take_imports();
+        assert!(imports
+            .iter()
+            .any(|i| i.names == "{ CreateUserBody }" && i.from == "../schemas"));
+        assert!(imports.iter().any(|i| i.from == "../middleware/validate"));
+    }
+
+    #[test]
+    fn test_emit_middleware_def_derive() {
+        let adapter = ElysiaAdapter;
+        let emitter = crate::emitters::typescript::TypeScriptEmitter;
+        let mut ctx = EmitContext::new(IndentStyle::Spaces(2));
+
+        let mut provides = IndexMap::new();
+        provides.insert("user".to_string(), serde_json::json!({ "type": "object" }));
+        let mw = MiddlewareIR {
+            name: "auth".to_string(),
+            middleware_type: "request".to_string(),
+            handler_ref: None,
+            provides,
+            compose: vec![],
+            short_circuit: false,
+        };
+
+        let code = adapter.emit_middleware_def(&mw, &emitter, &mut ctx);
+        assert!(
+            code.contains("export async function auth(ctx: Context<any> & Record<string, any>)")
+        );
+        assert!(code.contains(
+            "export async function authDerive(ctx: Context<any>): Promise<{ user: any }> {"
+        ));
+        assert!(code.contains("return { user: undefined };"));
+    }
+
+    #[test]
+    fn test_emit_entrypoint() {
+        let adapter = ElysiaAdapter;
+        let mut provides = IndexMap::new();
+        provides.insert("user".to_string(), serde_json::json!({}));
+        let project = ProjectIR {
+            config: serde_json::json!({
+                "name": "test-app",
+                "server": { "port": 8080, "basePath": "/api" },
+                "middleware": { "global": [{ "ref": "cors" }] }
+            }),
+            routes: vec![],
+            schemas: vec![],
+            models: vec![],
+            middleware: vec![MiddlewareIR {
+                name: "auth".to_string(),
+                middleware_type: "request".to_string(),
+                handler_ref: None,
+                provides,
+                compose: vec![],
+                short_circuit: false,
+            }],
+            handlers: vec![],
+            functions: vec![],
+        };
+
+        let mut ctx = EmitContext::new(IndentStyle::Spaces(2));
+        let code = adapter.emit_entrypoint(&project, &mut ctx);
+        assert!(code.contains("import { authDerive } from \"./middleware/auth\";"));
+        assert!(code.contains(
+            "const app = new Elysia({ prefix: \"/api\" })\n  .derive({ as: \"global\" }, authDerive)\n  .onBeforeHandle({ as: \"global\" }, cors)\n  .use(routes)\n  .listen(port);"
+        ));
+        assert!(code.contains("8080"));
+        assert!(code.contains("export type App = typeof app;"));
+    }
+
+    #[test]
+    fn test_wrap_route_file_terminates_chain() {
+        let adapter = ElysiaAdapter;
+        let mut ctx = EmitContext::new(IndentStyle::Spaces(2));
+        let code = adapter.wrap_route_file("", ".get(\"/health\", health)", &mut ctx);
+        assert!(code.ends_with("export const routes = new Elysia()\n  .get(\"/health\", health);"));
+
+        let empty = adapter.wrap_route_file("", "", &mut ctx);
+        assert!(empty.ends_with("export const routes = new Elysia();"));
+    }
+
+    #[test]
+    fn test_json_schema_to_typebox_basic() {
+        let schema = serde_json::json!({
+            "type": "object",
+            "properties": {
+                "email": { "type": "string", "format": "email" },
+                "age": { "type": "integer", "minimum": 0 },
+                "role": { "enum": ["admin", "user"] }
+            },
+            "required": ["email"]
+        });
+        let typebox = json_schema_to_typebox(&schema);
+        assert!(typebox.starts_with("t.Object({"));
+        assert!(typebox.contains("email: t.String({ format: \"email\" })"));
+        assert!(typebox.contains("age: t.Optional(t.Integer({ minimum: 0 }))"));
+        assert!(typebox
+            .contains("role: t.Optional(t.Union([t.Literal(\"admin\"), t.Literal(\"user\")]))"));
+    }
+
+    #[test]
+    fn test_emit_elysia_context() {
+        let emitter = crate::emitters::typescript::TypeScriptEmitter;
+        let mut ctx = EmitContext::new(IndentStyle::Spaces(2)).with_adapter(Rc::new(ElysiaAdapter));
+        let get = |path: &str| ExprIR::CtxGet {
+            path: path.to_string(),
+        };
+        assert_eq!(emitter.emit_expression(&get("params.id"), &mut ctx), "ctx.params.id");
+        assert_eq!(emitter.emit_expression(&get("user.role"), &mut ctx), "ctx.user.role");
+        assert_eq!(
+            emitter.emit_expression(&get("headers.authorization"), &mut ctx),
+            "ctx.headers[\"authorization\"]"
+        );
+        assert_eq!(
+            emitter.emit_expression(
+                &ExprIR::HttpRespond(HttpRespondIR {
+                    status: 201,
+                    headers: None,
+                    body: Some(Box::new(ExprIR::ident("user"))),
+                }),
+                &mut ctx
+            ),
+            "ctx.status(201, user)"
+        );
+    }
+}
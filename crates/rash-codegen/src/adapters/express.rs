@@ -1,5 +1,6 @@
 use rash_spec::types::common::{Framework, Language};
 
+use super::{hmu_enabled, is_handler_module};
 use crate::context::EmitContext;
 use crate::generator::{normalize_identifier, normalize_filename};
 use crate::traits::{CtxAccessPattern, FrameworkAdapter, LanguageEmitter};
@@ -112,7 +113,7 @@ impl FrameworkAdapter for ExpressAdapter {
             .unwrap_or("rash-app");
 
         format!(
-            r#"import express from "express";
+            r#"{hmu}import express from "express";
 import {{ registerRoutes }} from "./routes";
 
 const app = express();
@@ -127,6 +128,7 @@ app.listen(PORT, () => {{
 
 export default app;
 "#,
+            hmu = hmu_import(project),
             port = port,
             name = name
         )
@@ -172,34 +174,15 @@ export default app;
         ));
 
         // tsconfig.json
-        let tsconfig = serde_json::json!({
-            "compilerOptions": {
-                "target": "ES2022",
-                "module": "NodeNext",
-                "moduleResolution": "NodeNext",
-                "outDir": "./dist",
-                "rootDir": "./src",
-                "strict": true,
-                "esModuleInterop": true,
-                "skipLibCheck": true,
-                "forceConsistentCasingInFileNames": true,
-                "resolveJsonModule": true,
-                "declaration": true,
-                "declarationMap": true,
-                "sourceMap": true
-            },
-            "include": ["src/**/*"],
-            "exclude": ["node_modules", "dist"]
-        });
         files.push((
             "tsconfig.json".to_string(),
-            serde_json::to_string_pretty(&tsconfig).unwrap(),
+            serde_json::to_string_pretty(&tsconfig_json()).unwrap(),
         ));
 
         // prisma/schema.prisma
         let prisma_schema = generate_prisma_schema(project);
         if !prisma_schema.is_empty() {
-            files.push(("prisma/schema.prisma".to_string(), prisma_schema));
+            files.push((PRISMA_SCHEMA_PATH.to_string(), prisma_schema));
         }
 
         files
@@ -207,12 +190,12 @@ export default app;
 
     fn emit_domain_expr(
         &self,
-        _expr: &ExprIR,
+        expr: &ExprIR,
         _emitter: &dyn LanguageEmitter,
         _ctx: &mut EmitContext,
     ) -> Option<String> {
-        // Use default TypeScript emitter for domain expressions
-        None
+        // Everything but middleware state uses the default TypeScript lowering
+        express_state_get(expr)
     }
 
     fn emit_middleware_def(
@@ -222,12 +205,31 @@ export default app;
         ctx: &mut EmitContext,
     ) -> String {
         ctx.add_import("{ Request, Response, NextFunction }", "express");
+        if !mw.compose.is_empty() {
+            return emit_composed(mw, ctx);
+        }
         format!(
             "export function {}(req: Request, res: Response, next: NextFunction) {{\n  // TODO: implement {} middleware\n  next();\n}}",
             mw.name, mw.middleware_type
         )
     }
 
+    fn orm_schema_files(&self) -> Vec<String> {
+        vec![PRISMA_SCHEMA_PATH.to_string()]
+    }
+
+    fn emit_hmu_client(&self, _project: &ProjectIR) -> Option<(String, String)> {
+        Some(typescript_hmu_client())
+    }
+
+    fn hot_swappable_handler(&self, code: String, handler_name: &str) -> String {
+        hot_swappable_ts_handler(code, handler_name)
+    }
+
+    fn hot_swappable(&self, path: &str) -> bool {
+        is_handler_module(path, "ts")
+    }
+
     fn ctx_access_pattern(&self) -> CtxAccessPattern {
         CtxAccessPattern::ReqRes
     }
@@ -254,8 +256,276 @@ export default app;
     }
 }
 
+/// Middleware hands values to handlers through `res.locals`.
+pub(crate) fn express_state_get(expr: &ExprIR) -> Option<String> {
+    match expr {
+        ExprIR::CtxGet { path } => path
+            .strip_prefix("state.")
+            .map(|rest| format!("res.locals.{}", rest)),
+        _ => None,
+    }
+}
+
+/// A composed middleware awaits each step until it calls `next()` or the
+/// response finishes. A response can only be sent once, so the chain stops at
+/// the first step that responds, whatever `shortCircuit` says, and the route
+/// only runs if nothing responded.
+fn emit_composed(mw: &MiddlewareIR, ctx: &mut EmitContext) -> String {
+    let steps = import_composed_steps(mw, ctx);
+    format!(
+        r#"const steps = [{steps}];
+
+export async function {name}(req: Request, res: Response, next: NextFunction) {{
+  for (const step of steps) {{
+    try {{
+      await new Promise<void>((resolve, reject) => {{
+        res.once("finish", resolve);
+        step(req, res, (err?: unknown) => (err ? reject(err) : resolve()));
+      }});
+    }} catch (err) {{
+      return next(err);
+    }}
+    // Stop at the first step that responds
+    if (res.headersSent) return;
+  }}
+  next();
+}}"#,
+        name = mw.name,
+        steps = steps.join(", ")
+    )
+}
+
+/// Import the steps of a composed middleware from their sibling modules,
+/// returning their identifiers in order.
+pub(crate) fn import_composed_steps(mw: &MiddlewareIR, ctx: &mut EmitContext) -> Vec<String> {
+    mw.compose
+        .iter()
+        .map(|step| {
+            let step_id = normalize_identifier(step);
+            ctx.add_import(
+                format!("{{ {} }}", step_id),
+                format!("./{}", normalize_filename(step)),
+            );
+            step_id
+        })
+        .collect()
+}
+
+/// `src/hmu.ts` for TypeScript targets. Handler modules register through
+/// `hot()`, so a replaced handler file is re-imported and its export swapped
+/// behind a stable dispatcher; any other change is written and acknowledged
+/// with `requiresRestart`.
+pub(crate) fn typescript_hmu_client() -> (String, String) {
+    ("src/hmu.ts".to_string(), TS_HMU_CLIENT.to_string())
+}
+
+/// Entrypoint side-effect import that starts the HMU client.
+pub(crate) fn hmu_import(project: &ProjectIR) -> &'static str {
+    if hmu_enabled(&project.config) {
+        "import \"./hmu\";\n"
+    } else {
+        ""
+    }
+}
+
+/// Route a handler's export through `hot()` so the HMU client can swap it.
+pub(crate) fn hot_swappable_ts_handler(code: String, handler_name: &str) -> String {
+    let Some(async_kw) = ["async ", ""]
+        .into_iter()
+        .find(|kw| code.contains(&format!("export {}function {}(", kw, handler_name)))
+    else {
+        return code;
+    };
+    let code = code.replacen(
+        &format!("export {}function {}(", async_kw, handler_name),
+        &format!("{}function {}Impl(", async_kw, handler_name),
+        1,
+    );
+    format!(
+        "import {{ hot }} from \"../hmu\";\n{}\n\nexport const {name} = hot(\"{name}\", {name}Impl);",
+        code,
+        name = handler_name
+    )
+}
+
+const TS_HMU_CLIENT: &str = r#"import { createHash } from "node:crypto";
+import { readFile, rename, rm, writeFile } from "node:fs/promises";
+import { connect } from "node:net";
+import { resolve } from "node:path";
+import { createInterface } from "node:readline";
+import { pathToFileURL } from "node:url";
+
+// Applies HMU_UPDATE messages from Rash and answers each with an HMU_ACK on the
+// channel it came from: newline-delimited JSON on stdin/stdout, or
+// length-prefixed frames on the RASH_HMU_SOCKET socket when Rash offers one.
+
+const HEARTBEAT_MS = 2000;
+
+type HmuModule = {
+  path: string;
+  action: "replace" | "add" | "remove";
+  content: string;
+  hash: string;
+};
+
+type HmuUpdate = {
+  type: "HMU_UPDATE";
+  id: string;
+  timestamp: string;
+  modules: HmuModule[];
+};
+
+type Impl = (...args: any[]) => unknown;
+
+const live = new Map<string, Impl>();
+let staged: Map<string, Impl> | null = null;
+
+/** Register a swappable handler; the returned dispatcher always calls the live version. */
+export function hot<T extends Impl>(key: string, impl: T): T {
+  (staged ?? live).set(key, impl);
+  return ((...args: unknown[]) => live.get(key)!(...args)) as T;
+}
+
+function sha256(content: string): string {
+  return "sha256:" + createHash("sha256").update(content).digest("hex");
+}
+
+function isSwappable(mod: HmuModule): boolean {
+  return mod.action === "replace" && /^src\/handlers\/[^/]+\.ts$/.test(mod.path.replace(/\\/g, "/"));
+}
+
+function ack(update: HmuUpdate, fields: Record<string, unknown>) {
+  return { type: "HMU_ACK", id: update.id, ...fields };
+}
+
+async function apply(update: HmuUpdate) {
+  const backups = new Map<string, string | null>();
+  const applied: string[] = [];
+  try {
+    for (const mod of update.modules) {
+      if (mod.action !== "remove" && sha256(mod.content) !== mod.hash) {
+        throw new Error(`hash mismatch for ${mod.path}`);
+      }
+    }
+    for (const mod of update.modules) {
+      backups.set(mod.path, await readFile(mod.path, "utf8").catch(() => null));
+      if (mod.action === "remove") {
+        await rm(mod.path, { force: true });
+      } else {
+        await writeFile(`${mod.path}.hmu`, mod.content);
+        await rename(`${mod.path}.hmu`, mod.path);
+      }
+      applied.push(mod.path);
+    }
+    // Re-import swapped handlers under a fresh URL; their hot() calls land in
+    // `staged` and only go live once every module loaded
+    staged = new Map();
+    for (const mod of update.modules.filter(isSwappable)) {
+      await import(`${pathToFileURL(resolve(mod.path)).href}?hmu=${update.id}`);
+    }
+    for (const [key, impl] of staged) live.set(key, impl);
+    return ack(update, {
+      status: "success",
+      applied,
+      failed: [],
+      requiresRestart: !update.modules.every(isSwappable),
+    });
+  } catch (err) {
+    console.error(`[hmu] ${update.id}: ${err}`);
+    for (const [path, content] of backups) {
+      if (content === null) await rm(path, { force: true });
+      else await writeFile(path, content);
+    }
+    return ack(update, {
+      status: "failed",
+      applied: [],
+      failed: update.modules.map((mod) => mod.path),
+      rolledBack: true,
+      requiresRestart: false,
+    });
+  } finally {
+    staged = null;
+  }
+}
+
+// Updates are applied one at a time, in arrival order, whichever channel
+// they came on
+let queue = Promise.resolve();
+
+function receive(raw: string, reply: (ack: object) => void) {
+  let update: HmuUpdate;
+  try {
+    update = JSON.parse(raw);
+  } catch {
+    return;
+  }
+  if (update?.type !== "HMU_UPDATE") return;
+  queue = queue.then(async () => reply(await apply(update)));
+}
+
+createInterface({ input: process.stdin }).on("line", (line) =>
+  receive(line, (ack) => process.stdout.write(JSON.stringify(ack) + "\n")),
+);
+
+// A Unix socket path, or tcp://host:port. Frames are a 4-byte big-endian
+// length followed by JSON. The first frame presents RASH_HMU_TOKEN so Rash
+// knows it is this server; heartbeats tell Rash the channel is alive.
+const endpoint = process.env.RASH_HMU_SOCKET;
+if (endpoint) {
+  const tcp = endpoint.match(/^tcp:\/\/(.+):(\d+)$/);
+  const socket = tcp ? connect(Number(tcp[2]), tcp[1]) : connect(endpoint);
+  const send = (message: object) => {
+    const body = Buffer.from(JSON.stringify(message));
+    const header = Buffer.alloc(4);
+    header.writeUInt32BE(body.length);
+    socket.write(Buffer.concat([header, body]));
+  };
+  let pending = Buffer.alloc(0);
+  socket.on("data", (chunk: Buffer) => {
+    pending = Buffer.concat([pending, chunk]);
+    while (pending.length >= 4 && pending.length >= 4 + pending.readUInt32BE(0)) {
+      const length = pending.readUInt32BE(0);
+      receive(pending.subarray(4, 4 + length).toString("utf8"), send);
+      pending = pending.subarray(4 + length);
+    }
+  });
+  socket.on("connect", () => {
+    send({ type: "HMU_HELLO", token: process.env.RASH_HMU_TOKEN ?? "" });
+    send({ type: "HMU_HEARTBEAT" });
+    setInterval(() => send({ type: "HMU_HEARTBEAT" }), HEARTBEAT_MS).unref();
+  });
+  socket.on("error", (err) => console.error(`[hmu] socket: ${err.message}`));
+}
+"#;
+
+/// Shared `tsconfig.json` for TypeScript targets.
+pub(crate) fn tsconfig_json() -> serde_json::Value {
+    serde_json::json!({
+        "compilerOptions": {
+            "target": "ES2022",
+            "module": "NodeNext",
+            "moduleResolution": "NodeNext",
+            "outDir": "./dist",
+            "rootDir": "./src",
+            "strict": true,
+            "esModuleInterop": true,
+            "skipLibCheck": true,
+            "forceConsistentCasingInFileNames": true,
+            "resolveJsonModule": true,
+            "declaration": true,
+            "declarationMap": true,
+            "sourceMap": true
+        },
+        "include": ["src/**/*"],
+        "exclude": ["node_modules", "dist"]
+    })
+}
+
+/// Path of the generated Prisma schema, shared by the TypeScript adapters.
+pub(crate) const PRISMA_SCHEMA_PATH: &str = "prisma/schema.prisma";
+
 /// Generate Prisma schema from project models.
-fn generate_prisma_schema(project: &ProjectIR) -> String {
+pub(crate) fn generate_prisma_schema(project: &ProjectIR) -> String {
     if project.models.is_empty() {
         return String::new();
     }
@@ -429,6 +699,7 @@ fn emit_prisma_default(value: &serde_json::Value) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::rc::Rc;
     use crate::context::IndentStyle;
     use indexmap::IndexMap;
     use rash_ir::types::{EndpointIR, RequestIR};
@@ -451,6 +722,7 @@ mod tests {
                         summary: None,
                         handler_ref: "listUsers".to_string(),
                         middleware: vec!["auth".to_string()],
+                        provides: IndexMap::new(),
                         request: RequestIR {
                             query_schema: None,
                             body_schema: None,
@@ -481,6 +753,7 @@ mod tests {
             models: vec![],
             middleware: vec![],
             handlers: vec![],
+            functions: vec![],
         };
 
         let mut ctx = EmitContext::new(IndentStyle::Spaces(2));
@@ -518,6 +791,7 @@ mod tests {
             }],
             middleware: vec![],
             handlers: vec![],
+            functions: vec![],
         };
 
         let schema = generate_prisma_schema(&project);
@@ -526,4 +800,28 @@ mod tests {
         assert!(schema.contains("email String @unique"));
         assert!(schema.contains("provider = \"postgresql\""));
     }
+
+    #[test]
+    fn test_state_reads_res_locals() {
+        let emitter = crate::emitters::typescript::TypeScriptEmitter;
+        let mut ctx = EmitContext::new(IndentStyle::Spaces(2)).with_adapter(Rc::new(ExpressAdapter));
+        let get = |path: &str| ExprIR::CtxGet {
+            path: path.to_string(),
+        };
+        assert_eq!(emitter.emit_expression(&get("state.user"), &mut ctx), "res.locals.user");
+        assert_eq!(emitter.emit_expression(&get("params.id"), &mut ctx), "req.params.id");
+    }
+
+    #[test]
+    fn test_hot_swappable_handler_modules_only() {
+        let adapter = ExpressAdapter;
+        assert!(adapter.hot_swappable("src/handlers/users_getUser.ts"));
+        assert!(!adapter.hot_swappable("src/handlers/nested/users_getUser.ts"));
+        assert!(!adapter.hot_swappable("src/routes/users.ts"));
+        assert!(!adapter.hot_swappable("src/middleware/auth.ts"));
+        assert!(!adapter.hot_swappable("prisma/schema.prisma"));
+        assert!(!adapter.hot_swappable("package.json"));
+        // The client only swaps handlers it also checks in TypeScript
+        assert!(TS_HMU_CLIENT.contains(r"/^src\/handlers\/[^/]+\.ts$/"));
+    }
 }
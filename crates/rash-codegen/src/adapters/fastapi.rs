@@ -5,7 +5,8 @@ use crate::traits::{CtxAccessPattern, FrameworkAdapter, LanguageEmitter};
 use rash_ir::expr::ExprIR;
 use rash_ir::types::{HandlerIR, MiddlewareIR, ProjectIR, RouteIR};
 
-use super::convert_colon_params_to_braces;
+use super::{convert_colon_params_to_braces, hmu_enabled, is_handler_module};
+use super::flask::{hmu_import, import_composed_steps, python_hmu_client, python_state_get};
 
 /// FastAPI framework adapter for Python.
 pub struct FastAPIAdapter;
@@ -99,19 +100,29 @@ impl FrameworkAdapter for FastAPIAdapter {
             .and_then(|n| n.as_str())
             .unwrap_or("rash-app");
 
+        // The reloader would restart the server under the HMU client and does
+        // not hand stdin to its worker
+        let reload = if hmu_enabled(&project.config) {
+            "False"
+        } else {
+            "True"
+        };
+
         format!(
             r#"import uvicorn
 from fastapi import FastAPI
-from routes import router
+{hmu}from routes import router
 
 app = FastAPI(title="{name}")
 app.include_router(router)
 
 if __name__ == "__main__":
-    uvicorn.run("main:app", host="0.0.0.0", port={port}, reload=True)
+    uvicorn.run("main:app", host="0.0.0.0", port={port}, reload={reload})
 "#,
+            hmu = hmu_import(project),
             name = name,
-            port = port
+            port = port,
+            reload = reload
         )
     }
 
@@ -161,25 +172,37 @@ python-jose[cryptography]>=3.3.0
 
     fn emit_domain_expr(
         &self,
-        _expr: &ExprIR,
+        expr: &ExprIR,
         _emitter: &dyn LanguageEmitter,
         _ctx: &mut EmitContext,
     ) -> Option<String> {
-        None
+        // Middleware sets provided values on Starlette's `request.state`
+        python_state_get("request.state", expr)
     }
 
     fn emit_middleware_def(
         &self,
         mw: &MiddlewareIR,
         _emitter: &dyn LanguageEmitter,
-        _ctx: &mut EmitContext,
+        ctx: &mut EmitContext,
     ) -> String {
+        if !mw.compose.is_empty() {
+            return emit_composed(mw, ctx);
+        }
         format!(
             "from fastapi import Request\n\nasync def {}(request: Request):\n    # TODO: implement {} middleware\n    pass",
             mw.name, mw.middleware_type
         )
     }
 
+    fn emit_hmu_client(&self, _project: &ProjectIR) -> Option<(String, String)> {
+        Some(python_hmu_client())
+    }
+
+    fn hot_swappable(&self, path: &str) -> bool {
+        is_handler_module(path, "py")
+    }
+
     fn ctx_access_pattern(&self) -> CtxAccessPattern {
         CtxAccessPattern::ParamInjection
     }
@@ -205,6 +228,25 @@ python-jose[cryptography]>=3.3.0
     }
 }
 
+/// A composed middleware awaits each step in turn; a step that raises an
+/// `HTTPException` has responded.
+fn emit_composed(mw: &MiddlewareIR, ctx: &mut EmitContext) -> String {
+    let steps = import_composed_steps(mw, ctx);
+    let body = if mw.short_circuit {
+        ctx.add_import("Request", "fastapi");
+        "    # The first step that raises stops the chain\n    for step in steps:\n        await step(request)"
+    } else {
+        ctx.add_import("HTTPException, Request", "fastapi");
+        "    failure = None\n    for step in steps:\n        try:\n            await step(request)\n        except HTTPException as exc:\n            failure = failure or exc\n    if failure is not None:\n        raise failure"
+    };
+    format!(
+        "async def {}(request: Request):\n    steps = ({},)\n{}",
+        mw.name,
+        steps.join(", "),
+        body
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -230,6 +272,7 @@ mod tests {
                         summary: None,
                         handler_ref: "list_users".to_string(),
                         middleware: vec![],
+                        provides: IndexMap::new(),
                         request: RequestIR {
                             query_schema: None,
                             body_schema: None,
@@ -261,6 +304,7 @@ mod tests {
             models: vec![],
             middleware: vec![],
             handlers: vec![],
+            functions: vec![],
         };
 
         let mut ctx = EmitContext::new(IndentStyle::Spaces(4));
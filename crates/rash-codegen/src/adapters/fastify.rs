@@ -0,0 +1,588 @@
+use rash_spec::types::common::{Framework, Language};
+
+use crate::context::EmitContext;
+use crate::generator::{normalize_filename, normalize_identifier};
+use crate::traits::{CtxAccessPattern, FrameworkAdapter, LanguageEmitter};
+use rash_ir::expr::ExprIR;
+use rash_ir::types::{EndpointIR, HandlerIR, MiddlewareIR, ProjectIR, RouteIR};
+
+use super::express::{
+    generate_prisma_schema, PRISMA_SCHEMA_PATH, hmu_import, hot_swappable_ts_handler, import_composed_steps, tsconfig_json, typescript_hmu_client,
+};
+use super::is_handler_module;
+
+/// Fastify framework adapter for TypeScript.
+pub struct FastifyAdapter;
+
+impl FrameworkAdapter for FastifyAdapter {
+    fn framework(&self) -> Framework {
+        Framework::Fastify
+    }
+
+    fn compatible_language(&self) -> Language {
+        Language::Typescript
+    }
+
+    fn emit_route_registration(
+        &self,
+        route: &RouteIR,
+        _emitter: &dyn LanguageEmitter,
+        ctx: &mut EmitContext,
+    ) -> String {
+        let path_params = extract_path_params(&route.path);
+        let mut blocks = Vec::new();
+        for (method, endpoint) in &route.methods {
+            let method_lower = format!("{:?}", method).to_lowercase();
+            let handler_id = normalize_identifier(&endpoint.handler_ref);
+            let handler_file = normalize_filename(&endpoint.handler_ref);
+
+            ctx.add_import(
+                format!("{{ {} }}", handler_id),
+                format!("../handlers/{}", handler_file),
+            );
+
+            let mw_chain: Vec<String> = endpoint
+                .middleware
+                .iter()
+                .map(|mw| {
+                    let mw_id = normalize_identifier(mw);
+                    let mw_file = normalize_filename(mw);
+                    ctx.add_import(
+                        format!("{{ {} }}", mw_id),
+                        format!("../middleware/{}", mw_file),
+                    );
+                    mw_id
+                })
+                .collect();
+
+            let mut opts = Vec::new();
+            let schema = build_route_schema(endpoint, &path_params);
+            if !schema.is_empty() {
+                opts.push(format!("  schema: {{\n{}\n  }},", schema.join("\n")));
+            }
+            if !mw_chain.is_empty() {
+                opts.push(format!("  preHandler: [{}],", mw_chain.join(", ")));
+            }
+
+            if opts.is_empty() {
+                blocks.push(format!(
+                    "app.{}(\"{}\", {});",
+                    method_lower, route.path, handler_id
+                ));
+            } else {
+                blocks.push(format!(
+                    "app.{}(\"{}\", {{\n{}\n}}, {});",
+                    method_lower,
+                    route.path,
+                    opts.join("\n"),
+                    handler_id
+                ));
+            }
+        }
+        blocks.join("\n\n")
+    }
+
+    fn emit_middleware_apply(&self, mw_ref: &str, _ctx: &mut EmitContext) -> String {
+        let mw_id = normalize_identifier(mw_ref);
+        format!("app.addHook(\"onRequest\", {});", mw_id)
+    }
+
+    fn emit_handler(
+        &self,
+        handler: &HandlerIR,
+        emitter: &dyn LanguageEmitter,
+        ctx: &mut EmitContext,
+    ) -> String {
+        ctx.add_import("{ FastifyRequest, FastifyReply }", "fastify");
+
+        let async_kw = if handler.is_async { "async " } else { "" };
+        let mut lines = Vec::new();
+
+        lines.push(format!(
+            "export {}function {}(request: FastifyRequest<RouteGeneric>, reply: FastifyReply) {{",
+            async_kw, handler.name
+        ));
+
+        ctx.push_indent();
+        for stmt in &handler.body {
+            lines.push(emitter.emit_statement(stmt, ctx));
+        }
+        ctx.pop_indent();
+
+        lines.push("}".to_string());
+
+        // Params/querystring are validated by the route schema, so handlers
+        // can treat them as plain string maps.
+        format!(
+            "type RouteGeneric = {{\n  Params: Record<string, string>;\n  Querystring: Record<string, string>;\n  Body: any;\n}};\n\n{}",
+            lines.join("\n")
+        )
+    }
+
+    fn emit_entrypoint(&self, project: &ProjectIR, _ctx: &mut EmitContext) -> String {
+        let port = project
+            .config
+            .get("server")
+            .and_then(|s| s.get("port"))
+            .and_then(|p| p.as_u64())
+            .unwrap_or(3000);
+
+        let name = project
+            .config
+            .get("name")
+            .and_then(|n| n.as_str())
+            .unwrap_or("rash-app");
+
+        let register = match project
+            .config
+            .get("server")
+            .and_then(|s| s.get("basePath"))
+            .and_then(|b| b.as_str())
+            .filter(|b| !b.is_empty() && *b != "/")
+        {
+            Some(base) => format!("app.register(registerRoutes, {{ prefix: \"{}\" }});", base),
+            None => "app.register(registerRoutes);".to_string(),
+        };
+
+        format!(
+            r#"{hmu}import Fastify from "fastify";
+import {{ registerSchemas }} from "./plugins/schemas";
+import {{ registerRoutes }} from "./routes";
+
+const app = Fastify({{ logger: true }});
+
+registerSchemas(app);
+
+{register}
+
+const PORT = Number(process.env.PORT) || {port};
+app
+  .listen({{ port: PORT, host: "0.0.0.0" }})
+  .then(() => {{
+    app.log.info(`{name} running on port ${{PORT}}`);
+  }})
+  .catch((err) => {{
+    app.log.error(err);
+    process.exit(1);
+  }});
+
+export default app;
+"#,
+            hmu = hmu_import(project),
+            register = register,
+            port = port,
+            name = name
+        )
+    }
+
+    fn emit_project_config(&self, project: &ProjectIR) -> Vec<(String, String)> {
+        let mut files = Vec::new();
+
+        let name = project
+            .config
+            .get("name")
+            .and_then(|n| n.as_str())
+            .unwrap_or("rash-app");
+
+        // package.json
+        let package_json = serde_json::json!({
+            "name": name,
+            "version": "0.1.0",
+            "private": true,
+            "scripts": {
+                "dev": "tsx watch src/index.ts",
+                "build": "tsc",
+                "start": "node dist/index.js",
+                "db:generate": "prisma generate",
+                "db:push": "prisma db push"
+            },
+            "dependencies": {
+                "fastify": "^5.0.0",
+                "zod": "^3.22.0",
+                "@prisma/client": "^5.0.0"
+            },
+            "devDependencies": {
+                "typescript": "^5.3.0",
+                "@types/node": "^20.0.0",
+                "tsx": "^4.0.0",
+                "prisma": "^5.0.0"
+            }
+        });
+        files.push((
+            "package.json".to_string(),
+            serde_json::to_string_pretty(&package_json).unwrap(),
+        ));
+
+        // tsconfig.json
+        files.push((
+            "tsconfig.json".to_string(),
+            serde_json::to_string_pretty(&tsconfig_json()).unwrap(),
+        ));
+
+        // src/plugins/schemas.ts — shared JSON schemas referenced by route options
+        files.push((
+            "src/plugins/schemas.ts".to_string(),
+            generate_shared_schemas(project),
+        ));
+
+        // prisma/schema.prisma
+        let prisma_schema = generate_prisma_schema(project);
+        if !prisma_schema.is_empty() {
+            files.push((PRISMA_SCHEMA_PATH.to_string(), prisma_schema));
+        }
+
+        files
+    }
+
+    fn emit_domain_expr(
+        &self,
+        expr: &ExprIR,
+        emitter: &dyn LanguageEmitter,
+        ctx: &mut EmitContext,
+    ) -> Option<String> {
+        match expr {
+            ExprIR::HttpRespond(r) => {
+                let body = r
+                    .body
+                    .as_ref()
+                    .map(|b| emitter.emit_expression(b, ctx))
+                    .unwrap_or_default();
+                Some(format!("reply.code({}).send({})", r.status, body))
+            }
+            ExprIR::CtxGet { path } => {
+                let parts: Vec<&str> = path.splitn(2, '.').collect();
+                Some(match parts.as_slice() {
+                    ["params", rest] => format!("request.params.{}", rest),
+                    ["query", rest] => format!("request.query.{}", rest),
+                    ["body"] => "request.body".to_string(),
+                    ["body", rest] => format!("request.body.{}", rest),
+                    ["headers", rest] => format!("request.headers[\"{}\"]", rest),
+                    // Middleware-provided values are request decorations
+                    ["state", rest] => format!("request.{}", rest),
+                    _ => format!("request.{}", path),
+                })
+            }
+            _ => None,
+        }
+    }
+
+    fn emit_middleware_def(
+        &self,
+        mw: &MiddlewareIR,
+        _emitter: &dyn LanguageEmitter,
+        ctx: &mut EmitContext,
+    ) -> String {
+        ctx.add_import("{ FastifyRequest, FastifyReply }", "fastify");
+        if !mw.compose.is_empty() {
+            return emit_composed(mw, ctx);
+        }
+        format!(
+            "export async function {}(request: FastifyRequest, reply: FastifyReply) {{\n  // TODO: implement {} middleware\n}}",
+            mw.name, mw.middleware_type
+        )
+    }
+
+    fn orm_schema_files(&self) -> Vec<String> {
+        vec![PRISMA_SCHEMA_PATH.to_string()]
+    }
+
+    fn emit_hmu_client(&self, _project: &ProjectIR) -> Option<(String, String)> {
+        Some(typescript_hmu_client())
+    }
+
+    fn hot_swappable_handler(&self, code: String, handler_name: &str) -> String {
+        hot_swappable_ts_handler(code, handler_name)
+    }
+
+    fn hot_swappable(&self, path: &str) -> bool {
+        is_handler_module(path, "ts")
+    }
+
+    fn ctx_access_pattern(&self) -> CtxAccessPattern {
+        CtxAccessPattern::ReqRes
+    }
+
+    fn wrap_route_file(&self, imports: &str, route_blocks: &str, _ctx: &mut EmitContext) -> String {
+        let mut lines = Vec::new();
+        lines.push("import { FastifyInstance } from \"fastify\";".to_string());
+        if !imports.is_empty() {
+            lines.push(imports.to_string());
+        }
+        lines.push(String::new());
+        lines.push("export async function registerRoutes(app: FastifyInstance) {".to_string());
+        for line in route_blocks.lines() {
+            if line.is_empty() {
+                lines.push(String::new());
+            } else {
+                lines.push(format!("  {}", line));
+            }
+        }
+        lines.push("}".to_string());
+        lines.join("\n")
+    }
+}
+
+/// A composed middleware awaits each step in turn; a step has responded once
+/// the reply is sent.
+fn emit_composed(mw: &MiddlewareIR, ctx: &mut EmitContext) -> String {
+    let steps = import_composed_steps(mw, ctx);
+    let body = if mw.short_circuit {
+        "  for (const step of steps) {\n    await step(request, reply);\n    // Stop at the first step that responds\n    if (reply.sent) return reply;\n  }"
+    } else {
+        "  for (const step of steps) {\n    await step(request, reply);\n  }\n  if (reply.sent) return reply;"
+    };
+    format!(
+        "const steps = [{}];\n\nexport async function {}(request: FastifyRequest, reply: FastifyReply) {{\n{}\n}}",
+        steps.join(", "),
+        mw.name,
+        body
+    )
+}
+
+/// Build the lines of a route's `schema` option from its request/response IR.
+fn build_route_schema(endpoint: &EndpointIR, path_params: &[String]) -> Vec<String> {
+    let mut entries = Vec::new();
+
+    if let Some(body) = &endpoint.request.body_schema {
+        entries.push(format!("    body: {{ $ref: \"{}#\" }},", schema_id(body)));
+    }
+    if let Some(query) = &endpoint.request.query_schema {
+        entries.push(format!(
+            "    querystring: {{ $ref: \"{}#\" }},",
+            schema_id(query)
+        ));
+    }
+    if !path_params.is_empty() {
+        let props: Vec<String> = path_params
+            .iter()
+            .map(|p| format!("{}: {{ type: \"string\" }}", p))
+            .collect();
+        let required: Vec<String> = path_params.iter().map(|p| format!("\"{}\"", p)).collect();
+        entries.push(format!(
+            "    params: {{ type: \"object\", properties: {{ {} }}, required: [{}] }},",
+            props.join(", "),
+            required.join(", ")
+        ));
+    }
+
+    let responses: Vec<String> = endpoint
+        .response
+        .iter()
+        .filter_map(|(status, resp)| {
+            resp.schema_ref
+                .as_ref()
+                .map(|r| format!("      {}: {{ $ref: \"{}#\" }},", status, schema_id(r)))
+        })
+        .collect();
+    if !responses.is_empty() {
+        entries.push(format!(
+            "    response: {{\n{}\n    }},",
+            responses.join("\n")
+        ));
+    }
+
+    entries
+}
+
+/// Extract `:param` names from a route path.
+pub(crate) fn extract_path_params(path: &str) -> Vec<String> {
+    path.split('/')
+        .filter_map(|seg| seg.strip_prefix(':'))
+        .filter(|p| !p.is_empty())
+        .map(|p| p.to_string())
+        .collect()
+}
+
+/// Resolve a schema reference to the `$id` it is registered under.
+/// e.g., `"CreateUserBody"` or `"user.schema#CreateUserBody"` → `"CreateUserBody"`
+fn schema_id(reference: &str) -> &str {
+    let name = reference.rsplit('#').next().unwrap_or(reference);
+    name.rsplit('/').next().unwrap_or(name)
+}
+
+/// Generate the `registerSchemas` plugin that adds every schema definition
+/// to the Fastify instance, so routes can reference them by `$id`.
+fn generate_shared_schemas(project: &ProjectIR) -> String {
+    let mut lines = Vec::new();
+    lines.push("import { FastifyInstance } from \"fastify\";".to_string());
+    lines.push(String::new());
+    lines.push("export function registerSchemas(app: FastifyInstance) {".to_string());
+    for schema in &project.schemas {
+        for (name, def) in &schema.definitions {
+            let mut shared = rewrite_local_refs(def);
+            if let serde_json::Value::Object(map) = &mut shared {
+                map.insert("$id".to_string(), serde_json::json!(name));
+            }
+            let json = serde_json::to_string_pretty(&shared).unwrap();
+            let indented: Vec<String> = json.lines().map(|l| format!("  {}", l)).collect();
+            lines.push(format!(
+                "  app.addSchema({});",
+                indented.join("\n").trim_start()
+            ));
+        }
+    }
+    lines.push("}".to_string());
+    lines.join("\n")
+}
+
+/// Rewrite `$ref`s between definitions to point at the shared `$id`s.
+fn rewrite_local_refs(value: &serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::Object(map) => serde_json::Value::Object(
+            map.iter()
+                .map(|(k, v)| match (k.as_str(), v.as_str()) {
+                    ("$ref", Some(r)) => {
+                        (k.clone(), serde_json::json!(format!("{}#", schema_id(r))))
+                    }
+                    _ => (k.clone(), rewrite_local_refs(v)),
+                })
+                .collect(),
+        ),
+        serde_json::Value::Array(arr) => {
+            serde_json::Value::Array(arr.iter().map(rewrite_local_refs).collect())
+        }
+        other => other.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::rc::Rc;
+    use crate::context::IndentStyle;
+    use rash_ir::expr::HttpRespondIR;
+    use indexmap::IndexMap;
+    use rash_ir::types::{RequestIR, ResponseIR, SchemaIR};
+    use rash_spec::types::common::HttpMethod;
+
+    #[test]
+    fn test_emit_route_registration() {
+        let adapter = FastifyAdapter;
+        let emitter = crate::emitters::typescript::TypeScriptEmitter;
+        let mut ctx = EmitContext::new(IndentStyle::Spaces(2));
+
+        let route = RouteIR {
+            path: "/users/:id".to_string(),
+            methods: {
+                let mut m = IndexMap::new();
+                m.insert(
+                    HttpMethod::Put,
+                    EndpointIR {
+                        operation_id: "updateUser".to_string(),
+                        summary: None,
+                        handler_ref: "users.updateUser".to_string(),
+                        middleware: vec!["auth".to_string(), "validate".to_string()],
+                        provides: IndexMap::new(),
+                        request: RequestIR {
+                            query_schema: None,
+                            body_schema: Some("CreateUserBody".to_string()),
+                            content_type: None,
+                        },
+                        response: {
+                            let mut r = IndexMap::new();
+                            r.insert(
+                                200,
+                                ResponseIR {
+                                    description: None,
+                                    schema_ref: Some("UserResponse".to_string()),
+                                },
+                            );
+                            r
+                        },
+                    },
+                );
+                m
+            },
+            tags: vec![],
+        };
+
+        let code = adapter.emit_route_registration(&route, &emitter, &mut ctx);
+        assert!(code.contains("app.put(\"/users/:id\", {"));
+        assert!(code.contains("body: { $ref: \"CreateUserBody#\" }"));
+        assert!(code.contains(
+            "params: { type: \"object\", properties: { id: { type: \"string\" } }, required: [\"id\"] }"
+        ));
+        assert!(code.contains("200: { $ref: \"UserResponse#\" }"));
+        assert!(code.contains("preHandler: [auth, validate],"));
+        assert!(code.ends_with("}, usersUpdateUser);"));
+    }
+
+    #[test]
+    fn test_emit_entrypoint() {
+        let adapter = FastifyAdapter;
+        let project = ProjectIR {
+            config: serde_json::json!({
+                "name": "test-app",
+                "server": { "port": 4000, "basePath": "/api" }
+            }),
+            routes: vec![],
+            schemas: vec![],
+            models: vec![],
+            middleware: vec![],
+            handlers: vec![],
+            functions: vec![],
+        };
+
+        let mut ctx = EmitContext::new(IndentStyle::Spaces(2));
+        let code = adapter.emit_entrypoint(&project, &mut ctx);
+        assert!(code.contains("import Fastify from \"fastify\""));
+        assert!(code.contains("4000"));
+        assert!(code.contains("app.register(registerRoutes, { prefix: \"/api\" });"));
+    }
+
+    #[test]
+    fn test_shared_schemas_rewrite_refs() {
+        let project = ProjectIR {
+            config: serde_json::json!({}),
+            routes: vec![],
+            schemas: vec![SchemaIR {
+                name: "User".to_string(),
+                definitions: {
+                    let mut d = IndexMap::new();
+                    d.insert(
+                        "UserListResponse".to_string(),
+                        serde_json::json!({
+                            "type": "array",
+                            "items": { "$ref": "#/definitions/UserResponse" }
+                        }),
+                    );
+                    d
+                },
+            }],
+            models: vec![],
+            middleware: vec![],
+            handlers: vec![],
+            functions: vec![],
+        };
+
+        let code = generate_shared_schemas(&project);
+        assert!(code.contains("\"$id\": \"UserListResponse\""));
+        assert!(code.contains("\"$ref\": \"UserResponse#\""));
+    }
+
+    #[test]
+    fn test_emit_fastify_reply_and_request() {
+        let emitter = crate::emitters::typescript::TypeScriptEmitter;
+        let mut ctx = EmitContext::new(IndentStyle::Spaces(2)).with_adapter(Rc::new(FastifyAdapter));
+        assert_eq!(
+            emitter.emit_expression(
+                &ExprIR::CtxGet {
+                    path: "params.id".to_string()
+                },
+                &mut ctx
+            ),
+            "request.params.id"
+        );
+        assert_eq!(
+            emitter.emit_expression(
+                &ExprIR::HttpRespond(HttpRespondIR {
+                    status: 201,
+                    headers: None,
+                    body: Some(Box::new(ExprIR::ident("user"))),
+                }),
+                &mut ctx
+            ),
+            "reply.code(201).send(user)"
+        );
+    }
+}
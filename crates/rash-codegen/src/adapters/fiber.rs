@@ -0,0 +1,376 @@
+use rash_spec::types::common::{Framework, Language};
+
+use super::gin::{generate_go_mod, go_state_get};
+use super::{config_base_path, config_global_middleware, expand_composed_middleware};
+use crate::context::EmitContext;
+use crate::generator::normalize_identifier;
+use crate::traits::{CtxAccessPattern, FrameworkAdapter, LanguageEmitter};
+use rash_ir::expr::ExprIR;
+use rash_ir::statement::StatementIR;
+use rash_ir::types::{HandlerIR, MiddlewareIR, ProjectIR, RouteIR};
+
+const FIBER_IMPORT: &str = "github.com/gofiber/fiber/v2";
+
+/// Guard run after each expanded composed step. Fiber buffers the response,
+/// so a step that responded is seen by its status or body.
+const STOP_IF_RESPONDED: &str = r#"import (
+	"github.com/gofiber/fiber/v2"
+)
+
+// stopIfResponded ends the chain once a composed step has responded, so later
+// steps and the route handler don't overwrite the response.
+func stopIfResponded(c *fiber.Ctx) error {
+	if c.Response().StatusCode() != fiber.StatusOK || len(c.Response().Body()) > 0 {
+		return nil
+	}
+	return c.Next()
+}"#;
+
+/// Fiber framework adapter for Go.
+pub struct FiberAdapter;
+
+impl FrameworkAdapter for FiberAdapter {
+    fn framework(&self) -> Framework {
+        Framework::Fiber
+    }
+
+    fn compatible_language(&self) -> Language {
+        Language::Go
+    }
+
+    fn emit_route_registration(
+        &self,
+        route: &RouteIR,
+        _emitter: &dyn LanguageEmitter,
+        _ctx: &mut EmitContext,
+    ) -> String {
+        let mut lines = Vec::new();
+        for (method, endpoint) in &route.methods {
+            let method_name = fiber_method(&format!("{:?}", method));
+            let mut args: Vec<String> = endpoint
+                .middleware
+                .iter()
+                .map(|mw| normalize_identifier(mw))
+                .collect();
+            args.push(normalize_identifier(&endpoint.handler_ref));
+
+            lines.push(format!(
+                "router.{}(\"{}\", {})",
+                method_name,
+                route.path,
+                args.join(", ")
+            ));
+        }
+        lines.join("\n")
+    }
+
+    fn emit_middleware_apply(&self, mw_ref: &str, _ctx: &mut EmitContext) -> String {
+        format!("app.Use({})", normalize_identifier(mw_ref))
+    }
+
+    fn emit_handler(
+        &self,
+        handler: &HandlerIR,
+        emitter: &dyn LanguageEmitter,
+        ctx: &mut EmitContext,
+    ) -> String {
+        let mut lines = Vec::new();
+        ctx.add_import(FIBER_IMPORT, FIBER_IMPORT);
+
+        lines.push(format!("func {}(c *fiber.Ctx) error {{", handler.name));
+
+        ctx.push_indent();
+        for stmt in &handler.body {
+            lines.push(emitter.emit_statement(stmt, ctx));
+        }
+        if !matches!(handler.body.last(), Some(StatementIR::Return { .. })) {
+            lines.push(format!("{}return nil", ctx.indent()));
+        }
+        ctx.pop_indent();
+
+        lines.push("}".to_string());
+        lines.join("\n")
+    }
+
+    fn emit_entrypoint(&self, project: &ProjectIR, ctx: &mut EmitContext) -> String {
+        let port = project
+            .config
+            .get("server")
+            .and_then(|s| s.get("port"))
+            .and_then(|p| p.as_u64())
+            .unwrap_or(8080);
+
+        let name = project
+            .config
+            .get("name")
+            .and_then(|n| n.as_str())
+            .unwrap_or("rash-app");
+
+        let global = config_global_middleware(&project.config);
+        let guard = self.composed_step_guard();
+        let mw_uses: String = expand_composed_middleware(&global, &project.middleware, guard)
+            .iter()
+            .map(|mw_ref| format!("\t{}\n", self.emit_middleware_apply(mw_ref, ctx)))
+            .collect();
+
+        let router = match config_base_path(&project.config) {
+            Some(base) => format!("app.Group(\"{}\")", base),
+            None => "app".to_string(),
+        };
+
+        format!(
+            r#"package main
+
+import (
+	"fmt"
+	"log"
+	"os"
+
+	"github.com/gofiber/fiber/v2"
+)
+
+func main() {{
+	app := fiber.New()
+{mw_uses}
+	RegisterRoutes({router})
+
+	port := os.Getenv("PORT")
+	if port == "" {{
+		port = "{port}"
+	}}
+	fmt.Printf("{name} running on port %s\n", port)
+	log.Fatal(app.Listen(":" + port))
+}}
+"#,
+            mw_uses = mw_uses,
+            router = router,
+            port = port,
+            name = name
+        )
+    }
+
+    fn emit_project_config(&self, project: &ProjectIR) -> Vec<(String, String)> {
+        let mut files = vec![(
+            "go.mod".to_string(),
+            generate_go_mod(project, "github.com/gofiber/fiber/v2 v2.52.0"),
+        )];
+        if project.middleware.iter().any(|mw| !mw.compose.is_empty()) {
+            files.push((
+                "src/middleware/stop_if_responded.go".to_string(),
+                STOP_IF_RESPONDED.to_string(),
+            ));
+        }
+        files
+    }
+
+    fn emit_domain_expr(
+        &self,
+        expr: &ExprIR,
+        emitter: &dyn LanguageEmitter,
+        ctx: &mut EmitContext,
+    ) -> Option<String> {
+        match expr {
+            ExprIR::HttpRespond(r) => Some(match &r.body {
+                Some(body) => format!(
+                    "c.Status({}).JSON({})",
+                    r.status,
+                    emitter.emit_expression(body, ctx)
+                ),
+                None => format!("c.SendStatus({})", r.status),
+            }),
+            ExprIR::CtxGet { path } if path.starts_with("state.") => {
+                go_state_get("c.Locals", expr, ctx)
+            }
+            ExprIR::CtxGet { path } => {
+                let parts: Vec<&str> = path.splitn(2, '.').collect();
+                Some(match parts[0] {
+                    "params" if parts.len() > 1 => format!("c.Params(\"{}\")", parts[1]),
+                    "query" if parts.len() > 1 => format!("c.Query(\"{}\")", parts[1]),
+                    "body" => "c.BodyParser(&body)".to_string(),
+                    "headers" if parts.len() > 1 => format!("c.Get(\"{}\")", parts[1]),
+                    _ => format!("c.Locals(\"{}\")", path),
+                })
+            }
+            _ => None,
+        }
+    }
+
+    fn handlers_return_error(&self) -> bool {
+        true
+    }
+
+    fn emit_middleware_def(
+        &self,
+        mw: &MiddlewareIR,
+        _emitter: &dyn LanguageEmitter,
+        ctx: &mut EmitContext,
+    ) -> String {
+        ctx.add_import(FIBER_IMPORT, FIBER_IMPORT);
+        if !mw.compose.is_empty() {
+            // Fiber steps hand on with c.Next(), so routes register the steps
+            // themselves, each followed by stopIfResponded
+            let steps: Vec<String> = mw.compose.iter().map(|s| normalize_identifier(s)).collect();
+            return format!(
+                "// {name} runs {steps} in order. Routes register these steps in place of {name}.\nvar {name} = []fiber.Handler{{{steps}}}",
+                name = mw.name,
+                steps = steps.join(", ")
+            );
+        }
+        format!(
+            "func {}(c *fiber.Ctx) error {{\n\t// TODO: implement {} middleware\n\treturn c.Next()\n}}",
+            mw.name, mw.middleware_type
+        )
+    }
+
+    fn expands_composed_middleware(&self) -> bool {
+        true
+    }
+
+    fn composed_step_guard(&self) -> Option<&'static str> {
+        Some("stopIfResponded")
+    }
+
+    fn ctx_access_pattern(&self) -> CtxAccessPattern {
+        CtxAccessPattern::SingleContext
+    }
+
+    fn wrap_route_file(&self, imports: &str, route_blocks: &str, _ctx: &mut EmitContext) -> String {
+        let mut lines = vec![
+            "package routes".to_string(),
+            String::new(),
+            "import (".to_string(),
+            format!("\t\"{}\"", FIBER_IMPORT),
+            ")".to_string(),
+        ];
+        if !imports.is_empty() {
+            lines.push(imports.to_string());
+        }
+        lines.push(String::new());
+        lines.push("func RegisterRoutes(router fiber.Router) {".to_string());
+        for line in route_blocks.lines() {
+            if !line.is_empty() {
+                lines.push(format!("\t{}", line));
+            }
+        }
+        lines.push("}".to_string());
+        lines.join("\n")
+    }
+}
+
+/// Fiber route methods are title-cased (`Get`, `Post`, ...) rather than upper-cased.
+fn fiber_method(method: &str) -> String {
+    let lower = method.to_lowercase();
+    let mut chars = lower.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::rc::Rc;
+    use crate::context::IndentStyle;
+    use indexmap::IndexMap;
+    use rash_ir::expr::{HttpRespondIR, TypeIR};
+    use rash_ir::types::{EndpointIR, RequestIR};
+    use rash_spec::types::common::{HttpMethod, Tier};
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_emit_route_registration() {
+        let adapter = FiberAdapter;
+        let emitter = crate::emitters::go_lang::GoEmitter;
+        let mut ctx = EmitContext::new(IndentStyle::Tabs).with_adapter(Rc::new(FiberAdapter));
+
+        let route = RouteIR {
+            path: "/users/:id".to_string(),
+            methods: {
+                let mut m = IndexMap::new();
+                m.insert(
+                    HttpMethod::Delete,
+                    EndpointIR {
+                        operation_id: "deleteUser".to_string(),
+                        summary: None,
+                        handler_ref: "users.deleteUser".to_string(),
+                        middleware: vec!["auth".to_string()],
+                        provides: IndexMap::new(),
+                        request: RequestIR {
+                            query_schema: None,
+                            body_schema: None,
+                            content_type: None,
+                        },
+                        response: IndexMap::new(),
+                    },
+                );
+                m
+            },
+            tags: vec![],
+        };
+
+        let code = adapter.emit_route_registration(&route, &emitter, &mut ctx);
+        assert_eq!(code, "router.Delete(\"/users/:id\", auth, usersDeleteUser)");
+    }
+
+    #[test]
+    fn test_emit_handler_appends_return_nil() {
+        let adapter = FiberAdapter;
+        let emitter = crate::emitters::go_lang::GoEmitter;
+        let mut ctx = EmitContext::new(IndentStyle::Tabs).with_adapter(Rc::new(FiberAdapter));
+
+        let handler = HandlerIR {
+            name: "usersGetUser".to_string(),
+            is_async: true,
+            params: vec![],
+            return_type: TypeIR::Any,
+            body: vec![StatementIR::Expression {
+                expr: ExprIR::HttpRespond(HttpRespondIR {
+                    status: 200,
+                    headers: None,
+                    body: Some(Box::new(ExprIR::ident("user"))),
+                }),
+            }],
+            max_tier: Tier::Domain,
+            bridge_languages: HashSet::new(),
+        };
+
+        let code = adapter.emit_handler(&handler, &emitter, &mut ctx);
+        assert!(code.starts_with("func usersGetUser(c *fiber.Ctx) error {"));
+        assert!(code.contains("\tc.Status(200).JSON(user)\n\treturn nil\n}"));
+    }
+
+    #[test]
+    fn test_emit_entrypoint() {
+        let adapter = FiberAdapter;
+        let project = ProjectIR {
+            config: serde_json::json!({
+                "name": "test-app",
+                "server": { "port": 3000, "basePath": "/api" },
+                "middleware": { "global": [{ "ref": "cors" }] }
+            }),
+            routes: vec![],
+            schemas: vec![],
+            models: vec![],
+            middleware: vec![],
+            handlers: vec![],
+            functions: vec![],
+        };
+
+        let mut ctx = EmitContext::new(IndentStyle::Tabs);
+        let code = adapter.emit_entrypoint(&project, &mut ctx);
+        assert!(code.contains("app := fiber.New()"));
+        assert!(code.contains("\tapp.Use(cors)\n"));
+        assert!(code.contains("RegisterRoutes(app.Group(\"/api\"))"));
+        assert!(code.contains("app.Listen(\":\" + port)"));
+        assert!(code.contains("3000"));
+        assert!(code.contains("test-app"));
+    }
+
+    #[test]
+    fn test_fiber_method_casing() {
+        assert_eq!(fiber_method("Get"), "Get");
+        assert_eq!(fiber_method("DELETE"), "Delete");
+        assert_eq!(fiber_method("Patch"), "Patch");
+    }
+}
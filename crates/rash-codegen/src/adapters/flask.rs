@@ -0,0 +1,735 @@
+use rash_spec::types::common::{Framework, Language};
+
+use super::{
+    config_base_path, config_global_middleware, convert_colon_params_to_angle_brackets, hmu_enabled,
+    is_handler_module,
+};
+use crate::context::EmitContext;
+use crate::generator::{normalize_filename, normalize_identifier};
+use crate::traits::{CtxAccessPattern, FrameworkAdapter, LanguageEmitter};
+use rash_ir::expr::ExprIR;
+use rash_ir::types::{HandlerIR, MiddlewareIR, ProjectIR, RouteIR};
+
+/// Flask framework adapter for Python.
+pub struct FlaskAdapter;
+
+impl FrameworkAdapter for FlaskAdapter {
+    fn framework(&self) -> Framework {
+        Framework::Flask
+    }
+
+    fn compatible_language(&self) -> Language {
+        Language::Python
+    }
+
+    fn emit_route_registration(
+        &self,
+        route: &RouteIR,
+        _emitter: &dyn LanguageEmitter,
+        ctx: &mut EmitContext,
+    ) -> String {
+        let blueprint = blueprint_name(route.tags.first().map(String::as_str));
+        let mut lines = Vec::new();
+        for (method, endpoint) in &route.methods {
+            let method_upper = format!("{:?}", method).to_uppercase();
+            let handler_id = normalize_identifier(&endpoint.handler_ref);
+            ctx.add_import(
+                handler_id.clone(),
+                format!("handlers.{}", normalize_filename(&endpoint.handler_ref)),
+            );
+
+            let mw_ids: Vec<String> = endpoint
+                .middleware
+                .iter()
+                .map(|mw| {
+                    let mw_id = normalize_identifier(mw);
+                    ctx.add_import(
+                        mw_id.clone(),
+                        format!("middleware.{}", normalize_filename(mw)),
+                    );
+                    mw_id
+                })
+                .collect();
+
+            let view = if mw_ids.is_empty() {
+                handler_id
+            } else {
+                format!("use({})({})", mw_ids.join(", "), handler_id)
+            };
+
+            lines.push(format!(
+                "{}_bp.add_url_rule(\"{}\", \"{}\", {}, methods=[\"{}\"])",
+                blueprint, route.path, endpoint.operation_id, view, method_upper
+            ));
+        }
+        lines.join("\n")
+    }
+
+    fn emit_middleware_apply(&self, mw_ref: &str, _ctx: &mut EmitContext) -> String {
+        format!("app.before_request({})", normalize_identifier(mw_ref))
+    }
+
+    fn emit_handler(
+        &self,
+        handler: &HandlerIR,
+        emitter: &dyn LanguageEmitter,
+        ctx: &mut EmitContext,
+    ) -> String {
+        let async_kw = if handler.is_async { "async " } else { "" };
+        let mut lines = Vec::new();
+
+        // Flask passes URL parameters as keyword arguments
+        lines.push(format!("{}def {}(**params):", async_kw, handler.name));
+
+        ctx.push_indent();
+        if handler.body.is_empty() {
+            lines.push(format!("{}pass", ctx.indent()));
+        } else {
+            for stmt in &handler.body {
+                lines.push(emitter.emit_statement(stmt, ctx));
+            }
+        }
+        ctx.pop_indent();
+
+        lines.join("\n")
+    }
+
+    fn emit_entrypoint(&self, project: &ProjectIR, ctx: &mut EmitContext) -> String {
+        let port = project
+            .config
+            .get("server")
+            .and_then(|s| s.get("port"))
+            .and_then(|p| p.as_u64())
+            .unwrap_or(5000);
+
+        let name = project
+            .config
+            .get("name")
+            .and_then(|n| n.as_str())
+            .unwrap_or("rash-app");
+
+        let global_refs = config_global_middleware(&project.config);
+        let mw_imports: String = global_refs
+            .iter()
+            .map(|mw_ref| {
+                format!(
+                    "from middleware.{} import {}\n",
+                    normalize_filename(mw_ref),
+                    normalize_identifier(mw_ref)
+                )
+            })
+            .collect();
+        let mw_hooks: String = global_refs
+            .iter()
+            .map(|mw_ref| format!("{}\n", self.emit_middleware_apply(mw_ref, ctx)))
+            .collect();
+
+        let prefix = match config_base_path(&project.config) {
+            Some(base) => format!("\"{}\"", base),
+            None => "None".to_string(),
+        };
+
+        format!(
+            r#"import os
+
+from flask import Flask
+
+{hmu}{mw_imports}from routes.index import register_routes
+
+app = Flask("{name}")
+{mw_hooks}
+register_routes(app, url_prefix={prefix})
+
+if __name__ == "__main__":
+    app.run(host="0.0.0.0", port=int(os.environ.get("PORT", {port})))
+"#,
+            hmu = hmu_import(project),
+            mw_imports = mw_imports,
+            name = name,
+            mw_hooks = mw_hooks,
+            prefix = prefix,
+            port = port
+        )
+    }
+
+    fn emit_project_config(&self, project: &ProjectIR) -> Vec<(String, String)> {
+        let mut files = Vec::new();
+
+        let name = project
+            .config
+            .get("name")
+            .and_then(|n| n.as_str())
+            .unwrap_or("rash-app");
+
+        let pyproject = format!(
+            r#"[project]
+name = "{name}"
+version = "0.1.0"
+requires-python = ">=3.11"
+dependencies = [
+    "flask[async]>=3.0.0",
+    "sqlalchemy>=2.0.0",
+    "passlib[bcrypt]>=1.7.0",
+    "python-jose[cryptography]>=3.3.0",
+]
+
+[build-system]
+requires = ["hatchling"]
+build-backend = "hatchling.backends"
+"#,
+            name = name
+        );
+        files.push(("pyproject.toml".to_string(), pyproject));
+
+        let requirements = "\
+flask[async]>=3.0.0
+sqlalchemy>=2.0.0
+passlib[bcrypt]>=1.7.0
+python-jose[cryptography]>=3.3.0
+";
+        files.push(("requirements.txt".to_string(), requirements.to_string()));
+
+        files
+    }
+
+    fn emit_domain_expr(
+        &self,
+        expr: &ExprIR,
+        emitter: &dyn LanguageEmitter,
+        ctx: &mut EmitContext,
+    ) -> Option<String> {
+        match expr {
+            ExprIR::HttpRespond(r) => Some(match &r.body {
+                Some(body) => {
+                    ctx.add_import("jsonify", "flask");
+                    format!("jsonify({}), {}", emitter.emit_expression(body, ctx), r.status)
+                }
+                None => format!("\"\", {}", r.status),
+            }),
+            ExprIR::CtxGet { path } => {
+                ctx.add_import("request", "flask");
+                let parts: Vec<&str> = path.splitn(2, '.').collect();
+                Some(match parts.as_slice() {
+                    ["params", rest] => format!("request.view_args[\"{}\"]", rest),
+                    ["query", rest] => format!("request.args.get(\"{}\")", rest),
+                    ["body"] => "request.get_json()".to_string(),
+                    ["body", rest] => format!("request.get_json()[\"{}\"]", rest),
+                    ["headers", rest] => format!("request.headers.get(\"{}\")", rest),
+                    // Middleware stores provided values on `g`
+                    ["state", _] => {
+                        ctx.add_import("g", "flask");
+                        python_state_get("g", expr)?
+                    }
+                    _ => {
+                        ctx.add_import("g", "flask");
+                        format!("g.get(\"{}\")", path)
+                    }
+                })
+            }
+            _ => None,
+        }
+    }
+
+    fn emit_middleware_def(
+        &self,
+        mw: &MiddlewareIR,
+        _emitter: &dyn LanguageEmitter,
+        ctx: &mut EmitContext,
+    ) -> String {
+        // before_request hooks take no arguments; returning a response short-circuits
+        if !mw.compose.is_empty() {
+            return emit_composed(mw, ctx);
+        }
+        ctx.add_import("request", "flask");
+        format!(
+            "def {}():\n    # TODO: implement {} middleware\n    return None",
+            mw.name, mw.middleware_type
+        )
+    }
+
+    fn emit_hmu_client(&self, _project: &ProjectIR) -> Option<(String, String)> {
+        Some(python_hmu_client())
+    }
+
+    fn hot_swappable(&self, path: &str) -> bool {
+        is_handler_module(path, "py")
+    }
+
+    fn ctx_access_pattern(&self) -> CtxAccessPattern {
+        CtxAccessPattern::SingleContext
+    }
+
+    fn wrap_route_file(&self, imports: &str, route_blocks: &str, _ctx: &mut EmitContext) -> String {
+        let mut blueprints: Vec<&str> = Vec::new();
+        for line in route_blocks.lines() {
+            if let Some((bp, _)) = line.split_once("_bp.add_url_rule(") {
+                if !blueprints.contains(&bp) {
+                    blueprints.push(bp);
+                }
+            }
+        }
+
+        let mut lines = vec![
+            "from functools import wraps".to_string(),
+            String::new(),
+            "from flask import Blueprint, current_app".to_string(),
+        ];
+        if !imports.is_empty() {
+            lines.push(imports.to_string());
+        }
+        lines.push(String::new());
+        for bp in &blueprints {
+            lines.push(format!("{}_bp = Blueprint(\"{}\", __name__)", bp, bp));
+        }
+        lines.push(String::new());
+        lines.push(String::new());
+        lines.push(USE_HELPER.to_string());
+        lines.push(String::new());
+        lines.push(String::new());
+        if !route_blocks.is_empty() {
+            lines.push(route_blocks.to_string());
+            lines.push(String::new());
+            lines.push(String::new());
+        }
+        lines.push("def register_routes(app, url_prefix=None):".to_string());
+        if blueprints.is_empty() {
+            lines.push("    pass".to_string());
+        }
+        for bp in &blueprints {
+            lines.push(format!(
+                "    app.register_blueprint({}_bp, url_prefix=url_prefix)",
+                bp
+            ));
+        }
+        lines.join("\n")
+    }
+
+    fn normalize_path(&self, path: &str) -> String {
+        // Flask uses <param> instead of :param
+        convert_colon_params_to_angle_brackets(path)
+    }
+}
+
+/// Route-level middleware runs like a `before_request` hook scoped to one view.
+const USE_HELPER: &str = r#"def use(*middleware):
+    def decorate(view):
+        @wraps(view)
+        def wrapped(*args, **kwargs):
+            for mw in middleware:
+                response = current_app.ensure_sync(mw)()
+                if response is not None:
+                    return response
+            return current_app.ensure_sync(view)(*args, **kwargs)
+
+        return wrapped
+
+    return decorate"#;
+
+/// Blueprint name for a route tag (`"user-admin"` → `user_admin`), defaulting to `api`.
+fn blueprint_name(tag: Option<&str>) -> String {
+    let name: String = tag
+        .unwrap_or("api")
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if name.is_empty() {
+        "api".to_string()
+    } else {
+        name
+    }
+}
+
+/// A composed middleware calls each step in turn; a step that returns a
+/// response has responded.
+fn emit_composed(mw: &MiddlewareIR, ctx: &mut EmitContext) -> String {
+    let steps = import_composed_steps(mw, ctx);
+    let body = if mw.short_circuit {
+        "    for step in steps:\n        result = step()\n        # Stop at the first step that responds\n        if result is not None:\n            return result\n    return None"
+    } else {
+        "    response = None\n    for step in steps:\n        result = step()\n        if response is None:\n            response = result\n    return response"
+    };
+    format!(
+        "def {}():\n    steps = ({},)\n{}",
+        mw.name,
+        steps.join(", "),
+        body
+    )
+}
+
+/// Import each step of a composed middleware, returning their identifiers.
+/// Read a middleware-provided value, `state.<key>[.<field>...]`, from the
+/// attribute `holder` the middleware sets it on. Provided objects are dicts.
+pub(crate) fn python_state_get(holder: &str, expr: &ExprIR) -> Option<String> {
+    let ExprIR::CtxGet { path } = expr else {
+        return None;
+    };
+    let mut fields = path.strip_prefix("state.")?.split('.');
+    let key = fields.next()?;
+    let index: String = fields.map(|f| format!("[\"{}\"]", f)).collect();
+    Some(format!("{}.{}{}", holder, key, index))
+}
+
+pub(crate) fn import_composed_steps(mw: &MiddlewareIR, ctx: &mut EmitContext) -> Vec<String> {
+    mw.compose
+        .iter()
+        .map(|step| {
+            let step_id = normalize_identifier(step);
+            ctx.add_import(
+                step_id.clone(),
+                format!("middleware.{}", normalize_filename(step)),
+            );
+            step_id
+        })
+        .collect()
+}
+
+/// `src/hmu.py` for Python targets. Replaced handler modules are reloaded and
+/// their new code moved into the existing function objects, so views the
+/// routes already hold run it; any other change is written and acknowledged
+/// with `requiresRestart`.
+pub(crate) fn python_hmu_client() -> (String, String) {
+    ("src/hmu.py".to_string(), PY_HMU_CLIENT.to_string())
+}
+
+/// Entrypoint import that starts the HMU client thread.
+pub(crate) fn hmu_import(project: &ProjectIR) -> &'static str {
+    if hmu_enabled(&project.config) {
+        "import hmu  # noqa: F401\n"
+    } else {
+        ""
+    }
+}
+
+const PY_HMU_CLIENT: &str = r#""""Applies HMU_UPDATE messages from Rash and answers each with an HMU_ACK on the
+channel it came from: newline-delimited JSON on stdin/stdout, or length-prefixed
+frames on the RASH_HMU_SOCKET socket when Rash offers one."""
+
+import hashlib
+import importlib
+import importlib.util
+import json
+import os
+import socket
+import struct
+import sys
+import threading
+import time
+import types
+
+SRC_DIR = os.path.dirname(os.path.abspath(__file__))
+HEARTBEAT_SECONDS = 2.0
+
+# Updates are applied one at a time, whichever channel they came on
+_apply_lock = threading.Lock()
+
+
+def _sha256(content):
+    return "sha256:" + hashlib.sha256(content.encode("utf-8")).hexdigest()
+
+
+def _module_name(path):
+    rel = os.path.relpath(os.path.abspath(path), SRC_DIR)
+    if rel.startswith("..") or not rel.endswith(".py"):
+        return None
+    return rel[:-3].replace(os.sep, ".")
+
+
+def _is_swappable(mod):
+    name = _module_name(mod["path"])
+    return (
+        mod["action"] == "replace"
+        and name is not None
+        and name.startswith("handlers.")
+        and name.count(".") == 1
+    )
+
+
+def _functions(module):
+    return {
+        name: value
+        for name, value in vars(module).items()
+        if isinstance(value, types.FunctionType) and value.__module__ == module.__name__
+    }
+
+
+def _swap(path):
+    """Reload a loaded module and move the new code into its old function
+    objects, which the routes already reference."""
+    module = sys.modules.get(_module_name(path))
+    if module is None:
+        return
+    cached = importlib.util.cache_from_source(os.path.abspath(path))
+    if os.path.exists(cached):
+        os.remove(cached)
+    old = _functions(module)
+    importlib.reload(module)
+    for name, new in _functions(module).items():
+        previous = old.get(name)
+        if previous is None:
+            continue
+        previous.__code__ = new.__code__
+        previous.__defaults__ = new.__defaults__
+        previous.__kwdefaults__ = new.__kwdefaults__
+        setattr(module, name, previous)
+
+
+def _read(path):
+    try:
+        with open(path, encoding="utf-8") as f:
+            return f.read()
+    except FileNotFoundError:
+        return None
+
+
+def _write(path, content):
+    with open(path + ".hmu", "w", encoding="utf-8") as f:
+        f.write(content)
+    os.replace(path + ".hmu", path)
+
+
+def _apply(update):
+    modules = update.get("modules", [])
+    backups = {}
+    applied = []
+    try:
+        for mod in modules:
+            if mod["action"] == "remove":
+                continue
+            if _sha256(mod["content"]) != mod["hash"]:
+                raise ValueError(f"hash mismatch for {mod['path']}")
+            if mod["path"].endswith(".py"):
+                compile(mod["content"], mod["path"], "exec")
+        for mod in modules:
+            path = mod["path"]
+            backups[path] = _read(path)
+            if mod["action"] == "remove":
+                if os.path.exists(path):
+                    os.remove(path)
+            else:
+                _write(path, mod["content"])
+            applied.append(path)
+        importlib.invalidate_caches()
+        for mod in filter(_is_swappable, modules):
+            _swap(mod["path"])
+    except Exception as err:
+        print(f"[hmu] {update.get('id')}: {err}", file=sys.stderr, flush=True)
+        for path, content in backups.items():
+            if content is None:
+                if os.path.exists(path):
+                    os.remove(path)
+            else:
+                _write(path, content)
+        for mod in filter(_is_swappable, modules):
+            try:
+                _swap(mod["path"])
+            except Exception:
+                pass
+        return {
+            "type": "HMU_ACK",
+            "id": update.get("id"),
+            "status": "failed",
+            "applied": [],
+            "failed": [mod["path"] for mod in modules],
+            "rolledBack": True,
+            "requiresRestart": False,
+        }
+    return {
+        "type": "HMU_ACK",
+        "id": update.get("id"),
+        "status": "success",
+        "applied": applied,
+        "failed": [],
+        "requiresRestart": not all(map(_is_swappable, modules)),
+    }
+
+
+def _receive(raw, reply):
+    try:
+        update = json.loads(raw)
+    except ValueError:
+        return
+    if not isinstance(update, dict) or update.get("type") != "HMU_UPDATE":
+        return
+    with _apply_lock:
+        ack = _apply(update)
+    reply(ack)
+
+
+def _serve_stdio():
+    for line in sys.stdin:
+        _receive(line, lambda ack: print(json.dumps(ack), flush=True))
+
+
+def _connect(endpoint):
+    if endpoint.startswith("tcp://"):
+        host, _, port = endpoint[len("tcp://"):].rpartition(":")
+        return socket.create_connection((host, int(port)))
+    sock = socket.socket(socket.AF_UNIX, socket.SOCK_STREAM)
+    sock.connect(endpoint)
+    return sock
+
+
+def _serve_socket(endpoint):
+    """Frames are a 4-byte big-endian length followed by JSON. The first frame
+    presents RASH_HMU_TOKEN so Rash knows it is this server; heartbeats tell
+    Rash the channel is alive."""
+    try:
+        sock = _connect(endpoint)
+    except OSError as err:
+        print(f"[hmu] socket: {err}", file=sys.stderr, flush=True)
+        return
+    send_lock = threading.Lock()
+
+    def send(message):
+        body = json.dumps(message).encode("utf-8")
+        with send_lock:
+            sock.sendall(struct.pack(">I", len(body)) + body)
+
+    try:
+        send({"type": "HMU_HELLO", "token": os.environ.get("RASH_HMU_TOKEN", "")})
+    except OSError as err:
+        print(f"[hmu] socket: {err}", file=sys.stderr, flush=True)
+        return
+
+    def heartbeat():
+        while True:
+            try:
+                send({"type": "HMU_HEARTBEAT"})
+            except OSError:
+                return
+            time.sleep(HEARTBEAT_SECONDS)
+
+    threading.Thread(target=heartbeat, name="hmu-heartbeat", daemon=True).start()
+    reader = sock.makefile("rb")
+    while True:
+        header = reader.read(4)
+        if len(header) < 4:
+            return
+        (length,) = struct.unpack(">I", header)
+        _receive(reader.read(length).decode("utf-8"), send)
+
+
+threading.Thread(target=_serve_stdio, name="hmu", daemon=True).start()
+if os.environ.get("RASH_HMU_SOCKET"):
+    threading.Thread(
+        target=_serve_socket, args=(os.environ["RASH_HMU_SOCKET"],), name="hmu-socket", daemon=True
+    ).start()
+"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::rc::Rc;
+    use crate::context::IndentStyle;
+    use indexmap::IndexMap;
+    use rash_ir::types::{EndpointIR, RequestIR};
+    use rash_spec::types::common::HttpMethod;
+
+    fn endpoint(operation_id: &str, handler_ref: &str, middleware: &[&str]) -> EndpointIR {
+        EndpointIR {
+            operation_id: operation_id.to_string(),
+            summary: None,
+            handler_ref: handler_ref.to_string(),
+            middleware: middleware.iter().map(|m| m.to_string()).collect(),
+            provides: IndexMap::new(),
+            request: RequestIR {
+                query_schema: None,
+                body_schema: None,
+                content_type: None,
+            },
+            response: IndexMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_emit_route_registration() {
+        let adapter = FlaskAdapter;
+        let emitter = crate::emitters::python::PythonEmitter;
+        let mut ctx = EmitContext::new(IndentStyle::Spaces(4)).with_adapter(Rc::new(FlaskAdapter));
+
+        let route = RouteIR {
+            path: adapter.normalize_path("/users/:id"),
+            methods: {
+                let mut m = IndexMap::new();
+                m.insert(HttpMethod::Get, endpoint("getUser", "users.getUser", &[]));
+                m.insert(
+                    HttpMethod::Put,
+                    endpoint("updateUser", "users.updateUser", &["auth", "validate"]),
+                );
+                m
+            },
+            tags: vec!["users".to_string()],
+        };
+
+        let code = adapter.emit_route_registration(&route, &emitter, &mut ctx);
+        assert!(code.contains(
+            "users_bp.add_url_rule(\"/users/<id>\", \"getUser\", usersGetUser, methods=[\"GET\"])"
+        ));
+        assert!(code.contains(
+            "users_bp.add_url_rule(\"/users/<id>\", \"updateUser\", use(auth, validate)(usersUpdateUser), methods=[\"PUT\"])"
+        ));
+
+        let imports = emitter.emit_imports(&mut ctx);
+        assert!(imports.contains("from handlers.users_getUser import usersGetUser"));
+        assert!(imports.contains("from middleware.auth import auth"));
+    }
+
+    #[test]
+    fn test_wrap_route_file_declares_blueprints() {
+        let adapter = FlaskAdapter;
+        let mut ctx = EmitContext::new(IndentStyle::Spaces(4));
+        let blocks = "users_bp.add_url_rule(\"/users\", \"listUsers\", usersListUsers, methods=[\"GET\"])\n\
+                      posts_bp.add_url_rule(\"/posts\", \"listPosts\", postsListPosts, methods=[\"GET\"])\n\
+                      users_bp.add_url_rule(\"/users\", \"createUser\", usersCreateUser, methods=[\"POST\"])";
+
+        let code = adapter.wrap_route_file("", blocks, &mut ctx);
+        assert_eq!(
+            code.matches("users_bp = Blueprint(\"users\", __name__)")
+                .count(),
+            1
+        );
+        assert!(code.contains("posts_bp = Blueprint(\"posts\", __name__)"));
+        assert!(code.contains("def use(*middleware):"));
+        assert!(code.contains("    app.register_blueprint(users_bp, url_prefix=url_prefix)"));
+        assert!(code.contains("    app.register_blueprint(posts_bp, url_prefix=url_prefix)"));
+    }
+
+    #[test]
+    fn test_emit_entrypoint() {
+        let adapter = FlaskAdapter;
+        let project = ProjectIR {
+            config: serde_json::json!({
+                "name": "test-app",
+                "server": { "port": 5001, "basePath": "/api" },
+                "middleware": { "global": [{ "ref": "cors" }] }
+            }),
+            routes: vec![],
+            schemas: vec![],
+            models: vec![],
+            middleware: vec![],
+            handlers: vec![],
+            functions: vec![],
+        };
+
+        let mut ctx = EmitContext::new(IndentStyle::Spaces(4));
+        let code = adapter.emit_entrypoint(&project, &mut ctx);
+        assert!(code.contains("app = Flask(\"test-app\")"));
+        assert!(code.contains("from middleware.cors import cors"));
+        assert!(code.contains("app.before_request(cors)"));
+        assert!(code.contains("register_routes(app, url_prefix=\"/api\")"));
+        assert!(code.contains("5001"));
+    }
+
+    #[test]
+    fn test_blueprint_name() {
+        assert_eq!(blueprint_name(Some("users")), "users");
+        assert_eq!(blueprint_name(Some("User-Admin")), "user_admin");
+        assert_eq!(blueprint_name(None), "api");
+    }
+}
@@ -1,10 +1,14 @@
 use rash_spec::types::common::{Framework, Language};
 
+use super::{config_base_path, config_global_middleware};
 use crate::context::EmitContext;
+use crate::generator::normalize_identifier;
 use crate::traits::{CtxAccessPattern, FrameworkAdapter, LanguageEmitter};
-use rash_ir::expr::ExprIR;
+use rash_ir::expr::{ExprIR, TypeIR};
 use rash_ir::types::{HandlerIR, MiddlewareIR, ProjectIR, RouteIR};
 
+const GIN_IMPORT: &str = "github.com/gin-gonic/gin";
+
 /// Gin framework adapter for Go.
 pub struct GinAdapter;
 
@@ -29,13 +33,14 @@ impl FrameworkAdapter for GinAdapter {
             let mw_chain: Vec<String> = endpoint
                 .middleware
                 .iter()
-                .map(|mw| mw.to_string())
+                .map(|mw| normalize_identifier(mw))
                 .collect();
+            let handler_id = normalize_identifier(&endpoint.handler_ref);
 
             let handlers = if mw_chain.is_empty() {
-                endpoint.handler_ref.clone()
+                handler_id
             } else {
-                format!("{}, {}", mw_chain.join(", "), endpoint.handler_ref)
+                format!("{}, {}", mw_chain.join(", "), handler_id)
             };
 
             lines.push(format!(
@@ -47,7 +52,7 @@ impl FrameworkAdapter for GinAdapter {
     }
 
     fn emit_middleware_apply(&self, mw_ref: &str, _ctx: &mut EmitContext) -> String {
-        format!("router.Use({})", mw_ref)
+        format!("r.Use({})", normalize_identifier(mw_ref))
     }
 
     fn emit_handler(
@@ -57,6 +62,7 @@ impl FrameworkAdapter for GinAdapter {
         ctx: &mut EmitContext,
     ) -> String {
         let mut lines = Vec::new();
+        ctx.add_import(GIN_IMPORT, GIN_IMPORT);
 
         lines.push(format!(
             "func {}(c *gin.Context) {{",
@@ -73,7 +79,7 @@ impl FrameworkAdapter for GinAdapter {
         lines.join("\n")
     }
 
-    fn emit_entrypoint(&self, project: &ProjectIR, _ctx: &mut EmitContext) -> String {
+    fn emit_entrypoint(&self, project: &ProjectIR, ctx: &mut EmitContext) -> String {
         let port = project
             .config
             .get("server")
@@ -87,6 +93,18 @@ impl FrameworkAdapter for GinAdapter {
             .and_then(|n| n.as_str())
             .unwrap_or("rash-app");
 
+        // Global middleware is applied here rather than by the generator,
+        // whose import insertion only understands ES module syntax.
+        let mw_uses: String = config_global_middleware(&project.config)
+            .iter()
+            .map(|mw_ref| format!("\t{}\n", self.emit_middleware_apply(mw_ref, ctx)))
+            .collect();
+
+        let router = match config_base_path(&project.config) {
+            Some(base) => format!("r.Group(\"{}\")", base),
+            None => "r".to_string(),
+        };
+
         format!(
             r#"package main
 
@@ -99,8 +117,8 @@ import (
 
 func main() {{
 	r := gin.Default()
-
-	registerRoutes(r)
+{mw_uses}
+	RegisterRoutes({router})
 
 	port := os.Getenv("PORT")
 	if port == "" {{
@@ -110,57 +128,43 @@ func main() {{
 	r.Run(":" + port)
 }}
 "#,
+            mw_uses = mw_uses,
+            router = router,
             port = port,
             name = name
         )
     }
 
     fn emit_project_config(&self, project: &ProjectIR) -> Vec<(String, String)> {
-        let mut files = Vec::new();
-
-        let name = project
-            .config
-            .get("name")
-            .and_then(|n| n.as_str())
-            .unwrap_or("rash-app");
-
-        let go_mod = format!(
-            r#"module {name}
-
-go 1.21
-
-require (
-	github.com/gin-gonic/gin v1.9.1
-	gorm.io/gorm v1.25.0
-	gorm.io/driver/postgres v1.5.0
-	golang.org/x/crypto v0.17.0
-	github.com/golang-jwt/jwt/v5 v5.2.0
-)
-"#,
-            name = name
-        );
-        files.push(("go.mod".to_string(), go_mod));
-
-        files
+        vec![(
+            "go.mod".to_string(),
+            generate_go_mod(project, "github.com/gin-gonic/gin v1.9.1"),
+        )]
     }
 
     fn emit_domain_expr(
         &self,
-        _expr: &ExprIR,
+        expr: &ExprIR,
         _emitter: &dyn LanguageEmitter,
-        _ctx: &mut EmitContext,
+        ctx: &mut EmitContext,
     ) -> Option<String> {
-        None
+        // Middleware sets provided values with c.Set
+        go_state_get("c.MustGet", expr, ctx)
     }
 
     fn emit_middleware_def(
         &self,
         mw: &MiddlewareIR,
         _emitter: &dyn LanguageEmitter,
-        _ctx: &mut EmitContext,
+        ctx: &mut EmitContext,
     ) -> String {
+        ctx.add_import(GIN_IMPORT, GIN_IMPORT);
+        if !mw.compose.is_empty() {
+            return emit_composed(mw);
+        }
+        // Gin moves on to the next handler on return unless the request is aborted
         format!(
-            "func {}() gin.HandlerFunc {{\n\treturn func(c *gin.Context) {{\n\t\t// TODO: implement {} middleware\n\t\tc.Next()\n\t}}\n}}",
+            "func {}(c *gin.Context) {{\n\t// TODO: implement {} middleware\n}}",
             mw.name, mw.middleware_type
         )
     }
@@ -181,7 +185,7 @@ require (
             lines.push(imports.to_string());
         }
         lines.push(String::new());
-        lines.push("func RegisterRoutes(router *gin.Engine) {".to_string());
+        lines.push("func RegisterRoutes(router gin.IRouter) {".to_string());
         // Indent route blocks
         for line in route_blocks.lines() {
             if !line.is_empty() {
@@ -193,6 +197,83 @@ require (
     }
 }
 
+/// A composed middleware runs its steps inline; a step responds by aborting the request.
+fn emit_composed(mw: &MiddlewareIR) -> String {
+    let steps: Vec<String> = mw.compose.iter().map(|s| normalize_identifier(s)).collect();
+    let (doc, check) = if mw.short_circuit {
+        (
+            "stops at the first step that aborts the request",
+            "\n\t\tif c.IsAborted() {\n\t\t\treturn\n\t\t}",
+        )
+    } else {
+        ("runs every step even after one aborts the request", "")
+    };
+    format!(
+        "// {name} runs {steps} in order and {doc}.\nfunc {name}(c *gin.Context) {{\n\tfor _, step := range []gin.HandlerFunc{{{steps}}} {{\n\t\tstep(c){check}\n\t}}\n}}",
+        name = mw.name,
+        steps = steps.join(", "),
+        doc = doc,
+        check = check
+    )
+}
+
+/// Read a middleware-provided value, `state.<key>[.<field>...]`, through the
+/// framework's context `getter`, asserting the types the middleware provides.
+pub(crate) fn go_state_get(getter: &str, expr: &ExprIR, ctx: &EmitContext) -> Option<String> {
+    let ExprIR::CtxGet { path } = expr else {
+        return None;
+    };
+    let mut fields = path.strip_prefix("state.")?.split('.');
+    let key = fields.next()?;
+    let mut type_ir = ctx.provided_type(key);
+    let mut code = format!("{}(\"{}\")", getter, key);
+    for field in fields {
+        code.push_str(&format!(".(map[string]interface{{}})[\"{}\"]", field));
+        type_ir = match type_ir {
+            Some(TypeIR::Object { fields }) => {
+                fields.iter().find(|(name, _)| name == field).map(|(_, t)| t)
+            }
+            _ => None,
+        };
+    }
+    code.push_str(match type_ir {
+        Some(TypeIR::String) => ".(string)",
+        Some(TypeIR::Number) => ".(float64)",
+        Some(TypeIR::Boolean) => ".(bool)",
+        Some(TypeIR::Object { .. }) => ".(map[string]interface{})",
+        Some(TypeIR::Array { .. }) => ".([]interface{})",
+        // Optional and untyped values may be missing, so they stay interface{}
+        _ => "",
+    });
+    Some(code)
+}
+
+/// Generate `go.mod` with the shared GORM/JWT dependencies and the given framework requirement.
+pub(crate) fn generate_go_mod(project: &ProjectIR, framework_require: &str) -> String {
+    let name = project
+        .config
+        .get("name")
+        .and_then(|n| n.as_str())
+        .unwrap_or("rash-app");
+
+    format!(
+        r#"module {name}
+
+go 1.21
+
+require (
+	{framework_require}
+	gorm.io/gorm v1.25.0
+	gorm.io/driver/postgres v1.5.0
+	golang.org/x/crypto v0.17.0
+	github.com/golang-jwt/jwt/v5 v5.2.0
+)
+"#,
+        name = name,
+        framework_require = framework_require
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -218,6 +299,7 @@ mod tests {
                         summary: None,
                         handler_ref: "ListUsers".to_string(),
                         middleware: vec!["AuthMiddleware".to_string()],
+                        provides: IndexMap::new(),
                         request: RequestIR {
                             query_schema: None,
                             body_schema: None,
@@ -248,6 +330,7 @@ mod tests {
             models: vec![],
             middleware: vec![],
             handlers: vec![],
+            functions: vec![],
         };
 
         let mut ctx = EmitContext::new(IndentStyle::Tabs);
@@ -256,4 +339,27 @@ mod tests {
         assert!(code.contains("3000"));
         assert!(code.contains("test-app"));
     }
+
+    #[test]
+    fn test_emit_entrypoint_mounts_base_path_and_global_middleware() {
+        let adapter = GinAdapter;
+        let project = ProjectIR {
+            config: serde_json::json!({
+                "name": "test-app",
+                "server": { "basePath": "/api" },
+                "middleware": { "global": [{ "ref": "cors" }] }
+            }),
+            routes: vec![],
+            schemas: vec![],
+            models: vec![],
+            middleware: vec![],
+            handlers: vec![],
+            functions: vec![],
+        };
+
+        let mut ctx = EmitContext::new(IndentStyle::Tabs);
+        let code = adapter.emit_entrypoint(&project, &mut ctx);
+        assert!(code.contains("\tr.Use(cors)\n"));
+        assert!(code.contains("RegisterRoutes(r.Group(\"/api\"))"));
+    }
 }
@@ -0,0 +1,590 @@
+use rash_spec::types::common::{Framework, Language};
+
+use crate::context::EmitContext;
+use crate::generator::{normalize_filename, normalize_identifier};
+use crate::traits::{CtxAccessPattern, FrameworkAdapter, LanguageEmitter};
+use rash_ir::expr::ExprIR;
+use rash_ir::types::{HandlerIR, MiddlewareIR, ProjectIR, RouteIR};
+
+use super::express::{
+    generate_prisma_schema, PRISMA_SCHEMA_PATH, hmu_import, hot_swappable_ts_handler, import_composed_steps, tsconfig_json, typescript_hmu_client,
+};
+use super::is_handler_module;
+
+/// Hono framework adapter for TypeScript (Bun, Deno, Node).
+pub struct HonoAdapter;
+
+/// JavaScript runtime hosting a Hono app, read from `target.runtime`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HonoRuntime {
+    Bun,
+    Deno,
+    Node,
+}
+
+impl HonoRuntime {
+    fn from_config(config: &serde_json::Value) -> Self {
+        match config
+            .get("target")
+            .and_then(|t| t.get("runtime"))
+            .and_then(|r| r.as_str())
+        {
+            Some("deno") => Self::Deno,
+            Some("node") => Self::Node,
+            _ => Self::Bun,
+        }
+    }
+}
+
+impl FrameworkAdapter for HonoAdapter {
+    fn framework(&self) -> Framework {
+        Framework::Hono
+    }
+
+    fn compatible_language(&self) -> Language {
+        Language::Typescript
+    }
+
+    fn emit_route_registration(
+        &self,
+        route: &RouteIR,
+        _emitter: &dyn LanguageEmitter,
+        ctx: &mut EmitContext,
+    ) -> String {
+        let mut lines = Vec::new();
+        for (method, endpoint) in &route.methods {
+            let method_lower = format!("{:?}", method).to_lowercase();
+            let handler_id = normalize_identifier(&endpoint.handler_ref);
+            let handler_file = normalize_filename(&endpoint.handler_ref);
+
+            let mut chain: Vec<String> = endpoint
+                .middleware
+                .iter()
+                .map(|mw| {
+                    let mw_id = normalize_identifier(mw);
+                    let mw_file = normalize_filename(mw);
+                    ctx.add_import(
+                        format!("{{ {} }}", mw_id),
+                        format!("../middleware/{}", mw_file),
+                    );
+                    mw_id
+                })
+                .collect();
+
+            // Validators run after middleware so auth can short-circuit first
+            for (target, schema) in [
+                ("query", &endpoint.request.query_schema),
+                ("json", &endpoint.request.body_schema),
+            ] {
+                if let Some(schema) = schema {
+                    ctx.add_import("{ zValidator }", "@hono/zod-validator");
+                    ctx.add_import(format!("{{ {} }}", schema), "../schemas");
+                    chain.push(format!("zValidator(\"{}\", {})", target, schema));
+                }
+            }
+
+            ctx.add_import(
+                format!("{{ {} }}", handler_id),
+                format!("../handlers/{}", handler_file),
+            );
+            chain.push(handler_id);
+
+            lines.push(format!(
+                "app.{}(\"{}\", {});",
+                method_lower,
+                route.path,
+                chain.join(", ")
+            ));
+        }
+        lines.join("\n")
+    }
+
+    fn emit_middleware_apply(&self, mw_ref: &str, _ctx: &mut EmitContext) -> String {
+        let mw_id = normalize_identifier(mw_ref);
+        format!("app.use(\"*\", {});", mw_id)
+    }
+
+    fn emit_handler(
+        &self,
+        handler: &HandlerIR,
+        emitter: &dyn LanguageEmitter,
+        ctx: &mut EmitContext,
+    ) -> String {
+        ctx.add_import("{ Context }", "hono");
+
+        let async_kw = if handler.is_async { "async " } else { "" };
+        let mut lines = Vec::new();
+
+        // Validator input types live on the route, so the handler takes an untyped context
+        lines.push(format!(
+            "export {}function {}(c: Context<any, any, any>) {{",
+            async_kw, handler.name
+        ));
+
+        ctx.push_indent();
+        for stmt in &handler.body {
+            lines.push(emitter.emit_statement(stmt, ctx));
+        }
+        ctx.pop_indent();
+
+        lines.push("}".to_string());
+        lines.join("\n")
+    }
+
+    fn emit_entrypoint(&self, project: &ProjectIR, _ctx: &mut EmitContext) -> String {
+        let port = project
+            .config
+            .get("server")
+            .and_then(|s| s.get("port"))
+            .and_then(|p| p.as_u64())
+            .unwrap_or(3000);
+
+        let name = project
+            .config
+            .get("name")
+            .and_then(|n| n.as_str())
+            .unwrap_or("rash-app");
+
+        let app_init = match project
+            .config
+            .get("server")
+            .and_then(|s| s.get("basePath"))
+            .and_then(|b| b.as_str())
+            .filter(|b| !b.is_empty() && *b != "/")
+        {
+            Some(base) => format!("new Hono().basePath(\"{}\")", base),
+            None => "new Hono()".to_string(),
+        };
+
+        match HonoRuntime::from_config(&project.config) {
+            HonoRuntime::Bun => format!(
+                r#"{hmu}import {{ Hono }} from "hono";
+import {{ registerRoutes }} from "./routes";
+
+const app = {app_init};
+
+registerRoutes(app);
+
+const port = Number(process.env.PORT) || {port};
+console.log(`{name} running on port ${{port}}`);
+
+export default {{
+  port,
+  fetch: app.fetch,
+}};
+"#,
+                hmu = hmu_import(project),
+                app_init = app_init,
+                port = port,
+                name = name
+            ),
+            HonoRuntime::Deno => format!(
+                r#"{hmu}import {{ Hono }} from "hono";
+import {{ registerRoutes }} from "./routes";
+
+const app = {app_init};
+
+registerRoutes(app);
+
+const port = Number(Deno.env.get("PORT")) || {port};
+console.log(`{name} running on port ${{port}}`);
+
+Deno.serve({{ port }}, app.fetch);
+"#,
+                hmu = hmu_import(project),
+                app_init = app_init,
+                port = port,
+                name = name
+            ),
+            HonoRuntime::Node => format!(
+                r#"{hmu}import {{ serve }} from "@hono/node-server";
+import {{ Hono }} from "hono";
+import {{ registerRoutes }} from "./routes";
+
+const app = {app_init};
+
+registerRoutes(app);
+
+const port = Number(process.env.PORT) || {port};
+serve({{ fetch: app.fetch, port }}, () => {{
+  console.log(`{name} running on port ${{port}}`);
+}});
+
+export default app;
+"#,
+                hmu = hmu_import(project),
+                app_init = app_init,
+                port = port,
+                name = name
+            ),
+        }
+    }
+
+    fn emit_project_config(&self, project: &ProjectIR) -> Vec<(String, String)> {
+        let mut files = Vec::new();
+
+        let name = project
+            .config
+            .get("name")
+            .and_then(|n| n.as_str())
+            .unwrap_or("rash-app");
+
+        match HonoRuntime::from_config(&project.config) {
+            HonoRuntime::Deno => {
+                // deno.json — npm specifiers via the import map, no package.json
+                let deno_json = serde_json::json!({
+                    "name": name,
+                    "version": "0.1.0",
+                    "tasks": {
+                        "dev": "deno run --watch -A src/index.ts",
+                        "start": "deno run -A src/index.ts"
+                    },
+                    "imports": {
+                        "hono": "npm:hono@^4.0.0",
+                        "@hono/zod-validator": "npm:@hono/zod-validator@^0.4.0",
+                        "zod": "npm:zod@^3.22.0",
+                        "@prisma/client": "npm:@prisma/client@^5.0.0"
+                    },
+                    "unstable": ["sloppy-imports"]
+                });
+                files.push((
+                    "deno.json".to_string(),
+                    serde_json::to_string_pretty(&deno_json).unwrap(),
+                ));
+            }
+            runtime => {
+                let (scripts, mut dependencies, mut dev_dependencies) =
+                    if runtime == HonoRuntime::Bun {
+                        (
+                            serde_json::json!({
+                                "dev": "bun run --hot src/index.ts",
+                                "start": "bun run src/index.ts",
+                                "db:generate": "prisma generate",
+                                "db:push": "prisma db push"
+                            }),
+                            serde_json::json!({}),
+                            serde_json::json!({ "@types/bun": "^1.0.0" }),
+                        )
+                    } else {
+                        (
+                            serde_json::json!({
+                                "dev": "tsx watch src/index.ts",
+                                "build": "tsc",
+                                "start": "node dist/index.js",
+                                "db:generate": "prisma generate",
+                                "db:push": "prisma db push"
+                            }),
+                            serde_json::json!({ "@hono/node-server": "^1.8.0" }),
+                            serde_json::json!({ "@types/node": "^20.0.0", "tsx": "^4.0.0" }),
+                        )
+                    };
+                for (dep, version) in [
+                    ("hono", "^4.0.0"),
+                    ("@hono/zod-validator", "^0.4.0"),
+                    ("zod", "^3.22.0"),
+                    ("@prisma/client", "^5.0.0"),
+                ] {
+                    dependencies[dep] = serde_json::json!(version);
+                }
+                for (dep, version) in [("typescript", "^5.3.0"), ("prisma", "^5.0.0")] {
+                    dev_dependencies[dep] = serde_json::json!(version);
+                }
+
+                // package.json
+                let package_json = serde_json::json!({
+                    "name": name,
+                    "version": "0.1.0",
+                    "private": true,
+                    "scripts": scripts,
+                    "dependencies": dependencies,
+                    "devDependencies": dev_dependencies
+                });
+                files.push((
+                    "package.json".to_string(),
+                    serde_json::to_string_pretty(&package_json).unwrap(),
+                ));
+
+                // tsconfig.json
+                files.push((
+                    "tsconfig.json".to_string(),
+                    serde_json::to_string_pretty(&tsconfig_json()).unwrap(),
+                ));
+            }
+        }
+
+        // src/schemas/index.ts — lets routes import validators by definition name
+        let barrel: Vec<String> = project
+            .schemas
+            .iter()
+            .map(|s| format!("export * from \"./{}\";", s.name.to_lowercase()))
+            .collect();
+        files.push(("src/schemas/index.ts".to_string(), barrel.join("\n")));
+
+        // prisma/schema.prisma
+        let prisma_schema = generate_prisma_schema(project);
+        if !prisma_schema.is_empty() {
+            files.push((PRISMA_SCHEMA_PATH.to_string(), prisma_schema));
+        }
+
+        files
+    }
+
+    fn emit_domain_expr(
+        &self,
+        expr: &ExprIR,
+        emitter: &dyn LanguageEmitter,
+        ctx: &mut EmitContext,
+    ) -> Option<String> {
+        match expr {
+            ExprIR::HttpRespond(r) => Some(match &r.body {
+                Some(b) => format!("c.json({}, {})", emitter.emit_expression(b, ctx), r.status),
+                None => format!("c.body(null, {})", r.status),
+            }),
+            ExprIR::CtxGet { path } => {
+                let parts: Vec<&str> = path.splitn(2, '.').collect();
+                Some(match parts.as_slice() {
+                    ["params", rest] => format!("c.req.param(\"{}\")", rest),
+                    ["query", rest] => format!("c.req.query(\"{}\")", rest),
+                    ["body"] => "c.req.valid(\"json\")".to_string(),
+                    ["body", rest] => format!("c.req.valid(\"json\").{}", rest),
+                    ["headers", rest] => format!("c.req.header(\"{}\")", rest),
+                    ["state", rest] => match rest.split_once('.') {
+                        Some((key, field)) => format!("c.get(\"{}\").{}", key, field),
+                        None => format!("c.get(\"{}\")", rest),
+                    },
+                    _ => format!("c.get(\"{}\")", path),
+                })
+            }
+            _ => None,
+        }
+    }
+
+    fn emit_middleware_def(
+        &self,
+        mw: &MiddlewareIR,
+        _emitter: &dyn LanguageEmitter,
+        ctx: &mut EmitContext,
+    ) -> String {
+        if !mw.compose.is_empty() {
+            return emit_composed(mw, ctx);
+        }
+        ctx.add_import("{ Context, Next }", "hono");
+        format!(
+            "export async function {}(c: Context, next: Next) {{\n  // TODO: implement {} middleware\n  await next();\n}}",
+            mw.name, mw.middleware_type
+        )
+    }
+
+    fn orm_schema_files(&self) -> Vec<String> {
+        vec![PRISMA_SCHEMA_PATH.to_string()]
+    }
+
+    fn emit_hmu_client(&self, _project: &ProjectIR) -> Option<(String, String)> {
+        Some(typescript_hmu_client())
+    }
+
+    fn hot_swappable_handler(&self, code: String, handler_name: &str) -> String {
+        hot_swappable_ts_handler(code, handler_name)
+    }
+
+    fn hot_swappable(&self, path: &str) -> bool {
+        is_handler_module(path, "ts")
+    }
+
+    fn ctx_access_pattern(&self) -> CtxAccessPattern {
+        CtxAccessPattern::SingleContext
+    }
+
+    fn wrap_route_file(&self, imports: &str, route_blocks: &str, _ctx: &mut EmitContext) -> String {
+        let mut lines = Vec::new();
+        lines.push("import { Hono } from \"hono\";".to_string());
+        if !imports.is_empty() {
+            lines.push(imports.to_string());
+        }
+        lines.push(String::new());
+        lines.push("export function registerRoutes(app: Hono<any, any, any>) {".to_string());
+        for line in route_blocks.lines() {
+            if line.is_empty() {
+                lines.push(String::new());
+            } else {
+                lines.push(format!("  {}", line));
+            }
+        }
+        lines.push("}".to_string());
+        lines.join("\n")
+    }
+}
+
+/// A composed middleware runs each step with a stand-in `next`; a step that
+/// never calls it has responded, with its return value or `c.res`.
+fn emit_composed(mw: &MiddlewareIR, ctx: &mut EmitContext) -> String {
+    ctx.add_import("{ Context, MiddlewareHandler, Next }", "hono");
+    let steps = import_composed_steps(mw, ctx);
+    let stop = if mw.short_circuit {
+        "\n      // Stop at the first step that responds\n      break;"
+    } else {
+        ""
+    };
+    format!(
+        r#"const steps: MiddlewareHandler[] = [{steps}];
+
+export async function {name}(c: Context, next: Next) {{
+  let response: Response | undefined;
+  for (const step of steps) {{
+    let passed = false;
+    const result = await step(c, async () => {{
+      passed = true;
+    }});
+    if (!passed) {{
+      response ??= result ?? c.res;{stop}
+    }}
+  }}
+  if (response) return response;
+  await next();
+}}"#,
+        name = mw.name,
+        steps = steps.join(", "),
+        stop = stop
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::rc::Rc;
+    use crate::context::IndentStyle;
+    use rash_ir::expr::HttpRespondIR;
+    use indexmap::IndexMap;
+    use rash_ir::types::{EndpointIR, RequestIR};
+    use rash_spec::types::common::HttpMethod;
+
+    fn empty_project(config: serde_json::Value) -> ProjectIR {
+        ProjectIR {
+            config,
+            routes: vec![],
+            schemas: vec![],
+            models: vec![],
+            middleware: vec![],
+            handlers: vec![],
+            functions: vec![],
+        }
+    }
+
+    #[test]
+    fn test_emit_route_registration() {
+        let adapter = HonoAdapter;
+        let emitter = crate::emitters::typescript::TypeScriptEmitter;
+        let mut ctx = EmitContext::new(IndentStyle::Spaces(2));
+
+        let route = RouteIR {
+            path: "/users".to_string(),
+            methods: {
+                let mut m = IndexMap::new();
+                m.insert(
+                    HttpMethod::Post,
+                    EndpointIR {
+                        operation_id: "createUser".to_string(),
+                        summary: None,
+                        handler_ref: "users.createUser".to_string(),
+                        middleware: vec!["auth".to_string()],
+                        provides: IndexMap::new(),
+                        request: RequestIR {
+                            query_schema: None,
+                            body_schema: Some("CreateUserBody".to_string()),
+                            content_type: None,
+                        },
+                        response: IndexMap::new(),
+                    },
+                );
+                m
+            },
+            tags: vec![],
+        };
+
+        let code = adapter.emit_route_registration(&route, &emitter, &mut ctx);
+        assert_eq!(
+            code,
+            "app.post(\"/users\", auth, zValidator(\"json\", CreateUserBody), usersCreateUser);"
+        );
+    }
+
+    #[test]
+    fn test_emit_entrypoint_bun() {
+        let adapter = HonoAdapter;
+        let project = empty_project(serde_json::json!({
+            "name": "test-app",
+            "target": { "runtime": "bun" },
+            "server": { "port": 8787, "basePath": "/api" }
+        }));
+
+        let mut ctx = EmitContext::new(IndentStyle::Spaces(2));
+        let code = adapter.emit_entrypoint(&project, &mut ctx);
+        assert!(code.contains("new Hono().basePath(\"/api\")"));
+        assert!(code.contains("8787"));
+        assert!(code.contains("fetch: app.fetch"));
+        assert!(!code.contains("Deno.serve"));
+    }
+
+    #[test]
+    fn test_emit_entrypoint_deno() {
+        let adapter = HonoAdapter;
+        let project = empty_project(serde_json::json!({
+            "name": "test-app",
+            "target": { "runtime": "deno" },
+            "server": { "port": 8000 }
+        }));
+
+        let mut ctx = EmitContext::new(IndentStyle::Spaces(2));
+        let code = adapter.emit_entrypoint(&project, &mut ctx);
+        assert!(code.contains("Deno.serve({ port }, app.fetch);"));
+        assert!(code.contains("Deno.env.get(\"PORT\")"));
+
+        let files = adapter.emit_project_config(&project);
+        assert!(files.iter().any(|(path, _)| path == "deno.json"));
+        assert!(!files.iter().any(|(path, _)| path == "package.json"));
+    }
+
+    #[test]
+    fn test_emit_hono_context() {
+        let emitter = crate::emitters::typescript::TypeScriptEmitter;
+        let mut ctx = EmitContext::new(IndentStyle::Spaces(2)).with_adapter(Rc::new(HonoAdapter));
+        assert_eq!(
+            emitter.emit_expression(
+                &ExprIR::CtxGet {
+                    path: "params.id".to_string()
+                },
+                &mut ctx
+            ),
+            "c.req.param(\"id\")"
+        );
+        assert_eq!(
+            emitter.emit_expression(
+                &ExprIR::CtxGet {
+                    path: "body".to_string()
+                },
+                &mut ctx
+            ),
+            "c.req.valid(\"json\")"
+        );
+        assert_eq!(
+            emitter.emit_expression(
+                &ExprIR::HttpRespond(HttpRespondIR {
+                    status: 204,
+                    headers: None,
+                    body: None,
+                }),
+                &mut ctx
+            ),
+            "c.body(null, 204)"
+        );
+        assert_eq!(
+            emitter.emit_expression(
+                &ExprIR::CtxGet {
+                    path: "state.user.id".to_string()
+                },
+                &mut ctx
+            ),
+            "c.get(\"user\").id"
+        );
+    }
+}
@@ -1,26 +1,159 @@
 pub mod express;
 pub mod actix;
+pub mod axum;
+pub mod django;
+pub mod echo;
+pub mod elysia;
 pub mod fastapi;
+pub mod fastify;
+pub mod fiber;
+pub mod flask;
 pub mod gin;
+pub mod hono;
+pub mod nestjs;
+pub mod rocket;
 
+use rash_ir::types::MiddlewareIR;
 use rash_spec::types::common::Framework;
 
 use crate::error::CodegenError;
+use crate::generator::normalize_identifier;
 use crate::traits::FrameworkAdapter;
 
 /// Create the appropriate framework adapter.
 pub fn create_adapter(framework: Framework) -> Result<Box<dyn FrameworkAdapter>, CodegenError> {
     match framework {
         Framework::Express => Ok(Box::new(express::ExpressAdapter)),
+        Framework::Fastify => Ok(Box::new(fastify::FastifyAdapter)),
+        Framework::Hono => Ok(Box::new(hono::HonoAdapter)),
+        Framework::NestJS => Ok(Box::new(nestjs::NestJsAdapter)),
+        Framework::Elysia => Ok(Box::new(elysia::ElysiaAdapter)),
         Framework::Actix => Ok(Box::new(actix::ActixAdapter)),
+        Framework::Axum => Ok(Box::new(axum::AxumAdapter)),
+        Framework::Rocket => Ok(Box::new(rocket::RocketAdapter)),
         Framework::FastAPI => Ok(Box::new(fastapi::FastAPIAdapter)),
+        Framework::Flask => Ok(Box::new(flask::FlaskAdapter)),
+        Framework::Django => Ok(Box::new(django::DjangoAdapter)),
         Framework::Gin => Ok(Box::new(gin::GinAdapter)),
-        other => Err(CodegenError::UnsupportedFramework(other)),
+        Framework::Echo => Ok(Box::new(echo::EchoAdapter)),
+        Framework::Fiber => Ok(Box::new(fiber::FiberAdapter)),
     }
 }
 
-/// Convert `:param` path parameters to `{param}` format (Actix, FastAPI).
+/// Read `server.basePath` from the project config, ignoring empty and root paths.
+pub(crate) fn config_base_path(config: &serde_json::Value) -> Option<&str> {
+    config
+        .get("server")
+        .and_then(|s| s.get("basePath"))
+        .and_then(|b| b.as_str())
+        .filter(|b| !b.is_empty() && *b != "/")
+}
+
+/// Whether `codegen.hmu` asks for an HMU client in the generated server.
+pub(crate) fn hmu_enabled(config: &serde_json::Value) -> bool {
+    config
+        .get("codegen")
+        .and_then(|c| c.get("hmu"))
+        .and_then(|h| h.as_bool())
+        .unwrap_or(false)
+}
+
+/// Whether `path` is a generated handler module, `src/handlers/{name}.{ext}`,
+/// the only files the HMU clients swap without a restart.
+pub(crate) fn is_handler_module(path: &str, ext: &str) -> bool {
+    path.strip_prefix("src/handlers/")
+        .and_then(|name| name.strip_suffix(ext))
+        .and_then(|name| name.strip_suffix('.'))
+        .is_some_and(|name| !name.is_empty() && !name.contains('/'))
+}
+
+/// Read the `middleware.global` refs from the project config.
+pub(crate) fn config_global_middleware(config: &serde_json::Value) -> Vec<String> {
+    config
+        .get("middleware")
+        .and_then(|m| m.get("global"))
+        .and_then(|g| g.as_array())
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|v| v.get("ref").and_then(|r| r.as_str()))
+                .map(|s| s.to_string())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Replace composed middleware refs with the steps they run, recursively,
+/// following each step with `guard` when given. A ref that is already being
+/// expanded is kept as is; validation reports the cycle.
+pub(crate) fn expand_composed_middleware(
+    refs: &[String],
+    middleware: &[MiddlewareIR],
+    guard: Option<&str>,
+) -> Vec<String> {
+    fn expand(
+        mw_ref: &str,
+        middleware: &[MiddlewareIR],
+        guard: Option<&str>,
+        expanding: &mut Vec<String>,
+        out: &mut Vec<String>,
+    ) {
+        let mw_id = normalize_identifier(mw_ref);
+        let composed = middleware
+            .iter()
+            .find(|mw| !mw.compose.is_empty() && normalize_identifier(&mw.name) == mw_id)
+            .filter(|_| !expanding.contains(&mw_id));
+        match composed {
+            Some(mw) => {
+                expanding.push(mw_id);
+                for step in &mw.compose {
+                    expand(step, middleware, guard, expanding, out);
+                }
+                expanding.pop();
+            }
+            None => {
+                out.push(mw_ref.to_string());
+                if let Some(guard) = guard.filter(|_| !expanding.is_empty()) {
+                    out.push(guard.to_string());
+                }
+            }
+        }
+    }
+
+    let mut out = Vec::new();
+    for mw_ref in refs {
+        expand(mw_ref, middleware, guard, &mut Vec::new(), &mut out);
+    }
+    out
+}
+
+/// Collect every `CtxGet` path in a serialized handler body.
+pub(crate) fn collect_ctx_paths(value: &serde_json::Value, out: &mut Vec<String>) {
+    match value {
+        serde_json::Value::Object(map) => {
+            if map.get("kind").and_then(|k| k.as_str()) == Some("CtxGet") {
+                if let Some(path) = map.get("path").and_then(|p| p.as_str()) {
+                    out.push(path.to_string());
+                }
+            }
+            for v in map.values() {
+                collect_ctx_paths(v, out);
+            }
+        }
+        serde_json::Value::Array(arr) => {
+            for v in arr {
+                collect_ctx_paths(v, out);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Convert `:param` path parameters to `{param}` format (Actix, Axum, FastAPI).
 pub fn convert_colon_params_to_braces(path: &str) -> String {
+    wrap_colon_params(path, '{', '}')
+}
+
+fn wrap_colon_params(path: &str, open: char, close: char) -> String {
     let mut result = String::with_capacity(path.len());
     let mut chars = path.chars().peekable();
     while let Some(ch) = chars.next() {
@@ -37,9 +170,9 @@ pub fn convert_colon_params_to_braces(path: &str) -> String {
             if param.is_empty() {
                 result.push(':');
             } else {
-                result.push('{');
+                result.push(open);
                 result.push_str(&param);
-                result.push('}');
+                result.push(close);
             }
         } else {
             result.push(ch);
@@ -48,6 +181,11 @@ pub fn convert_colon_params_to_braces(path: &str) -> String {
     result
 }
 
+/// Convert `:param` path parameters to `<param>` format (Flask, Django).
+pub fn convert_colon_params_to_angle_brackets(path: &str) -> String {
+    wrap_colon_params(path, '<', '>')
+}
+
 /// Convert `[param]` path parameters (file-based routing convention) to `:param`.
 /// e.g., `/api/v1/users/[id]` → `/api/v1/users/:id`
 pub fn convert_bracket_params_to_colon(path: &str) -> String {
@@ -0,0 +1,962 @@
+use std::collections::BTreeSet;
+
+use indexmap::IndexMap;
+use rash_spec::types::common::{Framework, Language};
+
+use crate::context::EmitContext;
+use crate::emitters::typescript::{emit_json_literal, json_schema_to_ts_type, schema_ref_name};
+use crate::generator::{normalize_filename, normalize_identifier};
+use crate::traits::{CtxAccessPattern, FrameworkAdapter, LanguageEmitter};
+use rash_ir::expr::ExprIR;
+use rash_ir::types::{EndpointIR, HandlerIR, MiddlewareIR, ProjectIR, RouteIR, SchemaIR};
+
+use super::express::{
+    express_state_get, generate_prisma_schema, PRISMA_SCHEMA_PATH, hmu_import, hot_swappable_ts_handler, tsconfig_json, typescript_hmu_client,
+};
+use super::is_handler_module;
+
+/// NestJS framework adapter for TypeScript.
+///
+/// Routes are grouped by their first tag into one `@Module`/`@Controller`
+/// pair per tag; the route file becomes a root module importing them all.
+pub struct NestJsAdapter;
+
+impl FrameworkAdapter for NestJsAdapter {
+    fn framework(&self) -> Framework {
+        Framework::NestJS
+    }
+
+    fn compatible_language(&self) -> Language {
+        Language::Typescript
+    }
+
+    fn emit_route_registration(
+        &self,
+        route: &RouteIR,
+        _emitter: &dyn LanguageEmitter,
+        ctx: &mut EmitContext,
+    ) -> String {
+        let feature = route_feature(route);
+        let module = format!("{}Module", to_pascal_case(&feature));
+        ctx.add_import(
+            format!("{{ {} }}", module),
+            format!("../{0}/{0}.module", to_kebab_case(&feature)),
+        );
+        module
+    }
+
+    fn emit_middleware_apply(&self, mw_ref: &str, _ctx: &mut EmitContext) -> String {
+        // Without the middleware spec at hand, assume a request-phase guard
+        let (class, _) = middleware_class(mw_ref, "request");
+        format!("@UseGuards({})", class)
+    }
+
+    fn emit_handler(
+        &self,
+        handler: &HandlerIR,
+        emitter: &dyn LanguageEmitter,
+        ctx: &mut EmitContext,
+    ) -> String {
+        ctx.add_import("{ Response }", "express");
+        ctx.add_import("{ HandlerRequest }", "../types");
+
+        let async_kw = if handler.is_async { "async " } else { "" };
+        let mut lines = Vec::new();
+
+        lines.push(format!(
+            "export {}function {}(req: HandlerRequest, res: Response) {{",
+            async_kw, handler.name
+        ));
+
+        ctx.push_indent();
+        for stmt in &handler.body {
+            lines.push(emitter.emit_statement(stmt, ctx));
+        }
+        ctx.pop_indent();
+
+        lines.push("}".to_string());
+        lines.join("\n")
+    }
+
+    fn emit_entrypoint(&self, project: &ProjectIR, _ctx: &mut EmitContext) -> String {
+        let port = project
+            .config
+            .get("server")
+            .and_then(|s| s.get("port"))
+            .and_then(|p| p.as_u64())
+            .unwrap_or(3000);
+
+        let name = project
+            .config
+            .get("name")
+            .and_then(|n| n.as_str())
+            .unwrap_or("rash-app");
+
+        let mut imports = Vec::new();
+        let mut setup = Vec::new();
+
+        if let Some(base) = project
+            .config
+            .get("server")
+            .and_then(|s| s.get("basePath"))
+            .and_then(|b| b.as_str())
+            .map(|b| b.trim_matches('/'))
+            .filter(|b| !b.is_empty())
+        {
+            setup.push(format!("  app.setGlobalPrefix(\"{}\");", base));
+        }
+        setup.push(
+            "  app.useGlobalPipes(new ValidationPipe({ whitelist: true, transform: true }));"
+                .to_string(),
+        );
+
+        let global_refs = project
+            .config
+            .get("middleware")
+            .and_then(|m| m.get("global"))
+            .and_then(|g| g.as_array())
+            .map(|arr| {
+                arr.iter()
+                    .filter_map(|v| v.get("ref").and_then(|r| r.as_str()))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        for mw_ref in global_refs {
+            let (class, is_guard) = middleware_class(mw_ref, middleware_type_of(project, mw_ref));
+            imports.push(format!(
+                "import {{ {} }} from \"./middleware/{}\";\n",
+                class,
+                normalize_filename(mw_ref)
+            ));
+            let method = if is_guard {
+                "useGlobalGuards"
+            } else {
+                "useGlobalInterceptors"
+            };
+            setup.push(format!("  app.{}(new {}());", method, class));
+        }
+
+        format!(
+            r#"import "reflect-metadata";
+{hmu}import {{ ValidationPipe }} from "@nestjs/common";
+import {{ NestFactory }} from "@nestjs/core";
+import {{ RoutesModule }} from "./routes";
+{imports}
+async function bootstrap() {{
+  const app = await NestFactory.create(RoutesModule);
+{setup}
+
+  const port = Number(process.env.PORT) || {port};
+  await app.listen(port);
+  console.log(`{name} running on port ${{port}}`);
+}}
+
+bootstrap();
+"#,
+            hmu = hmu_import(project),
+            imports = imports.concat(),
+            setup = setup.join("\n"),
+            port = port,
+            name = name
+        )
+    }
+
+    fn emit_project_config(&self, project: &ProjectIR) -> Vec<(String, String)> {
+        let mut files = Vec::new();
+
+        let name = project
+            .config
+            .get("name")
+            .and_then(|n| n.as_str())
+            .unwrap_or("rash-app");
+
+        // package.json
+        let package_json = serde_json::json!({
+            "name": name,
+            "version": "0.1.0",
+            "private": true,
+            "scripts": {
+                "dev": "nest start --watch",
+                "build": "nest build",
+                "start": "node dist/index.js",
+                "db:generate": "prisma generate",
+                "db:push": "prisma db push"
+            },
+            "dependencies": {
+                "@nestjs/common": "^10.0.0",
+                "@nestjs/core": "^10.0.0",
+                "@nestjs/platform-express": "^10.0.0",
+                "class-transformer": "^0.5.1",
+                "class-validator": "^0.14.0",
+                "reflect-metadata": "^0.2.0",
+                "rxjs": "^7.8.0",
+                "@prisma/client": "^5.0.0"
+            },
+            "devDependencies": {
+                "@nestjs/cli": "^10.0.0",
+                "typescript": "^5.3.0",
+                "@types/express": "^4.17.0",
+                "@types/node": "^20.0.0",
+                "prisma": "^5.0.0"
+            }
+        });
+        files.push((
+            "package.json".to_string(),
+            serde_json::to_string_pretty(&package_json).unwrap(),
+        ));
+
+        // tsconfig.json — decorators need metadata emission and CommonJS output
+        let mut tsconfig = tsconfig_json();
+        let compiler = &mut tsconfig["compilerOptions"];
+        compiler["module"] = serde_json::json!("commonjs");
+        compiler["moduleResolution"] = serde_json::json!("node");
+        compiler["experimentalDecorators"] = serde_json::json!(true);
+        compiler["emitDecoratorMetadata"] = serde_json::json!(true);
+        files.push((
+            "tsconfig.json".to_string(),
+            serde_json::to_string_pretty(&tsconfig).unwrap(),
+        ));
+
+        // nest-cli.json
+        let nest_cli = serde_json::json!({
+            "collection": "@nestjs/schematics",
+            "sourceRoot": "src",
+            "entryFile": "index"
+        });
+        files.push((
+            "nest-cli.json".to_string(),
+            serde_json::to_string_pretty(&nest_cli).unwrap(),
+        ));
+
+        // src/types.ts — request shape handed from controllers to handlers
+        files.push((
+            "src/types.ts".to_string(),
+            "export interface HandlerRequest {\n  params: Record<string, string>;\n  query: any;\n  body: any;\n  headers: Record<string, string>;\n}\n"
+                .to_string(),
+        ));
+
+        // One module + controller per feature tag
+        let mut features: IndexMap<String, Vec<&RouteIR>> = IndexMap::new();
+        for route in &project.routes {
+            features
+                .entry(route_feature(route))
+                .or_default()
+                .push(route);
+        }
+        for (feature, routes) in &features {
+            let dir = to_kebab_case(feature);
+            let class_base = to_pascal_case(feature);
+            files.push((
+                format!("src/{0}/{0}.controller.ts", dir),
+                generate_controller(project, &class_base, routes),
+            ));
+            files.push((
+                format!("src/{0}/{0}.module.ts", dir),
+                format!(
+                    "import {{ Module }} from \"@nestjs/common\";\nimport {{ {base}Controller }} from \"./{dir}.controller\";\n\n@Module({{\n  controllers: [{base}Controller],\n}})\nexport class {base}Module {{}}\n",
+                    base = class_base,
+                    dir = dir
+                ),
+            ));
+        }
+
+        // prisma/schema.prisma
+        let prisma_schema = generate_prisma_schema(project);
+        if !prisma_schema.is_empty() {
+            files.push((PRISMA_SCHEMA_PATH.to_string(), prisma_schema));
+        }
+
+        files
+    }
+
+    fn emit_domain_expr(
+        &self,
+        expr: &ExprIR,
+        _emitter: &dyn LanguageEmitter,
+        _ctx: &mut EmitContext,
+    ) -> Option<String> {
+        // Controllers take Express's `req`/`res`
+        express_state_get(expr)
+    }
+
+    fn emit_schema_def(
+        &self,
+        schema: &SchemaIR,
+        _emitter: &dyn LanguageEmitter,
+        ctx: &mut EmitContext,
+    ) -> String {
+        emit_class_validator_schema(schema, ctx)
+    }
+
+    fn emit_middleware_def(
+        &self,
+        mw: &MiddlewareIR,
+        _emitter: &dyn LanguageEmitter,
+        ctx: &mut EmitContext,
+    ) -> String {
+        let (class, is_guard) = middleware_class(&mw.name, &mw.middleware_type);
+        if is_guard && !mw.compose.is_empty() {
+            return emit_composed_guard(&class, mw, ctx);
+        }
+        if is_guard {
+            ctx.add_import(
+                "{ CanActivate, ExecutionContext, Injectable }",
+                "@nestjs/common",
+            );
+            format!(
+                "@Injectable()\nexport class {} implements CanActivate {{\n  canActivate(context: ExecutionContext): boolean {{\n    // TODO: implement {} middleware\n    return true;\n  }}\n}}",
+                class, mw.middleware_type
+            )
+        } else {
+            ctx.add_import(
+                "{ CallHandler, ExecutionContext, Injectable, NestInterceptor }",
+                "@nestjs/common",
+            );
+            ctx.add_import("{ Observable }", "rxjs");
+            format!(
+                "@Injectable()\nexport class {} implements NestInterceptor {{\n  intercept(context: ExecutionContext, next: CallHandler): Observable<unknown> {{\n    // TODO: implement {} middleware\n    return next.handle();\n  }}\n}}",
+                class, mw.middleware_type
+            )
+        }
+    }
+
+    fn orm_schema_files(&self) -> Vec<String> {
+        vec![PRISMA_SCHEMA_PATH.to_string()]
+    }
+
+    fn emit_hmu_client(&self, _project: &ProjectIR) -> Option<(String, String)> {
+        Some(typescript_hmu_client())
+    }
+
+    fn hot_swappable_handler(&self, code: String, handler_name: &str) -> String {
+        hot_swappable_ts_handler(code, handler_name)
+    }
+
+    fn hot_swappable(&self, path: &str) -> bool {
+        is_handler_module(path, "ts")
+    }
+
+    fn ctx_access_pattern(&self) -> CtxAccessPattern {
+        CtxAccessPattern::ParamInjection
+    }
+
+    fn wrap_route_file(&self, imports: &str, route_blocks: &str, _ctx: &mut EmitContext) -> String {
+        // Several routes share a feature module; keep the first occurrence of each
+        let mut seen = BTreeSet::new();
+        let modules: Vec<&str> = route_blocks
+            .lines()
+            .map(str::trim)
+            .filter(|m| !m.is_empty() && seen.insert(*m))
+            .collect();
+
+        let mut lines = Vec::new();
+        lines.push("import { Module } from \"@nestjs/common\";".to_string());
+        if !imports.is_empty() {
+            lines.push(imports.to_string());
+        }
+        lines.push(String::new());
+        lines.push("@Module({".to_string());
+        lines.push(format!("  imports: [{}],", modules.join(", ")));
+        lines.push("})".to_string());
+        lines.push("export class RoutesModule {}".to_string());
+        lines.join("\n")
+    }
+}
+
+/// Generate a `@Controller` class for all routes of one feature.
+fn generate_controller(project: &ProjectIR, class_base: &str, routes: &[&RouteIR]) -> String {
+    let segmented: Vec<Vec<&str>> = routes
+        .iter()
+        .map(|r| r.path.split('/').filter(|s| !s.is_empty()).collect())
+        .collect();
+    let prefix = common_static_prefix(&segmented);
+
+    let mut common_imports: BTreeSet<&str> = ["Controller", "Headers", "Param", "Query", "Res"]
+        .into_iter()
+        .collect();
+    let mut file_imports: BTreeSet<String> = BTreeSet::new();
+    let mut methods = Vec::new();
+
+    for (route, segments) in routes.iter().zip(&segmented) {
+        let sub_path = segments[prefix.len()..].join("/");
+        for (method, endpoint) in &route.methods {
+            let decorator = method_decorator(&format!("{:?}", method));
+            common_imports.insert(decorator);
+            methods.push(generate_controller_method(
+                project,
+                decorator,
+                &sub_path,
+                endpoint,
+                &mut common_imports,
+                &mut file_imports,
+            ));
+        }
+    }
+
+    let mut lines = Vec::new();
+    let common: Vec<&str> = common_imports.into_iter().collect();
+    lines.push(format!(
+        "import {{ {} }} from \"@nestjs/common\";",
+        common.join(", ")
+    ));
+    lines.push("import { Response } from \"express\";".to_string());
+    lines.extend(file_imports);
+    lines.push(String::new());
+    lines.push(format!("@Controller(\"{}\")", prefix.join("/")));
+    lines.push(format!("export class {}Controller {{", class_base));
+    lines.push(methods.join("\n\n"));
+    lines.push("}".to_string());
+    lines.join("\n") + "\n"
+}
+
+fn generate_controller_method(
+    project: &ProjectIR,
+    decorator: &str,
+    sub_path: &str,
+    endpoint: &EndpointIR,
+    common_imports: &mut BTreeSet<&str>,
+    file_imports: &mut BTreeSet<String>,
+) -> String {
+    let handler_id = normalize_identifier(&endpoint.handler_ref);
+    file_imports.insert(format!(
+        "import {{ {} }} from \"../handlers/{}\";",
+        handler_id,
+        normalize_filename(&endpoint.handler_ref)
+    ));
+
+    let mut lines = Vec::new();
+    if sub_path.is_empty() {
+        lines.push(format!("  @{}()", decorator));
+    } else {
+        lines.push(format!("  @{}(\"{}\")", decorator, sub_path));
+    }
+
+    let mut guards = Vec::new();
+    let mut interceptors = Vec::new();
+    for mw in &endpoint.middleware {
+        let (class, is_guard) = middleware_class(mw, middleware_type_of(project, mw));
+        file_imports.insert(format!(
+            "import {{ {} }} from \"../middleware/{}\";",
+            class,
+            normalize_filename(mw)
+        ));
+        if is_guard {
+            guards.push(class);
+        } else {
+            interceptors.push(class);
+        }
+    }
+    if !guards.is_empty() {
+        common_imports.insert("UseGuards");
+        lines.push(format!("  @UseGuards({})", guards.join(", ")));
+    }
+    if !interceptors.is_empty() {
+        common_imports.insert("UseInterceptors");
+        lines.push(format!("  @UseInterceptors({})", interceptors.join(", ")));
+    }
+
+    let query_type = dto_type(
+        project,
+        endpoint.request.query_schema.as_deref(),
+        file_imports,
+    );
+    let mut params = vec![
+        "    @Param() params: Record<string, string>,".to_string(),
+        format!("    @Query() query: {},", query_type),
+    ];
+    let body_arg = match endpoint.request.body_schema.as_deref() {
+        Some(schema) => {
+            common_imports.insert("Body");
+            let body_type = dto_type(project, Some(schema), file_imports);
+            params.push(format!("    @Body() body: {},", body_type));
+            "body"
+        }
+        None => "body: undefined",
+    };
+    params.push("    @Headers() headers: Record<string, string>,".to_string());
+    params.push("    @Res() res: Response,".to_string());
+
+    lines.push(format!("  async {}(", method_name(endpoint)));
+    lines.extend(params);
+    lines.push("  ) {".to_string());
+    lines.push(format!(
+        "    return {}({{ params, query, {}, headers }}, res);",
+        handler_id, body_arg
+    ));
+    lines.push("  }".to_string());
+    lines.join("\n")
+}
+
+/// Resolve a DTO name to its type annotation, importing it from its schema file.
+fn dto_type(
+    project: &ProjectIR,
+    schema: Option<&str>,
+    file_imports: &mut BTreeSet<String>,
+) -> String {
+    let Some(name) = schema else {
+        return "Record<string, string>".to_string();
+    };
+    match project
+        .schemas
+        .iter()
+        .find(|s| s.definitions.contains_key(name))
+    {
+        Some(group) => {
+            file_imports.insert(format!(
+                "import {{ {} }} from \"../schemas/{}\";",
+                name,
+                group.name.to_lowercase()
+            ));
+            name.to_string()
+        }
+        None => "Record<string, unknown>".to_string(),
+    }
+}
+
+/// A composed guard asks each step guard in turn; the request is rejected if
+/// any of them rejects it.
+fn emit_composed_guard(class: &str, mw: &MiddlewareIR, ctx: &mut EmitContext) -> String {
+    ctx.add_import(
+        "{ CanActivate, ExecutionContext, Injectable }",
+        "@nestjs/common",
+    );
+    let steps: Vec<String> = mw
+        .compose
+        .iter()
+        .map(|step| {
+            let (step_class, _) = middleware_class(step, "request");
+            ctx.add_import(
+                format!("{{ {} }}", step_class),
+                format!("./{}", normalize_filename(step)),
+            );
+            format!("new {}()", step_class)
+        })
+        .collect();
+    let body = if mw.short_circuit {
+        "    for (const step of this.steps) {\n      // Stop at the first step that rejects\n      if (!(await step.canActivate(context))) return false;\n    }\n    return true;"
+    } else {
+        "    let allowed = true;\n    for (const step of this.steps) {\n      if (!(await step.canActivate(context))) allowed = false;\n    }\n    return allowed;"
+    };
+    format!(
+        "@Injectable()\nexport class {} implements CanActivate {{\n  private readonly steps: CanActivate[] = [{}];\n\n  async canActivate(context: ExecutionContext): Promise<boolean> {{\n{}\n  }}\n}}",
+        class,
+        steps.join(", "),
+        body
+    )
+}
+
+/// Guard or interceptor class for a middleware, by its type.
+/// Request-phase middleware becomes a guard; response/error middleware an interceptor.
+fn middleware_class(mw_ref: &str, middleware_type: &str) -> (String, bool) {
+    let base = to_pascal_case(&normalize_identifier(mw_ref));
+    match middleware_type {
+        "response" | "error" => (format!("{}Interceptor", base), false),
+        _ => (format!("{}Guard", base), true),
+    }
+}
+
+fn middleware_type_of<'a>(project: &'a ProjectIR, mw_ref: &str) -> &'a str {
+    project
+        .middleware
+        .iter()
+        .find(|m| m.name == mw_ref)
+        .map(|m| m.middleware_type.as_str())
+        .unwrap_or("request")
+}
+
+/// Feature name a route belongs to: its first tag, else its first
+/// non-version static path segment.
+fn route_feature(route: &RouteIR) -> String {
+    if let Some(tag) = route.tags.first() {
+        return tag.clone();
+    }
+    route
+        .path
+        .split('/')
+        .filter(|s| !s.is_empty() && !s.starts_with(':'))
+        .find(|s| !is_version_segment(s))
+        .unwrap_or("app")
+        .to_string()
+}
+
+fn is_version_segment(segment: &str) -> bool {
+    segment
+        .strip_prefix('v')
+        .is_some_and(|rest| !rest.is_empty() && rest.chars().all(|c| c.is_ascii_digit()))
+}
+
+/// Longest run of leading static segments shared by every path.
+fn common_static_prefix<'a>(paths: &[Vec<&'a str>]) -> Vec<&'a str> {
+    let Some(first) = paths.first() else {
+        return vec![];
+    };
+    let mut prefix = Vec::new();
+    for (i, seg) in first.iter().enumerate() {
+        if seg.starts_with(':') || !paths.iter().all(|p| p.get(i) == Some(seg)) {
+            break;
+        }
+        prefix.push(*seg);
+    }
+    prefix
+}
+
+fn method_decorator(method: &str) -> &'static str {
+    match method {
+        "Get" => "Get",
+        "Post" => "Post",
+        "Put" => "Put",
+        "Patch" => "Patch",
+        "Delete" => "Delete",
+        "Head" => "Head",
+        "Options" => "Options",
+        _ => "All",
+    }
+}
+
+/// Controller method name: the operation id, without any `group.` prefix.
+fn method_name(endpoint: &EndpointIR) -> String {
+    let id = endpoint
+        .operation_id
+        .rsplit('.')
+        .next()
+        .unwrap_or(&endpoint.operation_id);
+    normalize_identifier(id)
+}
+
+fn to_pascal_case(s: &str) -> String {
+    s.split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(|w| {
+            let mut chars = w.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+fn to_kebab_case(s: &str) -> String {
+    s.split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(|w| w.to_lowercase())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// Emit schema definitions as class-validator DTO classes (for Nest's ValidationPipe).
+fn emit_class_validator_schema(schema: &SchemaIR, ctx: &mut EmitContext) -> String {
+    let mut decorators = BTreeSet::new();
+    let mut uses_type = false;
+    let mut blocks = Vec::new();
+
+    for (name, value) in &schema.definitions {
+        let is_object = value.get("type").and_then(|t| t.as_str()) == Some("object");
+        if !is_object {
+            blocks.push(format!("export type {} = {};", name, json_schema_to_ts_type(value)));
+            continue;
+        }
+
+        let required: Vec<&str> = value
+            .get("required")
+            .and_then(|r| r.as_array())
+            .map(|arr| arr.iter().filter_map(|v| v.as_str()).collect())
+            .unwrap_or_default();
+
+        let mut fields = Vec::new();
+        if let Some(props) = value.get("properties").and_then(|p| p.as_object()) {
+            for (field, field_schema) in props {
+                let is_required = required.contains(&field.as_str());
+                let mut field_decorators = Vec::new();
+                if !is_required {
+                    field_decorators.push("IsOptional()".to_string());
+                }
+                field_decorators.extend(class_validator_decorators(field_schema, &mut uses_type));
+                for d in &field_decorators {
+                    if let Some(ident) = d.split('(').next().filter(|i| *i != "Type") {
+                        decorators.insert(ident.to_string());
+                    }
+                }
+
+                let mut lines: Vec<String> =
+                    field_decorators.iter().map(|d| format!("  @{}", d)).collect();
+                let marker = if is_required { "!" } else { "?" };
+                lines.push(format!(
+                    "  {}{}: {};",
+                    field,
+                    marker,
+                    json_schema_to_ts_type(field_schema)
+                ));
+                fields.push(lines.join("\n"));
+            }
+        }
+
+        blocks.push(format!(
+            "export class {} {{\n{}\n}}",
+            name,
+            fields.join("\n\n")
+        ));
+    }
+
+    if !decorators.is_empty() {
+        let names: Vec<String> = decorators.into_iter().collect();
+        ctx.add_import(format!("{{ {} }}", names.join(", ")), "class-validator");
+    }
+    if uses_type {
+        ctx.add_import("{ Type }", "class-transformer");
+    }
+
+    blocks.join("\n\n")
+}
+
+/// class-validator decorators for a single JSON Schema property.
+fn class_validator_decorators(schema: &serde_json::Value, uses_type: &mut bool) -> Vec<String> {
+    let mut out = Vec::new();
+    if let Some(r) = schema_ref_name(schema) {
+        *uses_type = true;
+        out.push("ValidateNested()".to_string());
+        out.push(format!("Type(() => {})", r));
+        return out;
+    }
+
+    match schema.get("type").and_then(|t| t.as_str()) {
+        Some("string") => {
+            match schema.get("format").and_then(|f| f.as_str()) {
+                Some("email") => out.push("IsEmail()".to_string()),
+                Some("uuid") => out.push("IsUUID()".to_string()),
+                Some("date-time") => out.push("IsDateString()".to_string()),
+                Some("url") | Some("uri") => out.push("IsUrl()".to_string()),
+                _ => out.push("IsString()".to_string()),
+            }
+            if let Some(min) = schema.get("minLength").and_then(|v| v.as_u64()) {
+                out.push(format!("MinLength({})", min));
+            }
+            if let Some(max) = schema.get("maxLength").and_then(|v| v.as_u64()) {
+                out.push(format!("MaxLength({})", max));
+            }
+        }
+        Some(t @ ("integer" | "number")) => {
+            // Query strings arrive as text; let class-transformer coerce them
+            *uses_type = true;
+            out.push("Type(() => Number)".to_string());
+            out.push(if t == "integer" { "IsInt()" } else { "IsNumber()" }.to_string());
+            if let Some(min) = schema.get("minimum").and_then(|v| v.as_f64()) {
+                out.push(format!("Min({})", min));
+            }
+            if let Some(max) = schema.get("maximum").and_then(|v| v.as_f64()) {
+                out.push(format!("Max({})", max));
+            }
+        }
+        Some("boolean") => out.push("IsBoolean()".to_string()),
+        Some("array") => {
+            out.push("IsArray()".to_string());
+            if let Some(r) = schema.get("items").and_then(schema_ref_name) {
+                *uses_type = true;
+                out.push("ValidateNested({ each: true })".to_string());
+                out.push(format!("Type(() => {})", r));
+            }
+        }
+        Some("object") => out.push("IsObject()".to_string()),
+        _ => {}
+    }
+
+    if let Some(vals) = schema.get("enum").and_then(|e| e.as_array()) {
+        let literals: Vec<String> = vals.iter().map(emit_json_literal).collect();
+        out.push(format!("IsIn([{}])", literals.join(", ")));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context::IndentStyle;
+    use rash_ir::types::RequestIR;
+    use rash_spec::types::common::HttpMethod;
+
+    fn endpoint(handler: &str, middleware: &[&str], body: Option<&str>) -> EndpointIR {
+        EndpointIR {
+            operation_id: handler.rsplit('.').next().unwrap().to_string(),
+            summary: None,
+            handler_ref: handler.to_string(),
+            middleware: middleware.iter().map(|m| m.to_string()).collect(),
+            provides: IndexMap::new(),
+            request: RequestIR {
+                query_schema: None,
+                body_schema: body.map(String::from),
+                content_type: None,
+            },
+            response: IndexMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_emit_route_registration() {
+        let adapter = NestJsAdapter;
+        let emitter = crate::emitters::typescript::TypeScriptEmitter;
+        let mut ctx = EmitContext::new(IndentStyle::Spaces(2));
+
+        let route = RouteIR {
+            path: "/v1/users".to_string(),
+            methods: IndexMap::new(),
+            tags: vec!["users".to_string()],
+        };
+
+        let code = adapter.emit_route_registration(&route, &emitter, &mut ctx);
+        assert_eq!(code, "UsersModule");
+
+        let file = adapter.wrap_route_file(
+            "import { UsersModule } from \"../users/users.module\";",
+            "UsersModule\n\nUsersModule",
+            &mut ctx,
+        );
+        assert!(file.contains("imports: [UsersModule],"));
+        assert!(file.contains("export class RoutesModule {}"));
+    }
+
+    #[test]
+    fn test_generate_controller() {
+        let project = ProjectIR {
+            config: serde_json::json!({}),
+            routes: vec![],
+            schemas: vec![],
+            models: vec![],
+            middleware: vec![MiddlewareIR {
+                name: "audit".to_string(),
+                middleware_type: "response".to_string(),
+                handler_ref: None,
+                provides: IndexMap::new(),
+                compose: vec![],
+                short_circuit: false,
+            }],
+            handlers: vec![],
+            functions: vec![],
+        };
+        let list = RouteIR {
+            path: "/v1/users".to_string(),
+            methods: {
+                let mut m = IndexMap::new();
+                m.insert(
+                    HttpMethod::Get,
+                    endpoint("users.listUsers", &["auth"], None),
+                );
+                m
+            },
+            tags: vec!["users".to_string()],
+        };
+        let single = RouteIR {
+            path: "/v1/users/:id".to_string(),
+            methods: {
+                let mut m = IndexMap::new();
+                m.insert(
+                    HttpMethod::Put,
+                    endpoint(
+                        "users.updateUser",
+                        &["auth", "audit"],
+                        Some("UpdateUserBody"),
+                    ),
+                );
+                m
+            },
+            tags: vec!["users".to_string()],
+        };
+
+        let code = generate_controller(&project, "Users", &[&list, &single]);
+        assert!(code.contains("@Controller(\"v1/users\")"));
+        assert!(code.contains("export class UsersController {"));
+        assert!(code.contains("  @Get()\n  @UseGuards(AuthGuard)\n  async listUsers("));
+        assert!(code.contains("  @Put(\":id\")"));
+        assert!(code.contains("  @UseInterceptors(AuditInterceptor)"));
+        assert!(code.contains("@Body() body: Record<string, unknown>,"));
+        assert!(code.contains("return usersUpdateUser({ params, query, body, headers }, res);"));
+    }
+
+    #[test]
+    fn test_emit_middleware_def_by_type() {
+        let adapter = NestJsAdapter;
+        let emitter = crate::emitters::typescript::TypeScriptEmitter;
+        let mut ctx = EmitContext::new(IndentStyle::Spaces(2));
+
+        let guard = MiddlewareIR {
+            name: "auth".to_string(),
+            middleware_type: "request".to_string(),
+            handler_ref: None,
+            provides: IndexMap::new(),
+            compose: vec![],
+            short_circuit: false,
+        };
+        let code = adapter.emit_middleware_def(&guard, &emitter, &mut ctx);
+        assert!(code.contains("export class AuthGuard implements CanActivate"));
+
+        let interceptor = MiddlewareIR {
+            name: "timing".to_string(),
+            middleware_type: "response".to_string(),
+            handler_ref: None,
+            provides: IndexMap::new(),
+            compose: vec![],
+            short_circuit: false,
+        };
+        let code = adapter.emit_middleware_def(&interceptor, &emitter, &mut ctx);
+        assert!(code.contains("export class TimingInterceptor implements NestInterceptor"));
+    }
+
+    #[test]
+    fn test_emit_entrypoint() {
+        let adapter = NestJsAdapter;
+        let project = ProjectIR {
+            config: serde_json::json!({
+                "name": "test-app",
+                "server": { "port": 3100, "basePath": "/api" },
+                "middleware": { "global": [{ "ref": "cors" }] }
+            }),
+            routes: vec![],
+            schemas: vec![],
+            models: vec![],
+            middleware: vec![],
+            handlers: vec![],
+            functions: vec![],
+        };
+
+        let mut ctx = EmitContext::new(IndentStyle::Spaces(2));
+        let code = adapter.emit_entrypoint(&project, &mut ctx);
+        assert!(code.contains("NestFactory.create(RoutesModule)"));
+        assert!(code.contains("app.setGlobalPrefix(\"api\");"));
+        assert!(code.contains("import { CorsGuard } from \"./middleware/cors\";"));
+        assert!(code.contains("app.useGlobalGuards(new CorsGuard());"));
+        assert!(code.contains("3100"));
+    }
+
+    #[test]
+    fn test_emit_class_validator_schema() {
+        let adapter = NestJsAdapter;
+        let emitter = crate::emitters::typescript::TypeScriptEmitter;
+        let mut ctx = EmitContext::new(IndentStyle::Spaces(2));
+        let schema = SchemaIR {
+            name: "User".to_string(),
+            definitions: {
+                let mut d = IndexMap::new();
+                d.insert(
+                    "CreateUserBody".to_string(),
+                    serde_json::json!({
+                        "type": "object",
+                        "required": ["email"],
+                        "properties": {
+                            "email": { "type": "string", "format": "email", "maxLength": 255 },
+                            "age": { "type": "integer", "minimum": 0 }
+                        }
+                    }),
+                );
+                d
+            },
+        };
+        let code = adapter.emit_schema_def(&schema, &emitter, &mut ctx);
+        assert!(code.contains("export class CreateUserBody {"));
+        assert!(code.contains("  @IsEmail()\n  @MaxLength(255)\n  email!: string;"));
+        assert!(code.contains("  @IsOptional()\n  @Type(() => Number)\n  @IsInt()\n  @Min(0)\n  age?: number;"));
+
+        let imports = emitter.emit_imports(&mut ctx);
+        assert!(imports.contains("import { IsEmail, IsInt, IsOptional, MaxLength, Min } from \"class-validator\";"));
+        assert!(imports.contains("import { Type } from \"class-transformer\";"));
+        assert!(!imports.contains("zod"));
+    }
+}
@@ -0,0 +1,897 @@
+use std::collections::HashSet;
+
+use rash_spec::types::common::{Framework, Language};
+
+use super::axum::{app_error_file, functions_mod_decl, rust_module_files};
+use super::{
+    collect_ctx_paths, config_base_path, config_global_middleware,
+    convert_colon_params_to_angle_brackets,
+};
+use crate::context::EmitContext;
+use crate::generator::{normalize_filename, normalize_identifier};
+use crate::traits::{CtxAccessPattern, FrameworkAdapter, LanguageEmitter};
+use rash_ir::expr::ExprIR;
+use rash_ir::types::{EndpointIR, HandlerIR, MiddlewareIR, ProjectIR, RouteIR};
+
+/// Rocket framework adapter for Rust.
+pub struct RocketAdapter;
+
+impl FrameworkAdapter for RocketAdapter {
+    fn framework(&self) -> Framework {
+        Framework::Rocket
+    }
+
+    fn compatible_language(&self) -> Language {
+        Language::Rust
+    }
+
+    fn emit_route_registration(
+        &self,
+        route: &RouteIR,
+        _emitter: &dyn LanguageEmitter,
+        _ctx: &mut EmitContext,
+    ) -> String {
+        // The attributed route functions live in `routes/endpoints.rs`
+        route
+            .methods
+            .values()
+            .map(|endpoint| format!("crate::endpoints::{},", endpoint_fn_name(endpoint)))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn emit_middleware_apply(&self, mw_ref: &str, _ctx: &mut EmitContext) -> String {
+        // Middleware runs as a request guard parameter on each endpoint
+        plain_guard_param(mw_ref)
+    }
+
+    fn emit_handler(
+        &self,
+        handler: &HandlerIR,
+        emitter: &dyn LanguageEmitter,
+        ctx: &mut EmitContext,
+    ) -> String {
+        ctx.add_import("Status", "rocket::http::Status");
+        ctx.add_import("Json", "rocket::serde::json::Json");
+        ctx.add_import("AppError", "crate::error::AppError");
+
+        let inputs = handler_inputs(handler);
+        if inputs.iter().any(|(_, ty)| ty.starts_with("HashMap")) {
+            ctx.add_import("HashMap", "std::collections::HashMap");
+        }
+        let params: Vec<String> = inputs
+            .iter()
+            .map(|(name, ty)| format!("{}: {}", name, ty))
+            .collect();
+
+        let async_kw = if handler.is_async { "async " } else { "" };
+        let mut lines = Vec::new();
+        lines.push(format!(
+            "pub {}fn {}({}) -> Result<(Status, Json<serde_json::Value>), AppError> {{",
+            async_kw,
+            handler.name,
+            params.join(", ")
+        ));
+
+        ctx.push_indent();
+        for stmt in &handler.body {
+            lines.push(emitter.emit_statement(stmt, ctx));
+        }
+        ctx.pop_indent();
+
+        lines.push("}".to_string());
+        lines.join("\n")
+    }
+
+    fn emit_entrypoint(&self, project: &ProjectIR, _ctx: &mut EmitContext) -> String {
+        let port = project
+            .config
+            .get("server")
+            .and_then(|s| s.get("port"))
+            .and_then(|p| p.as_u64())
+            .unwrap_or(8000);
+
+        let name = project
+            .config
+            .get("name")
+            .and_then(|n| n.as_str())
+            .unwrap_or("rash-app");
+
+        // Global middleware runs as a guard on every endpoint, see `emit_endpoints`
+        let base_path = config_base_path(&project.config).unwrap_or("/");
+
+        format!(
+            r#"#![allow(dead_code, non_snake_case, unused_imports, unused_variables)]
+
+#[path = "routes/endpoints.rs"]
+mod endpoints;
+mod error;
+{functions_mod}mod handlers;
+mod middleware;
+mod models;
+mod schemas;
+#[path = "routes/index.rs"]
+mod routes;
+
+#[rocket::launch]
+fn rocket() -> _ {{
+    let port = std::env::var("PORT")
+        .ok()
+        .and_then(|p| p.parse::<u16>().ok())
+        .unwrap_or({port});
+
+    let figment = rocket::Config::figment()
+        .merge(("address", "0.0.0.0"))
+        .merge(("port", port));
+
+    println!("{name} running on port {{}}", port);
+
+    rocket::custom(figment).mount("{base_path}", routes::routes())
+}}
+"#,
+            functions_mod = functions_mod_decl(project),
+            port = port,
+            name = name,
+            base_path = base_path
+        )
+    }
+
+    fn emit_project_config(&self, project: &ProjectIR) -> Vec<(String, String)> {
+        let mut files = Vec::new();
+
+        let name = project
+            .config
+            .get("name")
+            .and_then(|n| n.as_str())
+            .unwrap_or("rash-app");
+
+        let cargo_toml = format!(
+            r#"[package]
+name = "{name}"
+version = "0.1.0"
+edition = "2021"
+
+[[bin]]
+name = "{name}"
+path = "src/index.rs"
+
+[dependencies]
+rocket = {{ version = "0.5", features = ["json"] }}
+serde = {{ version = "1", features = ["derive"] }}
+serde_json = "1"
+chrono = {{ version = "0.4", features = ["serde"] }}
+sea-orm = {{ version = "1", features = ["sqlx-postgres", "runtime-tokio-rustls"] }}
+jsonwebtoken = "9"
+argon2 = "0.5"
+"#,
+            name = name
+        );
+        files.push(("Cargo.toml".to_string(), cargo_toml));
+        files.push((
+            "src/routes/endpoints.rs".to_string(),
+            self.emit_endpoints(project),
+        ));
+        files.push(app_error_file(Framework::Rocket, project));
+        files.extend(rust_module_files(project));
+
+        files
+    }
+
+    fn emit_domain_expr(
+        &self,
+        expr: &ExprIR,
+        emitter: &dyn LanguageEmitter,
+        ctx: &mut EmitContext,
+    ) -> Option<String> {
+        match expr {
+            ExprIR::HttpRespond(r) => {
+                let status = rocket_status(r.status);
+                Some(match r.body.as_deref() {
+                    // Object literals already build a `serde_json::Value`
+                    Some(b @ ExprIR::Object { .. }) => {
+                        let body = emitter.emit_expression(b, ctx);
+                        format!("({}, Json({}))", status, body)
+                    }
+                    Some(b) => {
+                        let body = emitter.emit_expression(b, ctx);
+                        format!("({}, Json(serde_json::json!({})))", status, body)
+                    }
+                    None => format!("({}, Json(serde_json::Value::Null))", status),
+                })
+            }
+            // Rocket handlers receive each input as a plain parameter
+            ExprIR::CtxGet { path } => {
+                let parts: Vec<&str> = path.splitn(2, '.').collect();
+                Some(match parts.as_slice() {
+                    ["params", rest] => format!("{}.clone()", rest),
+                    ["query", rest] => format!("query.get(\"{}\").cloned()", rest),
+                    ["headers", rest] => format!("headers.get(\"{}\").cloned()", rest),
+                    // Provided values arrive as handler parameters named by key
+                    ["state", rest] => {
+                        let mut fields = rest.split('.');
+                        let key = fields.next().unwrap_or(rest);
+                        let index: String = fields.map(|f| format!("[\"{}\"]", f)).collect();
+                        format!("{}{}.clone()", key, index)
+                    }
+                    [root] => format!("{}.clone()", root),
+                    [root, rest] => format!("{}[\"{}\"].clone()", root, rest),
+                    _ => path.replace('.', "_"),
+                })
+            }
+            _ => None,
+        }
+    }
+
+    fn handlers_return_error(&self) -> bool {
+        true
+    }
+
+    fn emit_middleware_def(
+        &self,
+        mw: &MiddlewareIR,
+        _emitter: &dyn LanguageEmitter,
+        _ctx: &mut EmitContext,
+    ) -> String {
+        let outcome = format!("{}Outcome", to_pascal_case(&mw.name));
+
+        let guards: Vec<String> = if mw.provides.is_empty() {
+            vec![emit_guard(&mw.name, None)]
+        } else {
+            mw.provides
+                .keys()
+                .map(|key| emit_guard(&mw.name, Some(key)))
+                .collect()
+        };
+
+        let run = if mw.compose.is_empty() {
+            format!(
+                "// TODO: implement {} middleware\npub async fn {}(req: &Request<'_>) -> Result<serde_json::Map<String, serde_json::Value>, Status> {{\n    Ok(serde_json::Map::new())\n}}",
+                mw.middleware_type, mw.name
+            )
+        } else {
+            emit_composed(mw)
+        };
+
+        format!(
+            r#"use rocket::http::Status;
+use rocket::request::{{FromRequest, Outcome, Request}};
+
+{run}
+
+/// Cached per request so that every guard below runs `{name}` once.
+struct {outcome}(Result<serde_json::Map<String, serde_json::Value>, Status>);
+
+async fn run_cached<'r>(
+    req: &'r Request<'_>,
+) -> &'r Result<serde_json::Map<String, serde_json::Value>, Status> {{
+    &req.local_cache_async(async {{ {outcome}({name}(req).await) }})
+        .await
+        .0
+}}
+
+{guards}"#,
+            run = run,
+            name = mw.name,
+            outcome = outcome,
+            guards = guards.join("\n\n")
+        )
+    }
+
+    fn ctx_access_pattern(&self) -> CtxAccessPattern {
+        CtxAccessPattern::Extractors
+    }
+
+    fn wrap_route_file(&self, imports: &str, route_blocks: &str, _ctx: &mut EmitContext) -> String {
+        let mut lines = Vec::new();
+        lines.push("use rocket::Route;".to_string());
+        if !imports.is_empty() {
+            lines.push(imports.to_string());
+        }
+        lines.push(String::new());
+        lines.push("pub fn routes() -> Vec<Route> {".to_string());
+        lines.push("    rocket::routes![".to_string());
+        for line in route_blocks.lines() {
+            if !line.is_empty() {
+                lines.push(format!("        {}", line));
+            }
+        }
+        lines.push("    ]".to_string());
+        lines.push("}".to_string());
+        lines.join("\n")
+    }
+
+    fn normalize_path(&self, path: &str) -> String {
+        // Rocket uses <param> instead of :param
+        convert_colon_params_to_angle_brackets(path)
+    }
+}
+
+impl RocketAdapter {
+    /// Generate `routes/endpoints.rs`: one attributed Rocket route per endpoint
+    /// that resolves middleware guards and forwards to the handler.
+    fn emit_endpoints(&self, project: &ProjectIR) -> String {
+        let global = config_global_middleware(&project.config);
+        let mut fns = Vec::new();
+        for route in &project.routes {
+            let path = self.normalize_path(&route.path);
+            for (method, endpoint) in &route.methods {
+                let method_lower = format!("{:?}", method).to_lowercase();
+                fns.push(self.emit_endpoint(project, &global, &method_lower, &path, endpoint));
+            }
+        }
+
+        format!(
+            r#"use std::collections::HashMap;
+use std::convert::Infallible;
+
+use rocket::http::Status;
+use rocket::request::{{FromRequest, Outcome, Request}};
+use rocket::serde::json::Json;
+
+use crate::error::AppError;
+
+/// Request headers keyed by lower-cased name.
+pub struct RequestHeaders(pub HashMap<String, String>);
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for RequestHeaders {{
+    type Error = Infallible;
+
+    async fn from_request(req: &'r Request<'_>) -> Outcome<Self, Self::Error> {{
+        let headers = req
+            .headers()
+            .iter()
+            .map(|h| (h.name().as_str().to_lowercase(), h.value().to_string()))
+            .collect();
+        Outcome::Success(RequestHeaders(headers))
+    }}
+}}
+
+{}
+"#,
+            fns.join("\n\n")
+        )
+    }
+
+    fn emit_endpoint(
+        &self,
+        project: &ProjectIR,
+        global: &[String],
+        method: &str,
+        path: &str,
+        endpoint: &EndpointIR,
+    ) -> String {
+        let handler = project
+            .handlers
+            .iter()
+            .find(|h| h.name == endpoint.handler_ref);
+        let inputs = handler.map(handler_inputs).unwrap_or_default();
+        let uses = |root: &str| inputs.iter().any(|(name, _)| name == root);
+
+        let path_params = rocket_path_params(path);
+        let mut params: Vec<String> = path_params
+            .iter()
+            .map(|p| format!("{}: String", p))
+            .collect();
+
+        // Guards resolve left to right: global middleware first, then the
+        // route's own in spec order
+        let mut seen = HashSet::new();
+        let mut provided: Vec<String> = Vec::new();
+        for mw_ref in global.iter().chain(endpoint.middleware.iter()) {
+            let mw_id = normalize_identifier(mw_ref);
+            if !seen.insert(mw_id.clone()) {
+                continue;
+            }
+            let Some(mw) = project
+                .middleware
+                .iter()
+                .find(|m| normalize_identifier(&m.name) == mw_id)
+            else {
+                continue;
+            };
+            if mw.provides.is_empty() {
+                params.push(plain_guard_param(&mw.name));
+                continue;
+            }
+            for key in mw.provides.keys() {
+                let param = if provided.contains(key) {
+                    format!("_{}_{}", to_snake_case(&mw_id), key)
+                } else {
+                    provided.push(key.clone());
+                    key.clone()
+                };
+                params.push(format!(
+                    "{}: crate::middleware::{}::{}",
+                    param,
+                    normalize_filename(&mw.name),
+                    guard_type(&mw.name, Some(key))
+                ));
+            }
+        }
+
+        let mut uri = path.to_string();
+        if uses("headers") {
+            params.push("headers: RequestHeaders".to_string());
+        }
+        if uses("query") {
+            params.push("query: HashMap<String, String>".to_string());
+            uri.push_str("?<query..>");
+        }
+        let mut data = "";
+        // The data guard consumes the request body, so it goes last
+        if uses("body") {
+            params.push("body: Json<serde_json::Value>".to_string());
+            data = ", data = \"<body>\"";
+        }
+
+        let args: Vec<String> = inputs
+            .iter()
+            .map(|(name, ty)| match name.as_str() {
+                "query" => "query".to_string(),
+                "headers" => "headers.0".to_string(),
+                "body" => "body.into_inner()".to_string(),
+                n if *ty == "String" && path_params.iter().any(|p| p == n) => n.to_string(),
+                _ if *ty == "String" => "String::new()".to_string(),
+                n if provided.iter().any(|p| p == n) => format!("{}.0", n),
+                _ => "serde_json::Value::Null".to_string(),
+            })
+            .collect();
+        let await_kw = if handler.is_some_and(|h| h.is_async) {
+            ".await"
+        } else {
+            ""
+        };
+
+        format!(
+            "#[rocket::{method}(\"{uri}\"{data})]\npub async fn {fn_name}({params}) -> Result<(Status, Json<serde_json::Value>), AppError> {{\n    crate::handlers::{file}::{handler_id}({args}){await_kw}\n}}",
+            method = method,
+            uri = uri,
+            data = data,
+            fn_name = endpoint_fn_name(endpoint),
+            params = params.join(", "),
+            file = normalize_filename(&endpoint.handler_ref),
+            handler_id = normalize_identifier(&endpoint.handler_ref),
+            args = args.join(", "),
+            await_kw = await_kw
+        )
+    }
+}
+
+/// Parameters a Rocket handler takes, derived from the `CtxGet` paths its body
+/// reads. Path params come first and the request body last; `state.<key>`
+/// and any root other than `params`, `query`, `headers` and `body` is a
+/// middleware-provided value.
+fn handler_inputs(handler: &HandlerIR) -> Vec<(String, &'static str)> {
+    let mut ctx_paths = Vec::new();
+    if let Ok(body) = serde_json::to_value(&handler.body) {
+        collect_ctx_paths(&body, &mut ctx_paths);
+    }
+
+    let mut path_params: Vec<String> = Vec::new();
+    let mut provided: Vec<String> = Vec::new();
+    let (mut query, mut headers, mut body) = (false, false, false);
+    for path in &ctx_paths {
+        let mut parts = path.splitn(2, '.');
+        match (parts.next().unwrap_or_default(), parts.next()) {
+            ("params", Some(name)) => {
+                if !path_params.iter().any(|p| p == name) {
+                    path_params.push(name.to_string());
+                }
+            }
+            ("params", None) => {}
+            ("query", _) => query = true,
+            ("headers", _) => headers = true,
+            ("body", _) => body = true,
+            // `state.<key>` is the value the `<key>` guard hands over
+            ("state", Some(rest)) => {
+                let key = rest.split('.').next().unwrap_or(rest);
+                if !provided.iter().any(|p| p == key) {
+                    provided.push(key.to_string());
+                }
+            }
+            ("state", None) => {}
+            (root, _) => {
+                if !provided.iter().any(|p| p == root) {
+                    provided.push(root.to_string());
+                }
+            }
+        }
+    }
+
+    let mut inputs: Vec<(String, &'static str)> =
+        path_params.into_iter().map(|p| (p, "String")).collect();
+    if query {
+        inputs.push(("query".to_string(), "HashMap<String, String>"));
+    }
+    if headers {
+        inputs.push(("headers".to_string(), "HashMap<String, String>"));
+    }
+    inputs.extend(provided.into_iter().map(|p| (p, "serde_json::Value")));
+    if body {
+        inputs.push(("body".to_string(), "serde_json::Value"));
+    }
+    inputs
+}
+
+/// Emit a `FromRequest` guard that runs its middleware through `run_cached`
+/// and, given a `key`, exposes that provided value.
+fn emit_guard(mw_name: &str, key: Option<&str>) -> String {
+    let type_name = guard_type(mw_name, key);
+    let (doc, decl, bind, success) = match key {
+        Some(key) => (
+            format!("/// `{}` provided by the `{}` middleware.", key, mw_name),
+            format!("pub struct {}(pub serde_json::Value);", type_name),
+            "provided",
+            format!(
+                "Outcome::Success(Self(provided.get(\"{}\").cloned().unwrap_or_default()))",
+                key
+            ),
+        ),
+        None => (
+            format!(
+                "/// Runs the `{}` middleware before the route handler.",
+                mw_name
+            ),
+            format!("pub struct {};", type_name),
+            "_",
+            "Outcome::Success(Self)".to_string(),
+        ),
+    };
+    format!(
+        r#"{doc}
+{decl}
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for {type_name} {{
+    type Error = ();
+
+    async fn from_request(req: &'r Request<'_>) -> Outcome<Self, Self::Error> {{
+        match run_cached(req).await {{
+            Ok({bind}) => {success},
+            Err(status) => Outcome::Error((*status, ())),
+        }}
+    }}
+}}"#,
+        doc = doc,
+        decl = decl,
+        type_name = type_name,
+        bind = bind,
+        success = success
+    )
+}
+
+/// A composed middleware runs each step and merges what they provide; a step
+/// that fails has responded with its status.
+fn emit_composed(mw: &MiddlewareIR) -> String {
+    let on_failure = if mw.short_circuit {
+        "        // Stop at the first step that responds\n        Err(status) => return Err(status),"
+    } else {
+        "        Err(status) => {\n            failure.get_or_insert(status);\n        }"
+    };
+    let steps: String = mw
+        .compose
+        .iter()
+        .map(|step| {
+            format!(
+                "\n    match crate::middleware::{}::{}(req).await {{\n        Ok(values) => provided.extend(values),\n{}\n    }}",
+                normalize_filename(step),
+                normalize_identifier(step),
+                on_failure
+            )
+        })
+        .collect();
+    let (failure_decl, result) = if mw.short_circuit {
+        ("", "Ok(provided)")
+    } else {
+        (
+            "\n    let mut failure = None;",
+            "match failure {\n        Some(status) => Err(status),\n        None => Ok(provided),\n    }",
+        )
+    };
+    format!(
+        "// Runs {} in order\npub async fn {}(req: &Request<'_>) -> Result<serde_json::Map<String, serde_json::Value>, Status> {{\n    let mut provided = serde_json::Map::new();{}{}\n    {}\n}}",
+        mw.compose.join(", "),
+        mw.name,
+        failure_decl,
+        steps,
+        result
+    )
+}
+
+/// Request guard parameter that only runs a middleware providing nothing.
+fn plain_guard_param(mw_ref: &str) -> String {
+    format!(
+        "_{}: crate::middleware::{}::{}",
+        to_snake_case(&normalize_identifier(mw_ref)),
+        normalize_filename(mw_ref),
+        guard_type(mw_ref, None)
+    )
+}
+
+/// Name of the guard exposing `key` from a middleware, or the plain guard that
+/// only runs it when the middleware provides nothing.
+fn guard_type(mw_name: &str, key: Option<&str>) -> String {
+    let base = to_pascal_case(&normalize_identifier(mw_name));
+    match key {
+        Some(key) => format!("{}{}", base, to_pascal_case(key)),
+        None => format!("{}Guard", base),
+    }
+}
+
+/// Rocket route functions are named after the endpoint's operation id.
+fn endpoint_fn_name(endpoint: &EndpointIR) -> String {
+    to_snake_case(&endpoint.operation_id)
+}
+
+/// Names of the `<param>` segments in a Rocket path.
+fn rocket_path_params(path: &str) -> Vec<String> {
+    path.split('/')
+        .filter_map(|seg| seg.strip_prefix('<').and_then(|s| s.strip_suffix('>')))
+        .map(|s| s.to_string())
+        .collect()
+}
+
+fn to_pascal_case(s: &str) -> String {
+    s.split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(|w| {
+            let mut chars = w.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+fn to_snake_case(s: &str) -> String {
+    let mut result = String::new();
+    for (i, ch) in s.chars().enumerate() {
+        if ch.is_uppercase() && i > 0 {
+            result.push('_');
+        }
+        result.push(ch.to_lowercase().next().unwrap_or(ch));
+    }
+    result
+}
+
+/// Map an HTTP status to the `rocket::http::Status` constant expression.
+fn rocket_status(status: u16) -> String {
+    let name = match status {
+        200 => "Ok",
+        201 => "Created",
+        202 => "Accepted",
+        204 => "NoContent",
+        400 => "BadRequest",
+        401 => "Unauthorized",
+        403 => "Forbidden",
+        404 => "NotFound",
+        409 => "Conflict",
+        422 => "UnprocessableEntity",
+        500 => "InternalServerError",
+        _ => return format!("Status::new({})", status),
+    };
+    format!("Status::{}", name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::rc::Rc;
+    use crate::context::IndentStyle;
+    use indexmap::IndexMap;
+    use rash_ir::expr::TypeIR;
+    use rash_ir::statement::StatementIR;
+    use rash_ir::types::RequestIR;
+    use rash_spec::types::common::{HttpMethod, Tier};
+
+    fn endpoint(operation_id: &str, handler_ref: &str, middleware: &[&str]) -> EndpointIR {
+        EndpointIR {
+            operation_id: operation_id.to_string(),
+            summary: None,
+            handler_ref: handler_ref.to_string(),
+            middleware: middleware.iter().map(|m| m.to_string()).collect(),
+            provides: IndexMap::new(),
+            request: RequestIR {
+                query_schema: None,
+                body_schema: None,
+                content_type: None,
+            },
+            response: IndexMap::new(),
+        }
+    }
+
+    fn handler(name: &str, paths: &[&str]) -> HandlerIR {
+        HandlerIR {
+            name: name.to_string(),
+            is_async: true,
+            params: vec![],
+            return_type: TypeIR::Any,
+            body: paths
+                .iter()
+                .map(|p| StatementIR::Expression {
+                    expr: ExprIR::CtxGet {
+                        path: p.to_string(),
+                    },
+                })
+                .collect(),
+            max_tier: Tier::Domain,
+            bridge_languages: HashSet::new(),
+        }
+    }
+
+    #[test]
+    fn test_emit_route_registration() {
+        let adapter = RocketAdapter;
+        let emitter = crate::emitters::rust_lang::RustEmitter;
+        let mut ctx = EmitContext::new(IndentStyle::Spaces(4));
+
+        let route = RouteIR {
+            path: "/users/<id>".to_string(),
+            methods: {
+                let mut m = IndexMap::new();
+                m.insert(HttpMethod::Get, endpoint("getUser", "users.getUser", &[]));
+                m.insert(
+                    HttpMethod::Delete,
+                    endpoint("deleteUser", "users.deleteUser", &[]),
+                );
+                m
+            },
+            tags: vec![],
+        };
+
+        let code = adapter.emit_route_registration(&route, &emitter, &mut ctx);
+        assert_eq!(
+            code,
+            "crate::endpoints::get_user,\ncrate::endpoints::delete_user,"
+        );
+        assert_eq!(adapter.normalize_path("/users/:id"), "/users/<id>");
+    }
+
+    #[test]
+    fn test_emit_handler_inputs() {
+        let adapter = RocketAdapter;
+        let emitter = crate::emitters::rust_lang::RustEmitter;
+        let mut ctx = EmitContext::new(IndentStyle::Spaces(4)).with_adapter(Rc::new(RocketAdapter));
+
+        let handler = handler(
+            "updatePost",
+            &["body.title", "user.id", "params.id", "headers.x-trace"],
+        );
+        let code = adapter.emit_handler(&handler, &emitter, &mut ctx);
+        assert!(code.starts_with(
+            "pub async fn updatePost(id: String, headers: HashMap<String, String>, user: serde_json::Value, body: serde_json::Value) -> Result<(Status, Json<serde_json::Value>), AppError> {"
+        ));
+        assert!(code.contains("user[\"id\"].clone()"));
+    }
+
+    #[test]
+    fn test_endpoint_guards_from_middleware_provides() {
+        let adapter = RocketAdapter;
+        let mut provides = IndexMap::new();
+        provides.insert("user".to_string(), serde_json::json!({ "type": "object" }));
+        let middleware = |name: &str, provides: IndexMap<String, serde_json::Value>| MiddlewareIR {
+            name: name.to_string(),
+            middleware_type: "request".to_string(),
+            handler_ref: None,
+            provides,
+            compose: vec![],
+            short_circuit: false,
+        };
+
+        let project = ProjectIR {
+            config: serde_json::json!({ "middleware": { "global": [{ "ref": "cors" }] } }),
+            routes: vec![RouteIR {
+                path: "/posts/:id".to_string(),
+                methods: {
+                    let mut m = IndexMap::new();
+                    m.insert(
+                        HttpMethod::Put,
+                        endpoint("updatePost", "posts.update", &["auth"]),
+                    );
+                    m
+                },
+                tags: vec![],
+            }],
+            schemas: vec![],
+            models: vec![],
+            middleware: vec![
+                middleware("auth", provides),
+                middleware("cors", IndexMap::new()),
+            ],
+            handlers: vec![handler("posts.update", &["params.id", "user", "body"])],
+            functions: vec![],
+        };
+
+        let endpoints = adapter.emit_endpoints(&project);
+        assert!(endpoints.contains("#[rocket::put(\"/posts/<id>\", data = \"<body>\")]"));
+        assert!(endpoints.contains(
+            "pub async fn update_post(id: String, _cors: crate::middleware::cors::CorsGuard, user: crate::middleware::auth::AuthUser, body: Json<serde_json::Value>)"
+        ));
+        assert!(endpoints.contains(
+            "crate::handlers::posts_update::postsUpdate(id, user.0, body.into_inner()).await"
+        ));
+    }
+
+    #[test]
+    fn test_emit_middleware_def_guards() {
+        let adapter = RocketAdapter;
+        let emitter = crate::emitters::rust_lang::RustEmitter;
+        let mut ctx = EmitContext::new(IndentStyle::Spaces(4));
+
+        let mut provides = IndexMap::new();
+        provides.insert("user".to_string(), serde_json::json!({}));
+        provides.insert("session".to_string(), serde_json::json!({}));
+        let mw = MiddlewareIR {
+            name: "auth".to_string(),
+            middleware_type: "request".to_string(),
+            handler_ref: None,
+            provides,
+            compose: vec![],
+            short_circuit: false,
+        };
+
+        let code = adapter.emit_middleware_def(&mw, &emitter, &mut ctx);
+        assert!(code.contains("pub async fn auth(req: &Request<'_>)"));
+        assert!(code.contains("struct AuthOutcome("));
+        assert!(code.contains("impl<'r> FromRequest<'r> for AuthUser"));
+        assert!(code.contains("impl<'r> FromRequest<'r> for AuthSession"));
+        assert!(code.contains("provided.get(\"session\")"));
+        assert!(!code.contains("AuthGuard"));
+    }
+
+    #[test]
+    fn test_emit_entrypoint() {
+        let adapter = RocketAdapter;
+        let project = ProjectIR {
+            config: serde_json::json!({
+                "name": "test-app",
+                "server": { "port": 9090, "basePath": "/api" }
+            }),
+            routes: vec![],
+            schemas: vec![],
+            models: vec![],
+            middleware: vec![],
+            handlers: vec![],
+            functions: vec![],
+        };
+
+        let mut ctx = EmitContext::new(IndentStyle::Spaces(4));
+        let code = adapter.emit_entrypoint(&project, &mut ctx);
+        assert!(code.contains("#[rocket::launch]"));
+        assert!(code.contains("unwrap_or(9090)"));
+        assert!(code.contains(".mount(\"/api\", routes::routes())"));
+        assert!(code.contains("#[path = \"routes/endpoints.rs\"]\nmod endpoints;"));
+    }
+
+    #[test]
+    fn test_emit_rocket_respond_and_ctx_get() {
+        let emitter = crate::emitters::rust_lang::RustEmitter;
+        let mut ctx = EmitContext::new(IndentStyle::Spaces(4)).with_adapter(Rc::new(RocketAdapter));
+
+        let respond = ExprIR::HttpRespond(rash_ir::expr::HttpRespondIR {
+            status: 404,
+            headers: None,
+            body: Some(Box::new(ExprIR::ident("err"))),
+        });
+        assert_eq!(
+            emitter.emit_expression(&respond, &mut ctx),
+            "(Status::NotFound, Json(serde_json::json!(err)))"
+        );
+
+        let get = |path: &str| ExprIR::CtxGet {
+            path: path.to_string(),
+        };
+        assert_eq!(emitter.emit_expression(&get("params.id"), &mut ctx), "id.clone()");
+        assert_eq!(emitter.emit_expression(&get("user"), &mut ctx), "user.clone()");
+        assert_eq!(
+            emitter.emit_expression(&get("user.role"), &mut ctx),
+            "user[\"role\"].clone()"
+        );
+        assert_eq!(
+            emitter.emit_expression(&get("state.user.role"), &mut ctx),
+            "user[\"role\"].clone()"
+        );
+    }
+}
@@ -1,4 +1,11 @@
-use indexmap::IndexSet;
+use std::rc::Rc;
+
+use indexmap::{IndexMap, IndexSet};
+use rash_ir::expr::{ExprIR, TypeIR};
+use rash_ir::types::FunctionIR;
+use rash_spec::types::common::Orm;
+
+use crate::traits::{FrameworkAdapter, LanguageEmitter};
 
 /// Tracks state during code emission: indentation, collected imports, etc.
 #[derive(Debug, Clone)]
@@ -11,6 +18,30 @@ pub struct EmitContext {
     use_tabs: bool,
     /// Collected import paths (deduped, insertion-ordered)
     imports: IndexSet<ImportIR>,
+    /// Target framework adapter, when emitting inside a framework project
+    adapter: Option<AdapterRef>,
+    /// Target ORM, taken from the project's database config
+    orm: Option<Orm>,
+    /// Nesting depth of `try` bodies, for emitters that lower exceptions to `Result`
+    try_depth: usize,
+    /// Nesting depth of database transactions, so DB calls use the transaction handle
+    transaction_depth: usize,
+    /// Parameter defaults of the project's user functions, keyed by function name
+    function_defaults: IndexMap<String, Vec<Option<serde_json::Value>>>,
+    /// Types of the middleware-provided values the current handler can read
+    provided: IndexMap<String, TypeIR>,
+    /// What the endpoint routed to the current handler accepts
+    request: Option<HandlerRequest>,
+}
+
+/// Shared handle to the target framework adapter.
+#[derive(Clone)]
+struct AdapterRef(Rc<dyn FrameworkAdapter>);
+
+impl std::fmt::Debug for AdapterRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.0.framework())
+    }
 }
 
 /// Represents a single import statement to be collected.
@@ -22,6 +53,27 @@ pub struct ImportIR {
     pub from: String,
 }
 
+/// What the endpoint routed to a handler accepts, for adapters that type the
+/// handler's request extractors.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HandlerRequest {
+    /// Path parameter names, in path order
+    pub path_params: Vec<String>,
+    /// Schema of the query string
+    pub query_schema: Option<SchemaRef>,
+    /// Schema of the request body
+    pub body_schema: Option<SchemaRef>,
+}
+
+/// A definition in one of the project's schema files.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaRef {
+    /// Schema file the definition is generated into, `src/schemas/{module}`
+    pub module: String,
+    /// Definition name (e.g., "CreateUserBody")
+    pub name: String,
+}
+
 /// Indentation style configuration.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum IndentStyle {
@@ -40,9 +92,130 @@ impl EmitContext {
             indent_width,
             use_tabs,
             imports: IndexSet::new(),
+            adapter: None,
+            orm: None,
+            try_depth: 0,
+            transaction_depth: 0,
+            function_defaults: IndexMap::new(),
+            provided: IndexMap::new(),
+            request: None,
         }
     }
 
+    /// Attach the target framework adapter, which emitters consult for
+    /// framework-specific expressions.
+    pub fn with_adapter(mut self, adapter: Rc<dyn FrameworkAdapter>) -> Self {
+        self.adapter = Some(AdapterRef(adapter));
+        self
+    }
+
+    /// Let the attached adapter emit `expr` (see
+    /// [`FrameworkAdapter::emit_domain_expr`]). Returns `None` when no adapter
+    /// is attached or it leaves `expr` to the language emitter.
+    pub fn emit_domain_expr(
+        &mut self,
+        expr: &ExprIR,
+        emitter: &dyn LanguageEmitter,
+    ) -> Option<String> {
+        let adapter = self.adapter.clone()?;
+        adapter.0.emit_domain_expr(expr, emitter, self)
+    }
+
+    /// Whether handlers of the attached framework return an error value
+    /// (see [`FrameworkAdapter::handlers_return_error`]).
+    pub fn handlers_return_error(&self) -> bool {
+        self.adapter
+            .as_ref()
+            .is_some_and(|a| a.0.handlers_return_error())
+    }
+
+    /// Attach the target ORM so emitters can pick ORM-specific model and query forms.
+    pub fn with_orm(mut self, orm: Orm) -> Self {
+        self.orm = Some(orm);
+        self
+    }
+
+    /// Target ORM, if one was attached.
+    pub fn orm(&self) -> Option<Orm> {
+        self.orm
+    }
+
+    /// Attach the project's user functions so calls can be padded with parameter
+    /// defaults in languages without default arguments.
+    pub fn with_functions(mut self, functions: &[FunctionIR]) -> Self {
+        self.function_defaults = functions
+            .iter()
+            .map(|f| {
+                let defaults = f.params.iter().map(|p| p.default.clone()).collect();
+                (f.name.clone(), defaults)
+            })
+            .collect();
+        self
+    }
+
+    /// Parameter defaults of a user function, in declaration order.
+    pub fn function_defaults(&self, name: &str) -> Option<&[Option<serde_json::Value>]> {
+        self.function_defaults.get(name).map(|d| d.as_slice())
+    }
+
+    /// Attach the values middleware provides to the handler being emitted,
+    /// read as `state.<name>`.
+    pub fn with_provided(mut self, provided: IndexMap<String, TypeIR>) -> Self {
+        self.provided = provided;
+        self
+    }
+
+    /// Type of a middleware-provided value, if the handler's middleware provides it.
+    pub fn provided_type(&self, name: &str) -> Option<&TypeIR> {
+        self.provided.get(name)
+    }
+
+    /// Attach what the endpoint routed to the handler being emitted accepts.
+    pub fn with_request(mut self, request: HandlerRequest) -> Self {
+        self.request = Some(request);
+        self
+    }
+
+    /// What the current handler's endpoint accepts, if the handler is routed.
+    pub fn request(&self) -> Option<&HandlerRequest> {
+        self.request.as_ref()
+    }
+
+    /// Enter a `try` body.
+    pub fn enter_try(&mut self) {
+        self.try_depth += 1;
+    }
+
+    /// Leave a `try` body.
+    pub fn exit_try(&mut self) {
+        self.try_depth = self.try_depth.saturating_sub(1);
+    }
+
+    /// Whether emission is currently inside a `try` body.
+    pub fn in_try(&self) -> bool {
+        self.try_depth > 0
+    }
+
+    /// Enter a database transaction body.
+    pub fn enter_transaction(&mut self) {
+        self.transaction_depth += 1;
+    }
+
+    /// Leave a database transaction body.
+    pub fn exit_transaction(&mut self) {
+        self.transaction_depth = self.transaction_depth.saturating_sub(1);
+    }
+
+    /// Whether emission is currently inside a database transaction.
+    pub fn in_transaction(&self) -> bool {
+        self.transaction_depth > 0
+    }
+
+    /// Number of enclosing database transactions.
+    pub fn transaction_depth(&self) -> usize {
+        self.transaction_depth
+    }
+
     /// Get the current indentation string.
     pub fn indent(&self) -> String {
         let unit = if self.use_tabs { "\t" } else { " " };
@@ -72,6 +245,11 @@ impl EmitContext {
         &self.imports
     }
 
+    /// Keep only the collected imports matching the predicate.
+    pub fn retain_imports(&mut self, keep: impl FnMut(&ImportIR) -> bool) {
+        self.imports.retain(keep);
+    }
+
     /// Drain and return all collected imports.
     pub fn take_imports(&mut self) -> IndexSet<ImportIR> {
         std::mem::take(&mut self.imports)
@@ -1,10 +1,13 @@
+use rash_spec::types::ast::IsolationLevel;
 use rash_spec::types::common::Language;
 
 use crate::context::{EmitContext, IndentStyle};
+use crate::emitters::{omitted_defaults, statements_return};
+use crate::generator::normalize_identifier;
 use crate::traits::LanguageEmitter;
-use rash_ir::expr::{ExprIR, TemplatePartIR, TypeIR};
+use rash_ir::expr::{ExprIR, FilterIR, FilterOp, IncludeIR, SortDirection, TemplatePartIR, TypeIR};
 use rash_ir::statement::StatementIR;
-use rash_ir::types::{ModelIR, SchemaIR};
+use rash_ir::types::{FunctionIR, ModelIR, SchemaIR};
 
 /// Go language emitter.
 pub struct GoEmitter;
@@ -28,7 +31,32 @@ impl LanguageEmitter for GoEmitter {
                     self.emit_expression(value, ctx)
                 )
             }
+            // The transaction closure returns `error`, so a handler return is
+            // flagged and repeated once the transaction commits.
+            StatementIR::Return { value } if ctx.in_transaction() => {
+                let mut lines = Vec::new();
+                let ret = match value {
+                    Some(v) if ctx.handlers_return_error() => {
+                        format!("return {}", self.emit_expression(v, ctx))
+                    }
+                    Some(v @ ExprIR::HttpRespond(_)) => {
+                        lines.push(format!("{}{}", indent, self.emit_expression(v, ctx)));
+                        "return nil".to_string()
+                    }
+                    _ => "return nil".to_string(),
+                };
+                lines.push(format!("{}txResponded = true", indent));
+                lines.push(format!("{}{}", indent, ret));
+                lines.join("\n")
+            }
             StatementIR::Return { value } => match value {
+                // Gin handlers return nothing, so the response is written first.
+                Some(v @ ExprIR::HttpRespond(_)) if !ctx.handlers_return_error() => format!(
+                    "{}{}\n{}return",
+                    indent,
+                    self.emit_expression(v, ctx),
+                    indent
+                ),
                 Some(v) => format!("{}return {}", indent, self.emit_expression(v, ctx)),
                 None => format!("{}return", indent),
             },
@@ -124,6 +152,61 @@ impl LanguageEmitter for GoEmitter {
                 ));
                 lines.join("\n")
             }
+            // GORM rolls back when the closure returns an error or panics; a
+            // nested Transaction call runs in a SAVEPOINT.
+            StatementIR::Transaction { body, isolation } => {
+                ctx.add_import("gorm", "gorm.io/gorm");
+                let outermost = !ctx.in_transaction();
+                let returns = statements_return(body);
+                let handle = gorm_handle(ctx);
+                let mut lines = Vec::new();
+                if returns && outermost {
+                    lines.push(format!("{}txResponded := false", indent));
+                }
+                lines.push(format!(
+                    "{}if err := {}.Transaction(func(tx *gorm.DB) error {{",
+                    indent, handle
+                ));
+                ctx.enter_transaction();
+                ctx.push_indent();
+                for s in body {
+                    lines.push(self.emit_statement(s, ctx));
+                }
+                if !matches!(body.last(), Some(StatementIR::Return { .. })) {
+                    lines.push(format!("{}return nil", ctx.indent()));
+                }
+                ctx.pop_indent();
+                ctx.exit_transaction();
+                let options = match isolation {
+                    Some(level) => {
+                        ctx.add_import("sql", "database/sql");
+                        format!(", &sql.TxOptions{{Isolation: {}}}", gorm_isolation_level(*level))
+                    }
+                    None => String::new(),
+                };
+                lines.push(format!("{}}}{}); err != nil {{", indent, options));
+                let on_error = if !outermost || ctx.handlers_return_error() {
+                    "return err"
+                } else {
+                    "panic(err)"
+                };
+                ctx.push_indent();
+                let inner = ctx.indent();
+                ctx.pop_indent();
+                lines.push(format!("{}{}", inner, on_error));
+                lines.push(format!("{}}}", indent));
+                if returns {
+                    let leave = if !outermost || ctx.handlers_return_error() {
+                        "return nil"
+                    } else {
+                        "return"
+                    };
+                    lines.push(format!("{}if txResponded {{", indent));
+                    lines.push(format!("{}{}", inner, leave));
+                    lines.push(format!("{}}}", indent));
+                }
+                lines.join("\n")
+            }
             StatementIR::Throw { value } => {
                 format!(
                     "{}panic({})",
@@ -131,6 +214,12 @@ impl LanguageEmitter for GoEmitter {
                     self.emit_expression(value, ctx)
                 )
             }
+            // Components are expanded inline at the use site
+            StatementIR::Component { body, .. } => body
+                .iter()
+                .map(|s| self.emit_statement(s, ctx))
+                .collect::<Vec<_>>()
+                .join("\n"),
             StatementIR::Expression { expr } => {
                 format!("{}{}", indent, self.emit_expression(expr, ctx))
             }
@@ -138,6 +227,9 @@ impl LanguageEmitter for GoEmitter {
     }
 
     fn emit_expression(&self, expr: &ExprIR, ctx: &mut EmitContext) -> String {
+        if let Some(code) = ctx.emit_domain_expr(expr, self) {
+            return code;
+        }
         match expr {
             ExprIR::Literal { value } => emit_go_literal(value),
             ExprIR::Identifier { name } => name.clone(),
@@ -170,6 +262,13 @@ impl LanguageEmitter for GoEmitter {
                     args_str.join(", ")
                 )
             }
+            ExprIR::CallFunction { function, args } => {
+                let mut args_str: Vec<String> =
+                    args.iter().map(|a| self.emit_expression(a, ctx)).collect();
+                // Go has no default arguments, so omitted ones are filled in here
+                args_str.extend(omitted_defaults(ctx, function, args.len()).iter().map(emit_go_literal));
+                format!("{}({})", normalize_identifier(function), args_str.join(", "))
+            }
             ExprIR::Member { object, property } => {
                 format!(
                     "{}.{}",
@@ -254,12 +353,20 @@ impl LanguageEmitter for GoEmitter {
             // Domain (Tier 1)
             ExprIR::DbQuery(q) => {
                 // GORM-style query
-                let mut chain = format!("db.Model(&{}{{}})", q.model);
+                let mut chain = format!("{}.Model(&{}{{}})", gorm_handle(ctx), q.model);
                 if let Some(w) = &q.r#where {
-                    chain.push_str(&format!(".Where({})", emit_go_literal(w)));
+                    chain.push_str(&self.emit_gorm_where(w, ctx));
                 }
-                if let Some(ob) = &q.order_by {
-                    chain.push_str(&format!(".Order({})", emit_go_literal(ob)));
+                if !q.order_by.is_empty() {
+                    let keys: Vec<String> = q
+                        .order_by
+                        .iter()
+                        .map(|o| match o.direction {
+                            SortDirection::Desc => format!("{} DESC", to_snake_case(&o.field)),
+                            SortDirection::Asc => to_snake_case(&o.field),
+                        })
+                        .collect();
+                    chain.push_str(&format!(".Order(\"{}\")", keys.join(", ")));
                 }
                 if let Some(skip) = &q.skip {
                     chain.push_str(&format!(".Offset({})", self.emit_expression(skip, ctx)));
@@ -267,6 +374,11 @@ impl LanguageEmitter for GoEmitter {
                 if let Some(take) = &q.take {
                     chain.push_str(&format!(".Limit({})", self.emit_expression(take, ctx)));
                 }
+                let mut preloads = Vec::new();
+                gorm_preload_paths(&q.include, "", &mut preloads);
+                for path in preloads {
+                    chain.push_str(&format!(".Preload(\"{}\")", path));
+                }
                 match q.operation.as_str() {
                     "findUnique" | "findFirst" => chain.push_str(".First(&result)"),
                     "findMany" => chain.push_str(".Find(&results)"),
@@ -280,17 +392,18 @@ impl LanguageEmitter for GoEmitter {
                     "create" => {
                         if let Some(data) = &m.data {
                             format!(
-                                "db.Create({})",
+                                "{}.Create({})",
+                                gorm_handle(ctx),
                                 self.emit_expression(data, ctx)
                             )
                         } else {
-                            format!("db.Create(&{}{{}})", m.model)
+                            format!("{}.Create(&{}{{}})", gorm_handle(ctx), m.model)
                         }
                     }
                     "update" => {
-                        let mut chain = format!("db.Model(&{}{{}})", m.model);
+                        let mut chain = format!("{}.Model(&{}{{}})", gorm_handle(ctx), m.model);
                         if let Some(w) = &m.r#where {
-                            chain.push_str(&format!(".Where({})", emit_go_literal(w)));
+                            chain.push_str(&self.emit_gorm_where(w, ctx));
                         }
                         if let Some(data) = &m.data {
                             chain.push_str(&format!(
@@ -301,13 +414,14 @@ impl LanguageEmitter for GoEmitter {
                         chain
                     }
                     "delete" => {
-                        let mut chain = format!("db.Delete(&{}{{}})", m.model);
-                        if let Some(w) = &m.r#where {
-                            chain.push_str(&format!(".Where({})", emit_go_literal(w)));
-                        }
-                        chain
+                        let filter = m
+                            .r#where
+                            .as_ref()
+                            .map(|w| self.emit_gorm_where(w, ctx))
+                            .unwrap_or_default();
+                        format!("{}{}.Delete(&{}{{}})", gorm_handle(ctx), filter, m.model)
                     }
-                    _ => format!("db.{}", to_pascal_case(&m.operation)),
+                    _ => format!("{}.{}", gorm_handle(ctx), to_pascal_case(&m.operation)),
                 }
             }
             ExprIR::HttpRespond(r) => {
@@ -428,6 +542,27 @@ impl LanguageEmitter for GoEmitter {
         lines.join("\n")
     }
 
+    fn emit_function(&self, func: &FunctionIR, ctx: &mut EmitContext) -> String {
+        let params: Vec<String> = func
+            .params
+            .iter()
+            .map(|p| format!("{} {}", p.name, self.emit_type(&p.type_ir)))
+            .collect();
+        let ret = self.emit_type(&func.return_type);
+        let mut lines = vec![if ret.is_empty() {
+            format!("func {}({}) {{", normalize_identifier(&func.name), params.join(", "))
+        } else {
+            format!("func {}({}) {} {{", normalize_identifier(&func.name), params.join(", "), ret)
+        }];
+        ctx.push_indent();
+        for stmt in &func.body {
+            lines.push(self.emit_statement(stmt, ctx));
+        }
+        ctx.pop_indent();
+        lines.push("}".to_string());
+        lines.join("\n")
+    }
+
     fn emit_imports(&self, ctx: &mut EmitContext) -> String {
         let imports = ctx.take_imports();
         if imports.is_empty() {
@@ -450,6 +585,107 @@ impl LanguageEmitter for GoEmitter {
     }
 }
 
+impl GoEmitter {
+    /// Compile a filter into a GORM `.Where("sql", args...)` call.
+    fn emit_gorm_where(&self, filter: &FilterIR, ctx: &mut EmitContext) -> String {
+        let mut args = Vec::new();
+        let sql = match filter {
+            // A top-level AND needs no parentheses.
+            FilterIR::And { filters } => filters
+                .iter()
+                .map(|f| self.gorm_filter_sql(f, &mut args, ctx))
+                .collect::<Vec<_>>()
+                .join(" AND "),
+            f => self.gorm_filter_sql(f, &mut args, ctx),
+        };
+        let mut call = format!(".Where(\"{}\"", sql);
+        for arg in args {
+            call.push_str(", ");
+            call.push_str(&arg);
+        }
+        call.push(')');
+        call
+    }
+
+    /// SQL fragment for a filter, pushing its `?` placeholder arguments onto `args`.
+    fn gorm_filter_sql(
+        &self,
+        filter: &FilterIR,
+        args: &mut Vec<String>,
+        ctx: &mut EmitContext,
+    ) -> String {
+        match filter {
+            FilterIR::Condition { field, op, value } => {
+                let column = to_snake_case(field);
+                let is_null = matches!(
+                    value.as_ref(),
+                    ExprIR::Literal {
+                        value: serde_json::Value::Null
+                    }
+                );
+                match op {
+                    FilterOp::Eq if is_null => return format!("{} IS NULL", column),
+                    FilterOp::Ne if is_null => return format!("{} IS NOT NULL", column),
+                    _ => {}
+                }
+                let value = self.emit_expression(value, ctx);
+                let (sql, arg) = match op {
+                    FilterOp::Eq => ("=", value),
+                    FilterOp::Ne => ("<>", value),
+                    FilterOp::Gt => (">", value),
+                    FilterOp::Gte => (">=", value),
+                    FilterOp::Lt => ("<", value),
+                    FilterOp::Lte => ("<=", value),
+                    FilterOp::In => ("IN", value),
+                    FilterOp::NotIn => ("NOT IN", value),
+                    FilterOp::Contains => ("LIKE", format!("\"%\" + {} + \"%\"", value)),
+                    FilterOp::StartsWith => ("LIKE", format!("{} + \"%\"", value)),
+                    FilterOp::EndsWith => ("LIKE", format!("\"%\" + {}", value)),
+                };
+                args.push(arg);
+                format!("{} {} ?", column, sql)
+            }
+            FilterIR::And { filters } | FilterIR::Or { filters } => {
+                let sep = if matches!(filter, FilterIR::And { .. }) { " AND " } else { " OR " };
+                let parts: Vec<String> = filters
+                    .iter()
+                    .map(|f| self.gorm_filter_sql(f, args, ctx))
+                    .collect();
+                format!("({})", parts.join(sep))
+            }
+            FilterIR::Not { filter } => format!("NOT ({})", self.gorm_filter_sql(filter, args, ctx)),
+        }
+    }
+}
+
+/// Flatten nested includes into GORM preload paths (`Profile.User`).
+fn gorm_preload_paths(include: &[IncludeIR], prefix: &str, out: &mut Vec<String>) {
+    for i in include {
+        let path = format!("{}{}", prefix, to_pascal_case(&to_snake_case(&i.relation)));
+        out.push(path.clone());
+        gorm_preload_paths(&i.include, &format!("{}.", path), out);
+    }
+}
+
+/// GORM handle for DB calls: the transaction's `tx` inside a transaction.
+fn gorm_handle(ctx: &EmitContext) -> &'static str {
+    if ctx.in_transaction() {
+        "tx"
+    } else {
+        "db"
+    }
+}
+
+/// `database/sql` isolation level constant.
+fn gorm_isolation_level(level: IsolationLevel) -> &'static str {
+    match level {
+        IsolationLevel::ReadUncommitted => "sql.LevelReadUncommitted",
+        IsolationLevel::ReadCommitted => "sql.LevelReadCommitted",
+        IsolationLevel::RepeatableRead => "sql.LevelRepeatableRead",
+        IsolationLevel::Serializable => "sql.LevelSerializable",
+    }
+}
+
 fn emit_go_literal(value: &serde_json::Value) -> String {
     match value {
         serde_json::Value::Null => "nil".to_string(),
@@ -562,6 +798,9 @@ fn json_schema_to_go_type(schema: &serde_json::Value) -> &str {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::rc::Rc;
+
+    use crate::adapters::{echo::EchoAdapter, fiber::FiberAdapter, gin::GinAdapter};
     use crate::context::IndentStyle;
     use rash_ir::expr::DbQueryIR;
 
@@ -589,15 +828,211 @@ mod tests {
             model: "User".to_string(),
             operation: "findMany".to_string(),
             r#where: None,
-            order_by: None,
+            order_by: vec![],
             skip: None,
             take: None,
             select: None,
-            include: None,
+            include: vec![],
         });
 
         let code = emitter.emit_expression(&expr, &mut ctx);
         assert!(code.contains("db.Model(&User{})"));
         assert!(code.contains(".Find(&results)"));
     }
+
+    #[test]
+    fn test_emit_gorm_filter() {
+        use rash_ir::expr::{DbMutateIR, FilterIR, FilterOp, IncludeIR, OrderByIR, SortDirection};
+
+        let emitter = GoEmitter;
+        let mut ctx = EmitContext::new(IndentStyle::Tabs);
+
+        let expr = ExprIR::DbQuery(DbQueryIR {
+            model: "User".to_string(),
+            operation: "findMany".to_string(),
+            r#where: Some(FilterIR::And {
+                filters: vec![
+                    FilterIR::Condition {
+                        field: "email".to_string(),
+                        op: FilterOp::Contains,
+                        value: Box::new(ExprIR::ident("term")),
+                    },
+                    FilterIR::Or {
+                        filters: vec![
+                            FilterIR::eq("role", ExprIR::Literal { value: serde_json::json!("admin") }),
+                            FilterIR::eq("deletedAt", ExprIR::Literal { value: serde_json::Value::Null }),
+                        ],
+                    },
+                ],
+            }),
+            order_by: vec![OrderByIR {
+                field: "createdAt".to_string(),
+                direction: SortDirection::Desc,
+            }],
+            skip: None,
+            take: None,
+            select: None,
+            include: vec![IncludeIR {
+                relation: "profile".to_string(),
+                include: vec![IncludeIR {
+                    relation: "user".to_string(),
+                    include: vec![],
+                }],
+            }],
+        });
+        assert_eq!(
+            emitter.emit_expression(&expr, &mut ctx),
+            "db.Model(&User{}).Where(\"email LIKE ? AND (role = ? OR deleted_at IS NULL)\", \
+             \"%\" + term + \"%\", \"admin\").Order(\"created_at DESC\")\
+             .Preload(\"Profile\").Preload(\"Profile.User\").Find(&results)"
+        );
+
+        let delete = ExprIR::DbMutate(DbMutateIR {
+            model: "User".to_string(),
+            operation: "delete".to_string(),
+            data: None,
+            r#where: Some(FilterIR::eq("id", ExprIR::ident("id"))),
+        });
+        assert_eq!(
+            emitter.emit_expression(&delete, &mut ctx),
+            "db.Where(\"id = ?\", id).Delete(&User{})"
+        );
+    }
+
+    #[test]
+    fn test_emit_respond_and_ctx_get_per_framework() {
+        use rash_ir::expr::HttpRespondIR;
+
+        let emitter = GoEmitter;
+        let ret = StatementIR::Return {
+            value: Some(ExprIR::HttpRespond(HttpRespondIR {
+                status: 404,
+                headers: None,
+                body: Some(Box::new(ExprIR::ident("err"))),
+            })),
+        };
+        let param = ExprIR::CtxGet {
+            path: "params.id".to_string(),
+        };
+
+        let mut ctx = EmitContext::new(IndentStyle::Tabs).with_adapter(Rc::new(GinAdapter));
+        assert_eq!(emitter.emit_statement(&ret, &mut ctx), "c.JSON(404, err)\nreturn");
+        assert_eq!(emitter.emit_expression(&param, &mut ctx), "c.Param(\"id\")");
+
+        let mut ctx = EmitContext::new(IndentStyle::Tabs).with_adapter(Rc::new(EchoAdapter));
+        assert_eq!(emitter.emit_statement(&ret, &mut ctx), "return c.JSON(404, err)");
+        assert_eq!(emitter.emit_expression(&param, &mut ctx), "c.Param(\"id\")");
+
+        let mut ctx = EmitContext::new(IndentStyle::Tabs).with_adapter(Rc::new(FiberAdapter));
+        assert_eq!(
+            emitter.emit_statement(&ret, &mut ctx),
+            "return c.Status(404).JSON(err)"
+        );
+        assert_eq!(emitter.emit_expression(&param, &mut ctx), "c.Params(\"id\")");
+    }
+
+    #[test]
+    fn test_emit_gorm_transaction() {
+        use rash_spec::types::ast::IsolationLevel;
+
+        let emitter = GoEmitter;
+        let mut ctx = EmitContext::new(IndentStyle::Tabs).with_adapter(Rc::new(GinAdapter));
+        let create_order = StatementIR::Expression {
+            expr: ExprIR::Await {
+                expr: Box::new(ExprIR::DbMutate(rash_ir::expr::DbMutateIR {
+                    model: "Order".to_string(),
+                    operation: "create".to_string(),
+                    data: Some(Box::new(ExprIR::ident("order"))),
+                    r#where: None,
+                })),
+            },
+        };
+        let tx = StatementIR::Transaction {
+            body: vec![
+                create_order,
+                StatementIR::If {
+                    condition: ExprIR::ident("outOfStock"),
+                    then_: vec![StatementIR::Throw {
+                        value: ExprIR::literal(serde_json::json!("out of stock")),
+                    }],
+                    else_: None,
+                },
+                StatementIR::Return {
+                    value: Some(ExprIR::HttpRespond(rash_ir::expr::HttpRespondIR {
+                        status: 201,
+                        headers: None,
+                        body: Some(Box::new(ExprIR::ident("order"))),
+                    })),
+                },
+            ],
+            isolation: Some(IsolationLevel::Serializable),
+        };
+
+        assert_eq!(
+            emitter.emit_statement(&tx, &mut ctx),
+            "txResponded := false
+if err := db.Transaction(func(tx *gorm.DB) error {
+\ttx.Create(order)
+\tif outOfStock {
+\t\tpanic(\"out of stock\")
+\t}
+\tc.JSON(201, order)
+\ttxResponded = true
+\treturn nil
+}, &sql.TxOptions{Isolation: sql.LevelSerializable}); err != nil {
+\tpanic(err)
+}
+if txResponded {
+\treturn
+}"
+        );
+        let imports = emitter.emit_imports(&mut ctx);
+        assert!(imports.contains("\"gorm.io/gorm\""));
+        assert!(imports.contains("\"database/sql\""));
+    }
+
+    #[test]
+    fn test_emit_user_function() {
+        use rash_ir::types::{FunctionIR, FunctionParamIR};
+
+        let emitter = GoEmitter;
+        let mut ctx = EmitContext::new(IndentStyle::Tabs);
+        let func = FunctionIR {
+            name: "formatCurrency".to_string(),
+            params: vec![
+                FunctionParamIR {
+                    name: "amount".to_string(),
+                    type_ir: TypeIR::Number,
+                    default: None,
+                },
+                FunctionParamIR {
+                    name: "currency".to_string(),
+                    type_ir: TypeIR::String,
+                    default: Some(serde_json::json!("KRW")),
+                },
+            ],
+            return_type: TypeIR::String,
+            body: vec![StatementIR::Return {
+                value: Some(ExprIR::ident("amount")),
+            }],
+            max_tier: rash_spec::types::common::Tier::Universal,
+        };
+        let call = ExprIR::CallFunction {
+            function: "formatCurrency".to_string(),
+            args: vec![ExprIR::ident("total")],
+        };
+
+        assert_eq!(
+            emitter.emit_function(&func, &mut ctx),
+            "func formatCurrency(amount float64, currency string) string {\n\treturn amount\n}"
+        );
+        // Without the project's functions attached the call is emitted as written
+        assert_eq!(emitter.emit_expression(&call, &mut ctx), "formatCurrency(total)");
+
+        let mut ctx = EmitContext::new(IndentStyle::Tabs).with_functions(&[func]);
+        assert_eq!(
+            emitter.emit_expression(&call, &mut ctx),
+            "formatCurrency(total, \"KRW\")"
+        );
+    }
 }
@@ -5,6 +5,9 @@ pub mod go_lang;
 
 use rash_spec::types::common::Language;
 
+use rash_ir::statement::StatementIR;
+
+use crate::context::EmitContext;
 use crate::error::CodegenError;
 use crate::traits::LanguageEmitter;
 
@@ -17,3 +20,42 @@ pub fn create_emitter(language: Language) -> Result<Box<dyn LanguageEmitter>, Co
         Language::Go => Ok(Box::new(go_lang::GoEmitter)),
     }
 }
+
+/// Whether any statement returns from the enclosing function. Closure bodies
+/// are not searched since their returns stay inside the closure.
+pub(crate) fn statements_return(stmts: &[StatementIR]) -> bool {
+    stmts.iter().any(|stmt| match stmt {
+        StatementIR::Return { .. } => true,
+        StatementIR::If { then_, else_, .. } => {
+            statements_return(then_) || else_.as_deref().is_some_and(statements_return)
+        }
+        StatementIR::For { body, .. }
+        | StatementIR::While { body, .. }
+        | StatementIR::Transaction { body, .. }
+        | StatementIR::Component { body, .. } => statements_return(body),
+        StatementIR::Match { arms, .. } => arms.iter().any(|a| statements_return(&a.body)),
+        StatementIR::TryCatch {
+            try_,
+            catch_,
+            finally_,
+        } => {
+            statements_return(try_)
+                || statements_return(&catch_.body)
+                || finally_.as_deref().is_some_and(statements_return)
+        }
+        _ => false,
+    })
+}
+
+/// Defaults for the trailing parameters a user-function call omits, for
+/// languages without default arguments. Stops at the first parameter
+/// that has no default.
+pub(crate) fn omitted_defaults(
+    ctx: &EmitContext,
+    function: &str,
+    given: usize,
+) -> Vec<serde_json::Value> {
+    ctx.function_defaults(function)
+        .map(|defaults| defaults.iter().skip(given).map_while(|d| d.clone()).collect())
+        .unwrap_or_default()
+}
@@ -1,10 +1,13 @@
-use rash_spec::types::common::Language;
+use rash_spec::types::ast::IsolationLevel;
+use rash_spec::types::common::{Language, Orm};
 
+use crate::adapters::django::DJANGO_APP_LABEL;
 use crate::context::{EmitContext, IndentStyle};
+use crate::generator::normalize_identifier;
 use crate::traits::LanguageEmitter;
-use rash_ir::expr::{ExprIR, TypeIR};
+use rash_ir::expr::{ExprIR, FilterIR, FilterOp, IncludeIR, SortDirection, TypeIR};
 use rash_ir::statement::StatementIR;
-use rash_ir::types::{ModelIR, SchemaIR};
+use rash_ir::types::{FunctionIR, ModelIR, SchemaIR};
 
 /// Python code emitter.
 pub struct PythonEmitter;
@@ -103,6 +106,44 @@ impl LanguageEmitter for PythonEmitter {
                 let v = self.emit_expression(value, ctx);
                 format!("{}raise Exception({})", ind, v)
             }
+            // Django's isolation level is fixed per connection, so `isolation` is not applied.
+            // Nested `atomic()` blocks become savepoints.
+            StatementIR::Transaction { body, .. } if ctx.orm() == Some(Orm::DjangoORM) => {
+                ctx.add_import("transaction", "django.db");
+                ctx.enter_transaction();
+                let body_code = self.emit_block(body, ctx);
+                ctx.exit_transaction();
+                format!("{}with transaction.atomic():\n{}", ind, body_code)
+            }
+            // Leaving the block by an exception rolls the session back; nested
+            // transactions become SAVEPOINTs.
+            StatementIR::Transaction { body, isolation } => {
+                let begin = if ctx.in_transaction() {
+                    "begin_nested"
+                } else {
+                    "begin"
+                };
+                let mut lines = vec![format!("{}async with session.{}():", ind, begin)];
+                ctx.push_indent();
+                if let (Some(level), false) = (isolation, ctx.in_transaction()) {
+                    lines.push(format!(
+                        "{}await session.connection(execution_options={{\"isolation_level\": \"{}\"}})",
+                        ctx.indent(),
+                        sqlalchemy_isolation_level(*level)
+                    ));
+                }
+                ctx.pop_indent();
+                ctx.enter_transaction();
+                lines.push(self.emit_block(body, ctx));
+                ctx.exit_transaction();
+                lines.join("\n")
+            }
+            // Components are expanded inline at the use site
+            StatementIR::Component { body, .. } => body
+                .iter()
+                .map(|s| self.emit_statement(s, ctx))
+                .collect::<Vec<_>>()
+                .join("\n"),
             StatementIR::Expression { expr } => {
                 let e = self.emit_expression(expr, ctx);
                 format!("{}{}", ind, e)
@@ -112,6 +153,9 @@ impl LanguageEmitter for PythonEmitter {
 
     #[allow(clippy::only_used_in_recursion)]
     fn emit_expression(&self, expr: &ExprIR, ctx: &mut EmitContext) -> String {
+        if let Some(code) = ctx.emit_domain_expr(expr, self) {
+            return code;
+        }
         match expr {
             ExprIR::Literal { value } => emit_python_literal(value),
             ExprIR::Identifier { name } => name.clone(),
@@ -136,6 +180,13 @@ impl LanguageEmitter for PythonEmitter {
                     args.iter().map(|a| self.emit_expression(a, ctx)).collect();
                 format!("{}({})", c, a.join(", "))
             }
+            ExprIR::CallFunction { function, args } => {
+                let name = normalize_identifier(function);
+                ctx.add_import(name.clone(), FUNCTIONS_MODULE);
+                let a: Vec<String> =
+                    args.iter().map(|a| self.emit_expression(a, ctx)).collect();
+                format!("{}({})", name, a.join(", "))
+            }
             ExprIR::Member { object, property } => {
                 let obj = self.emit_expression(object, ctx);
                 format!("{}.{}", obj, property)
@@ -169,6 +220,13 @@ impl LanguageEmitter for PythonEmitter {
                 }
                 format!("lambda {}: None  # complex lambda", params.join(", "))
             }
+            // SQLAlchemy queries already await their session call.
+            ExprIR::Await { expr }
+                if ctx.orm() != Some(Orm::DjangoORM)
+                    && matches!(**expr, ExprIR::DbQuery(_) | ExprIR::DbMutate(_)) =>
+            {
+                self.emit_expression(expr, ctx)
+            }
             ExprIR::Await { expr } => {
                 let e = self.emit_expression(expr, ctx);
                 format!("await {}", e)
@@ -198,21 +256,155 @@ impl LanguageEmitter for PythonEmitter {
                 s.push('"');
                 s
             }
+            ExprIR::DbQuery(q) if ctx.orm() == Some(Orm::DjangoORM) => {
+                let lookup = q
+                    .r#where
+                    .as_ref()
+                    .map(|w| self.emit_django_filter(w, ctx))
+                    .unwrap_or_default();
+                let mut qs = format!("{}.objects.filter({})", q.model, lookup);
+                if !q.order_by.is_empty() {
+                    let fields: Vec<String> = q
+                        .order_by
+                        .iter()
+                        .map(|o| match o.direction {
+                            SortDirection::Desc => format!("\"-{}\"", o.field),
+                            SortDirection::Asc => format!("\"{}\"", o.field),
+                        })
+                        .collect();
+                    qs.push_str(&format!(".order_by({})", fields.join(", ")));
+                }
+                if !q.include.is_empty() {
+                    let mut paths = Vec::new();
+                    django_prefetch_paths(&q.include, "", &mut paths);
+                    let paths: Vec<String> = paths.iter().map(|p| format!("\"{}\"", p)).collect();
+                    qs.push_str(&format!(".prefetch_related({})", paths.join(", ")));
+                }
+                match (&q.skip, &q.take) {
+                    (None, None) => {}
+                    (skip, take) => {
+                        let start = skip
+                            .as_ref()
+                            .map(|s| self.emit_expression(s, ctx))
+                            .unwrap_or_default();
+                        let end = match take {
+                            Some(t) if start.is_empty() => self.emit_expression(t, ctx),
+                            Some(t) => format!("{} + {}", start, self.emit_expression(t, ctx)),
+                            None => String::new(),
+                        };
+                        qs.push_str(&format!("[{}:{}]", start, end));
+                    }
+                }
+                match q.operation.as_str() {
+                    "findUnique" | "findFirst" => format!("{}.afirst()", qs),
+                    "count" => format!("{}.acount()", qs),
+                    "findMany" => {
+                        ctx.add_import("sync_to_async", "asgiref.sync");
+                        format!("sync_to_async(list)({})", qs)
+                    }
+                    _ => format!("{}.objects.{}()", q.model, q.operation),
+                }
+            }
+            ExprIR::DbMutate(m) if ctx.orm() == Some(Orm::DjangoORM) => {
+                let data = m
+                    .data
+                    .as_ref()
+                    .map(|d| format!("**{}", self.emit_expression(d, ctx)))
+                    .unwrap_or_default();
+                let lookup = m
+                    .r#where
+                    .as_ref()
+                    .map(|w| self.emit_django_filter(w, ctx))
+                    .unwrap_or_default();
+                match m.operation.as_str() {
+                    "create" => format!("{}.objects.acreate({})", m.model, data),
+                    "update" => format!("{}.objects.filter({}).aupdate({})", m.model, lookup, data),
+                    "delete" => format!("{}.objects.filter({}).adelete()", m.model, lookup),
+                    _ => format!("{}.objects.{}()", m.model, m.operation),
+                }
+            }
             ExprIR::DbQuery(q) => {
                 let model = &q.model;
+                if q.operation == "count" {
+                    ctx.add_import("func", "sqlalchemy");
+                    ctx.add_import("select", "sqlalchemy");
+                    let mut stmt = format!("select(func.count()).select_from({})", model);
+                    if let Some(w) = &q.r#where {
+                        stmt.push_str(&format!(".where({})", self.emit_sqlalchemy_where(model, w, ctx)));
+                    }
+                    return format!("await session.scalar({})", stmt);
+                }
+
+                ctx.add_import("select", "sqlalchemy");
+                let mut stmt = format!("select({})", model);
+                if let Some(w) = &q.r#where {
+                    stmt.push_str(&format!(".where({})", self.emit_sqlalchemy_where(model, w, ctx)));
+                }
+                if !q.order_by.is_empty() {
+                    let keys: Vec<String> = q
+                        .order_by
+                        .iter()
+                        .map(|o| match o.direction {
+                            SortDirection::Desc => format!("{}.{}.desc()", model, o.field),
+                            SortDirection::Asc => format!("{}.{}.asc()", model, o.field),
+                        })
+                        .collect();
+                    stmt.push_str(&format!(".order_by({})", keys.join(", ")));
+                }
+                if let Some(skip) = &q.skip {
+                    stmt.push_str(&format!(".offset({})", self.emit_expression(skip, ctx)));
+                }
+                if let Some(take) = &q.take {
+                    stmt.push_str(&format!(".limit({})", self.emit_expression(take, ctx)));
+                }
+                if !q.include.is_empty() {
+                    // Nested includes would need the related model class, which the
+                    // IR does not carry, so only direct relations are eager-loaded.
+                    ctx.add_import("selectinload", "sqlalchemy.orm");
+                    let loads: Vec<String> = q
+                        .include
+                        .iter()
+                        .map(|i| format!("selectinload({}.{})", model, i.relation))
+                        .collect();
+                    stmt.push_str(&format!(".options({})", loads.join(", ")));
+                }
                 match q.operation.as_str() {
-                    "findUnique" => format!("await {}.get_or_none(id=id)", model),
-                    "findMany" => format!("await {}.filter().all()", model),
-                    "count" => format!("await {}.filter().count()", model),
-                    _ => format!("await {}.{}()", model, q.operation),
+                    "findMany" => format!("(await session.scalars({})).all()", stmt),
+                    _ => format!("await session.scalar({})", stmt),
                 }
             }
             ExprIR::DbMutate(m) => {
                 let model = &m.model;
+                let values = m
+                    .data
+                    .as_ref()
+                    .map(|d| format!(".values(**{})", self.emit_expression(d, ctx)))
+                    .unwrap_or_default();
+                let filter = m
+                    .r#where
+                    .as_ref()
+                    .map(|w| format!(".where({})", self.emit_sqlalchemy_where(model, w, ctx)))
+                    .unwrap_or_default();
                 match m.operation.as_str() {
-                    "create" => format!("await {}.create(**data)", model),
-                    "delete" => "await instance.delete()".to_string(),
-                    _ => format!("await {}.{}()", model, m.operation),
+                    "create" => {
+                        ctx.add_import("insert", "sqlalchemy");
+                        format!(
+                            "await session.scalar(insert({}){}.returning({}))",
+                            model, values, model
+                        )
+                    }
+                    "update" => {
+                        ctx.add_import("update", "sqlalchemy");
+                        format!(
+                            "await session.scalar(update({}){}{}.returning({}))",
+                            model, filter, values, model
+                        )
+                    }
+                    "delete" => {
+                        ctx.add_import("delete", "sqlalchemy");
+                        format!("await session.execute(delete({}){})", model, filter)
+                    }
+                    _ => format!("await session.{}({})", m.operation, model),
                 }
             }
             ExprIR::HttpRespond(r) => {
@@ -299,7 +491,10 @@ impl LanguageEmitter for PythonEmitter {
         lines.join("\n")
     }
 
-    fn emit_model(&self, model: &ModelIR, _ctx: &mut EmitContext) -> String {
+    fn emit_model(&self, model: &ModelIR, ctx: &mut EmitContext) -> String {
+        if ctx.orm() == Some(Orm::DjangoORM) {
+            return emit_django_model(model, ctx);
+        }
         let mut lines = Vec::new();
         lines.push("from pydantic import BaseModel".to_string());
         lines.push("from typing import Optional".to_string());
@@ -325,6 +520,39 @@ impl LanguageEmitter for PythonEmitter {
         lines.join("\n")
     }
 
+    fn emit_function(&self, func: &FunctionIR, ctx: &mut EmitContext) -> String {
+        let params: Vec<String> = func
+            .params
+            .iter()
+            .map(|p| match &p.default {
+                Some(d) => format!("{}: {} = {}", p.name, self.emit_type(&p.type_ir), emit_python_literal(d)),
+                None => format!("{}: {}", p.name, self.emit_type(&p.type_ir)),
+            })
+            .collect();
+        let return_type = self.emit_type(&func.return_type);
+        let types: Vec<String> = func
+            .params
+            .iter()
+            .map(|p| self.emit_type(&p.type_ir))
+            .chain([return_type.clone()])
+            .collect();
+        for name in ["Any", "Optional", "Union"] {
+            if types.iter().any(|t| t.contains(name)) {
+                ctx.add_import(name, "typing");
+            }
+        }
+        let mut lines = vec![format!(
+            "def {}({}) -> {}:",
+            normalize_identifier(&func.name),
+            params.join(", "),
+            return_type
+        )];
+        lines.push(self.emit_block(&func.body, ctx));
+        // Functions calling each other share the module, so no self-import
+        ctx.retain_imports(|imp| imp.from != FUNCTIONS_MODULE);
+        lines.join("\n")
+    }
+
     fn emit_imports(&self, ctx: &mut EmitContext) -> String {
         let imports = ctx.take_imports();
         let lines: Vec<String> = imports
@@ -354,8 +582,89 @@ impl PythonEmitter {
             lines.join("\n")
         }
     }
+
+    /// Render a filter as Django `filter()` arguments: keyword lookups
+    /// (`id=userId, age__gte=18`) when possible, a `Q` expression otherwise.
+    fn emit_django_filter(&self, filter: &FilterIR, ctx: &mut EmitContext) -> String {
+        if !django_kwargs_only(filter) {
+            return self.emit_django_q(filter, ctx);
+        }
+        let conditions = match filter {
+            FilterIR::And { filters } => filters.iter().collect(),
+            f => vec![f],
+        };
+        conditions
+            .into_iter()
+            .filter_map(|f| match f {
+                FilterIR::Condition { field, op, value } => {
+                    let lookup = django_lookup(field, *op)?;
+                    Some(format!("{}={}", lookup, self.emit_expression(value, ctx)))
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    fn emit_django_q(&self, filter: &FilterIR, ctx: &mut EmitContext) -> String {
+        ctx.add_import("Q", "django.db.models");
+        match filter {
+            FilterIR::Condition { field, op, value } => {
+                let value = self.emit_expression(value, ctx);
+                match django_lookup(field, *op) {
+                    Some(lookup) if *op == FilterOp::NotIn => format!("~Q({}={})", lookup, value),
+                    Some(lookup) => format!("Q({}={})", lookup, value),
+                    None => format!("~Q({}={})", field, value),
+                }
+            }
+            FilterIR::And { filters } | FilterIR::Or { filters } => {
+                let sep = if matches!(filter, FilterIR::And { .. }) { " & " } else { " | " };
+                let parts: Vec<String> = filters.iter().map(|f| self.emit_django_q(f, ctx)).collect();
+                format!("({})", parts.join(sep))
+            }
+            FilterIR::Not { filter } => format!("~{}", self.emit_django_q(filter, ctx)),
+        }
+    }
+
+    /// Render a filter as SQLAlchemy `where()` criteria against `model`'s columns.
+    /// A top-level AND becomes separate criteria, which `where()` joins with AND.
+    fn emit_sqlalchemy_where(&self, model: &str, filter: &FilterIR, ctx: &mut EmitContext) -> String {
+        match filter {
+            FilterIR::And { filters } => filters
+                .iter()
+                .map(|f| self.emit_sqlalchemy_filter(model, f, ctx))
+                .collect::<Vec<_>>()
+                .join(", "),
+            f => self.emit_sqlalchemy_filter(model, f, ctx),
+        }
+    }
+
+    fn emit_sqlalchemy_filter(&self, model: &str, filter: &FilterIR, ctx: &mut EmitContext) -> String {
+        match filter {
+            FilterIR::Condition { field, op, value } => {
+                let value = self.emit_expression(value, ctx);
+                sqlalchemy_condition(&format!("{}.{}", model, field), *op, &value)
+            }
+            FilterIR::And { filters } | FilterIR::Or { filters } => {
+                let combinator = if matches!(filter, FilterIR::And { .. }) { "and_" } else { "or_" };
+                ctx.add_import(combinator, "sqlalchemy");
+                let parts: Vec<String> = filters
+                    .iter()
+                    .map(|f| self.emit_sqlalchemy_filter(model, f, ctx))
+                    .collect();
+                format!("{}({})", combinator, parts.join(", "))
+            }
+            FilterIR::Not { filter } => {
+                ctx.add_import("not_", "sqlalchemy");
+                format!("not_({})", self.emit_sqlalchemy_filter(model, filter, ctx))
+            }
+        }
+    }
 }
 
+/// Module path of the shared `functions` module; generated apps run from `src/`.
+const FUNCTIONS_MODULE: &str = "functions";
+
 fn emit_python_literal(value: &serde_json::Value) -> String {
     match value {
         serde_json::Value::Null => "None".to_string(),
@@ -388,6 +697,250 @@ fn emit_python_literal(value: &serde_json::Value) -> String {
     }
 }
 
+/// Django field lookup suffix for a comparison operator (`None` for `Ne`,
+/// which has no lookup and is expressed as a negated `Q`).
+fn django_lookup(field: &str, op: FilterOp) -> Option<String> {
+    let suffix = match op {
+        FilterOp::Eq => return Some(field.to_string()),
+        FilterOp::Ne => return None,
+        FilterOp::Gt => "gt",
+        FilterOp::Gte => "gte",
+        FilterOp::Lt => "lt",
+        FilterOp::Lte => "lte",
+        FilterOp::In | FilterOp::NotIn => "in",
+        FilterOp::Contains => "contains",
+        FilterOp::StartsWith => "startswith",
+        FilterOp::EndsWith => "endswith",
+    };
+    Some(format!("{}__{}", field, suffix))
+}
+
+/// Whether a filter can be written as plain `filter()` keyword arguments.
+fn django_kwargs_only(filter: &FilterIR) -> bool {
+    let mut seen = std::collections::HashSet::new();
+    let plain = |f: &FilterIR, seen: &mut std::collections::HashSet<String>| match f {
+        FilterIR::Condition { field, op, .. } => {
+            !matches!(op, FilterOp::Ne | FilterOp::NotIn)
+                && django_lookup(field, *op).is_some_and(|l| seen.insert(l))
+        }
+        _ => false,
+    };
+    match filter {
+        FilterIR::And { filters } => filters.iter().all(|f| plain(f, &mut seen)),
+        f => plain(f, &mut seen),
+    }
+}
+
+/// Flatten nested includes into `prefetch_related` lookup paths (`profile__user`).
+fn django_prefetch_paths(include: &[IncludeIR], prefix: &str, out: &mut Vec<String>) {
+    for i in include {
+        let path = format!("{}{}", prefix, i.relation);
+        out.push(path.clone());
+        django_prefetch_paths(&i.include, &format!("{}__", path), out);
+    }
+}
+
+/// SQLAlchemy `isolation_level` execution option value.
+fn sqlalchemy_isolation_level(level: IsolationLevel) -> &'static str {
+    match level {
+        IsolationLevel::ReadUncommitted => "READ UNCOMMITTED",
+        IsolationLevel::ReadCommitted => "READ COMMITTED",
+        IsolationLevel::RepeatableRead => "REPEATABLE READ",
+        IsolationLevel::Serializable => "SERIALIZABLE",
+    }
+}
+
+/// SQLAlchemy column comparison for a filter condition.
+fn sqlalchemy_condition(column: &str, op: FilterOp, value: &str) -> String {
+    match (op, value) {
+        (FilterOp::Eq, "None") => format!("{}.is_(None)", column),
+        (FilterOp::Ne, "None") => format!("{}.is_not(None)", column),
+        (FilterOp::Eq, _) => format!("{} == {}", column, value),
+        (FilterOp::Ne, _) => format!("{} != {}", column, value),
+        (FilterOp::Gt, _) => format!("{} > {}", column, value),
+        (FilterOp::Gte, _) => format!("{} >= {}", column, value),
+        (FilterOp::Lt, _) => format!("{} < {}", column, value),
+        (FilterOp::Lte, _) => format!("{} <= {}", column, value),
+        (FilterOp::In, _) => format!("{}.in_({})", column, value),
+        (FilterOp::NotIn, _) => format!("{}.not_in({})", column, value),
+        (FilterOp::Contains, _) => format!("{}.contains({})", column, value),
+        (FilterOp::StartsWith, _) => format!("{}.startswith({})", column, value),
+        (FilterOp::EndsWith, _) => format!("{}.endswith({})", column, value),
+    }
+}
+
+/// Emit a `ModelIR` as a Django `models.Model` subclass.
+fn emit_django_model(model: &ModelIR, ctx: &mut EmitContext) -> String {
+    ctx.add_import("models", "django.db");
+
+    // belongsTo relations replace their foreign key column with a ForeignKey field
+    let mut foreign_keys: Vec<(&str, &serde_json::Value)> = Vec::new();
+    for (rel_name, rel) in &model.relations {
+        if rel.get("type").and_then(|t| t.as_str()) == Some("belongsTo") {
+            foreign_keys.push((rel_name.as_str(), rel));
+        }
+    }
+    let fk_column = |col: &str| {
+        foreign_keys
+            .iter()
+            .any(|(_, rel)| rel.get("foreignKey").and_then(|f| f.as_str()) == Some(col))
+    };
+
+    let mut lines = vec![format!("class {}(models.Model):", model.name)];
+    for (col_name, col_def) in &model.columns {
+        if fk_column(col_name) {
+            continue;
+        }
+        lines.push(format!("    {} = {}", col_name, django_field(col_def, ctx)));
+    }
+    for (rel_name, rel) in &foreign_keys {
+        let target = rel.get("target").and_then(|t| t.as_str()).unwrap_or("Model");
+        let mut args = vec![
+            format!("\"{}\"", target),
+            "on_delete=models.CASCADE".to_string(),
+        ];
+        if let Some(fk) = rel.get("foreignKey").and_then(|f| f.as_str()) {
+            args.push(format!("db_column=\"{}\"", fk));
+        }
+        lines.push(format!(
+            "    {} = models.ForeignKey({})",
+            rel_name,
+            args.join(", ")
+        ));
+    }
+
+    let mut indexes = Vec::new();
+    let mut constraints = Vec::new();
+    for index in &model.indexes {
+        let columns: Vec<&str> = index
+            .get("columns")
+            .and_then(|c| c.as_array())
+            .map(|a| a.iter().filter_map(|c| c.as_str()).collect())
+            .unwrap_or_default();
+        if columns.is_empty() {
+            continue;
+        }
+        let fields = columns
+            .iter()
+            .map(|c| format!("\"{}\"", c))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let name = format!("{}_{}", model.table_name, columns.join("_"));
+        if index.get("unique").and_then(|u| u.as_bool()).unwrap_or(false) {
+            constraints.push(format!(
+                "models.UniqueConstraint(fields=[{}], name=\"{}_key\")",
+                fields, name
+            ));
+        } else {
+            indexes.push(format!(
+                "models.Index(fields=[{}], name=\"{}_idx\")",
+                fields, name
+            ));
+        }
+    }
+
+    lines.push(String::new());
+    lines.push("    class Meta:".to_string());
+    lines.push(format!("        app_label = \"{}\"", DJANGO_APP_LABEL));
+    lines.push(format!("        db_table = \"{}\"", model.table_name));
+    if !indexes.is_empty() {
+        lines.push(format!("        indexes = [{}]", indexes.join(", ")));
+    }
+    if !constraints.is_empty() {
+        lines.push(format!("        constraints = [{}]", constraints.join(", ")));
+    }
+    lines.join("\n")
+}
+
+/// Map a model column definition to a Django field constructor.
+fn django_field(def: &serde_json::Value, ctx: &mut EmitContext) -> String {
+    let col_type = def.get("type").and_then(|t| t.as_str()).unwrap_or("text");
+    let default = def.get("default");
+    let default_str = default.and_then(|d| d.as_str());
+    let mut args = Vec::new();
+
+    let field = if let Some(len) = col_type
+        .strip_prefix("varchar(")
+        .and_then(|rest| rest.strip_suffix(')'))
+    {
+        args.push(format!("max_length={}", len));
+        "CharField"
+    } else {
+        match col_type {
+            "uuid" => {
+                if default_str == Some("gen_random_uuid()") {
+                    ctx.add_import("uuid4", "uuid");
+                    args.push("default=uuid4".to_string());
+                }
+                "UUIDField"
+            }
+            "varchar" | "string" => {
+                args.push("max_length=255".to_string());
+                "CharField"
+            }
+            "enum" => {
+                let values: Vec<&str> = def
+                    .get("values")
+                    .and_then(|v| v.as_array())
+                    .map(|a| a.iter().filter_map(|v| v.as_str()).collect())
+                    .unwrap_or_default();
+                let max_len = values.iter().map(|v| v.len()).max().unwrap_or(1);
+                let choices = values
+                    .iter()
+                    .map(|v| format!("(\"{}\", \"{}\")", v, v))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                args.push(format!("max_length={}", max_len));
+                args.push(format!("choices=[{}]", choices));
+                "CharField"
+            }
+            "serial" => "AutoField",
+            "integer" | "int" => "IntegerField",
+            "bigint" => "BigIntegerField",
+            "boolean" | "bool" => "BooleanField",
+            "float" | "double" | "number" => "FloatField",
+            "decimal" => {
+                args.push("max_digits=10".to_string());
+                args.push("decimal_places=2".to_string());
+                "DecimalField"
+            }
+            "timestamp" | "datetime" => {
+                if def.get("onUpdate").and_then(|u| u.as_str()) == Some("now()") {
+                    args.push("auto_now=True".to_string());
+                } else if default_str == Some("now()") {
+                    args.push("auto_now_add=True".to_string());
+                }
+                "DateTimeField"
+            }
+            "date" => "DateField",
+            "json" | "jsonb" => "JSONField",
+            _ => "TextField",
+        }
+    };
+
+    if def.get("primaryKey").and_then(|p| p.as_bool()).unwrap_or(false) {
+        args.push("primary_key=True".to_string());
+    }
+    if def.get("unique").and_then(|u| u.as_bool()).unwrap_or(false) {
+        args.push("unique=True".to_string());
+    }
+    if def.get("index").and_then(|i| i.as_bool()).unwrap_or(false) {
+        args.push("db_index=True".to_string());
+    }
+    if def.get("nullable").and_then(|n| n.as_bool()).unwrap_or(false) {
+        args.push("null=True".to_string());
+        args.push("blank=True".to_string());
+    }
+    // SQL function defaults were translated above; plain literals pass through.
+    if let Some(d) = default {
+        if !d.as_str().is_some_and(|s| s.ends_with("()")) {
+            args.push(format!("default={}", emit_python_literal(d)));
+        }
+    }
+
+    format!("models.{}({})", field, args.join(", "))
+}
+
 fn emit_pydantic_model(name: &str, schema: &serde_json::Value) -> String {
     let mut lines = Vec::new();
     lines.push(format!("class {}(BaseModel):", name));
@@ -440,6 +993,9 @@ fn json_type_to_python(type_str: &str) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::rc::Rc;
+
+    use crate::adapters::{django::DjangoAdapter, flask::FlaskAdapter};
 
     #[test]
     fn test_emit_type() {
@@ -473,4 +1029,298 @@ mod tests {
         assert!(code.contains("if user == None:"));
         assert!(code.contains("return 404"));
     }
+
+    #[test]
+    fn test_emit_respond_and_ctx_get_per_framework() {
+        use rash_ir::expr::HttpRespondIR;
+
+        let emitter = PythonEmitter;
+        let respond = ExprIR::HttpRespond(HttpRespondIR {
+            status: 201,
+            headers: None,
+            body: Some(Box::new(ExprIR::ident("user"))),
+        });
+        let param = ExprIR::CtxGet {
+            path: "params.id".to_string(),
+        };
+
+        let mut ctx = EmitContext::new(IndentStyle::Spaces(4)).with_adapter(Rc::new(FlaskAdapter));
+        assert_eq!(emitter.emit_expression(&respond, &mut ctx), "jsonify(user), 201");
+        assert_eq!(
+            emitter.emit_expression(&param, &mut ctx),
+            "request.view_args[\"id\"]"
+        );
+        assert!(emitter.emit_imports(&mut ctx).contains("from flask import jsonify"));
+
+        let mut ctx = EmitContext::new(IndentStyle::Spaces(4)).with_adapter(Rc::new(DjangoAdapter));
+        assert_eq!(
+            emitter.emit_expression(&respond, &mut ctx),
+            "JsonResponse(user, status=201, safe=False)"
+        );
+        assert_eq!(emitter.emit_expression(&param, &mut ctx), "params[\"id\"]");
+    }
+
+    #[test]
+    fn test_emit_django_model() {
+        let emitter = PythonEmitter;
+        let mut ctx = EmitContext::new(IndentStyle::Spaces(4)).with_orm(Orm::DjangoORM);
+        let model = ModelIR {
+            name: "Post".to_string(),
+            table_name: "posts".to_string(),
+            columns: serde_json::from_value(serde_json::json!({
+                "id": { "type": "uuid", "primaryKey": true, "default": "gen_random_uuid()" },
+                "title": { "type": "varchar(255)" },
+                "status": { "type": "enum", "values": ["draft", "published"], "default": "draft" },
+                "authorId": { "type": "uuid", "index": true },
+                "createdAt": { "type": "timestamp", "default": "now()" }
+            }))
+            .unwrap(),
+            relations: serde_json::from_value(serde_json::json!({
+                "author": { "type": "belongsTo", "target": "User", "foreignKey": "authorId" }
+            }))
+            .unwrap(),
+            indexes: vec![serde_json::json!({ "columns": ["title"], "unique": true })],
+        };
+
+        let code = emitter.emit_model(&model, &mut ctx);
+        assert!(code.contains("class Post(models.Model):"));
+        assert!(code.contains("    id = models.UUIDField(default=uuid4, primary_key=True)"));
+        assert!(code.contains("    title = models.CharField(max_length=255)"));
+        assert!(code.contains(
+            "models.CharField(max_length=9, choices=[(\"draft\", \"draft\"), (\"published\", \"published\")], default=\"draft\")"
+        ));
+        assert!(code.contains("models.DateTimeField(auto_now_add=True)"));
+        assert!(code.contains(
+            "    author = models.ForeignKey(\"User\", on_delete=models.CASCADE, db_column=\"authorId\")"
+        ));
+        assert!(!code.contains("authorId = "));
+        assert!(code.contains("        app_label = \"api\""));
+        assert!(code.contains("        db_table = \"posts\""));
+        assert!(code.contains("models.UniqueConstraint(fields=[\"title\"], name=\"posts_title_key\")"));
+
+        let imports = emitter.emit_imports(&mut ctx);
+        assert!(imports.contains("from django.db import models"));
+        assert!(imports.contains("from uuid import uuid4"));
+    }
+
+    #[test]
+    fn test_emit_django_orm_queries() {
+        use rash_ir::expr::{DbMutateIR, DbQueryIR, OrderByIR};
+
+        let emitter = PythonEmitter;
+        let mut ctx = EmitContext::new(IndentStyle::Spaces(4)).with_orm(Orm::DjangoORM);
+        let by_id = FilterIR::eq("id", ExprIR::ident("userId"));
+
+        let find = ExprIR::DbQuery(DbQueryIR {
+            model: "User".to_string(),
+            operation: "findUnique".to_string(),
+            r#where: Some(by_id.clone()),
+            order_by: vec![],
+            skip: None,
+            take: None,
+            select: None,
+            include: vec![],
+        });
+        assert_eq!(
+            emitter.emit_expression(&find, &mut ctx),
+            "User.objects.filter(id=userId).afirst()"
+        );
+
+        let delete = ExprIR::DbMutate(DbMutateIR {
+            model: "User".to_string(),
+            operation: "delete".to_string(),
+            r#where: Some(by_id),
+            data: None,
+        });
+        assert_eq!(
+            emitter.emit_expression(&delete, &mut ctx),
+            "User.objects.filter(id=userId).adelete()"
+        );
+
+        let adults = ExprIR::DbQuery(DbQueryIR {
+            model: "User".to_string(),
+            operation: "findMany".to_string(),
+            r#where: Some(FilterIR::Or {
+                filters: vec![
+                    FilterIR::Condition {
+                        field: "age".to_string(),
+                        op: FilterOp::Gte,
+                        value: Box::new(ExprIR::Literal { value: serde_json::json!(18) }),
+                    },
+                    FilterIR::Not {
+                        filter: Box::new(FilterIR::eq("role", ExprIR::Literal { value: serde_json::json!("guest") })),
+                    },
+                ],
+            }),
+            order_by: vec![OrderByIR {
+                field: "createdAt".to_string(),
+                direction: SortDirection::Desc,
+            }],
+            skip: None,
+            take: None,
+            select: None,
+            include: vec![IncludeIR {
+                relation: "profile".to_string(),
+                include: vec![IncludeIR {
+                    relation: "user".to_string(),
+                    include: vec![],
+                }],
+            }],
+        });
+        assert_eq!(
+            emitter.emit_expression(&adults, &mut ctx),
+            "sync_to_async(list)(User.objects.filter((Q(age__gte=18) | ~Q(role=\"guest\")))\
+             .order_by(\"-createdAt\").prefetch_related(\"profile\", \"profile__user\"))"
+        );
+        let imports = emitter.emit_imports(&mut ctx);
+        assert!(imports.contains("from django.db.models import Q"));
+    }
+
+    #[test]
+    fn test_emit_sqlalchemy_queries() {
+        use rash_ir::expr::{DbMutateIR, DbQueryIR};
+
+        let emitter = PythonEmitter;
+        let mut ctx = EmitContext::new(IndentStyle::Spaces(4)).with_orm(Orm::SQLAlchemy);
+        let filter = FilterIR::And {
+            filters: vec![
+                FilterIR::eq("id", ExprIR::ident("userId")),
+                FilterIR::eq("deletedAt", ExprIR::Literal { value: serde_json::Value::Null }),
+            ],
+        };
+
+        let find = ExprIR::Await {
+            expr: Box::new(ExprIR::DbQuery(DbQueryIR {
+                model: "User".to_string(),
+                operation: "findUnique".to_string(),
+                r#where: Some(filter.clone()),
+                order_by: vec![],
+                skip: None,
+                take: None,
+                select: None,
+                include: vec![IncludeIR {
+                    relation: "posts".to_string(),
+                    include: vec![],
+                }],
+            })),
+        };
+        assert_eq!(
+            emitter.emit_expression(&find, &mut ctx),
+            "await session.scalar(select(User).where(User.id == userId, User.deletedAt.is_(None))\
+             .options(selectinload(User.posts)))"
+        );
+
+        let delete = ExprIR::DbMutate(DbMutateIR {
+            model: "User".to_string(),
+            operation: "delete".to_string(),
+            data: None,
+            r#where: Some(FilterIR::Condition {
+                field: "role".to_string(),
+                op: FilterOp::In,
+                value: Box::new(ExprIR::ident("roles")),
+            }),
+        });
+        assert_eq!(
+            emitter.emit_expression(&delete, &mut ctx),
+            "await session.execute(delete(User).where(User.role.in_(roles)))"
+        );
+
+        let imports = emitter.emit_imports(&mut ctx);
+        assert!(imports.contains("from sqlalchemy import select"));
+        assert!(imports.contains("from sqlalchemy.orm import selectinload"));
+        assert!(imports.contains("from sqlalchemy import delete"));
+    }
+
+    #[test]
+    fn test_emit_transaction_per_orm() {
+        let emitter = PythonEmitter;
+        let create_order = StatementIR::Expression {
+            expr: ExprIR::Await {
+                expr: Box::new(ExprIR::DbMutate(rash_ir::expr::DbMutateIR {
+                    model: "Order".to_string(),
+                    operation: "create".to_string(),
+                    data: Some(Box::new(ExprIR::ident("order"))),
+                    r#where: None,
+                })),
+            },
+        };
+        let tx = StatementIR::Transaction {
+            body: vec![
+                create_order,
+                StatementIR::If {
+                    condition: ExprIR::ident("outOfStock"),
+                    then_: vec![StatementIR::Throw {
+                        value: ExprIR::literal(serde_json::json!("out of stock")),
+                    }],
+                    else_: None,
+                },
+                StatementIR::Return {
+                    value: Some(ExprIR::HttpRespond(rash_ir::expr::HttpRespondIR {
+                        status: 201,
+                        headers: None,
+                        body: Some(Box::new(ExprIR::ident("order"))),
+                    })),
+                },
+            ],
+            isolation: Some(IsolationLevel::Serializable),
+        };
+
+        let mut ctx = EmitContext::new(IndentStyle::Spaces(4)).with_orm(Orm::SQLAlchemy);
+        assert_eq!(
+            emitter.emit_statement(&tx, &mut ctx),
+            r#"async with session.begin():
+    await session.connection(execution_options={"isolation_level": "SERIALIZABLE"})
+    await session.scalar(insert(Order).values(**order).returning(Order))
+    if outOfStock:
+        raise Exception("out of stock")
+    return JSONResponse(status_code=201, content=order)"#
+        );
+
+        let mut ctx = EmitContext::new(IndentStyle::Spaces(4)).with_orm(Orm::DjangoORM);
+        let code = emitter.emit_statement(&tx, &mut ctx);
+        assert!(code.starts_with("with transaction.atomic():\n    await Order.objects.acreate(**order)"));
+        assert!(emitter.emit_imports(&mut ctx).contains("from django.db import transaction"));
+    }
+
+    #[test]
+    fn test_emit_user_function() {
+        use rash_ir::types::{FunctionIR, FunctionParamIR};
+
+        let emitter = PythonEmitter;
+        let mut ctx = EmitContext::new(IndentStyle::Spaces(4));
+        let func = FunctionIR {
+            name: "formatCurrency".to_string(),
+            params: vec![
+                FunctionParamIR {
+                    name: "amount".to_string(),
+                    type_ir: TypeIR::Number,
+                    default: None,
+                },
+                FunctionParamIR {
+                    name: "currency".to_string(),
+                    type_ir: TypeIR::String,
+                    default: Some(serde_json::json!("KRW")),
+                },
+            ],
+            return_type: TypeIR::String,
+            body: vec![StatementIR::Return {
+                value: Some(ExprIR::ident("amount")),
+            }],
+            max_tier: rash_spec::types::common::Tier::Universal,
+        };
+        let call = ExprIR::CallFunction {
+            function: "formatCurrency".to_string(),
+            args: vec![ExprIR::ident("total")],
+        };
+
+        assert_eq!(
+            emitter.emit_function(&func, &mut ctx),
+            "def formatCurrency(amount: float, currency: str = \"KRW\") -> str:\n    return amount"
+        );
+        assert_eq!(emitter.emit_expression(&call, &mut ctx), "formatCurrency(total)");
+        assert_eq!(
+            emitter.emit_imports(&mut ctx),
+            "from functions import formatCurrency"
+        );
+    }
 }
@@ -1,10 +1,13 @@
+use rash_spec::types::ast::IsolationLevel;
 use rash_spec::types::common::Language;
 
 use crate::context::{EmitContext, IndentStyle};
+use crate::emitters::{omitted_defaults, statements_return};
+use crate::generator::normalize_identifier;
 use crate::traits::LanguageEmitter;
-use rash_ir::expr::{ExprIR, TypeIR};
-use rash_ir::statement::StatementIR;
-use rash_ir::types::{ModelIR, SchemaIR};
+use rash_ir::expr::{ExprIR, FilterIR, FilterOp, SortDirection, TypeIR};
+use rash_ir::statement::{CatchClauseIR, StatementIR};
+use rash_ir::types::{FunctionIR, ModelIR, SchemaIR};
 
 /// Rust code emitter.
 pub struct RustEmitter;
@@ -30,13 +33,45 @@ impl LanguageEmitter for RustEmitter {
                 let v = self.emit_expression(value, ctx);
                 format!("{}{} = {};", ind, t, v)
             }
-            StatementIR::Return { value } => match value {
-                Some(expr) => {
-                    let v = self.emit_expression(expr, ctx);
-                    format!("{}return {};", ind, v)
+            // Inside a lowered `try` body, returns leave the handler via the `Ok(Some(..))` arm
+            StatementIR::Return { value } => {
+                let v = match value {
+                    // Handlers returning `Result` respond through `Ok`
+                    Some(expr @ ExprIR::HttpRespond(_)) if ctx.handlers_return_error() => {
+                        Some(format!("Ok({})", self.emit_expression(expr, ctx)))
+                    }
+                    Some(expr) => Some(self.emit_expression(expr, ctx)),
+                    None if ctx.in_try() => Some("()".to_string()),
+                    None => None,
+                };
+                let ret = match v {
+                    Some(v) if ctx.in_try() => format!("Ok(Some({}))", v),
+                    Some(v) => v,
+                    None => String::new(),
+                };
+                if !ctx.in_transaction() {
+                    return if ret.is_empty() {
+                        format!("{}return;", ind)
+                    } else {
+                        format!("{}return {};", ind, ret)
+                    };
                 }
-                None => format!("{}return;", ind),
-            },
+                // Returning from inside a transaction commits it; dropping it would roll back.
+                // The value is computed first since it may still read through the transaction.
+                let mut lines = Vec::new();
+                if !ret.is_empty() {
+                    lines.push(format!("{}let ret = {};", ind, ret));
+                }
+                for depth in (1..=ctx.transaction_depth()).rev() {
+                    lines.push(format!("{}{}.commit().await?;", ind, seaorm_txn(depth)));
+                }
+                if ret.is_empty() {
+                    lines.push(format!("{}return;", ind));
+                } else {
+                    lines.push(format!("{}return ret;", ind));
+                }
+                lines.join("\n")
+            }
             StatementIR::If {
                 condition,
                 then_,
@@ -91,13 +126,65 @@ impl LanguageEmitter for RustEmitter {
                 lines.push(format!("{}}}", ind));
                 lines.join("\n")
             }
-            StatementIR::TryCatch { .. } => {
-                format!("{}// TODO: error handling (Rust uses Result<T, E>)", ind)
+            StatementIR::TryCatch {
+                try_,
+                catch_,
+                finally_,
+            } => self.emit_try_catch(try_, catch_, finally_.as_deref(), ctx),
+            // An early exit drops the `DatabaseTransaction`, which rolls it back;
+            // nested transactions run in SAVEPOINTs.
+            StatementIR::Transaction { body, isolation } => {
+                ctx.add_import("TransactionTrait", "sea_orm::TransactionTrait");
+                let outer = if ctx.in_transaction() {
+                    seaorm_txn(ctx.transaction_depth())
+                } else {
+                    "db".to_string()
+                };
+                let begin = match isolation {
+                    Some(level) if !ctx.in_transaction() => {
+                        ctx.add_import("IsolationLevel", "sea_orm::IsolationLevel");
+                        format!(
+                            "begin_with_config(Some({}), None)",
+                            seaorm_isolation_level(*level)
+                        )
+                    }
+                    _ => "begin()".to_string(),
+                };
+                ctx.enter_transaction();
+                let txn = seaorm_txn(ctx.transaction_depth());
+                ctx.push_indent();
+                let inner = ctx.indent();
+                ctx.pop_indent();
+                let body_code = self.emit_block(body, ctx);
+                ctx.exit_transaction();
+                let mut lines = vec![
+                    format!("{}{{", ind),
+                    format!("{}let {} = {}.{}.await?;", inner, txn, outer, begin),
+                ];
+                if !body.is_empty() {
+                    lines.push(body_code);
+                }
+                if !matches!(body.last(), Some(StatementIR::Return { .. })) {
+                    lines.push(format!("{}{}.commit().await?;", inner, txn));
+                }
+                lines.push(format!("{}}}", ind));
+                lines.join("\n")
+            }
+            StatementIR::Throw { value } if ctx.in_try() || ctx.handlers_return_error() => {
+                ctx.add_import("AppError", "crate::error::AppError");
+                let v = self.emit_expression(value, ctx);
+                format!("{}return Err(AppError::from({}));", ind, v)
             }
             StatementIR::Throw { value } => {
                 let v = self.emit_expression(value, ctx);
                 format!("{}return Err({}.into());", ind, v)
             }
+            // Components are expanded inline at the use site
+            StatementIR::Component { body, .. } => body
+                .iter()
+                .map(|s| self.emit_statement(s, ctx))
+                .collect::<Vec<_>>()
+                .join("\n"),
             StatementIR::Expression { expr } => {
                 let e = self.emit_expression(expr, ctx);
                 format!("{}{};", ind, e)
@@ -106,6 +193,9 @@ impl LanguageEmitter for RustEmitter {
     }
 
     fn emit_expression(&self, expr: &ExprIR, ctx: &mut EmitContext) -> String {
+        if let Some(code) = ctx.emit_domain_expr(expr, self) {
+            return code;
+        }
         match expr {
             ExprIR::Literal { value } => emit_rust_literal(value),
             ExprIR::Identifier { name } => name.clone(),
@@ -124,6 +214,13 @@ impl LanguageEmitter for RustEmitter {
                     args.iter().map(|a| self.emit_expression(a, ctx)).collect();
                 format!("{}({})", c, a.join(", "))
             }
+            ExprIR::CallFunction { function, args } => {
+                let mut a: Vec<String> =
+                    args.iter().map(|a| self.emit_expression(a, ctx)).collect();
+                // Rust has no default arguments, so omitted ones are filled in here
+                a.extend(omitted_defaults(ctx, function, args.len()).iter().map(emit_rust_literal));
+                format!("crate::functions::{}({})", normalize_identifier(function), a.join(", "))
+            }
             ExprIR::Member { object, property } => {
                 let obj = self.emit_expression(object, ctx);
                 format!("{}.{}", obj, property)
@@ -133,12 +230,19 @@ impl LanguageEmitter for RustEmitter {
                 let idx = self.emit_expression(index, ctx);
                 format!("{}[{}]", obj, idx)
             }
+            // Literal values are written as JSON, anything else as a Rust expression
             ExprIR::Object { properties } => {
                 let entries: Vec<String> = properties
                     .iter()
-                    .map(|(k, v)| format!("{}: {}", k, self.emit_expression(v, ctx)))
+                    .map(|(k, v)| {
+                        let val = match v {
+                            ExprIR::Literal { value } => value.to_string(),
+                            _ => self.emit_expression(v, ctx),
+                        };
+                        format!("\"{}\": {}", k, val)
+                    })
                     .collect();
-                format!("{{ {} }}", entries.join(", "))
+                format!("serde_json::json!({{ {} }})", entries.join(", "))
             }
             ExprIR::Array { elements } => {
                 let elems: Vec<String> =
@@ -150,6 +254,19 @@ impl LanguageEmitter for RustEmitter {
                 let body_code = self.emit_block(body, ctx);
                 format!("|{}| {{\n{}\n{}}}", params_str, body_code, ctx.indent())
             }
+            // SeaORM calls already end in `.await?`.
+            // SeaORM calls await inside their own lowering; token helpers are synchronous
+            ExprIR::Await { expr }
+                if matches!(
+                    **expr,
+                    ExprIR::DbQuery(_)
+                        | ExprIR::DbMutate(_)
+                        | ExprIR::SignToken { .. }
+                        | ExprIR::VerifyToken { .. }
+                ) =>
+            {
+                self.emit_expression(expr, ctx)
+            }
             ExprIR::Await { expr } => {
                 let e = self.emit_expression(expr, ctx);
                 format!("{}.await", e)
@@ -185,22 +302,80 @@ impl LanguageEmitter for RustEmitter {
                     format!("format!(\"{}\", {})", fmt_str, args.join(", "))
                 }
             }
+            // SeaORM query builder; `include` has no builder equivalent (related
+            // rows are loaded with `find_also_related`/`load_many` on the result).
             ExprIR::DbQuery(q) => {
+                ctx.add_import("EntityTrait", "sea_orm::EntityTrait");
+                let conn = seaorm_conn(ctx);
                 let model = &q.model;
+                import_seaorm_entity(model, ctx);
+                let mut chain = format!("{}::find()", model);
+                if let Some(w) = &q.r#where {
+                    chain.push_str(&self.emit_seaorm_filter(model, w, ctx));
+                }
+                for o in &q.order_by {
+                    ctx.add_import("QueryOrder", "sea_orm::QueryOrder");
+                    let dir = match o.direction {
+                        SortDirection::Asc => "asc",
+                        SortDirection::Desc => "desc",
+                    };
+                    chain.push_str(&format!(
+                        ".order_by_{}({})",
+                        dir,
+                        seaorm_column(model, &o.field)
+                    ));
+                }
+                if let Some(skip) = &q.skip {
+                    ctx.add_import("QuerySelect", "sea_orm::QuerySelect");
+                    chain.push_str(&format!(".offset({} as u64)", self.emit_expression(skip, ctx)));
+                }
+                if let Some(take) = &q.take {
+                    ctx.add_import("QuerySelect", "sea_orm::QuerySelect");
+                    chain.push_str(&format!(".limit({} as u64)", self.emit_expression(take, ctx)));
+                }
                 match q.operation.as_str() {
-                    "findUnique" => format!("{}::find_by_id(id).one(&db).await?", model),
-                    "findMany" => format!("{}::find().all(&db).await?", model),
-                    "count" => format!("{}::find().count(&db).await?", model),
-                    _ => format!("{}::{}(&db).await?", model, q.operation),
+                    "findUnique" | "findFirst" => format!("{}.one({}).await?", chain, conn),
+                    "findMany" => format!("{}.all({}).await?", chain, conn),
+                    "count" => {
+                        ctx.add_import("PaginatorTrait", "sea_orm::PaginatorTrait");
+                        format!("{}.count({}).await?", chain, conn)
+                    }
+                    _ => format!("{}::{}({}).await?", model, q.operation, conn),
                 }
             }
             ExprIR::DbMutate(m) => {
+                ctx.add_import("EntityTrait", "sea_orm::EntityTrait");
+                let conn = seaorm_conn(ctx);
                 let model = &m.model;
+                import_seaorm_entity(model, ctx);
+                let filter = m
+                    .r#where
+                    .as_ref()
+                    .map(|w| self.emit_seaorm_filter(model, w, ctx))
+                    .unwrap_or_default();
+                // Data arrives as JSON, so the active model is built from it
+                let active = match &m.data {
+                    Some(data) => {
+                        ctx.add_import("ActiveModelTrait", "sea_orm::ActiveModelTrait");
+                        let data = match data.as_ref() {
+                            ExprIR::Identifier { name } => format!("{}.clone()", name),
+                            other => self.emit_expression(other, ctx),
+                        };
+                        format!("{}::ActiveModel::from_json({})?", to_snake_case(model), data)
+                    }
+                    None => format!("{}::ActiveModel::default()", to_snake_case(model)),
+                };
                 match m.operation.as_str() {
-                    "create" => format!("{}::insert(model).exec(&db).await?", model),
-                    "update" => format!("{}::update(model).exec(&db).await?", model),
-                    "delete" => format!("{}::delete_by_id(id).exec(&db).await?", model),
-                    _ => format!("{}::{}(&db).await?", model, m.operation),
+                    "create" => {
+                        ctx.add_import("ActiveModelTrait", "sea_orm::ActiveModelTrait");
+                        format!("{}.insert({}).await?", active, conn)
+                    }
+                    "update" => format!(
+                        "{}::update_many().set({}){}.exec_with_returning({}).await?",
+                        model, active, filter, conn
+                    ),
+                    "delete" => format!("{}::delete_many(){}.exec({}).await?", model, filter, conn),
+                    _ => format!("{}::{}({}).await?", model, m.operation, conn),
                 }
             }
             ExprIR::HttpRespond(r) => {
@@ -249,17 +424,19 @@ impl LanguageEmitter for RustEmitter {
                 format!("verify({}, &{})?", p, h)
             }
             ExprIR::SignToken { payload, .. } => {
+                ctx.add_import("encode", "jsonwebtoken::{encode, EncodingKey, Header}");
                 let p = self.emit_expression(payload, ctx);
                 format!(
-                    "encode(&Header::default(), &{}, &EncodingKey::from_secret(secret))?",
-                    p
+                    "encode(&Header::default(), &{}, &EncodingKey::from_secret({}))?",
+                    p, JWT_SECRET
                 )
             }
             ExprIR::VerifyToken { token } => {
+                ctx.add_import("decode", "jsonwebtoken::{decode, DecodingKey, Validation}");
                 let t = self.emit_expression(token, ctx);
                 format!(
-                    "decode::<Claims>(&{}, &DecodingKey::from_secret(secret), &Validation::default())?",
-                    t
+                    "decode::<serde_json::Value>(&{}, &DecodingKey::from_secret({}), &Validation::default())?.claims",
+                    t, JWT_SECRET
                 )
             }
             ExprIR::NativeBridge(nb) => {
@@ -313,19 +490,39 @@ impl LanguageEmitter for RustEmitter {
         lines.join("\n")
     }
 
+    /// A SeaORM entity module: `Model`, `Relation` and `ActiveModel`.
     fn emit_model(&self, model: &ModelIR, _ctx: &mut EmitContext) -> String {
-        let mut lines = Vec::new();
-        lines.push("use serde::{Deserialize, Serialize};".to_string());
-        lines.push(String::new());
-        lines.push("#[derive(Debug, Clone, Serialize, Deserialize)]".to_string());
-        lines.push(format!("pub struct {} {{", model.name));
+        let mut lines = vec![
+            "use sea_orm::entity::prelude::*;".to_string(),
+            "use serde::{Deserialize, Serialize};".to_string(),
+            String::new(),
+            "#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]"
+                .to_string(),
+            format!("#[sea_orm(table_name = \"{}\")]", model.table_name),
+            "pub struct Model {".to_string(),
+        ];
         for (col_name, col_def) in &model.columns {
-            let rust_type = json_type_to_rust(
-                col_def
-                    .get("type")
-                    .and_then(|t| t.as_str())
-                    .unwrap_or("String"),
-            );
+            let col_type = col_def.get("type").and_then(|t| t.as_str()).unwrap_or("text");
+            let rust_type = seaorm_column_type(col_type);
+
+            let mut attrs = Vec::new();
+            if col_def.get("primaryKey").and_then(|p| p.as_bool()) == Some(true) {
+                attrs.push("primary_key".to_string());
+                if !matches!(rust_type, "i32" | "i64") {
+                    attrs.push("auto_increment = false".to_string());
+                }
+            }
+            if col_def.get("unique").and_then(|u| u.as_bool()) == Some(true) {
+                attrs.push("unique".to_string());
+            }
+            // Columns keep the spec's names, as in the Prisma schema
+            if to_snake_case(col_name) != *col_name {
+                attrs.push(format!("column_name = \"{}\"", col_name));
+            }
+            if !attrs.is_empty() {
+                lines.push(format!("    #[sea_orm({})]", attrs.join(", ")));
+            }
+
             let nullable = col_def
                 .get("nullable")
                 .and_then(|n| n.as_bool())
@@ -337,6 +534,36 @@ impl LanguageEmitter for RustEmitter {
             }
         }
         lines.push("}".to_string());
+        lines.push(String::new());
+        lines.push("#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]".to_string());
+        lines.push("pub enum Relation {}".to_string());
+        lines.push(String::new());
+        lines.push("impl ActiveModelBehavior for ActiveModel {}".to_string());
+        lines.join("\n")
+    }
+
+    fn emit_function(&self, func: &FunctionIR, ctx: &mut EmitContext) -> String {
+        let params: Vec<String> = func
+            .params
+            .iter()
+            .map(|p| format!("{}: {}", p.name, self.emit_type(&p.type_ir)))
+            .collect();
+        let ret = match &func.return_type {
+            TypeIR::Void => String::new(),
+            other => format!(" -> {}", self.emit_type(other)),
+        };
+        let mut lines = vec![format!(
+            "pub fn {}({}){} {{",
+            normalize_identifier(&func.name),
+            params.join(", "),
+            ret
+        )];
+        ctx.push_indent();
+        for stmt in &func.body {
+            lines.push(self.emit_statement(stmt, ctx));
+        }
+        ctx.pop_indent();
+        lines.push("}".to_string());
         lines.join("\n")
     }
 
@@ -365,6 +592,251 @@ impl RustEmitter {
         ctx.pop_indent();
         lines.join("\n")
     }
+
+    /// Lower try/catch/finally to a `Result`-returning block matched on the error.
+    ///
+    /// The try body runs in an async block (or a closure when it never awaits)
+    /// where `throw` becomes `return Err(..)` and `?` converts into `AppError`.
+    /// A `return` in the body yields `Ok(Some(value))`, which the match re-returns
+    /// from the handler. `finally` code is held by a drop guard so it runs on
+    /// every exit path, including those early returns.
+    fn emit_try_catch(
+        &self,
+        try_: &[StatementIR],
+        catch_: &CatchClauseIR,
+        finally_: Option<&[StatementIR]>,
+        ctx: &mut EmitContext,
+    ) -> String {
+        ctx.add_import("AppError", "crate::error::AppError");
+        let ind = ctx.indent();
+        let returns = statements_return(try_);
+        // SeaORM calls and transaction commits await even without an explicit Await node
+        let is_async = (returns && ctx.in_transaction())
+            || serde_json::to_value(try_)
+                .map(|v| {
+                    ["Await", "DbQuery", "DbMutate", "Transaction"]
+                        .iter()
+                        .any(|k| json_has_kind(&v, k))
+                })
+                .unwrap_or(false);
+        let nested = ctx.in_try();
+
+        let mut lines = vec![format!("{}{{", ind)];
+        ctx.push_indent();
+        let inner = ctx.indent();
+        if let Some(fin) = finally_ {
+            ctx.add_import("Finally", "crate::error::Finally");
+            let fin_body = self.emit_block(fin, ctx);
+            lines.push(format!(
+                "{}let _finally = Finally::new(|| {{\n{}\n{}}});",
+                inner, fin_body, inner
+            ));
+        }
+
+        let ok_type = if returns { "Option<_>" } else { "()" };
+        let ok_tail = if returns { "None" } else { "()" };
+        let opener = if is_async {
+            "async {".to_string()
+        } else {
+            format!("(|| -> Result<{}, AppError> {{", ok_type)
+        };
+        lines.push(format!(
+            "{}let result: Result<{}, AppError> = {}",
+            inner, ok_type, opener
+        ));
+        ctx.enter_try();
+        lines.push(self.emit_block(try_, ctx));
+        ctx.exit_try();
+        ctx.push_indent();
+        lines.push(format!("{}Ok::<_, AppError>({})", ctx.indent(), ok_tail));
+        ctx.pop_indent();
+        let closer = if is_async { "}.await;" } else { "})();" };
+        lines.push(format!("{}{}", inner, closer));
+
+        lines.push(format!("{}match result {{", inner));
+        ctx.push_indent();
+        let arm = ctx.indent();
+        if returns {
+            let rethrow = if nested {
+                "return Ok(Some(value))"
+            } else {
+                "return value"
+            };
+            lines.push(format!("{}Ok(Some(value)) => {},", arm, rethrow));
+            lines.push(format!("{}Ok(None) => {{}}", arm));
+        } else {
+            lines.push(format!("{}Ok(()) => {{}}", arm));
+        }
+        let catch_body = self.emit_block(&catch_.body, ctx);
+        lines.push(format!(
+            "{}Err({}) => {{\n{}\n{}}}",
+            arm, catch_.binding, catch_body, arm
+        ));
+        ctx.pop_indent();
+        lines.push(format!("{}}}", inner));
+        ctx.pop_indent();
+        lines.push(format!("{}}}", ind));
+        lines.join("\n")
+    }
+
+    /// Compile a filter into SeaORM `.filter(...)` calls. A top-level AND becomes
+    /// one call per condition, which SeaORM combines with AND.
+    fn emit_seaorm_filter(&self, model: &str, filter: &FilterIR, ctx: &mut EmitContext) -> String {
+        let conditions = match filter {
+            FilterIR::And { filters } => filters.iter().collect(),
+            f => vec![f],
+        };
+        ctx.add_import("QueryFilter", "sea_orm::{ColumnTrait, QueryFilter}");
+        conditions
+            .into_iter()
+            .map(|f| format!(".filter({})", self.emit_seaorm_condition(model, f, ctx)))
+            .collect()
+    }
+
+    fn emit_seaorm_condition(&self, model: &str, filter: &FilterIR, ctx: &mut EmitContext) -> String {
+        match filter {
+            FilterIR::Condition { field, op, value } => {
+                let column = seaorm_column(model, field);
+                let is_null = matches!(
+                    value.as_ref(),
+                    ExprIR::Literal {
+                        value: serde_json::Value::Null
+                    }
+                );
+                match op {
+                    FilterOp::Eq if is_null => return format!("{}.is_null()", column),
+                    FilterOp::Ne if is_null => return format!("{}.is_not_null()", column),
+                    _ => {}
+                }
+                let method = match op {
+                    FilterOp::Eq => "eq",
+                    FilterOp::Ne => "ne",
+                    FilterOp::Gt => "gt",
+                    FilterOp::Gte => "gte",
+                    FilterOp::Lt => "lt",
+                    FilterOp::Lte => "lte",
+                    FilterOp::In => "is_in",
+                    FilterOp::NotIn => "is_not_in",
+                    FilterOp::Contains => "contains",
+                    FilterOp::StartsWith => "starts_with",
+                    FilterOp::EndsWith => "ends_with",
+                };
+                // Bindings are reused across queries, so they're cloned into the filter
+                let value = match value.as_ref() {
+                    ExprIR::Identifier { name } => format!("{}.clone()", name),
+                    other => self.emit_expression(other, ctx),
+                };
+                format!("{}.{}({})", column, method, value)
+            }
+            FilterIR::And { filters } | FilterIR::Or { filters } => {
+                ctx.add_import("Condition", "sea_orm::Condition");
+                let mut cond = if matches!(filter, FilterIR::And { .. }) {
+                    "Condition::all()".to_string()
+                } else {
+                    "Condition::any()".to_string()
+                };
+                for f in filters {
+                    cond.push_str(&format!(".add({})", self.emit_seaorm_condition(model, f, ctx)));
+                }
+                cond
+            }
+            FilterIR::Not { filter } => {
+                ctx.add_import("Condition", "sea_orm::Condition");
+                format!(
+                    "Condition::all().add({}).not()",
+                    self.emit_seaorm_condition(model, filter, ctx)
+                )
+            }
+        }
+    }
+}
+
+/// Signing key for `jsonwebtoken`, read from the environment.
+const JWT_SECRET: &str = "std::env::var(\"JWT_SECRET\").unwrap_or_default().as_bytes()";
+
+/// Binding for the transaction at `depth` (1-based): `txn`, `txn2`, ...
+fn seaorm_txn(depth: usize) -> String {
+    if depth <= 1 {
+        "txn".to_string()
+    } else {
+        format!("txn{}", depth)
+    }
+}
+
+/// `sea_orm::IsolationLevel` variant.
+fn seaorm_isolation_level(level: IsolationLevel) -> &'static str {
+    match level {
+        IsolationLevel::ReadUncommitted => "IsolationLevel::ReadUncommitted",
+        IsolationLevel::ReadCommitted => "IsolationLevel::ReadCommitted",
+        IsolationLevel::RepeatableRead => "IsolationLevel::RepeatableRead",
+        IsolationLevel::Serializable => "IsolationLevel::Serializable",
+    }
+}
+
+/// Connection argument for SeaORM calls: the innermost transaction, if any.
+fn seaorm_conn(ctx: &EmitContext) -> String {
+    if ctx.in_transaction() {
+        format!("&{}", seaorm_txn(ctx.transaction_depth()))
+    } else {
+        "&db".to_string()
+    }
+}
+
+/// Whether a serialized IR tree contains a node of the given `kind`.
+pub(crate) fn json_has_kind(value: &serde_json::Value, kind: &str) -> bool {
+    match value {
+        serde_json::Value::Object(map) => {
+            map.get("kind").and_then(|k| k.as_str()) == Some(kind)
+                || map.values().any(|v| json_has_kind(v, kind))
+        }
+        serde_json::Value::Array(arr) => arr.iter().any(|v| json_has_kind(v, kind)),
+        _ => false,
+    }
+}
+
+/// Bring a model's entity (`User`) and its module (`user`, for columns and
+/// active models) into scope.
+fn import_seaorm_entity(model: &str, ctx: &mut EmitContext) {
+    let module = model.to_lowercase();
+    let alias = to_snake_case(model);
+    let module_use = if alias == module {
+        "self".to_string()
+    } else {
+        format!("self as {}", alias)
+    };
+    ctx.add_import(
+        model,
+        format!("crate::models::{}::{{{}, Entity as {}}}", module, module_use, model),
+    );
+}
+
+/// SeaORM column path for a model field (`user::Column::CreatedAt`).
+fn seaorm_column(model: &str, field: &str) -> String {
+    format!("{}::Column::{}", to_snake_case(model), to_pascal_case(field))
+}
+
+fn to_pascal_case(s: &str) -> String {
+    s.split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(|w| {
+            let mut chars = w.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+fn to_snake_case(s: &str) -> String {
+    let mut result = String::new();
+    for (i, ch) in s.chars().enumerate() {
+        if ch.is_uppercase() && i > 0 {
+            result.push('_');
+        }
+        result.push(ch.to_lowercase().next().unwrap_or(ch));
+    }
+    result
 }
 
 fn emit_rust_literal(value: &serde_json::Value) -> String {
@@ -433,6 +905,23 @@ fn json_schema_type_to_rust(schema: &serde_json::Value) -> String {
     json_type_to_rust(type_str)
 }
 
+/// SeaORM prelude type for a model column type such as `varchar(255)`.
+fn seaorm_column_type(type_str: &str) -> &'static str {
+    let base = type_str.split('(').next().unwrap_or(type_str).trim();
+    match base.to_ascii_lowercase().as_str() {
+        "uuid" => "Uuid",
+        "int" | "integer" | "smallint" | "serial" => "i32",
+        "bigint" | "bigserial" => "i64",
+        "float" | "double" | "decimal" | "numeric" | "real" | "number" => "f64",
+        "boolean" | "bool" => "bool",
+        "timestamp" | "datetime" => "DateTime",
+        "timestamptz" => "DateTimeWithTimeZone",
+        "date" => "Date",
+        "json" | "jsonb" | "object" | "array" => "Json",
+        _ => "String",
+    }
+}
+
 fn json_type_to_rust(type_str: &str) -> String {
     match type_str {
         "string" | "varchar" | "text" | "uuid" => "String".to_string(),
@@ -449,6 +938,9 @@ fn json_type_to_rust(type_str: &str) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::rc::Rc;
+
+    use crate::adapters::axum::AxumAdapter;
 
     #[test]
     fn test_emit_type() {
@@ -481,4 +973,295 @@ mod tests {
         let code = emitter.emit_statement(&stmt, &mut ctx);
         assert!(code.contains("let x: String = \"hello\".to_string();"));
     }
+
+    #[test]
+    fn test_emit_seaorm_query() {
+        use rash_ir::expr::{DbQueryIR, OrderByIR};
+
+        let emitter = RustEmitter;
+        let mut ctx = EmitContext::new(IndentStyle::Spaces(4));
+        let find = ExprIR::Await {
+            expr: Box::new(ExprIR::DbQuery(DbQueryIR {
+                model: "User".to_string(),
+                operation: "findMany".to_string(),
+                r#where: Some(FilterIR::And {
+                    filters: vec![
+                        FilterIR::eq("deletedAt", ExprIR::literal(serde_json::Value::Null)),
+                        FilterIR::Not {
+                            filter: Box::new(FilterIR::Or {
+                                filters: vec![
+                                    FilterIR::Condition {
+                                        field: "role".to_string(),
+                                        op: FilterOp::In,
+                                        value: Box::new(ExprIR::ident("blocked")),
+                                    },
+                                    FilterIR::Condition {
+                                        field: "age".to_string(),
+                                        op: FilterOp::Lt,
+                                        value: Box::new(ExprIR::ident("minAge")),
+                                    },
+                                ],
+                            }),
+                        },
+                    ],
+                }),
+                order_by: vec![OrderByIR {
+                    field: "createdAt".to_string(),
+                    direction: SortDirection::Desc,
+                }],
+                skip: None,
+                take: Some(Box::new(ExprIR::ident("limit"))),
+                select: None,
+                include: vec![],
+            })),
+        };
+        assert_eq!(
+            emitter.emit_expression(&find, &mut ctx),
+            "User::find().filter(user::Column::DeletedAt.is_null())\
+             .filter(Condition::all().add(Condition::any().add(user::Column::Role.is_in(blocked.clone()))\
+             .add(user::Column::Age.lt(minAge.clone()))).not())\
+             .order_by_desc(user::Column::CreatedAt).limit(limit as u64).all(&db).await?"
+        );
+        let imports = emitter.emit_imports(&mut ctx);
+        assert!(imports.contains("use sea_orm::{ColumnTrait, QueryFilter};"));
+        assert!(imports.contains("use sea_orm::Condition;"));
+    }
+
+    #[test]
+    fn test_emit_try_catch_finally() {
+        use rash_ir::statement::CatchClauseIR;
+        let emitter = RustEmitter;
+        let mut ctx = EmitContext::new(IndentStyle::Spaces(4)).with_adapter(Rc::new(AxumAdapter));
+        let call = |name: &str| StatementIR::Expression {
+            expr: ExprIR::Call {
+                callee: Box::new(ExprIR::ident(name)),
+                args: vec![],
+            },
+        };
+        let stmt = StatementIR::TryCatch {
+            try_: vec![
+                StatementIR::If {
+                    condition: ExprIR::ident("missing"),
+                    then_: vec![StatementIR::Throw {
+                        value: ExprIR::literal(serde_json::json!("not found")),
+                    }],
+                    else_: None,
+                },
+                StatementIR::Return {
+                    value: Some(ExprIR::ident("ok")),
+                },
+            ],
+            catch_: CatchClauseIR {
+                binding: "err".to_string(),
+                body: vec![StatementIR::Throw {
+                    value: ExprIR::ident("err"),
+                }],
+            },
+            finally_: Some(vec![call("cleanup")]),
+        };
+
+        let code = emitter.emit_statement(&stmt, &mut ctx);
+        assert_eq!(
+            code,
+            r#"{
+    let _finally = Finally::new(|| {
+        cleanup();
+    });
+    let result: Result<Option<_>, AppError> = (|| -> Result<Option<_>, AppError> {
+        if missing {
+            return Err(AppError::from("not found".to_string()));
+        }
+        return Ok(Some(ok));
+        Ok::<_, AppError>(None)
+    })();
+    match result {
+        Ok(Some(value)) => return value,
+        Ok(None) => {}
+        Err(err) => {
+            return Err(AppError::from(err));
+        }
+    }
+}"#
+        );
+        let imports = emitter.emit_imports(&mut ctx);
+        assert!(imports.contains("use crate::error::AppError;"));
+        assert!(imports.contains("use crate::error::Finally;"));
+    }
+
+    #[test]
+    fn test_emit_async_try_catch() {
+        use rash_ir::statement::CatchClauseIR;
+        let emitter = RustEmitter;
+        let mut ctx = EmitContext::new(IndentStyle::Spaces(4)).with_adapter(Rc::new(AxumAdapter));
+        let stmt = StatementIR::TryCatch {
+            try_: vec![StatementIR::Let {
+                name: "user".to_string(),
+                type_: None,
+                value: ExprIR::Await {
+                    expr: Box::new(ExprIR::Call {
+                        callee: Box::new(ExprIR::ident("fetchUser")),
+                        args: vec![],
+                    }),
+                },
+            }],
+            catch_: CatchClauseIR {
+                binding: "err".to_string(),
+                body: vec![StatementIR::Expression {
+                    expr: ExprIR::Call {
+                        callee: Box::new(ExprIR::ident("report")),
+                        args: vec![ExprIR::ident("err")],
+                    },
+                }],
+            },
+            finally_: None,
+        };
+
+        assert_eq!(
+            emitter.emit_statement(&stmt, &mut ctx),
+            r#"{
+    let result: Result<(), AppError> = async {
+        let user = fetchUser().await;
+        Ok::<_, AppError>(())
+    }.await;
+    match result {
+        Ok(()) => {}
+        Err(err) => {
+            report(err);
+        }
+    }
+}"#
+        );
+        let imports = emitter.emit_imports(&mut ctx);
+        assert!(imports.contains("use crate::error::AppError;"));
+        assert!(!imports.contains("Finally"));
+    }
+
+    #[test]
+    fn test_emit_throw_maps_to_app_error() {
+        let emitter = RustEmitter;
+        let throw = StatementIR::Throw {
+            value: ExprIR::Object {
+                properties: vec![
+                    ("status".to_string(), ExprIR::literal(serde_json::json!(404))),
+                    (
+                        "message".to_string(),
+                        ExprIR::literal(serde_json::json!("not found")),
+                    ),
+                ],
+            },
+        };
+
+        // Handlers that return `Result` convert the thrown value into `AppError`,
+        // which takes its status from the thrown object
+        let mut ctx = EmitContext::new(IndentStyle::Spaces(4)).with_adapter(Rc::new(AxumAdapter));
+        assert_eq!(
+            emitter.emit_statement(&throw, &mut ctx),
+            r#"return Err(AppError::from(serde_json::json!({ "status": 404, "message": "not found" })));"#
+        );
+        assert!(emitter
+            .emit_imports(&mut ctx)
+            .contains("use crate::error::AppError;"));
+
+        // Elsewhere the enclosing function's error type decides
+        let mut ctx = EmitContext::new(IndentStyle::Spaces(4));
+        assert_eq!(
+            emitter.emit_statement(&throw, &mut ctx),
+            r#"return Err(serde_json::json!({ "status": 404, "message": "not found" }).into());"#
+        );
+        assert!(ctx.imports().is_empty());
+    }
+
+    #[test]
+    fn test_emit_seaorm_transaction() {
+        let emitter = RustEmitter;
+        let mut ctx = EmitContext::new(IndentStyle::Spaces(4)).with_adapter(Rc::new(AxumAdapter));
+        let create_order = StatementIR::Expression {
+            expr: ExprIR::Await {
+                expr: Box::new(ExprIR::DbMutate(rash_ir::expr::DbMutateIR {
+                    model: "Order".to_string(),
+                    operation: "create".to_string(),
+                    data: Some(Box::new(ExprIR::ident("order"))),
+                    r#where: None,
+                })),
+            },
+        };
+        let tx = StatementIR::Transaction {
+            body: vec![
+                create_order,
+                StatementIR::If {
+                    condition: ExprIR::ident("outOfStock"),
+                    then_: vec![StatementIR::Throw {
+                        value: ExprIR::literal(serde_json::json!("out of stock")),
+                    }],
+                    else_: None,
+                },
+                StatementIR::Return {
+                    value: Some(ExprIR::HttpRespond(rash_ir::expr::HttpRespondIR {
+                        status: 201,
+                        headers: None,
+                        body: Some(Box::new(ExprIR::ident("order"))),
+                    })),
+                },
+            ],
+            isolation: Some(IsolationLevel::Serializable),
+        };
+
+        assert_eq!(
+            emitter.emit_statement(&tx, &mut ctx),
+            r#"{
+    let txn = db.begin_with_config(Some(IsolationLevel::Serializable), None).await?;
+    order::ActiveModel::from_json(order.clone())?.insert(&txn).await?;
+    if outOfStock {
+        return Err(AppError::from("out of stock".to_string()));
+    }
+    let ret = Ok((StatusCode::CREATED, Json(order)).into_response());
+    txn.commit().await?;
+    return ret;
+}"#
+        );
+    }
+
+    #[test]
+    fn test_emit_user_function() {
+        use rash_ir::types::{FunctionIR, FunctionParamIR};
+
+        let emitter = RustEmitter;
+        let mut ctx = EmitContext::new(IndentStyle::Spaces(4));
+        let func = FunctionIR {
+            name: "formatCurrency".to_string(),
+            params: vec![
+                FunctionParamIR {
+                    name: "amount".to_string(),
+                    type_ir: TypeIR::Number,
+                    default: None,
+                },
+                FunctionParamIR {
+                    name: "currency".to_string(),
+                    type_ir: TypeIR::String,
+                    default: Some(serde_json::json!("KRW")),
+                },
+            ],
+            return_type: TypeIR::String,
+            body: vec![StatementIR::Return {
+                value: Some(ExprIR::ident("amount")),
+            }],
+            max_tier: rash_spec::types::common::Tier::Universal,
+        };
+        let call = ExprIR::CallFunction {
+            function: "formatCurrency".to_string(),
+            args: vec![ExprIR::ident("total")],
+        };
+
+        assert_eq!(
+            emitter.emit_function(&func, &mut ctx),
+            "pub fn formatCurrency(amount: f64, currency: String) -> String {\n    return amount;\n}"
+        );
+
+        let mut ctx = EmitContext::new(IndentStyle::Spaces(4)).with_functions(&[func]);
+        assert_eq!(
+            emitter.emit_expression(&call, &mut ctx),
+            "crate::functions::formatCurrency(total, \"KRW\".to_string())"
+        );
+        assert!(ctx.imports().is_empty());
+    }
 }
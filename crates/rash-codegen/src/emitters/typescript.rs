@@ -1,10 +1,15 @@
+use std::collections::BTreeSet;
+
+use rash_spec::types::ast::IsolationLevel;
 use rash_spec::types::common::Language;
 
 use crate::context::{EmitContext, IndentStyle};
+use crate::generator::normalize_identifier;
+use crate::emitters::statements_return;
 use crate::traits::LanguageEmitter;
-use rash_ir::expr::{ExprIR, TypeIR};
+use rash_ir::expr::{ExprIR, FilterIR, FilterOp, IncludeIR, OrderByIR, SortDirection, TypeIR};
 use rash_ir::statement::StatementIR;
-use rash_ir::types::{ModelIR, SchemaIR};
+use rash_ir::types::{FunctionIR, ModelIR, SchemaIR};
 
 /// TypeScript code emitter.
 pub struct TypeScriptEmitter;
@@ -115,6 +120,41 @@ impl LanguageEmitter for TypeScriptEmitter {
                 let v = self.emit_expression(value, ctx);
                 format!("{}throw {};", ind, v)
             }
+            // Interactive transactions don't nest, so inner ones join the outer one
+            StatementIR::Transaction { body, .. } if ctx.in_transaction() => body
+                .iter()
+                .map(|s| self.emit_statement(s, ctx))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            // Prisma rolls back when the callback throws
+            StatementIR::Transaction { body, isolation } => {
+                ctx.add_import("{ prisma }", "./prisma");
+                let returns = statements_return(body);
+                ctx.enter_transaction();
+                let body_code = self.emit_block(body, ctx);
+                ctx.exit_transaction();
+                let options = isolation
+                    .map(|level| format!(", {{ isolationLevel: \"{}\" }}", prisma_isolation_level(level)))
+                    .unwrap_or_default();
+                let call = format!(
+                    "await prisma.$transaction(async (tx) => {{\n{}\n{}}}{});",
+                    body_code, ind, options
+                );
+                if returns {
+                    format!(
+                        "{}const txResult = {}\n{}if (txResult !== undefined) return txResult;",
+                        ind, call, ind
+                    )
+                } else {
+                    format!("{}{}", ind, call)
+                }
+            }
+            // Components are expanded inline at the use site
+            StatementIR::Component { body, .. } => body
+                .iter()
+                .map(|s| self.emit_statement(s, ctx))
+                .collect::<Vec<_>>()
+                .join("\n"),
             StatementIR::Expression { expr } => {
                 let e = self.emit_expression(expr, ctx);
                 format!("{}{};", ind, e)
@@ -123,6 +163,9 @@ impl LanguageEmitter for TypeScriptEmitter {
     }
 
     fn emit_expression(&self, expr: &ExprIR, ctx: &mut EmitContext) -> String {
+        if let Some(code) = ctx.emit_domain_expr(expr, self) {
+            return code;
+        }
         match expr {
             ExprIR::Literal { value } => emit_json_literal(value),
             ExprIR::Identifier { name } => name.clone(),
@@ -140,6 +183,12 @@ impl LanguageEmitter for TypeScriptEmitter {
                 let a: Vec<String> = args.iter().map(|a| self.emit_expression(a, ctx)).collect();
                 format!("{}({})", c, a.join(", "))
             }
+            ExprIR::CallFunction { function, args } => {
+                let name = normalize_identifier(function);
+                ctx.add_import(format!("{{ {} }}", name), FUNCTIONS_MODULE);
+                let a: Vec<String> = args.iter().map(|a| self.emit_expression(a, ctx)).collect();
+                format!("{}({})", name, a.join(", "))
+            }
             ExprIR::Member { object, property } => {
                 let obj = self.emit_expression(object, ctx);
                 format!("{}.{}", obj, property)
@@ -204,16 +253,16 @@ impl LanguageEmitter for TypeScriptEmitter {
             }
             // Domain nodes (Tier 1)
             ExprIR::DbQuery(q) => {
-                ctx.add_import("{ prisma }", "./prisma");
+                let client = self.prisma_client(ctx);
                 let model = to_camel_case(&q.model);
                 let op = &q.operation;
                 let mut args = String::new();
                 let mut arg_parts = Vec::new();
                 if let Some(w) = &q.r#where {
-                    arg_parts.push(format!("where: {}", emit_json_literal(w)));
+                    arg_parts.push(format!("where: {}", self.emit_prisma_where(w, ctx)));
                 }
-                if let Some(ob) = &q.order_by {
-                    arg_parts.push(format!("orderBy: {}", emit_json_literal(ob)));
+                if !q.order_by.is_empty() {
+                    arg_parts.push(format!("orderBy: {}", emit_prisma_order_by(&q.order_by)));
                 }
                 if let Some(skip) = &q.skip {
                     arg_parts.push(format!("skip: {}", self.emit_expression(skip, ctx)));
@@ -225,16 +274,16 @@ impl LanguageEmitter for TypeScriptEmitter {
                     let fields: Vec<String> = sel.iter().map(|f| format!("{}: true", f)).collect();
                     arg_parts.push(format!("select: {{ {} }}", fields.join(", ")));
                 }
-                if let Some(inc) = &q.include {
-                    arg_parts.push(format!("include: {}", emit_json_literal(inc)));
+                if !q.include.is_empty() {
+                    arg_parts.push(format!("include: {}", emit_prisma_include(&q.include)));
                 }
                 if !arg_parts.is_empty() {
                     args = format!("{{ {} }}", arg_parts.join(", "));
                 }
-                format!("prisma.{}.{}({})", model, op, args)
+                format!("{}.{}.{}({})", client, model, op, args)
             }
             ExprIR::DbMutate(m) => {
-                ctx.add_import("{ prisma }", "./prisma");
+                let client = self.prisma_client(ctx);
                 let model = to_camel_case(&m.model);
                 let op = &m.operation;
                 let mut arg_parts = Vec::new();
@@ -242,14 +291,14 @@ impl LanguageEmitter for TypeScriptEmitter {
                     arg_parts.push(format!("data: {}", self.emit_expression(data, ctx)));
                 }
                 if let Some(w) = &m.r#where {
-                    arg_parts.push(format!("where: {}", emit_json_literal(w)));
+                    arg_parts.push(format!("where: {}", self.emit_prisma_where(w, ctx)));
                 }
                 let args = if arg_parts.is_empty() {
                     String::new()
                 } else {
                     format!("{{ {} }}", arg_parts.join(", "))
                 };
-                format!("prisma.{}.{}({})", model, op, args)
+                format!("{}.{}.{}({})", client, model, op, args)
             }
             ExprIR::HttpRespond(r) => {
                 let body = r
@@ -395,6 +444,32 @@ impl LanguageEmitter for TypeScriptEmitter {
         lines.join("\n")
     }
 
+    fn emit_function(&self, func: &FunctionIR, ctx: &mut EmitContext) -> String {
+        let params: Vec<String> = func
+            .params
+            .iter()
+            .map(|p| match &p.default {
+                Some(d) => format!("{}: {} = {}", p.name, self.emit_type(&p.type_ir), emit_json_literal(d)),
+                None => format!("{}: {}", p.name, self.emit_type(&p.type_ir)),
+            })
+            .collect();
+        let mut lines = vec![format!(
+            "export function {}({}): {} {{",
+            normalize_identifier(&func.name),
+            params.join(", "),
+            self.emit_type(&func.return_type)
+        )];
+        ctx.push_indent();
+        for stmt in &func.body {
+            lines.push(self.emit_statement(stmt, ctx));
+        }
+        ctx.pop_indent();
+        lines.push("}".to_string());
+        // Functions calling each other share the module, so no self-import
+        ctx.retain_imports(|imp| imp.from != FUNCTIONS_MODULE);
+        lines.join("\n")
+    }
+
     fn emit_imports(&self, ctx: &mut EmitContext) -> String {
         let imports = ctx.take_imports();
         let mut lines: Vec<String> = imports
@@ -422,10 +497,128 @@ impl TypeScriptEmitter {
         ctx.pop_indent();
         lines.join("\n")
     }
+
+    /// Prisma client for DB calls: the interactive transaction client inside a transaction.
+    fn prisma_client(&self, ctx: &mut EmitContext) -> &'static str {
+        if ctx.in_transaction() {
+            "tx"
+        } else {
+            ctx.add_import("{ prisma }", "./prisma");
+            "prisma"
+        }
+    }
+
+    /// Compile a filter into a Prisma `where` object literal.
+    fn emit_prisma_where(&self, filter: &FilterIR, ctx: &mut EmitContext) -> String {
+        format!("{{ {} }}", self.prisma_where_entries(filter, ctx).join(", "))
+    }
+
+    /// Entries of a Prisma `where` object. Top-level AND over distinct fields is
+    /// flattened into sibling keys, which Prisma combines with AND implicitly.
+    fn prisma_where_entries(&self, filter: &FilterIR, ctx: &mut EmitContext) -> Vec<String> {
+        match filter {
+            FilterIR::Condition { field, op, value } => {
+                let value = self.emit_expression(value, ctx);
+                let entry = match op {
+                    FilterOp::Eq => value,
+                    op => format!("{{ {}: {} }}", prisma_filter_op(*op), value),
+                };
+                vec![format!("{}: {}", field, entry)]
+            }
+            FilterIR::And { filters } if prisma_fields_distinct(filters) => filters
+                .iter()
+                .flat_map(|f| self.prisma_where_entries(f, ctx))
+                .collect(),
+            FilterIR::And { filters } | FilterIR::Or { filters } => {
+                let key = if matches!(filter, FilterIR::And { .. }) { "AND" } else { "OR" };
+                let subs: Vec<String> = filters
+                    .iter()
+                    .map(|f| self.emit_prisma_where(f, ctx))
+                    .collect();
+                vec![format!("{}: [{}]", key, subs.join(", "))]
+            }
+            FilterIR::Not { filter } => {
+                vec![format!("NOT: {}", self.emit_prisma_where(filter, ctx))]
+            }
+        }
+    }
+}
+
+/// Import path of the shared `functions` module from files one level below `src/`.
+const FUNCTIONS_MODULE: &str = "../functions";
+
+/// Prisma `TransactionIsolationLevel` value.
+fn prisma_isolation_level(level: IsolationLevel) -> &'static str {
+    match level {
+        IsolationLevel::ReadUncommitted => "ReadUncommitted",
+        IsolationLevel::ReadCommitted => "ReadCommitted",
+        IsolationLevel::RepeatableRead => "RepeatableRead",
+        IsolationLevel::Serializable => "Serializable",
+    }
+}
+
+/// Prisma filter key for a comparison operator.
+fn prisma_filter_op(op: FilterOp) -> &'static str {
+    match op {
+        FilterOp::Eq => "equals",
+        FilterOp::Ne => "not",
+        FilterOp::Gt => "gt",
+        FilterOp::Gte => "gte",
+        FilterOp::Lt => "lt",
+        FilterOp::Lte => "lte",
+        FilterOp::In => "in",
+        FilterOp::NotIn => "notIn",
+        FilterOp::Contains => "contains",
+        FilterOp::StartsWith => "startsWith",
+        FilterOp::EndsWith => "endsWith",
+    }
+}
+
+/// Whether every filter is a plain condition on a field no other condition uses.
+fn prisma_fields_distinct(filters: &[FilterIR]) -> bool {
+    let mut seen = BTreeSet::new();
+    filters.iter().all(|f| match f {
+        FilterIR::Condition { field, .. } => seen.insert(field.as_str()),
+        _ => false,
+    })
+}
+
+/// Emit a Prisma `orderBy` argument; multiple keys need the array form.
+fn emit_prisma_order_by(order_by: &[OrderByIR]) -> String {
+    let keys: Vec<String> = order_by
+        .iter()
+        .map(|o| {
+            let dir = match o.direction {
+                SortDirection::Asc => "asc",
+                SortDirection::Desc => "desc",
+            };
+            format!("{{ {}: \"{}\" }}", o.field, dir)
+        })
+        .collect();
+    if keys.len() == 1 {
+        keys.into_iter().next().unwrap_or_default()
+    } else {
+        format!("[{}]", keys.join(", "))
+    }
+}
+
+/// Emit a Prisma `include` object, nesting `include` for deeper relations.
+fn emit_prisma_include(include: &[IncludeIR]) -> String {
+    let entries: Vec<String> = include
+        .iter()
+        .map(|i| {
+            if i.include.is_empty() {
+                format!("{}: true", i.relation)
+            } else {
+                format!("{}: {{ include: {} }}", i.relation, emit_prisma_include(&i.include))
+            }
+        })
+        .collect();
+    format!("{{ {} }}", entries.join(", "))
 }
 
 /// Convert a serde_json::Value to a TypeScript literal string.
-fn emit_json_literal(value: &serde_json::Value) -> String {
+pub(crate) fn emit_json_literal(value: &serde_json::Value) -> String {
     match value {
         serde_json::Value::Null => "null".to_string(),
         serde_json::Value::Bool(b) => b.to_string(),
@@ -553,6 +746,42 @@ fn json_schema_to_zod(schema: &serde_json::Value) -> String {
     }
 }
 
+/// Map a JSON Schema to a TypeScript type annotation.
+pub(crate) fn json_schema_to_ts_type(schema: &serde_json::Value) -> String {
+    if let Some(r) = schema_ref_name(schema) {
+        return r.to_string();
+    }
+    if let Some(vals) = schema.get("enum").and_then(|e| e.as_array()) {
+        let literals: Vec<String> = vals.iter().map(emit_json_literal).collect();
+        return literals.join(" | ");
+    }
+    match schema.get("type").and_then(|t| t.as_str()) {
+        Some("string") => "string".to_string(),
+        Some("integer") | Some("number") => "number".to_string(),
+        Some("boolean") => "boolean".to_string(),
+        Some("null") => "null".to_string(),
+        Some("array") => {
+            let inner = schema
+                .get("items")
+                .map(json_schema_to_ts_type)
+                .unwrap_or_else(|| "unknown".to_string());
+            format!("{}[]", inner)
+        }
+        Some("object") => "Record<string, unknown>".to_string(),
+        _ => "unknown".to_string(),
+    }
+}
+
+/// Name of the definition a `$ref` points to, e.g. `#/definitions/User` → `User`.
+pub(crate) fn schema_ref_name(schema: &serde_json::Value) -> Option<&str> {
+    let r = schema
+        .get("$ref")
+        .or_else(|| schema.get("ref"))
+        .and_then(|v| v.as_str())?;
+    let name = r.rsplit('#').next().unwrap_or(r);
+    Some(name.rsplit('/').next().unwrap_or(name))
+}
+
 /// Convert a Prisma column definition to a TypeScript type.
 fn prisma_col_to_ts_type(col_def: &serde_json::Value) -> String {
     let type_str = col_def
@@ -700,6 +929,78 @@ mod tests {
         assert!(zod.contains("age: z.number().int().min(0).optional()"));
     }
 
+    #[test]
+    fn test_emit_prisma_query_args() {
+        use rash_ir::expr::{DbQueryIR, IncludeIR, OrderByIR};
+
+        let emitter = TypeScriptEmitter;
+        let mut ctx = EmitContext::new(IndentStyle::Spaces(2));
+        let expr = ExprIR::DbQuery(DbQueryIR {
+            model: "User".to_string(),
+            operation: "findMany".to_string(),
+            r#where: Some(FilterIR::And {
+                filters: vec![
+                    FilterIR::eq("id", ExprIR::ident("userId")),
+                    FilterIR::Condition {
+                        field: "age".to_string(),
+                        op: FilterOp::Gte,
+                        value: Box::new(ExprIR::literal(serde_json::json!(18))),
+                    },
+                    FilterIR::Or {
+                        filters: vec![
+                            FilterIR::eq("role", ExprIR::literal(serde_json::json!("admin"))),
+                            FilterIR::Condition {
+                                field: "email".to_string(),
+                                op: FilterOp::Ne,
+                                value: Box::new(ExprIR::literal(serde_json::Value::Null)),
+                            },
+                        ],
+                    },
+                ],
+            }),
+            order_by: vec![OrderByIR {
+                field: "createdAt".to_string(),
+                direction: SortDirection::Desc,
+            }],
+            skip: None,
+            take: None,
+            select: None,
+            include: vec![IncludeIR {
+                relation: "profile".to_string(),
+                include: vec![IncludeIR {
+                    relation: "user".to_string(),
+                    include: vec![],
+                }],
+            }],
+        });
+        assert_eq!(
+            emitter.emit_expression(&expr, &mut ctx),
+            "prisma.user.findMany({ where: { AND: [{ id: userId }, { age: { gte: 18 } }, \
+             { OR: [{ role: \"admin\" }, { email: { not: null } }] }] }, \
+             orderBy: { createdAt: \"desc\" }, include: { profile: { include: { user: true } } } })"
+        );
+
+        let by_id = ExprIR::DbQuery(DbQueryIR {
+            model: "User".to_string(),
+            operation: "findUnique".to_string(),
+            r#where: Some(FilterIR::And {
+                filters: vec![
+                    FilterIR::eq("id", ExprIR::ident("userId")),
+                    FilterIR::eq("tenantId", ExprIR::ident("tenant")),
+                ],
+            }),
+            order_by: vec![],
+            skip: None,
+            take: None,
+            select: None,
+            include: vec![],
+        });
+        assert_eq!(
+            emitter.emit_expression(&by_id, &mut ctx),
+            "prisma.user.findUnique({ where: { id: userId, tenantId: tenant } })"
+        );
+    }
+
     #[test]
     fn test_emit_template_string() {
         let emitter = TypeScriptEmitter;
@@ -717,4 +1018,122 @@ mod tests {
         let code = emitter.emit_expression(&expr, &mut ctx);
         assert_eq!(code, "`Hello ${name}`");
     }
+
+    #[test]
+    fn test_emit_prisma_transaction() {
+        let emitter = TypeScriptEmitter;
+        let mut ctx = EmitContext::new(IndentStyle::Spaces(2));
+        let create_order = StatementIR::Expression {
+            expr: ExprIR::Await {
+                expr: Box::new(ExprIR::DbMutate(rash_ir::expr::DbMutateIR {
+                    model: "Order".to_string(),
+                    operation: "create".to_string(),
+                    data: Some(Box::new(ExprIR::ident("order"))),
+                    r#where: None,
+                })),
+            },
+        };
+        let tx = StatementIR::Transaction {
+            body: vec![
+                create_order,
+                StatementIR::If {
+                    condition: ExprIR::ident("outOfStock"),
+                    then_: vec![StatementIR::Throw {
+                        value: ExprIR::literal(serde_json::json!("out of stock")),
+                    }],
+                    else_: None,
+                },
+                StatementIR::Return {
+                    value: Some(ExprIR::HttpRespond(rash_ir::expr::HttpRespondIR {
+                        status: 201,
+                        headers: None,
+                        body: Some(Box::new(ExprIR::ident("order"))),
+                    })),
+                },
+            ],
+            isolation: Some(IsolationLevel::Serializable),
+        };
+
+        assert_eq!(
+            emitter.emit_statement(&tx, &mut ctx),
+            r#"const txResult = await prisma.$transaction(async (tx) => {
+  await tx.order.create({ data: order });
+  if (outOfStock) {
+    throw "out of stock";
+  }
+  return res.status(201).json(order);
+}, { isolationLevel: "Serializable" });
+if (txResult !== undefined) return txResult;"#
+        );
+    }
+
+    #[test]
+    fn test_emit_user_function() {
+        use rash_ir::types::{FunctionIR, FunctionParamIR};
+
+        let emitter = TypeScriptEmitter;
+        let mut ctx = EmitContext::new(IndentStyle::Spaces(2));
+        let func = FunctionIR {
+            name: "formatCurrency".to_string(),
+            params: vec![
+                FunctionParamIR {
+                    name: "amount".to_string(),
+                    type_ir: TypeIR::Number,
+                    default: None,
+                },
+                FunctionParamIR {
+                    name: "currency".to_string(),
+                    type_ir: TypeIR::String,
+                    default: Some(serde_json::json!("KRW")),
+                },
+            ],
+            return_type: TypeIR::String,
+            body: vec![StatementIR::Return {
+                value: Some(ExprIR::ident("amount")),
+            }],
+            max_tier: rash_spec::types::common::Tier::Universal,
+        };
+        let call = ExprIR::CallFunction {
+            function: "formatCurrency".to_string(),
+            args: vec![ExprIR::ident("total")],
+        };
+
+        assert_eq!(
+            emitter.emit_function(&func, &mut ctx),
+            "export function formatCurrency(amount: number, currency: string = \"KRW\"): string {\n  return amount;\n}"
+        );
+        assert_eq!(emitter.emit_expression(&call, &mut ctx), "formatCurrency(total)");
+        assert_eq!(
+            emitter.emit_imports(&mut ctx),
+            "import { formatCurrency } from \"../functions\";"
+        );
+    }
+
+    #[test]
+    fn test_emit_component_inline() {
+        let emitter = TypeScriptEmitter;
+        let mut ctx = EmitContext::new(IndentStyle::Spaces(2));
+        ctx.push_indent();
+        let stmt = StatementIR::Component {
+            name: "paginate".to_string(),
+            source: "components/pagination.component.json".to_string(),
+            body: vec![
+                StatementIR::Let {
+                    name: "skip".to_string(),
+                    type_: None,
+                    value: ExprIR::literal(serde_json::json!(0)),
+                },
+                StatementIR::Let {
+                    name: "result".to_string(),
+                    type_: None,
+                    value: ExprIR::ident("skip"),
+                },
+            ],
+        };
+
+        assert_eq!(
+            emitter.emit_statement(&stmt, &mut ctx),
+            "  const skip = 0;\n  const result = skip;"
+        );
+    }
 }
@@ -1,14 +1,15 @@
 use std::collections::BTreeMap;
 use std::path::Path;
+use std::rc::Rc;
 
-use rash_spec::types::common::{Framework, Language};
+use rash_spec::types::common::{Framework, Language, Orm};
 
-use crate::adapters;
-use crate::context::EmitContext;
+use crate::adapters::{self, expand_composed_middleware};
+use crate::context::{EmitContext, HandlerRequest, SchemaRef};
 use crate::emitters;
 use crate::error::CodegenError;
 use crate::traits::{FrameworkAdapter, LanguageEmitter};
-use rash_ir::types::ProjectIR;
+use rash_ir::types::{FunctionIR, ProjectIR};
 
 /// Normalize a dotted name to a valid identifier for the target language.
 /// e.g., "health.check" → "healthCheck" (camelCase)
@@ -76,7 +77,7 @@ impl GeneratedProject {
 /// with a framework adapter to produce a complete project.
 pub struct CodeGenerator {
     emitter: Box<dyn LanguageEmitter>,
-    adapter: Box<dyn FrameworkAdapter>,
+    adapter: Rc<dyn FrameworkAdapter>,
 }
 
 impl std::fmt::Debug for CodeGenerator {
@@ -92,7 +93,7 @@ impl CodeGenerator {
     /// Create a new code generator for the given language/framework combination.
     pub fn new(language: Language, framework: Framework) -> Result<Self, CodegenError> {
         let emitter = emitters::create_emitter(language)?;
-        let adapter = adapters::create_adapter(framework)?;
+        let adapter: Rc<dyn FrameworkAdapter> = adapters::create_adapter(framework)?.into();
 
         if adapter.compatible_language() != language {
             return Err(CodegenError::IncompatibleTarget { language, framework });
@@ -105,11 +106,18 @@ impl CodeGenerator {
     pub fn generate(&self, project: &ProjectIR) -> Result<GeneratedProject, CodegenError> {
         let mut output = GeneratedProject::new();
         let ext = self.emitter.file_extension();
+        let orm = project
+            .config
+            .get("database")
+            .and_then(|d| d.get("orm"))
+            .and_then(|o| serde_json::from_value::<Orm>(o.clone()).ok());
 
         // 1. Generate schemas (DTOs)
         for schema in &project.schemas {
-            let mut ctx = self.new_context();
-            let code = self.emitter.emit_schema(schema, &mut ctx);
+            let mut ctx = self.new_context(orm, &project.functions);
+            let code = self
+                .adapter
+                .emit_schema_def(schema, self.emitter.as_ref(), &mut ctx);
             let imports = self.emitter.emit_imports(&mut ctx);
             let full = if imports.is_empty() {
                 code
@@ -122,7 +130,7 @@ impl CodeGenerator {
 
         // 2. Generate models (ORM)
         for model in &project.models {
-            let mut ctx = self.new_context();
+            let mut ctx = self.new_context(orm, &project.functions);
             let code = self.emitter.emit_model(model, &mut ctx);
             let imports = self.emitter.emit_imports(&mut ctx);
             let full = if imports.is_empty() {
@@ -136,7 +144,8 @@ impl CodeGenerator {
 
         // 3. Generate middleware (normalize dotted names)
         for mw in &project.middleware {
-            let mut ctx = self.new_context();
+            self.adapter.check_middleware(mw)?;
+            let mut ctx = self.new_context(orm, &project.functions);
             let mut normalized = mw.clone();
             normalized.name = normalize_identifier(&mw.name);
             let code = self
@@ -153,9 +162,22 @@ impl CodeGenerator {
             output.add_file(path, full);
         }
 
+        let hmu = adapters::hmu_enabled(&project.config);
+
         // 4. Generate handlers (normalize dotted names)
         for handler in &project.handlers {
-            let mut ctx = self.new_context();
+            // A handler can read what the middleware of any endpoint routed to it provides
+            let provided = project
+                .routes
+                .iter()
+                .flat_map(|route| route.methods.values())
+                .filter(|endpoint| endpoint.handler_ref == handler.name)
+                .flat_map(|endpoint| endpoint.provides.clone())
+                .collect();
+            let mut ctx = self.new_context(orm, &project.functions).with_provided(provided);
+            if let Some(request) = handler_request(project, &handler.name) {
+                ctx = ctx.with_request(request);
+            }
             let mut normalized = handler.clone();
             normalized.name = normalize_identifier(&handler.name);
             let code = self
@@ -167,19 +189,53 @@ impl CodeGenerator {
             } else {
                 format!("{}\n\n{}", imports, code)
             };
+            let full = if hmu {
+                self.adapter.hot_swappable_handler(full, &normalized.name)
+            } else {
+                full
+            };
             let filename = normalize_filename(&handler.name);
             let path = format!("src/handlers/{}.{}", filename, ext);
             output.add_file(path, full);
         }
 
-        // 5. Generate route registration
+        // 5. Generate the shared user function module
+        if !project.functions.is_empty() {
+            // Functions are framework-independent, so no framework is attached
+            let mut ctx =
+                EmitContext::new(self.emitter.indent_style()).with_functions(&project.functions);
+            let code = project
+                .functions
+                .iter()
+                .map(|func| self.emitter.emit_function(func, &mut ctx))
+                .collect::<Vec<_>>()
+                .join("\n\n");
+            let imports = self.emitter.emit_imports(&mut ctx);
+            let full = if imports.is_empty() {
+                code
+            } else {
+                format!("{}\n\n{}", imports, code)
+            };
+            output.add_file(format!("src/functions.{}", ext), full);
+        }
+
+        // 6. Generate route registration
         {
-            let mut ctx = self.new_context();
+            let mut ctx = self.new_context(orm, &project.functions);
             let mut route_blocks = Vec::new();
             for route in &project.routes {
                 // Normalize path params for the target framework
                 let mut normalized_route = route.clone();
                 normalized_route.path = self.adapter.normalize_path(&route.path);
+                if self.adapter.expands_composed_middleware() {
+                    for endpoint in normalized_route.methods.values_mut() {
+                        endpoint.middleware = expand_composed_middleware(
+                            &endpoint.middleware,
+                            &project.middleware,
+                            self.adapter.composed_step_guard(),
+                        );
+                    }
+                }
                 let block = self.adapter.emit_route_registration(
                     &normalized_route,
                     self.emitter.as_ref(),
@@ -190,13 +246,13 @@ impl CodeGenerator {
             let imports = self.emitter.emit_imports(&mut ctx);
             let route_code = route_blocks.join("\n\n");
             let full = self.adapter.wrap_route_file(&imports, &route_code, &mut ctx);
-            let path = format!("src/routes/index.{}", ext);
+            let path = self.adapter.route_file_path(ext);
             output.add_file(path, full);
         }
 
-        // 6. Generate entrypoint (with global middleware)
+        // 7. Generate entrypoint (with global middleware)
         {
-            let mut ctx = self.new_context();
+            let mut ctx = self.new_context(orm, &project.functions);
             let mut entry = self.adapter.emit_entrypoint(project, &mut ctx);
 
             // Extract global middleware refs from config
@@ -235,24 +291,22 @@ impl CodeGenerator {
                     .collect();
                 let import_block = mw_imports.join("\n");
 
-                // Insert after the last import line in entrypoint
-                if let Some(last_import_pos) = entry.rfind("\nimport ") {
-                    let insert_pos = entry[last_import_pos + 1..]
-                        .find('\n')
-                        .map(|p| last_import_pos + 1 + p + 1)
-                        .unwrap_or(entry.len());
-                    entry.insert_str(insert_pos, &format!("{}\n", import_block));
-                }
-
-                // Insert middleware application before route registration
-                if let Some(route_pos) = entry.find("registerRoutes")
-                    .or_else(|| entry.find("routes::configure"))
-                    .or_else(|| entry.find("include_router"))
-                    .or_else(|| entry.find("registerRoutes(r)"))
-                {
-                    // Find the start of the line
-                    let line_start = entry[..route_pos].rfind('\n').map(|p| p + 1).unwrap_or(0);
-                    entry.insert_str(line_start, &format!("{}\n\n", mw_block));
+                // Entrypoints without a route registration line apply
+                // global middleware themselves (e.g. Axum, NestJS)
+                if find_route_registration_line(&entry).is_some() {
+                    // Insert after the last import line in entrypoint
+                    if let Some(last_import_pos) = entry.rfind("\nimport ") {
+                        let insert_pos = entry[last_import_pos + 1..]
+                            .find('\n')
+                            .map(|p| last_import_pos + 1 + p + 1)
+                            .unwrap_or(entry.len());
+                        entry.insert_str(insert_pos, &format!("{}\n", import_block));
+                    }
+
+                    // Insert middleware application before route registration
+                    if let Some(line_start) = find_route_registration_line(&entry) {
+                        entry.insert_str(line_start, &format!("{}\n\n", mw_block));
+                    }
                 }
             }
 
@@ -260,16 +314,29 @@ impl CodeGenerator {
             output.add_file(path, entry);
         }
 
-        // 7. Generate project config files
+        // 8. Generate project config files
         for (path, content) in self.adapter.emit_project_config(project) {
             output.add_file(path, content);
         }
 
+        // 9. Generate the HMU client
+        if hmu {
+            if let Some((path, content)) = self.adapter.emit_hmu_client(project) {
+                output.add_file(path, content);
+            }
+        }
+
         Ok(output)
     }
 
-    fn new_context(&self) -> EmitContext {
-        EmitContext::new(self.emitter.indent_style())
+    fn new_context(&self, orm: Option<Orm>, functions: &[FunctionIR]) -> EmitContext {
+        let ctx = EmitContext::new(self.emitter.indent_style())
+            .with_adapter(Rc::clone(&self.adapter))
+            .with_functions(functions);
+        match orm {
+            Some(orm) => ctx.with_orm(orm),
+            None => ctx,
+        }
     }
 
     /// Access the language emitter.
@@ -283,6 +350,53 @@ impl CodeGenerator {
     }
 }
 
+/// What the first endpoint routed to `handler_name` accepts, with its schema
+/// refs resolved to the schema files that define them.
+fn handler_request(project: &ProjectIR, handler_name: &str) -> Option<HandlerRequest> {
+    let (route, endpoint) = project.routes.iter().find_map(|route| {
+        route
+            .methods
+            .values()
+            .find(|endpoint| endpoint.handler_ref == handler_name)
+            .map(|endpoint| (route, endpoint))
+    })?;
+    let schema_ref = |reference: &Option<String>| {
+        let reference = reference.as_deref()?;
+        let name = reference.rsplit(['#', '/']).next().unwrap_or(reference);
+        project
+            .schemas
+            .iter()
+            .find(|s| s.definitions.contains_key(name))
+            .map(|group| SchemaRef {
+                module: group.name.to_lowercase(),
+                name: name.to_string(),
+            })
+    };
+    Some(HandlerRequest {
+        path_params: adapters::fastify::extract_path_params(&route.path),
+        query_schema: schema_ref(&endpoint.request.query_schema),
+        body_schema: schema_ref(&endpoint.request.body_schema),
+    })
+}
+
+/// Find the byte offset of the entrypoint line that registers routes.
+/// Import lines that merely mention the registration function are skipped.
+fn find_route_registration_line(entry: &str) -> Option<usize> {
+    const MARKERS: &[&str] = &["registerRoutes", "routes::configure", "include_router"];
+    MARKERS.iter().find_map(|marker| {
+        let mut offset = 0;
+        for line in entry.split_inclusive('\n') {
+            let trimmed = line.trim_start();
+            let is_import = trimmed.starts_with("import ") || trimmed.starts_with("from ");
+            if line.contains(marker) && !is_import {
+                return Some(offset);
+            }
+            offset += line.len();
+        }
+        None
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -326,6 +440,13 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_route_registration_line_skips_imports() {
+        let entry = "import { registerRoutes } from \"./routes\";\n\nconst app = express();\nregisterRoutes(app);\n";
+        let pos = find_route_registration_line(entry).unwrap();
+        assert!(entry[pos..].starts_with("registerRoutes(app);"));
+    }
+
     #[test]
     fn test_valid_target_creation() {
         let gen = CodeGenerator::new(Language::Typescript, Framework::Express);
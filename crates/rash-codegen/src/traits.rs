@@ -1,10 +1,11 @@
 use rash_spec::types::common::{Framework, Language};
 
 use crate::context::{EmitContext, IndentStyle};
+use crate::error::CodegenError;
 use rash_ir::expr::{ExprIR, TypeIR};
 use rash_ir::statement::StatementIR;
 use rash_ir::types::{
-    HandlerIR, MiddlewareIR, ModelIR, ProjectIR, RouteIR, SchemaIR,
+    FunctionIR, HandlerIR, MiddlewareIR, ModelIR, ProjectIR, RouteIR, SchemaIR,
 };
 
 /// Language-specific code emitter.
@@ -30,6 +31,9 @@ pub trait LanguageEmitter {
     /// Generate ORM model code (e.g., Prisma model definition).
     fn emit_model(&self, model: &ModelIR, ctx: &mut EmitContext) -> String;
 
+    /// Generate a user-defined function for the shared `functions` module.
+    fn emit_function(&self, func: &FunctionIR, ctx: &mut EmitContext) -> String;
+
     /// Generate import statements from collected imports.
     fn emit_imports(&self, ctx: &mut EmitContext) -> String;
 
@@ -89,7 +93,9 @@ pub trait FrameworkAdapter {
     /// Returns a list of (relative_path, content) pairs.
     fn emit_project_config(&self, project: &ProjectIR) -> Vec<(String, String)>;
 
-    /// Optionally override how a domain expression is emitted for this framework.
+    /// Optionally override how an expression is emitted for this framework
+    /// (e.g. `HttpRespond`, `CtxGet`). Language emitters consult this through
+    /// [`EmitContext::emit_domain_expr`] before their own lowering.
     /// Returns `None` to fall back to the language emitter's default.
     fn emit_domain_expr(
         &self,
@@ -98,6 +104,12 @@ pub trait FrameworkAdapter {
         ctx: &mut EmitContext,
     ) -> Option<String>;
 
+    /// Check that the framework can run `mw` before its definition is emitted.
+    /// Default: every middleware is supported.
+    fn check_middleware(&self, _mw: &MiddlewareIR) -> Result<(), CodegenError> {
+        Ok(())
+    }
+
     /// Generate middleware definition code.
     fn emit_middleware_def(
         &self,
@@ -109,6 +121,24 @@ pub trait FrameworkAdapter {
     /// How this framework exposes request context to handlers.
     fn ctx_access_pattern(&self) -> CtxAccessPattern;
 
+    /// Generate DTO/validation code for a schema.
+    /// Default: the language emitter's schema code.
+    fn emit_schema_def(
+        &self,
+        schema: &SchemaIR,
+        emitter: &dyn LanguageEmitter,
+        ctx: &mut EmitContext,
+    ) -> String {
+        emitter.emit_schema(schema, ctx)
+    }
+
+    /// Whether handlers return an error value (Go `error`, Rust `Result`), so
+    /// responses are returned and `throw` leaves the handler with an error.
+    /// Default: handlers respond in place.
+    fn handlers_return_error(&self) -> bool {
+        false
+    }
+
     /// Generate global middleware application code for the entrypoint.
     /// Returns a list of code lines to insert into the entrypoint.
     fn emit_global_middleware(
@@ -122,6 +152,39 @@ pub trait FrameworkAdapter {
             .collect()
     }
 
+    /// Whether routes apply a composed middleware as its individual steps.
+    /// Frameworks whose middleware continues by advancing the framework's own
+    /// chain (e.g. Fiber's `c.Next()`) can't run steps inside one middleware.
+    fn expands_composed_middleware(&self) -> bool {
+        false
+    }
+
+    /// Middleware registered after each expanded composed step, stopping the
+    /// chain once a step has responded. Default: none.
+    fn composed_step_guard(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// Generate the HMU client module when `codegen.hmu` is set, as a
+    /// (relative_path, content) pair. The entrypoint is responsible for
+    /// starting it. Default: no client.
+    fn emit_hmu_client(&self, _project: &ProjectIR) -> Option<(String, String)> {
+        None
+    }
+
+    /// Make a generated handler file swappable by the HMU client.
+    /// Default: handlers are picked up by restarting the server.
+    fn hot_swappable_handler(&self, code: String, _handler_name: &str) -> String {
+        code
+    }
+
+    /// Whether the HMU client swaps the generated file at `path` in place
+    /// when its content is replaced. Any other change is loaded by restarting
+    /// the server. Default: nothing is swappable.
+    fn hot_swappable(&self, _path: &str) -> bool {
+        false
+    }
+
     /// Wrap the collected route registration blocks into a complete route file.
     /// `route_blocks` is the concatenated output of all `emit_route_registration` calls.
     /// `imports` is the collected import statements.
@@ -134,6 +197,18 @@ pub trait FrameworkAdapter {
         }
     }
 
+    /// Path of the generated route file, relative to the output directory.
+    /// Default: `src/routes/index.{ext}`.
+    fn route_file_path(&self, ext: &str) -> String {
+        format!("src/routes/index.{}", ext)
+    }
+
+    /// Generated files built from every model at once, such as an ORM schema,
+    /// relative to the output directory. Default: none.
+    fn orm_schema_files(&self) -> Vec<String> {
+        Vec::new()
+    }
+
     /// Convert a path parameter from the canonical `:param` format
     /// to the framework-specific format. Default: pass through unchanged.
     fn normalize_path(&self, path: &str) -> String {
@@ -72,6 +72,346 @@ fn test_golden_typescript_express_codegen() {
     );
 }
 
+#[test]
+fn test_golden_rust_axum_codegen() {
+    let ir = load_and_convert("golden-user-crud");
+
+    let gen = CodeGenerator::new(Language::Rust, Framework::Axum).unwrap();
+    let output = gen.generate(&ir).unwrap();
+
+    let dir = tempfile::tempdir().unwrap();
+    output.write_to_disk(dir.path()).unwrap();
+
+    // Dependencies are built once and shared across runs
+    let target_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../target/golden-axum");
+    let check = std::process::Command::new(env!("CARGO"))
+        .arg("check")
+        .arg("--quiet")
+        .current_dir(dir.path())
+        .env("CARGO_TARGET_DIR", &target_dir)
+        .output()
+        .expect("failed to run cargo check");
+    assert!(
+        check.status.success(),
+        "generated Axum project should compile:\n{}",
+        String::from_utf8_lossy(&check.stderr)
+    );
+}
+
+#[test]
+fn test_golden_rust_rocket_codegen() {
+    let mut ir = load_and_convert("golden-user-crud");
+    // The handler reads the user `auth` provides
+    let get_user = ir
+        .handlers
+        .iter_mut()
+        .find(|h| h.name == "users.getUser")
+        .unwrap();
+    get_user.body.insert(
+        0,
+        rash_ir::statement::StatementIR::Let {
+            name: "viewerId".into(),
+            type_: None,
+            value: rash_ir::expr::ExprIR::CtxGet {
+                path: "state.user.id".into(),
+            },
+        },
+    );
+
+    let gen = CodeGenerator::new(Language::Rust, Framework::Rocket).unwrap();
+    let output = gen.generate(&ir).unwrap();
+
+    let entrypoint = &output.files()["src/index.rs"];
+    assert!(entrypoint.contains("#[rocket::launch]"));
+    assert!(entrypoint.contains(".mount(\"/api\", routes::routes())"));
+
+    let routes = &output.files()["src/routes/index.rs"];
+    assert!(routes.contains("crate::endpoints::get_user,"));
+
+    // `auth` provides `user`, so every endpoint behind it takes the guard
+    let endpoints = &output.files()["src/routes/endpoints.rs"];
+    assert!(endpoints.contains("#[rocket::get(\"/v1/users/<id>\")]"));
+    assert_eq!(
+        endpoints
+            .matches("user: crate::middleware::auth::AuthUser")
+            .count(),
+        5
+    );
+    assert!(endpoints.contains("_cors: crate::middleware::cors::CorsGuard"));
+    assert!(endpoints.contains("data = \"<body>\""));
+
+    let auth = &output.files()["src/middleware/auth.rs"];
+    assert!(auth.contains("pub struct AuthUser(pub serde_json::Value);"));
+    assert!(auth.contains("impl<'r> FromRequest<'r> for AuthUser"));
+
+    // Guard values are handed to the handler under the key it reads
+    assert!(endpoints.contains("crate::handlers::users_getUser::usersGetUser(id, user.0).await"));
+    assert!(!endpoints.contains("serde_json::Value::Null"));
+
+    let handler = &output.files()["src/handlers/users_getUser.rs"];
+    assert!(handler.contains("pub async fn usersGetUser(id: String, user: serde_json::Value)"));
+    assert!(handler.contains("let viewerId = user[\"id\"].clone();"));
+
+    let cargo_toml = &output.files()["Cargo.toml"];
+    assert!(cargo_toml.contains("rocket = "));
+}
+
+#[test]
+fn test_golden_typescript_elysia_codegen() {
+    let ir = load_and_convert("golden-user-crud");
+
+    let gen = CodeGenerator::new(Language::Typescript, Framework::Elysia).unwrap();
+    let output = gen.generate(&ir).unwrap();
+
+    let entrypoint = &output.files()["src/index.ts"];
+    assert!(entrypoint.contains("new Elysia({ prefix: \"/api\" })"));
+    assert!(entrypoint.contains(".derive({ as: \"global\" }, authDerive)"));
+    assert!(entrypoint.contains(".onBeforeHandle({ as: \"global\" }, cors)"));
+    assert!(entrypoint.contains("export type App = typeof app;"));
+
+    let routes = &output.files()["src/routes/index.ts"];
+    assert!(routes.contains("export const routes = new Elysia()"));
+    assert!(routes.contains(".get(\"/v1/users/:id\", usersGetUser, {"));
+    assert!(routes.contains("beforeHandle: [auth, validate],"));
+    assert!(routes.contains("body: CreateUserBody,"));
+
+    let schemas = &output.files()["src/schemas/user.ts"];
+    assert!(schemas.contains("import { t } from \"elysia\";"));
+    assert!(schemas.contains("export const CreateUserBody = t.Object({"));
+    assert!(schemas.contains("export type CreateUserBody = typeof CreateUserBody.static;"));
+    assert!(!schemas.contains("zod"));
+
+    let auth = &output.files()["src/middleware/auth.ts"];
+    assert!(auth.contains("export async function authDerive("));
+
+    let handler = &output.files()["src/handlers/users_getUser.ts"];
+    assert!(handler.contains("ctx.params.id"));
+    assert!(handler.contains("ctx.status(404, "));
+
+    let package_json = &output.files()["package.json"];
+    assert!(package_json.contains("\"elysia\""));
+}
+
+#[test]
+fn test_golden_typescript_fastify_codegen() {
+    let ir = load_and_convert("golden-user-crud");
+
+    let gen = CodeGenerator::new(Language::Typescript, Framework::Fastify).unwrap();
+    let output = gen.generate(&ir).unwrap();
+
+    let entrypoint = &output.files()["src/index.ts"];
+    assert!(entrypoint.contains("import Fastify from \"fastify\""));
+    assert!(entrypoint.contains("app.register(registerRoutes, { prefix: \"/api\" });"));
+    // Global middleware is hooked in after the app is created, not among the imports
+    let hook_pos = entrypoint.find("app.addHook(\"onRequest\", cors);").unwrap();
+    assert!(hook_pos > entrypoint.find("const app = Fastify").unwrap());
+
+    let routes = &output.files()["src/routes/index.ts"];
+    assert!(routes.contains("export async function registerRoutes(app: FastifyInstance)"));
+    assert!(routes.contains("querystring: { $ref: \"ListUsersQuery#\" }"));
+    assert!(routes.contains("body: { $ref: \"CreateUserBody#\" }"));
+    assert!(routes.contains("preHandler: [auth, validate],"));
+
+    let schemas = &output.files()["src/plugins/schemas.ts"];
+    assert!(schemas.contains("\"$id\": \"CreateUserBody\""));
+    assert!(schemas.contains("\"$ref\": \"UserResponse#\""));
+
+    let handler = &output.files()["src/handlers/users_getUser.ts"];
+    assert!(handler.contains("request.params.id"));
+    assert!(handler.contains("reply.code(404)"));
+
+    let package_json = &output.files()["package.json"];
+    assert!(package_json.contains("\"fastify\""));
+}
+
+#[test]
+fn test_golden_typescript_hono_codegen() {
+    let ir = load_and_convert("golden-user-crud");
+
+    let gen = CodeGenerator::new(Language::Typescript, Framework::Hono).unwrap();
+    let output = gen.generate(&ir).unwrap();
+
+    // The golden fixture targets Bun
+    let entrypoint = &output.files()["src/index.ts"];
+    assert!(entrypoint.contains("new Hono().basePath(\"/api\")"));
+    assert!(entrypoint.contains("fetch: app.fetch"));
+    assert!(entrypoint.contains("import { cors } from \"./middleware/cors\";"));
+    let use_pos = entrypoint.find("app.use(\"*\", cors);").unwrap();
+    assert!(use_pos > entrypoint.find("const app = ").unwrap());
+
+    let routes = &output.files()["src/routes/index.ts"];
+    assert!(routes.contains(
+        "app.post(\"/v1/users\", auth, validate, zValidator(\"json\", CreateUserBody), usersCreateUser);"
+    ));
+    assert!(routes.contains("zValidator(\"query\", ListUsersQuery)"));
+    assert!(routes.contains("import { CreateUserBody } from \"../schemas\";"));
+
+    let handler = &output.files()["src/handlers/users_getUser.ts"];
+    assert!(handler.contains("c.req.param(\"id\")"));
+    assert!(handler.contains("c.json("));
+
+    assert!(output.files()["src/schemas/index.ts"].contains("export * from \"./user\";"));
+    assert!(output.files()["package.json"].contains("\"hono\""));
+}
+
+#[test]
+fn test_golden_typescript_nestjs_codegen() {
+    let ir = load_and_convert("golden-user-crud");
+
+    let gen = CodeGenerator::new(Language::Typescript, Framework::NestJS).unwrap();
+    let output = gen.generate(&ir).unwrap();
+
+    let entrypoint = &output.files()["src/index.ts"];
+    assert!(entrypoint.contains("NestFactory.create(RoutesModule)"));
+    assert!(entrypoint.contains("app.useGlobalGuards(new CorsGuard());"));
+    // The generator must not splice Express-style global middleware in
+    assert!(!entrypoint.contains("import { cors }"));
+
+    let routes = &output.files()["src/routes/index.ts"];
+    assert!(routes.contains("imports: [UsersModule],"));
+
+    let controller = &output.files()["src/users/users.controller.ts"];
+    assert!(controller.contains("@Controller(\"v1/users\")"));
+    assert!(controller.contains("@Get(\":id\")"));
+    assert!(controller.contains("@Body() body: CreateUserBody,"));
+    assert!(controller.contains("@Query() query: ListUsersQuery,"));
+    assert!(controller.contains("@UseGuards(AuthGuard, ValidateGuard)"));
+    assert!(controller.contains("import { CreateUserBody } from \"../schemas/user\";"));
+    assert!(output.files().contains_key("src/users/users.module.ts"));
+
+    let dto = &output.files()["src/schemas/user.ts"];
+    assert!(dto.contains("export class CreateUserBody {"));
+    assert!(dto.contains("from \"class-validator\""));
+    assert!(!dto.contains("zod"));
+
+    let guard = &output.files()["src/middleware/auth.ts"];
+    assert!(guard.contains("export class AuthGuard implements CanActivate"));
+}
+
+#[test]
+fn test_golden_go_frameworks_codegen() {
+    let ir = load_and_convert("golden-user-crud");
+
+    let gin = CodeGenerator::new(Language::Go, Framework::Gin)
+        .unwrap()
+        .generate(&ir)
+        .unwrap();
+    let echo = CodeGenerator::new(Language::Go, Framework::Echo)
+        .unwrap()
+        .generate(&ir)
+        .unwrap();
+    let fiber = CodeGenerator::new(Language::Go, Framework::Fiber)
+        .unwrap()
+        .generate(&ir)
+        .unwrap();
+
+    // Same routes, middleware order and base path across all three frameworks
+    let gin_routes = &gin.files()["src/routes/index.go"];
+    assert!(gin_routes.contains("router.GET(\"/v1/users/:id\", auth, usersGetUser)"));
+    assert!(gin_routes.contains("router.POST(\"/v1/users\", auth, validate, usersCreateUser)"));
+    let echo_routes = &echo.files()["src/routes/index.go"];
+    assert!(echo_routes.contains("router.GET(\"/v1/users/:id\", usersGetUser, auth)"));
+    assert!(echo_routes.contains("router.POST(\"/v1/users\", usersCreateUser, auth, validate)"));
+    let fiber_routes = &fiber.files()["src/routes/index.go"];
+    assert!(fiber_routes.contains("router.Get(\"/v1/users/:id\", auth, usersGetUser)"));
+    assert!(fiber_routes.contains("router.Post(\"/v1/users\", auth, validate, usersCreateUser)"));
+
+    assert!(gin.files()["src/index.go"].contains("r.Use(cors)"));
+    assert!(gin.files()["src/index.go"].contains("RegisterRoutes(r.Group(\"/api\"))"));
+    assert!(echo.files()["src/index.go"].contains("e.Use(cors)"));
+    assert!(echo.files()["src/index.go"].contains("RegisterRoutes(e.Group(\"/api\"))"));
+    assert!(fiber.files()["src/index.go"].contains("app.Use(cors)"));
+    assert!(fiber.files()["src/index.go"].contains("RegisterRoutes(app.Group(\"/api\"))"));
+
+    // Same status codes, each through its own context API
+    let gin_handler = &gin.files()["src/handlers/users_getUser.go"];
+    assert!(gin_handler.contains("c.Param(\"id\")"));
+    assert!(gin_handler.contains("c.JSON(404, "));
+    assert!(gin_handler.contains("c.JSON(200, user)"));
+    let echo_handler = &echo.files()["src/handlers/users_getUser.go"];
+    assert!(echo_handler.contains("func usersGetUser(c echo.Context) error {"));
+    assert!(echo_handler.contains("c.Param(\"id\")"));
+    assert!(echo_handler.contains("return c.JSON(404, "));
+    assert!(echo_handler.contains("return c.JSON(200, user)"));
+    let fiber_handler = &fiber.files()["src/handlers/users_getUser.go"];
+    assert!(fiber_handler.contains("func usersGetUser(c *fiber.Ctx) error {"));
+    assert!(fiber_handler.contains("c.Params(\"id\")"));
+    assert!(fiber_handler.contains("return c.Status(404).JSON("));
+    assert!(fiber_handler.contains("return c.Status(200).JSON(user)"));
+
+    assert!(gin.files()["src/handlers/users_deleteUser.go"].contains("c.Status(204)"));
+    assert!(echo.files()["src/handlers/users_deleteUser.go"].contains("return c.NoContent(204)"));
+    assert!(fiber.files()["src/handlers/users_deleteUser.go"].contains("return c.SendStatus(204)"));
+
+    assert!(echo.files()["go.mod"].contains("github.com/labstack/echo/v4"));
+    assert!(fiber.files()["go.mod"].contains("github.com/gofiber/fiber/v2"));
+    assert!(echo.files()["src/middleware/auth.go"]
+        .contains("func auth(next echo.HandlerFunc) echo.HandlerFunc {"));
+    assert!(fiber.files()["src/middleware/auth.go"].contains("return c.Next()"));
+}
+
+#[test]
+fn test_golden_python_flask_codegen() {
+    let ir = load_and_convert("golden-user-crud");
+
+    let gen = CodeGenerator::new(Language::Python, Framework::Flask).unwrap();
+    let output = gen.generate(&ir).unwrap();
+
+    let entrypoint = &output.files()["src/index.py"];
+    assert!(entrypoint.contains("app = Flask(\"golden-user-crud\")"));
+    assert!(entrypoint.contains("app.before_request(cors)"));
+    assert!(entrypoint.contains("register_routes(app, url_prefix=\"/api\")"));
+
+    let routes = &output.files()["src/routes/index.py"];
+    assert!(routes.contains("users_bp = Blueprint(\"users\", __name__)"));
+    assert!(routes.contains(
+        "users_bp.add_url_rule(\"/v1/users/<id>\", \"getUser\", use(auth)(usersGetUser), methods=[\"GET\"])"
+    ));
+    assert!(routes.contains("from handlers.users_getUser import usersGetUser"));
+    assert!(routes.contains("app.register_blueprint(users_bp, url_prefix=url_prefix)"));
+
+    let handler = &output.files()["src/handlers/users_getUser.py"];
+    assert!(handler.contains("def usersGetUser(**params):"));
+    assert!(handler.contains("request.view_args[\"id\"]"));
+    assert!(handler.contains("return jsonify(user), 200"));
+
+    assert!(output.files()["src/middleware/auth.py"].contains("def auth():"));
+    assert!(output.files()["requirements.txt"].contains("flask[async]"));
+}
+
+#[test]
+fn test_golden_python_django_codegen() {
+    let mut ir = load_and_convert("golden-user-crud");
+    ir.config["database"]["orm"] = serde_json::json!("django-orm");
+
+    let gen = CodeGenerator::new(Language::Python, Framework::Django).unwrap();
+    let output = gen.generate(&ir).unwrap();
+
+    let urls = &output.files()["src/api/urls.py"];
+    assert!(urls.contains("path(\"v1/users/<id>\", V1UsersIdView.as_view()),"));
+    assert!(urls.contains("from api.views import V1UsersIdView"));
+    assert!(!output.files().contains_key("src/routes/index.py"));
+    assert!(output.files()["src/urls.py"].contains("path(\"api/\", include(\"api.urls\"))"));
+
+    let views = &output.files()["src/api/views.py"];
+    assert!(views.contains("GLOBAL_MIDDLEWARE = [cors]"));
+    assert!(views.contains("class V1UsersView(View):"));
+    assert!(views.contains("return await run(request, [auth, validate], usersCreateUser, params)"));
+
+    let handler = &output.files()["src/handlers/users_getUser.py"];
+    assert!(handler.contains("async def usersGetUser(request, **params):"));
+    assert!(handler.contains("User.objects.filter(id=userId).afirst()"));
+    assert!(handler.contains("JsonResponse(user, status=200, safe=False)"));
+
+    let model = &output.files()["src/models/user.py"];
+    assert!(model.contains("class User(models.Model):"));
+    assert!(model.contains("email = models.CharField(max_length=255, unique=True, db_index=True)"));
+    assert!(model.contains("deletedAt = models.DateTimeField(null=True, blank=True)"));
+    assert!(model.contains("app_label = \"api\""));
+    assert!(output.files()["src/models/post.py"].contains("author = models.ForeignKey(\"User\""));
+    assert!(output.files()["src/api/models.py"].contains("from models.user import User"));
+}
+
 #[test]
 fn test_golden_codegen_writes_to_disk() {
     let ir = load_and_convert("golden-user-crud");
@@ -124,9 +464,19 @@ fn test_incompatible_language_framework() {
 fn test_all_valid_language_framework_pairs() {
     // All compatible pairs should work
     assert!(CodeGenerator::new(Language::Typescript, Framework::Express).is_ok());
+    assert!(CodeGenerator::new(Language::Typescript, Framework::Fastify).is_ok());
+    assert!(CodeGenerator::new(Language::Typescript, Framework::Hono).is_ok());
+    assert!(CodeGenerator::new(Language::Typescript, Framework::NestJS).is_ok());
+    assert!(CodeGenerator::new(Language::Typescript, Framework::Elysia).is_ok());
     assert!(CodeGenerator::new(Language::Rust, Framework::Actix).is_ok());
+    assert!(CodeGenerator::new(Language::Rust, Framework::Axum).is_ok());
+    assert!(CodeGenerator::new(Language::Rust, Framework::Rocket).is_ok());
     assert!(CodeGenerator::new(Language::Python, Framework::FastAPI).is_ok());
+    assert!(CodeGenerator::new(Language::Python, Framework::Flask).is_ok());
+    assert!(CodeGenerator::new(Language::Python, Framework::Django).is_ok());
     assert!(CodeGenerator::new(Language::Go, Framework::Gin).is_ok());
+    assert!(CodeGenerator::new(Language::Go, Framework::Echo).is_ok());
+    assert!(CodeGenerator::new(Language::Go, Framework::Fiber).is_ok());
 }
 
 #[test]
@@ -141,6 +491,7 @@ fn test_empty_project_codegen() {
         models: vec![],
         middleware: vec![],
         handlers: vec![],
+        functions: vec![],
     };
 
     let gen = CodeGenerator::new(Language::Typescript, Framework::Express).unwrap();
@@ -152,3 +503,399 @@ fn test_empty_project_codegen() {
     assert!(output.files().contains_key("package.json"));
     assert!(output.files().contains_key("tsconfig.json"));
 }
+
+#[test]
+fn test_user_functions_emit_shared_module() {
+    use rash_ir::expr::{ExprIR, TypeIR};
+    use rash_ir::statement::StatementIR;
+    use rash_ir::types::{FunctionIR, FunctionParamIR, HandlerIR};
+
+    let ir = rash_ir::types::ProjectIR {
+        config: serde_json::json!({
+            "name": "functions-project",
+            "server": { "port": 3000 }
+        }),
+        routes: vec![],
+        schemas: vec![],
+        models: vec![],
+        middleware: vec![],
+        handlers: vec![HandlerIR {
+            name: "showPrice".into(),
+            is_async: false,
+            params: vec![],
+            return_type: TypeIR::Void,
+            body: vec![StatementIR::Return {
+                value: Some(ExprIR::CallFunction {
+                    function: "formatCurrency".into(),
+                    args: vec![ExprIR::ident("total")],
+                }),
+            }],
+            max_tier: rash_spec::types::common::Tier::Universal,
+            bridge_languages: Default::default(),
+        }],
+        functions: vec![FunctionIR {
+            name: "formatCurrency".into(),
+            params: vec![
+                FunctionParamIR {
+                    name: "amount".into(),
+                    type_ir: TypeIR::Number,
+                    default: None,
+                },
+                FunctionParamIR {
+                    name: "currency".into(),
+                    type_ir: TypeIR::String,
+                    default: Some(serde_json::json!("KRW")),
+                },
+            ],
+            return_type: TypeIR::String,
+            body: vec![StatementIR::Return {
+                value: Some(ExprIR::ident("amount")),
+            }],
+            max_tier: rash_spec::types::common::Tier::Universal,
+        }],
+    };
+
+    let gen = CodeGenerator::new(Language::Typescript, Framework::Express).unwrap();
+    let output = gen.generate(&ir).unwrap();
+    let functions = &output.files()["src/functions.ts"];
+    assert!(functions.starts_with("export function formatCurrency("));
+    let handler = &output.files()["src/handlers/showPrice.ts"];
+    assert!(handler.contains("import { formatCurrency } from \"../functions\";"));
+    assert!(handler.contains("formatCurrency(total)"));
+
+    let gen = CodeGenerator::new(Language::Rust, Framework::Axum).unwrap();
+    let output = gen.generate(&ir).unwrap();
+    assert!(output.files()["src/functions.rs"].starts_with("pub fn formatCurrency("));
+    assert!(output.files()["src/index.rs"].contains("mod functions;\nmod handlers;"));
+    assert!(output.files()["src/handlers/showPrice.rs"]
+        .contains("crate::functions::formatCurrency(total, \"KRW\".to_string())"));
+}
+
+#[test]
+fn test_composed_middleware_codegen() {
+    let mut ir = load_and_convert("golden-user-crud");
+    ir.middleware.push(rash_ir::types::MiddlewareIR {
+        name: "secured".into(),
+        middleware_type: "composed".into(),
+        handler_ref: None,
+        provides: Default::default(),
+        compose: vec!["auth".into(), "validate".into()],
+        short_circuit: true,
+    });
+    for route in &mut ir.routes {
+        if route.path == "/v1/users/:id" {
+            for endpoint in route.methods.values_mut() {
+                endpoint.middleware = vec!["secured".into()];
+            }
+        }
+    }
+
+    let pairs = [
+        (Language::Typescript, Framework::Express, "ts"),
+        (Language::Typescript, Framework::Fastify, "ts"),
+        (Language::Typescript, Framework::Hono, "ts"),
+        (Language::Typescript, Framework::NestJS, "ts"),
+        (Language::Typescript, Framework::Elysia, "ts"),
+        (Language::Rust, Framework::Actix, "rs"),
+        (Language::Rust, Framework::Axum, "rs"),
+        (Language::Rust, Framework::Rocket, "rs"),
+        (Language::Python, Framework::FastAPI, "py"),
+        (Language::Python, Framework::Flask, "py"),
+        (Language::Python, Framework::Django, "py"),
+        (Language::Go, Framework::Gin, "go"),
+        (Language::Go, Framework::Echo, "go"),
+        (Language::Go, Framework::Fiber, "go"),
+    ];
+    for (language, framework, ext) in pairs {
+        let output = CodeGenerator::new(language, framework)
+            .unwrap()
+            .generate(&ir)
+            .unwrap();
+        let composed = &output.files()[&format!("src/middleware/secured.{}", ext)];
+        assert!(!composed.contains("TODO"), "{:?}: {}", framework, composed);
+        let auth = composed.find("auth").unwrap_or_else(|| panic!("{:?}", framework));
+        let validate = composed.rfind("validate").unwrap();
+        assert!(auth < validate, "{:?} runs steps out of order", framework);
+    }
+
+    let express = CodeGenerator::new(Language::Typescript, Framework::Express)
+        .unwrap()
+        .generate(&ir)
+        .unwrap();
+    let composed = &express.files()["src/middleware/secured.ts"];
+    assert!(composed.contains("import { auth } from \"./auth\";"));
+    assert!(composed.contains("if (res.headersSent) return;"));
+
+    // Fiber has no way to run a sub-chain, so routes register the steps
+    let fiber = CodeGenerator::new(Language::Go, Framework::Fiber)
+        .unwrap()
+        .generate(&ir)
+        .unwrap();
+    assert!(fiber.files()["src/routes/index.go"].contains(
+        "router.Get(\"/v1/users/:id\", auth, stopIfResponded, validate, stopIfResponded, usersGetUser)"
+    ));
+    assert!(fiber.files()["src/middleware/secured.go"]
+        .contains("var secured = []fiber.Handler{auth, validate}"));
+    assert!(fiber.files()["src/middleware/stop_if_responded.go"]
+        .contains("func stopIfResponded(c *fiber.Ctx) error {"));
+
+    let gin = CodeGenerator::new(Language::Go, Framework::Gin)
+        .unwrap()
+        .generate(&ir)
+        .unwrap();
+    assert!(gin.files()["src/middleware/secured.go"].contains("if c.IsAborted() {"));
+
+    // Without shortCircuit, a step that responded still stops the chain
+    // rather than letting later steps or the route write a second response
+    ir.middleware.last_mut().unwrap().short_circuit = false;
+    let express = CodeGenerator::new(Language::Typescript, Framework::Express)
+        .unwrap()
+        .generate(&ir)
+        .unwrap();
+    assert!(express.files()["src/middleware/secured.ts"].contains("if (res.headersSent) return;"));
+    let fiber = CodeGenerator::new(Language::Go, Framework::Fiber)
+        .unwrap()
+        .generate(&ir)
+        .unwrap();
+    assert!(fiber.files()["src/routes/index.go"]
+        .contains("validate, stopIfResponded, usersGetUser)"));
+}
+
+#[test]
+fn test_state_reads_codegen() {
+    let mut ir = load_and_convert("golden-user-crud");
+    // `auth` provides `user` as an object with an optional `role`
+    let get_user = ir
+        .handlers
+        .iter_mut()
+        .find(|h| h.name == "users.getUser")
+        .unwrap();
+    get_user.body.insert(
+        0,
+        rash_ir::statement::StatementIR::Let {
+            name: "viewerRole".into(),
+            type_: None,
+            value: rash_ir::expr::ExprIR::CtxGet {
+                path: "state.user.role".into(),
+            },
+        },
+    );
+
+    let cases = [
+        (Language::Typescript, Framework::Express, "ts", "res.locals.user.role"),
+        (Language::Rust, Framework::Axum, "rs", "user.0[\"role\"].clone()"),
+        (Language::Rust, Framework::Actix, "rs", "user.0[\"role\"].clone()"),
+        (Language::Rust, Framework::Rocket, "rs", "user[\"role\"].clone()"),
+        (Language::Python, Framework::FastAPI, "py", "request.state.user[\"role\"]"),
+        (Language::Python, Framework::Flask, "py", "g.user[\"role\"]"),
+        (Language::Python, Framework::Django, "py", "request.user[\"role\"]"),
+        (
+            Language::Go,
+            Framework::Gin,
+            "go",
+            "c.MustGet(\"user\").(map[string]interface{})[\"role\"]\n",
+        ),
+        (
+            Language::Go,
+            Framework::Echo,
+            "go",
+            "c.Get(\"user\").(map[string]interface{})[\"role\"]\n",
+        ),
+        (
+            Language::Go,
+            Framework::Fiber,
+            "go",
+            "c.Locals(\"user\").(map[string]interface{})[\"role\"]\n",
+        ),
+    ];
+    for (language, framework, ext, read) in cases {
+        let output = CodeGenerator::new(language, framework)
+            .unwrap()
+            .generate(&ir)
+            .unwrap();
+        let handler = &output.files()[&format!("src/handlers/users_getUser.{}", ext)];
+        assert!(handler.contains(read), "{:?}: {}", framework, handler);
+    }
+
+    // Go asserts the provided type; optional fields may be missing
+    let get_user = ir
+        .handlers
+        .iter_mut()
+        .find(|h| h.name == "users.getUser")
+        .unwrap();
+    get_user.body[0] = rash_ir::statement::StatementIR::Let {
+        name: "viewerRole".into(),
+        type_: None,
+        value: rash_ir::expr::ExprIR::CtxGet {
+            path: "state.user".into(),
+        },
+    };
+    let gin = CodeGenerator::new(Language::Go, Framework::Gin)
+        .unwrap()
+        .generate(&ir)
+        .unwrap();
+    assert!(gin.files()["src/handlers/users_getUser.go"]
+        .contains("viewerRole := c.MustGet(\"user\").(map[string]interface{})\n"));
+
+    // Rust middleware hands each value over as its own extension type
+    let axum = CodeGenerator::new(Language::Rust, Framework::Axum)
+        .unwrap()
+        .generate(&ir)
+        .unwrap();
+    assert!(axum.files()["src/handlers/users_getUser.rs"]
+        .contains("Extension(user): Extension<crate::state::User>"));
+    assert!(axum.files()["src/state.rs"].contains("pub struct User(pub serde_json::Value);"));
+    assert!(axum.files()["src/index.rs"].contains("mod state;"));
+    assert!(axum.files()["src/middleware/auth.rs"]
+        .contains("req.extensions_mut().insert(crate::state::User("));
+}
+
+#[test]
+fn test_hmu_client_codegen() {
+    let mut ir = load_and_convert("golden-user-crud");
+    let pairs = [
+        (Language::Typescript, Framework::Express, "ts"),
+        (Language::Typescript, Framework::Fastify, "ts"),
+        (Language::Typescript, Framework::Hono, "ts"),
+        (Language::Typescript, Framework::NestJS, "ts"),
+        (Language::Typescript, Framework::Elysia, "ts"),
+        (Language::Python, Framework::FastAPI, "py"),
+        (Language::Python, Framework::Flask, "py"),
+        (Language::Python, Framework::Django, "py"),
+    ];
+    // Compiled targets load every change by restarting, so they get no client
+    let compiled = [
+        (Language::Rust, Framework::Actix, "rs"),
+        (Language::Rust, Framework::Axum, "rs"),
+        (Language::Rust, Framework::Rocket, "rs"),
+        (Language::Go, Framework::Gin, "go"),
+        (Language::Go, Framework::Echo, "go"),
+        (Language::Go, Framework::Fiber, "go"),
+    ];
+
+    // Off by default
+    for (language, framework, ext) in pairs {
+        let output = CodeGenerator::new(language, framework)
+            .unwrap()
+            .generate(&ir)
+            .unwrap();
+        assert!(
+            !output.files().contains_key(&format!("src/hmu.{}", ext)),
+            "{:?}",
+            framework
+        );
+    }
+
+    ir.config["codegen"]["hmu"] = serde_json::Value::Bool(true);
+    for (language, framework, ext) in pairs {
+        let output = CodeGenerator::new(language, framework)
+            .unwrap()
+            .generate(&ir)
+            .unwrap();
+        let client = output
+            .files()
+            .get(&format!("src/hmu.{}", ext))
+            .unwrap_or_else(|| panic!("{:?} emitted no HMU client", framework));
+        assert!(client.contains("HMU_ACK"), "{:?}", framework);
+        assert!(client.contains("sha256"), "{:?}", framework);
+        assert!(client.contains("RASH_HMU_SOCKET"), "{:?}", framework);
+        assert!(client.contains("RASH_HMU_TOKEN"), "{:?}", framework);
+        let entry = &output.files()[&format!("src/index.{}", ext)];
+        match language {
+            Language::Typescript => assert!(entry.contains("import \"./hmu\";"), "{:?}", framework),
+            Language::Python => assert!(entry.contains("import hmu"), "{:?}", framework),
+            _ => unreachable!(),
+        }
+    }
+    for (language, framework, ext) in compiled {
+        let generator = CodeGenerator::new(language, framework).unwrap();
+        let output = generator.generate(&ir).unwrap();
+        assert!(
+            !output.files().contains_key(&format!("src/hmu.{}", ext)),
+            "{:?}",
+            framework
+        );
+        if language == Language::Rust {
+            assert!(!output.files()["src/index.rs"].contains("mod hmu;"), "{:?}", framework);
+            assert!(!output.files()["Cargo.toml"].contains("sha2"), "{:?}", framework);
+        }
+        assert!(
+            !generator
+                .adapter()
+                .hot_swappable(&format!("src/handlers/users_getUser.{}", ext)),
+            "{:?}",
+            framework
+        );
+    }
+
+    // TypeScript handlers dispatch through the swappable registry
+    let express = CodeGenerator::new(Language::Typescript, Framework::Express)
+        .unwrap()
+        .generate(&ir)
+        .unwrap();
+    let handler = &express.files()["src/handlers/users_getUser.ts"];
+    assert!(handler.starts_with("import { hot } from \"../hmu\";"));
+    assert!(handler.contains("async function usersGetUserImpl("));
+    assert!(handler.contains("export const usersGetUser = hot(\"usersGetUser\", usersGetUserImpl);"));
+
+    let fastapi = CodeGenerator::new(Language::Python, Framework::FastAPI)
+        .unwrap()
+        .generate(&ir)
+        .unwrap();
+    assert!(fastapi.files()["src/index.py"].contains("reload=False"));
+}
+
+#[test]
+fn test_hmu_client_ack_matches_hot_swappable() {
+    let mut ir = load_and_convert("golden-user-crud");
+    ir.config["codegen"]["hmu"] = serde_json::Value::Bool(true);
+    let generator = CodeGenerator::new(Language::Python, Framework::Flask).unwrap();
+    let output = generator.generate(&ir).unwrap();
+    let dir = tempfile::tempdir().unwrap();
+    output.write_to_disk(dir.path()).unwrap();
+
+    let handler = output.files().keys().find(|p| p.starts_with("src/handlers/")).unwrap();
+    let route = output.files().keys().find(|p| p.starts_with("src/routes/")).unwrap();
+    let other = "requirements.txt";
+    let paths = [handler.as_str(), route.as_str(), other];
+
+    // Replace each file with its own content, one update at a time, and
+    // report whether the client asked for a restart
+    let script = r#"
+import hashlib, json, sys
+import hmu
+acks = []
+for i, path in enumerate(sys.argv[1:]):
+    content = open(path, encoding="utf-8").read()
+    digest = "sha256:" + hashlib.sha256(content.encode("utf-8")).hexdigest()
+    acks.append(hmu._apply({"type": "HMU_UPDATE", "id": str(i), "modules": [
+        {"path": path, "action": "replace", "content": content, "hash": digest},
+    ]}))
+print(json.dumps(acks))
+"#;
+    let run = std::process::Command::new("python3")
+        .arg("-c")
+        .arg(script)
+        .args(paths)
+        .current_dir(dir.path())
+        .env("PYTHONPATH", dir.path().join("src"))
+        .env_remove("RASH_HMU_SOCKET")
+        .stdin(std::process::Stdio::null())
+        .output()
+        .expect("failed to run python3");
+    assert!(run.status.success(), "{}", String::from_utf8_lossy(&run.stderr));
+    let acks: Vec<serde_json::Value> = serde_json::from_slice(&run.stdout).unwrap();
+
+    for (path, ack) in paths.iter().zip(&acks) {
+        assert_eq!(ack["status"], "success", "{}", path);
+        assert_eq!(
+            ack["requiresRestart"],
+            !generator.adapter().hot_swappable(path),
+            "{}",
+            path
+        );
+    }
+    assert!(generator.adapter().hot_swappable(handler));
+    assert!(!generator.adapter().hot_swappable(route));
+    assert!(!generator.adapter().hot_swappable(other));
+}
@@ -3,9 +3,12 @@ use std::collections::HashSet;
 use indexmap::IndexMap;
 use thiserror::Error;
 
+use rash_spec::index::{build_index, SpecIndex, SymbolKind};
 use rash_spec::loader::LoadedProject;
 use rash_spec::types::ast::{AstNode, CatchClause, MatchArm, TemplatePart};
-use rash_spec::types::common::{Language, Tier, TypeRef};
+use rash_spec::types::common::{Language, Ref, Tier, TypeRef};
+use rash_spec::types::component::ComponentSpec;
+use rash_spec::types::function::FunctionSpec;
 use rash_spec::types::handler::HandlerSpec;
 use rash_spec::types::middleware::MiddlewareSpec;
 use rash_spec::types::model::ModelSpec;
@@ -13,29 +16,58 @@ use rash_spec::types::route::{EndpointSpec, RouteSpec};
 use rash_spec::types::schema::SchemaSpec;
 
 use crate::expr::{
-    DbMutateIR, DbQueryIR, ExprIR, HttpRespondIR, NativeBridgeCallIR, NativeBridgeIR,
-    NativeBridgeImportIR, TemplatePartIR, TypeIR,
+    DbMutateIR, DbQueryIR, ExprIR, FilterIR, FilterOp, HttpRespondIR, IncludeIR,
+    NativeBridgeCallIR, NativeBridgeIR, NativeBridgeImportIR, OrderByIR, SortDirection,
+    TemplatePartIR, TypeIR,
 };
 use crate::statement::{CatchClauseIR, MatchArmIR, StatementIR};
 use crate::types::{
-    EndpointIR, HandlerIR, MiddlewareIR, ModelIR, ParamIR, ProjectIR, RequestIR, ResponseIR,
-    RouteIR, SchemaIR,
+    EndpointIR, FunctionIR, FunctionParamIR, HandlerIR, MiddlewareIR, ModelIR, ParamIR, ProjectIR,
+    RequestIR, ResponseIR, RouteIR, SchemaIR,
 };
 
 #[derive(Debug, Error)]
 pub enum ConvertError {
     #[error("handler '{name}' has no body statements")]
     EmptyHandler { name: String },
+    #[error("unknown component '{name}'")]
+    UnknownComponent { name: String },
+    #[error("component '{name}' ({file}) uses itself: {chain}")]
+    RecursiveComponent {
+        name: String,
+        file: String,
+        chain: String,
+    },
+    #[error("component '{name}' ({file}): {reason}")]
+    ComponentExpansion {
+        name: String,
+        file: String,
+        reason: String,
+    },
+    #[error("'where' of {model}.{operation} is a {kind} expression, not an object of field conditions")]
+    DynamicFilter {
+        model: String,
+        operation: String,
+        kind: String,
+    },
+    #[error("'orderBy' of {model}.{operation} sorts '{field}' by {direction}; expected \"asc\" or \"desc\"")]
+    UnknownSortDirection {
+        model: String,
+        operation: String,
+        field: String,
+        direction: String,
+    },
 }
 
 /// Convert a loaded project (spec types) into the intermediate representation.
 pub fn convert_project(project: &LoadedProject) -> Result<ProjectIR, ConvertError> {
     let config = serde_json::to_value(&project.config).unwrap_or_default();
+    let (index, _) = build_index(project);
 
     let routes = project
         .routes
         .iter()
-        .map(|(_, route)| convert_route(route))
+        .map(|(_, route)| convert_route(route, project, &index))
         .collect();
 
     let schemas = project
@@ -56,10 +88,18 @@ pub fn convert_project(project: &LoadedProject) -> Result<ProjectIR, ConvertErro
         .map(|(_, mw)| convert_middleware(mw))
         .collect();
 
+    let mut components = ComponentExpander::new(project, &index);
+
     let handlers = project
         .handlers
         .iter()
-        .map(|(_, handler)| convert_handler(handler))
+        .map(|(_, handler)| convert_handler(handler, &mut components))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let functions = project
+        .functions
+        .iter()
+        .map(|(_, function)| convert_function(function, &mut components))
         .collect::<Result<Vec<_>, _>>()?;
 
     Ok(ProjectIR {
@@ -69,14 +109,15 @@ pub fn convert_project(project: &LoadedProject) -> Result<ProjectIR, ConvertErro
         models,
         middleware,
         handlers,
+        functions,
     })
 }
 
-fn convert_route(route: &RouteSpec) -> RouteIR {
+fn convert_route(route: &RouteSpec, project: &LoadedProject, index: &SpecIndex) -> RouteIR {
     let methods = route
         .methods
         .iter()
-        .map(|(method, endpoint)| (*method, convert_endpoint(endpoint)))
+        .map(|(method, endpoint)| (*method, convert_endpoint(endpoint, project, index)))
         .collect();
 
     RouteIR {
@@ -86,7 +127,11 @@ fn convert_route(route: &RouteSpec) -> RouteIR {
     }
 }
 
-fn convert_endpoint(endpoint: &EndpointSpec) -> EndpointIR {
+fn convert_endpoint(
+    endpoint: &EndpointSpec,
+    project: &LoadedProject,
+    index: &SpecIndex,
+) -> EndpointIR {
     let operation_id = endpoint
         .operation_id
         .clone()
@@ -137,11 +182,79 @@ fn convert_endpoint(endpoint: &EndpointSpec) -> EndpointIR {
         summary: endpoint.summary.clone(),
         handler_ref: endpoint.handler.reference.clone(),
         middleware,
+        provides: provided_context(project, index, endpoint),
         request,
         response,
     }
 }
 
+/// The middleware that runs before an endpoint's handler: the project's
+/// global middleware first, then the endpoint's own, with `compose` lists
+/// expanded in place. Unresolved refs are skipped, and a middleware already
+/// on the chain is not expanded again.
+pub fn endpoint_middleware<'a>(
+    project: &'a LoadedProject,
+    index: &SpecIndex,
+    endpoint: &EndpointSpec,
+) -> Vec<&'a MiddlewareSpec> {
+    let global = project
+        .config
+        .middleware
+        .as_ref()
+        .map(|mw| mw.global.as_slice())
+        .unwrap_or_default();
+
+    let mut chain = Vec::new();
+    let mut visited = HashSet::new();
+    for mw_ref in global.iter().chain(&endpoint.middleware) {
+        expand_middleware(project, index, mw_ref, &mut visited, &mut chain);
+    }
+    chain
+}
+
+fn expand_middleware<'a>(
+    project: &'a LoadedProject,
+    index: &SpecIndex,
+    mw_ref: &Ref,
+    visited: &mut HashSet<(&'a str, &'a str)>,
+    chain: &mut Vec<&'a MiddlewareSpec>,
+) {
+    let Some(entry) = index.lookup(&mw_ref.reference, SymbolKind::Middleware) else {
+        return;
+    };
+    let Some((file, mw)) = project
+        .middleware
+        .iter()
+        .find(|(file, mw)| *file == entry.file && mw.name == entry.original_name)
+    else {
+        return;
+    };
+    if !visited.insert((file.as_str(), mw.name.as_str())) {
+        return;
+    }
+    chain.push(mw);
+    for inner in mw.compose.iter().flatten() {
+        expand_middleware(project, index, inner, visited, chain);
+    }
+}
+
+/// Context values the endpoint's middleware chain provides to its handler,
+/// read there as `CtxGet("state.<name>")`. A later middleware providing the
+/// same name overrides the type of an earlier one.
+pub fn provided_context(
+    project: &LoadedProject,
+    index: &SpecIndex,
+    endpoint: &EndpointSpec,
+) -> IndexMap<String, TypeIR> {
+    let mut provided = IndexMap::new();
+    for mw in endpoint_middleware(project, index, endpoint) {
+        for (name, schema) in mw.provides.iter().flatten() {
+            provided.insert(name.clone(), convert_json_schema(schema));
+        }
+    }
+    provided
+}
+
 fn convert_schema(schema: &SchemaSpec) -> SchemaIR {
     SchemaIR {
         name: schema.name.clone(),
@@ -192,10 +305,21 @@ fn convert_middleware(mw: &MiddlewareSpec) -> MiddlewareIR {
         name: mw.name.clone(),
         middleware_type,
         handler_ref: mw.handler.as_ref().map(|r| r.reference.clone()),
+        provides: mw.provides.clone().unwrap_or_default(),
+        compose: mw
+            .compose
+            .iter()
+            .flatten()
+            .map(|r| r.reference.clone())
+            .collect(),
+        short_circuit: mw.short_circuit.unwrap_or(false),
     }
 }
 
-fn convert_handler(handler: &HandlerSpec) -> Result<HandlerIR, ConvertError> {
+fn convert_handler(
+    handler: &HandlerSpec,
+    components: &mut ComponentExpander<'_>,
+) -> Result<HandlerIR, ConvertError> {
     let params = handler
         .params
         .as_ref()
@@ -221,8 +345,11 @@ fn convert_handler(handler: &HandlerSpec) -> Result<HandlerIR, ConvertError> {
     let body: Vec<StatementIR> = handler
         .body
         .iter()
-        .map(|node| convert_ast_to_statement(node, &mut max_tier, &mut bridge_languages))
+        .map(|node| {
+            convert_ast_to_statement(node, &mut max_tier, &mut bridge_languages, components)
+        })
         .collect();
+    components.take_error()?;
 
     Ok(HandlerIR {
         name: handler.name.clone(),
@@ -235,6 +362,47 @@ fn convert_handler(handler: &HandlerSpec) -> Result<HandlerIR, ConvertError> {
     })
 }
 
+fn convert_function(
+    function: &FunctionSpec,
+    components: &mut ComponentExpander<'_>,
+) -> Result<FunctionIR, ConvertError> {
+    let params = function
+        .params
+        .iter()
+        .map(|param| FunctionParamIR {
+            name: param.name.clone(),
+            type_ir: convert_type_ref(&param.param_type),
+            default: param.default.clone(),
+        })
+        .collect();
+
+    let return_type = function
+        .return_type
+        .as_ref()
+        .map(convert_type_ref)
+        .unwrap_or(TypeIR::Void);
+
+    let mut max_tier = Tier::Universal;
+    let mut bridge_languages = HashSet::new();
+
+    let body = function
+        .body
+        .iter()
+        .map(|node| {
+            convert_ast_to_statement(node, &mut max_tier, &mut bridge_languages, components)
+        })
+        .collect();
+    components.take_error()?;
+
+    Ok(FunctionIR {
+        name: function.name.clone(),
+        params,
+        return_type,
+        body,
+        max_tier,
+    })
+}
+
 fn convert_type_ref(tr: &TypeRef) -> TypeIR {
     match tr {
         TypeRef::Simple(s) => convert_type_ref_str(s),
@@ -255,6 +423,57 @@ fn convert_type_ref_str(s: &str) -> TypeIR {
     }
 }
 
+/// Convert a JSON Schema definition to a type. Properties not listed in
+/// `required` become optional; `$ref`s become named refs without their file
+/// or pointer prefix.
+pub fn convert_json_schema(schema: &serde_json::Value) -> TypeIR {
+    if let Some(reference) = schema
+        .get("$ref")
+        .or_else(|| schema.get("ref"))
+        .and_then(|r| r.as_str())
+    {
+        let name = reference.rsplit(['#', '/']).next().unwrap_or(reference);
+        return TypeIR::Ref(name.to_string());
+    }
+    match schema.get("type").and_then(|t| t.as_str()) {
+        Some("string") => TypeIR::String,
+        Some("integer" | "number") => TypeIR::Number,
+        Some("boolean") => TypeIR::Boolean,
+        Some("null") => TypeIR::Null,
+        Some("array") => TypeIR::array(
+            schema
+                .get("items")
+                .map(convert_json_schema)
+                .unwrap_or(TypeIR::Any),
+        ),
+        Some("object") | None if schema.get("properties").is_some() => {
+            let required: Vec<&str> = schema
+                .get("required")
+                .and_then(|r| r.as_array())
+                .map(|r| r.iter().filter_map(|v| v.as_str()).collect())
+                .unwrap_or_default();
+            let fields = schema["properties"]
+                .as_object()
+                .map(|props| {
+                    props
+                        .iter()
+                        .map(|(name, prop)| {
+                            let ty = convert_json_schema(prop);
+                            if required.contains(&name.as_str()) {
+                                (name.clone(), ty)
+                            } else {
+                                (name.clone(), TypeIR::optional(ty))
+                            }
+                        })
+                        .collect()
+                })
+                .unwrap_or_default();
+            TypeIR::Object { fields }
+        }
+        _ => TypeIR::Any,
+    }
+}
+
 fn update_tier(current: &mut Tier, node_tier: Tier) {
     if node_tier > *current {
         *current = node_tier;
@@ -265,6 +484,7 @@ fn convert_ast_to_statement(
     node: &AstNode,
     max_tier: &mut Tier,
     bridge_langs: &mut HashSet<Language>,
+    components: &mut ComponentExpander<'_>,
 ) -> StatementIR {
     match node {
         AstNode::LetStatement {
@@ -277,7 +497,7 @@ fn convert_ast_to_statement(
             StatementIR::Let {
                 name: name.clone(),
                 type_: value_type.as_ref().map(convert_type_ref),
-                value: convert_ast_to_expr(value, max_tier, bridge_langs),
+                value: convert_ast_to_expr(value, max_tier, bridge_langs, components),
             }
         }
         AstNode::AssignStatement {
@@ -287,14 +507,19 @@ fn convert_ast_to_statement(
         } => {
             update_tier(max_tier, *tier);
             StatementIR::Assign {
-                target: convert_ast_to_expr(target, max_tier, bridge_langs),
-                value: convert_ast_to_expr(value, max_tier, bridge_langs),
+                target: convert_ast_to_expr(target, max_tier, bridge_langs, components),
+                value: convert_ast_to_expr(value, max_tier, bridge_langs, components),
             }
         }
         AstNode::ReturnStatement { tier, value } => {
             update_tier(max_tier, *tier);
             StatementIR::Return {
-                value: Some(convert_ast_to_expr(value, max_tier, bridge_langs)),
+                value: Some(convert_ast_to_expr(
+                    value,
+                    max_tier,
+                    bridge_langs,
+                    components,
+                )),
             }
         }
         AstNode::IfStatement {
@@ -305,15 +530,15 @@ fn convert_ast_to_statement(
         } => {
             update_tier(max_tier, *tier);
             StatementIR::If {
-                condition: convert_ast_to_expr(condition, max_tier, bridge_langs),
+                condition: convert_ast_to_expr(condition, max_tier, bridge_langs, components),
                 then_: then
                     .iter()
-                    .map(|n| convert_ast_to_statement(n, max_tier, bridge_langs))
+                    .map(|n| convert_ast_to_statement(n, max_tier, bridge_langs, components))
                     .collect(),
                 else_: else_branch.as_ref().map(|stmts| {
                     stmts
                         .iter()
-                        .map(|n| convert_ast_to_statement(n, max_tier, bridge_langs))
+                        .map(|n| convert_ast_to_statement(n, max_tier, bridge_langs, components))
                         .collect()
                 }),
             }
@@ -327,10 +552,10 @@ fn convert_ast_to_statement(
             update_tier(max_tier, *tier);
             StatementIR::For {
                 binding: binding.clone(),
-                iterable: convert_ast_to_expr(iterable, max_tier, bridge_langs),
+                iterable: convert_ast_to_expr(iterable, max_tier, bridge_langs, components),
                 body: body
                     .iter()
-                    .map(|n| convert_ast_to_statement(n, max_tier, bridge_langs))
+                    .map(|n| convert_ast_to_statement(n, max_tier, bridge_langs, components))
                     .collect(),
             }
         }
@@ -341,20 +566,20 @@ fn convert_ast_to_statement(
         } => {
             update_tier(max_tier, *tier);
             StatementIR::While {
-                condition: convert_ast_to_expr(condition, max_tier, bridge_langs),
+                condition: convert_ast_to_expr(condition, max_tier, bridge_langs, components),
                 body: body
                     .iter()
-                    .map(|n| convert_ast_to_statement(n, max_tier, bridge_langs))
+                    .map(|n| convert_ast_to_statement(n, max_tier, bridge_langs, components))
                     .collect(),
             }
         }
         AstNode::MatchStatement { tier, expr, arms } => {
             update_tier(max_tier, *tier);
             StatementIR::Match {
-                expr: convert_ast_to_expr(expr, max_tier, bridge_langs),
+                expr: convert_ast_to_expr(expr, max_tier, bridge_langs, components),
                 arms: arms
                     .iter()
-                    .map(|arm| convert_match_arm(arm, max_tier, bridge_langs))
+                    .map(|arm| convert_match_arm(arm, max_tier, bridge_langs, components))
                     .collect(),
             }
         }
@@ -368,13 +593,13 @@ fn convert_ast_to_statement(
             StatementIR::TryCatch {
                 try_: try_block
                     .iter()
-                    .map(|n| convert_ast_to_statement(n, max_tier, bridge_langs))
+                    .map(|n| convert_ast_to_statement(n, max_tier, bridge_langs, components))
                     .collect(),
-                catch_: convert_catch_clause(catch_block, max_tier, bridge_langs),
+                catch_: convert_catch_clause(catch_block, max_tier, bridge_langs, components),
                 finally_: finally_block.as_ref().map(|stmts| {
                     stmts
                         .iter()
-                        .map(|n| convert_ast_to_statement(n, max_tier, bridge_langs))
+                        .map(|n| convert_ast_to_statement(n, max_tier, bridge_langs, components))
                         .collect()
                 }),
             }
@@ -382,18 +607,64 @@ fn convert_ast_to_statement(
         AstNode::ThrowStatement { tier, value } => {
             update_tier(max_tier, *tier);
             StatementIR::Throw {
-                value: convert_ast_to_expr(value, max_tier, bridge_langs),
+                value: convert_ast_to_expr(value, max_tier, bridge_langs, components),
+            }
+        }
+        AstNode::DbTransaction {
+            tier,
+            body,
+            isolation_level,
+        } => {
+            update_tier(max_tier, *tier);
+            StatementIR::Transaction {
+                body: body
+                    .iter()
+                    .map(|n| convert_ast_to_statement(n, max_tier, bridge_langs, components))
+                    .collect(),
+                isolation: *isolation_level,
             }
         }
         AstNode::ExpressionStatement { tier, expr } => {
             update_tier(max_tier, *tier);
             StatementIR::Expression {
-                expr: convert_ast_to_expr(expr, max_tier, bridge_langs),
+                expr: convert_ast_to_expr(expr, max_tier, bridge_langs, components),
+            }
+        }
+        AstNode::UseComponent {
+            tier,
+            reference,
+            inputs,
+            bind,
+            ..
+        } => {
+            update_tier(max_tier, *tier);
+            match components.instantiate(reference, inputs, bind.as_deref()) {
+                Ok((file, component, nodes)) => {
+                    components.active.push(component.name.clone());
+                    let body = nodes
+                        .iter()
+                        .map(|n| convert_ast_to_statement(n, max_tier, bridge_langs, components))
+                        .collect();
+                    components.active.pop();
+                    StatementIR::Component {
+                        name: component.name.clone(),
+                        source: file.to_string(),
+                        body,
+                    }
+                }
+                Err(err) => {
+                    components.error.get_or_insert(err);
+                    StatementIR::Component {
+                        name: reference.clone(),
+                        source: String::new(),
+                        body: vec![],
+                    }
+                }
             }
         }
         // Expression nodes used in statement position → wrap as Expression statement
         other => StatementIR::Expression {
-            expr: convert_ast_to_expr(other, max_tier, bridge_langs),
+            expr: convert_ast_to_expr(other, max_tier, bridge_langs, components),
         },
     }
 }
@@ -402,6 +673,7 @@ fn convert_ast_to_expr(
     node: &AstNode,
     max_tier: &mut Tier,
     bridge_langs: &mut HashSet<Language>,
+    components: &mut ComponentExpander<'_>,
 ) -> ExprIR {
     match node {
         AstNode::Literal { tier, value } => {
@@ -423,8 +695,18 @@ fn convert_ast_to_expr(
             update_tier(max_tier, *tier);
             ExprIR::Binary {
                 op: operator.clone(),
-                left: Box::new(convert_ast_to_expr(left, max_tier, bridge_langs)),
-                right: Box::new(convert_ast_to_expr(right, max_tier, bridge_langs)),
+                left: Box::new(convert_ast_to_expr(
+                    left,
+                    max_tier,
+                    bridge_langs,
+                    components,
+                )),
+                right: Box::new(convert_ast_to_expr(
+                    right,
+                    max_tier,
+                    bridge_langs,
+                    components,
+                )),
             }
         }
         AstNode::UnaryExpr {
@@ -435,16 +717,40 @@ fn convert_ast_to_expr(
             update_tier(max_tier, *tier);
             ExprIR::Unary {
                 op: operator.clone(),
-                operand: Box::new(convert_ast_to_expr(operand, max_tier, bridge_langs)),
+                operand: Box::new(convert_ast_to_expr(
+                    operand,
+                    max_tier,
+                    bridge_langs,
+                    components,
+                )),
             }
         }
         AstNode::CallExpr { tier, callee, args } => {
             update_tier(max_tier, *tier);
             ExprIR::Call {
-                callee: Box::new(convert_ast_to_expr(callee, max_tier, bridge_langs)),
+                callee: Box::new(convert_ast_to_expr(
+                    callee,
+                    max_tier,
+                    bridge_langs,
+                    components,
+                )),
                 args: args
                     .iter()
-                    .map(|a| convert_ast_to_expr(a, max_tier, bridge_langs))
+                    .map(|a| convert_ast_to_expr(a, max_tier, bridge_langs, components))
+                    .collect(),
+            }
+        }
+        AstNode::CallFunction {
+            tier,
+            function,
+            args,
+        } => {
+            update_tier(max_tier, *tier);
+            ExprIR::CallFunction {
+                function: function.clone(),
+                args: args
+                    .iter()
+                    .map(|a| convert_ast_to_expr(a, max_tier, bridge_langs, components))
                     .collect(),
             }
         }
@@ -455,7 +761,12 @@ fn convert_ast_to_expr(
         } => {
             update_tier(max_tier, *tier);
             ExprIR::Member {
-                object: Box::new(convert_ast_to_expr(object, max_tier, bridge_langs)),
+                object: Box::new(convert_ast_to_expr(
+                    object,
+                    max_tier,
+                    bridge_langs,
+                    components,
+                )),
                 property: property.clone(),
             }
         }
@@ -466,8 +777,18 @@ fn convert_ast_to_expr(
         } => {
             update_tier(max_tier, *tier);
             ExprIR::Index {
-                object: Box::new(convert_ast_to_expr(object, max_tier, bridge_langs)),
-                index: Box::new(convert_ast_to_expr(index, max_tier, bridge_langs)),
+                object: Box::new(convert_ast_to_expr(
+                    object,
+                    max_tier,
+                    bridge_langs,
+                    components,
+                )),
+                index: Box::new(convert_ast_to_expr(
+                    index,
+                    max_tier,
+                    bridge_langs,
+                    components,
+                )),
             }
         }
         AstNode::ObjectExpr { tier, properties } => {
@@ -475,7 +796,12 @@ fn convert_ast_to_expr(
             ExprIR::Object {
                 properties: properties
                     .iter()
-                    .map(|(k, v)| (k.clone(), convert_ast_to_expr(v, max_tier, bridge_langs)))
+                    .map(|(k, v)| {
+                        (
+                            k.clone(),
+                            convert_ast_to_expr(v, max_tier, bridge_langs, components),
+                        )
+                    })
                     .collect(),
             }
         }
@@ -484,7 +810,7 @@ fn convert_ast_to_expr(
             ExprIR::Array {
                 elements: elements
                     .iter()
-                    .map(|e| convert_ast_to_expr(e, max_tier, bridge_langs))
+                    .map(|e| convert_ast_to_expr(e, max_tier, bridge_langs, components))
                     .collect(),
             }
         }
@@ -494,14 +820,19 @@ fn convert_ast_to_expr(
                 params: params.clone(),
                 body: body
                     .iter()
-                    .map(|n| convert_ast_to_statement(n, max_tier, bridge_langs))
+                    .map(|n| convert_ast_to_statement(n, max_tier, bridge_langs, components))
                     .collect(),
             }
         }
         AstNode::AwaitExpr { tier, expr } => {
             update_tier(max_tier, *tier);
             ExprIR::Await {
-                expr: Box::new(convert_ast_to_expr(expr, max_tier, bridge_langs)),
+                expr: Box::new(convert_ast_to_expr(
+                    expr,
+                    max_tier,
+                    bridge_langs,
+                    components,
+                )),
             }
         }
         AstNode::PipeExpr { tier, stages } => {
@@ -509,7 +840,7 @@ fn convert_ast_to_expr(
             ExprIR::Pipe {
                 stages: stages
                     .iter()
-                    .map(|s| convert_ast_to_expr(s, max_tier, bridge_langs))
+                    .map(|s| convert_ast_to_expr(s, max_tier, bridge_langs, components))
                     .collect(),
             }
         }
@@ -518,7 +849,7 @@ fn convert_ast_to_expr(
             ExprIR::Template {
                 parts: parts
                     .iter()
-                    .map(|p| convert_template_part(p, max_tier, bridge_langs))
+                    .map(|p| convert_template_part(p, max_tier, bridge_langs, components))
                     .collect(),
             }
         }
@@ -538,16 +869,21 @@ fn convert_ast_to_expr(
             ExprIR::DbQuery(DbQueryIR {
                 model: model.clone(),
                 operation: operation.clone(),
-                r#where: r#where.clone(),
-                order_by: order_by.clone(),
+                r#where: r#where.as_ref().and_then(|w| {
+                    convert_where(model, operation, w, max_tier, bridge_langs, components)
+                }),
+                order_by: order_by
+                    .as_ref()
+                    .map(|o| convert_order_by(model, operation, o, components))
+                    .unwrap_or_default(),
                 skip: skip
                     .as_ref()
-                    .map(|s| Box::new(convert_ast_to_expr(s, max_tier, bridge_langs))),
+                    .map(|s| Box::new(convert_ast_to_expr(s, max_tier, bridge_langs, components))),
                 take: take
                     .as_ref()
-                    .map(|t| Box::new(convert_ast_to_expr(t, max_tier, bridge_langs))),
+                    .map(|t| Box::new(convert_ast_to_expr(t, max_tier, bridge_langs, components))),
                 select: select.clone(),
-                include: include.clone(),
+                include: include.as_ref().map(convert_include).unwrap_or_default(),
             })
         }
         AstNode::DbMutate {
@@ -563,8 +899,10 @@ fn convert_ast_to_expr(
                 operation: operation.clone(),
                 data: data
                     .as_ref()
-                    .map(|d| Box::new(convert_ast_to_expr(d, max_tier, bridge_langs))),
-                r#where: r#where.clone(),
+                    .map(|d| Box::new(convert_ast_to_expr(d, max_tier, bridge_langs, components))),
+                r#where: r#where.as_ref().and_then(|w| {
+                    convert_where(model, operation, w, max_tier, bridge_langs, components)
+                }),
             })
         }
         AstNode::HttpRespond {
@@ -578,12 +916,17 @@ fn convert_ast_to_expr(
                 status: *status,
                 headers: headers.as_ref().map(|h| {
                     h.iter()
-                        .map(|(k, v)| (k.clone(), convert_ast_to_expr(v, max_tier, bridge_langs)))
+                        .map(|(k, v)| {
+                            (
+                                k.clone(),
+                                convert_ast_to_expr(v, max_tier, bridge_langs, components),
+                            )
+                        })
                         .collect()
                 }),
                 body: body
                     .as_ref()
-                    .map(|b| Box::new(convert_ast_to_expr(b, max_tier, bridge_langs))),
+                    .map(|b| Box::new(convert_ast_to_expr(b, max_tier, bridge_langs, components))),
             })
         }
         AstNode::CtxGet { tier, path } => {
@@ -594,7 +937,12 @@ fn convert_ast_to_expr(
             update_tier(max_tier, *tier);
             ExprIR::Validate {
                 schema: schema.clone(),
-                data: Box::new(convert_ast_to_expr(data, max_tier, bridge_langs)),
+                data: Box::new(convert_ast_to_expr(
+                    data,
+                    max_tier,
+                    bridge_langs,
+                    components,
+                )),
             }
         }
         AstNode::HashPassword {
@@ -605,7 +953,12 @@ fn convert_ast_to_expr(
         } => {
             update_tier(max_tier, *tier);
             ExprIR::HashPassword {
-                input: Box::new(convert_ast_to_expr(input, max_tier, bridge_langs)),
+                input: Box::new(convert_ast_to_expr(
+                    input,
+                    max_tier,
+                    bridge_langs,
+                    components,
+                )),
                 algorithm: algorithm.clone(),
                 rounds: *rounds,
             }
@@ -618,8 +971,18 @@ fn convert_ast_to_expr(
         } => {
             update_tier(max_tier, *tier);
             ExprIR::VerifyPassword {
-                password: Box::new(convert_ast_to_expr(password, max_tier, bridge_langs)),
-                hash: Box::new(convert_ast_to_expr(hash, max_tier, bridge_langs)),
+                password: Box::new(convert_ast_to_expr(
+                    password,
+                    max_tier,
+                    bridge_langs,
+                    components,
+                )),
+                hash: Box::new(convert_ast_to_expr(
+                    hash,
+                    max_tier,
+                    bridge_langs,
+                    components,
+                )),
                 algorithm: algorithm.clone(),
             }
         }
@@ -631,14 +994,24 @@ fn convert_ast_to_expr(
         } => {
             update_tier(max_tier, *tier);
             ExprIR::SignToken {
-                payload: Box::new(convert_ast_to_expr(payload, max_tier, bridge_langs)),
+                payload: Box::new(convert_ast_to_expr(
+                    payload,
+                    max_tier,
+                    bridge_langs,
+                    components,
+                )),
                 options: options.clone(),
             }
         }
         AstNode::VerifyToken { tier, token, .. } => {
             update_tier(max_tier, *tier);
             ExprIR::VerifyToken {
-                token: Box::new(convert_ast_to_expr(token, max_tier, bridge_langs)),
+                token: Box::new(convert_ast_to_expr(
+                    token,
+                    max_tier,
+                    bridge_langs,
+                    components,
+                )),
             }
         }
         // Utility nodes → map to Call expressions
@@ -652,9 +1025,9 @@ fn convert_ast_to_expr(
             ExprIR::Call {
                 callee: Box::new(ExprIR::ident("sendEmail")),
                 args: vec![
-                    convert_ast_to_expr(to, max_tier, bridge_langs),
-                    convert_ast_to_expr(subject, max_tier, bridge_langs),
-                    convert_ast_to_expr(body, max_tier, bridge_langs),
+                    convert_ast_to_expr(to, max_tier, bridge_langs, components),
+                    convert_ast_to_expr(subject, max_tier, bridge_langs, components),
+                    convert_ast_to_expr(body, max_tier, bridge_langs, components),
                 ],
             }
         }
@@ -662,7 +1035,7 @@ fn convert_ast_to_expr(
             update_tier(max_tier, *tier);
             let mut args = vec![ExprIR::literal(serde_json::json!(event))];
             if let Some(d) = data {
-                args.push(convert_ast_to_expr(d, max_tier, bridge_langs));
+                args.push(convert_ast_to_expr(d, max_tier, bridge_langs, components));
             }
             ExprIR::Call {
                 callee: Box::new(ExprIR::ident("emitEvent")),
@@ -680,7 +1053,12 @@ fn convert_ast_to_expr(
                     object: Box::new(ExprIR::ident("logger")),
                     property: level.clone(),
                 }),
-                args: vec![convert_ast_to_expr(message, max_tier, bridge_langs)],
+                args: vec![convert_ast_to_expr(
+                    message,
+                    max_tier,
+                    bridge_langs,
+                    components,
+                )],
             }
         }
         // Bridge node
@@ -707,7 +1085,7 @@ fn convert_ast_to_expr(
                     args: call
                         .args
                         .iter()
-                        .map(|a| convert_ast_to_expr(a, max_tier, bridge_langs))
+                        .map(|a| convert_ast_to_expr(a, max_tier, bridge_langs, components))
                         .collect(),
                 },
                 return_type: return_type.clone(),
@@ -723,23 +1101,249 @@ fn convert_ast_to_expr(
         | AstNode::MatchStatement { .. }
         | AstNode::TryCatchStatement { .. }
         | AstNode::ThrowStatement { .. }
-        | AstNode::ExpressionStatement { .. } => ExprIR::Literal {
+        | AstNode::DbTransaction { .. }
+        | AstNode::ExpressionStatement { .. }
+        | AstNode::UseComponent { .. } => ExprIR::Literal {
             value: serde_json::Value::Null,
         },
     }
 }
 
+/// Convert the `where` of a `DbQuery`/`DbMutate`. An empty object means no
+/// filter. A whole filter given as an expression can't be lowered to field
+/// conditions, so it is parked as an error instead.
+fn convert_where(
+    model: &str,
+    operation: &str,
+    value: &serde_json::Value,
+    max_tier: &mut Tier,
+    bridge_langs: &mut HashSet<Language>,
+    components: &mut ComponentExpander<'_>,
+) -> Option<FilterIR> {
+    let is_node = value.get("tier").is_some()
+        && serde_json::from_value::<AstNode>(value.clone()).is_ok();
+    if is_node {
+        components.error.get_or_insert(ConvertError::DynamicFilter {
+            model: model.to_string(),
+            operation: operation.to_string(),
+            kind: value["type"].as_str().unwrap_or_default().to_string(),
+        });
+        return None;
+    }
+    match convert_filter(value, max_tier, bridge_langs, components) {
+        FilterIR::And { filters } if filters.is_empty() => None,
+        filter => Some(filter),
+    }
+}
+
+/// Convert a Prisma-style `where` object into a typed filter.
+///
+/// Each key is either a combinator (`AND`, `OR`, `NOT`) or a field whose value is
+/// an AST node, a plain literal, or an operator object such as `{ "gte": 18 }`.
+/// Sibling keys are combined with AND.
+fn convert_filter(
+    value: &serde_json::Value,
+    max_tier: &mut Tier,
+    bridge_langs: &mut HashSet<Language>,
+    components: &mut ComponentExpander<'_>,
+) -> FilterIR {
+    let mut filters = Vec::new();
+    if let Some(map) = value.as_object() {
+        for (key, v) in map {
+            match key.as_str() {
+                "AND" | "OR" => {
+                    let subs: Vec<FilterIR> = match v.as_array() {
+                        Some(arr) => arr
+                            .iter()
+                            .map(|f| convert_filter(f, max_tier, bridge_langs, components))
+                            .collect(),
+                        None => vec![convert_filter(v, max_tier, bridge_langs, components)],
+                    };
+                    filters.push(if key == "AND" {
+                        FilterIR::And { filters: subs }
+                    } else {
+                        FilterIR::Or { filters: subs }
+                    });
+                }
+                "NOT" => {
+                    // `NOT: [a, b]` excludes rows matching any of the filters
+                    let filter = match v.as_array() {
+                        Some(arr) if arr.is_empty() => continue,
+                        Some(arr) => FilterIR::Or {
+                            filters: arr
+                                .iter()
+                                .map(|f| convert_filter(f, max_tier, bridge_langs, components))
+                                .collect(),
+                        },
+                        None => convert_filter(v, max_tier, bridge_langs, components),
+                    };
+                    filters.push(FilterIR::Not {
+                        filter: Box::new(filter),
+                    });
+                }
+                _ => filters.extend(convert_field_filter(
+                    key,
+                    v,
+                    max_tier,
+                    bridge_langs,
+                    components,
+                )),
+            }
+        }
+    }
+
+    if filters.len() == 1 {
+        filters.remove(0)
+    } else {
+        FilterIR::And { filters }
+    }
+}
+
+/// Convert the conditions on a single field of a `where` object.
+fn convert_field_filter(
+    field: &str,
+    value: &serde_json::Value,
+    max_tier: &mut Tier,
+    bridge_langs: &mut HashSet<Language>,
+    components: &mut ComponentExpander<'_>,
+) -> Vec<FilterIR> {
+    let operators = value.as_object().filter(|map| {
+        !map.contains_key("type") && !map.is_empty() && map.keys().all(|k| filter_op(k).is_some())
+    });
+    match operators {
+        Some(map) => map
+            .iter()
+            .filter_map(|(op, v)| {
+                filter_op(op).map(|op| FilterIR::Condition {
+                    field: field.to_string(),
+                    op,
+                    value: Box::new(convert_filter_value(v, max_tier, bridge_langs, components)),
+                })
+            })
+            .collect(),
+        None => vec![FilterIR::Condition {
+            field: field.to_string(),
+            op: FilterOp::Eq,
+            value: Box::new(convert_filter_value(
+                value,
+                max_tier,
+                bridge_langs,
+                components,
+            )),
+        }],
+    }
+}
+
+/// Filter values are AST nodes when tagged with `type`, plain literals otherwise.
+fn convert_filter_value(
+    value: &serde_json::Value,
+    max_tier: &mut Tier,
+    bridge_langs: &mut HashSet<Language>,
+    components: &mut ComponentExpander<'_>,
+) -> ExprIR {
+    match serde_json::from_value::<AstNode>(value.clone()) {
+        Ok(node) if value.get("type").is_some() => {
+            convert_ast_to_expr(&node, max_tier, bridge_langs, components)
+        }
+        _ => ExprIR::Literal {
+            value: value.clone(),
+        },
+    }
+}
+
+fn filter_op(name: &str) -> Option<FilterOp> {
+    Some(match name {
+        "equals" | "eq" => FilterOp::Eq,
+        "not" | "ne" => FilterOp::Ne,
+        "gt" => FilterOp::Gt,
+        "gte" => FilterOp::Gte,
+        "lt" => FilterOp::Lt,
+        "lte" => FilterOp::Lte,
+        "in" => FilterOp::In,
+        "notIn" => FilterOp::NotIn,
+        "contains" => FilterOp::Contains,
+        "startsWith" => FilterOp::StartsWith,
+        "endsWith" => FilterOp::EndsWith,
+        _ => return None,
+    })
+}
+
+/// Convert `{ "field": "asc" | "desc" }` (or an array of them) into ordered sort keys.
+/// Any other direction is parked as an error and its key dropped.
+fn convert_order_by(
+    model: &str,
+    operation: &str,
+    value: &serde_json::Value,
+    components: &mut ComponentExpander<'_>,
+) -> Vec<OrderByIR> {
+    let entries: Vec<&serde_json::Value> = match value.as_array() {
+        Some(arr) => arr.iter().collect(),
+        None => vec![value],
+    };
+    entries
+        .iter()
+        .filter_map(|entry| entry.as_object())
+        .flat_map(|map| map.iter())
+        .filter_map(|(field, dir)| {
+            let direction = match dir.as_str() {
+                Some("asc") => SortDirection::Asc,
+                Some("desc") => SortDirection::Desc,
+                _ => {
+                    components
+                        .error
+                        .get_or_insert(ConvertError::UnknownSortDirection {
+                            model: model.to_string(),
+                            operation: operation.to_string(),
+                            field: field.clone(),
+                            direction: dir.to_string(),
+                        });
+                    return None;
+                }
+            };
+            Some(OrderByIR {
+                field: field.clone(),
+                direction,
+            })
+        })
+        .collect()
+}
+
+/// Convert `{ "posts": true, "profile": { "include": { ... } } }` or
+/// `["posts", "profile"]` into the relations to eager-load.
+fn convert_include(value: &serde_json::Value) -> Vec<IncludeIR> {
+    match value {
+        serde_json::Value::Array(arr) => arr
+            .iter()
+            .filter_map(|v| v.as_str())
+            .map(|relation| IncludeIR {
+                relation: relation.to_string(),
+                include: vec![],
+            })
+            .collect(),
+        serde_json::Value::Object(map) => map
+            .iter()
+            .filter(|(_, v)| v.as_bool() != Some(false))
+            .map(|(relation, v)| IncludeIR {
+                relation: relation.clone(),
+                include: v.get("include").map(convert_include).unwrap_or_default(),
+            })
+            .collect(),
+        _ => vec![],
+    }
+}
+
 fn convert_match_arm(
     arm: &MatchArm,
     max_tier: &mut Tier,
     bridge_langs: &mut HashSet<Language>,
+    components: &mut ComponentExpander<'_>,
 ) -> MatchArmIR {
     MatchArmIR {
-        pattern: convert_ast_to_expr(&arm.pattern, max_tier, bridge_langs),
+        pattern: convert_ast_to_expr(&arm.pattern, max_tier, bridge_langs, components),
         body: arm
             .body
             .iter()
-            .map(|n| convert_ast_to_statement(n, max_tier, bridge_langs))
+            .map(|n| convert_ast_to_statement(n, max_tier, bridge_langs, components))
             .collect(),
     }
 }
@@ -748,13 +1352,14 @@ fn convert_catch_clause(
     clause: &CatchClause,
     max_tier: &mut Tier,
     bridge_langs: &mut HashSet<Language>,
+    components: &mut ComponentExpander<'_>,
 ) -> CatchClauseIR {
     CatchClauseIR {
         binding: clause.binding.clone(),
         body: clause
             .body
             .iter()
-            .map(|n| convert_ast_to_statement(n, max_tier, bridge_langs))
+            .map(|n| convert_ast_to_statement(n, max_tier, bridge_langs, components))
             .collect(),
     }
 }
@@ -763,23 +1368,278 @@ fn convert_template_part(
     part: &TemplatePart,
     max_tier: &mut Tier,
     bridge_langs: &mut HashSet<Language>,
+    components: &mut ComponentExpander<'_>,
 ) -> TemplatePartIR {
     match part {
         TemplatePart::Text { value } => TemplatePartIR::Text {
             value: value.clone(),
         },
         TemplatePart::Expr { value } => TemplatePartIR::Expr {
-            value: Box::new(convert_ast_to_expr(value, max_tier, bridge_langs)),
+            value: Box::new(convert_ast_to_expr(
+                value,
+                max_tier,
+                bridge_langs,
+                components,
+            )),
         },
     }
 }
 
+/// Looks up components and instantiates their bodies for `UseComponent` sites.
+///
+/// Statement conversion is infallible, so the first expansion (or `where`)
+/// failure is parked in `error` and surfaced once the enclosing body has been
+/// converted.
+struct ComponentExpander<'a> {
+    project: &'a LoadedProject,
+    index: &'a SpecIndex,
+    /// Components currently being expanded, outermost first
+    active: Vec<String>,
+    error: Option<ConvertError>,
+}
+
+impl<'a> ComponentExpander<'a> {
+    fn new(project: &'a LoadedProject, index: &'a SpecIndex) -> Self {
+        Self {
+            project,
+            index,
+            active: Vec::new(),
+            error: None,
+        }
+    }
+
+    fn take_error(&mut self) -> Result<(), ConvertError> {
+        match self.error.take() {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+
+    /// Resolve a component and produce the statements a `UseComponent` site
+    /// expands to: one `let` per context input, then the body with `$input.*`
+    /// placeholders substituted and its trailing return bound to `bind`.
+    fn instantiate(
+        &self,
+        reference: &str,
+        inputs: &IndexMap<String, AstNode>,
+        bind: Option<&str>,
+    ) -> Result<(&'a str, &'a ComponentSpec, Vec<AstNode>), ConvertError> {
+        let (file, component) = self
+            .index
+            .lookup(reference, SymbolKind::Component)
+            .and_then(|entry| {
+                self.project
+                    .components
+                    .iter()
+                    .find(|(file, _)| *file == entry.file)
+            })
+            .map(|(file, component)| (file.as_str(), component))
+            .ok_or_else(|| ConvertError::UnknownComponent {
+                name: reference.to_string(),
+            })?;
+
+        if self.active.contains(&component.name) {
+            let mut chain = self.active.clone();
+            chain.push(component.name.clone());
+            return Err(ConvertError::RecursiveComponent {
+                name: component.name.clone(),
+                file: file.to_string(),
+                chain: chain.join(" -> "),
+            });
+        }
+
+        let fail = |reason: String| ConvertError::ComponentExpansion {
+            name: component.name.clone(),
+            file: file.to_string(),
+            reason,
+        };
+
+        if let Some(unknown) = inputs.keys().find(|k| !component.inputs.contains_key(*k)) {
+            return Err(fail(format!("has no input '{}'", unknown)));
+        }
+        if let Some((missing, _)) = component
+            .inputs
+            .iter()
+            .find(|(name, input)| !input.optional && !inputs.contains_key(*name))
+        {
+            return Err(fail(format!("is missing required input '{}'", missing)));
+        }
+
+        let mut nodes: Vec<AstNode> = component
+            .context_inputs
+            .iter()
+            .map(|(name, input)| {
+                let source = AstNode::CtxGet {
+                    tier: Tier::Universal,
+                    path: input.source.clone(),
+                };
+                let value = match &input.default {
+                    Some(default) => AstNode::BinaryExpr {
+                        tier: Tier::Universal,
+                        operator: "||".to_string(),
+                        left: Box::new(source),
+                        right: Box::new(AstNode::Literal {
+                            tier: Tier::Universal,
+                            value: default.clone(),
+                        }),
+                    },
+                    None => source,
+                };
+                AstNode::LetStatement {
+                    tier: Tier::Universal,
+                    name: name.clone(),
+                    value_type: None,
+                    value: Box::new(value),
+                }
+            })
+            .collect();
+
+        let given: IndexMap<&str, serde_json::Value> = inputs
+            .iter()
+            .map(|(name, node)| {
+                (
+                    name.as_str(),
+                    serde_json::to_value(node).unwrap_or_default(),
+                )
+            })
+            .collect();
+        let mut body = serde_json::to_value(&component.body).unwrap_or_default();
+        substitute_inputs(&mut body, component, &given, false).map_err(fail)?;
+        let mut body: Vec<AstNode> = serde_json::from_value(body)
+            .map_err(|e| fail(format!("body is invalid after substituting inputs: {}", e)))?;
+
+        match (body.pop(), bind) {
+            (Some(AstNode::ReturnStatement { tier, value }), Some(name)) => {
+                body.push(AstNode::LetStatement {
+                    tier,
+                    name: name.to_string(),
+                    value_type: None,
+                    value,
+                });
+            }
+            (Some(AstNode::ReturnStatement { tier, value }), None) => {
+                // A bare value has nothing left to do once nobody binds it
+                if !matches!(
+                    *value,
+                    AstNode::Literal { .. }
+                        | AstNode::Identifier { .. }
+                        | AstNode::ObjectExpr { .. }
+                        | AstNode::ArrayExpr { .. }
+                ) {
+                    body.push(AstNode::ExpressionStatement { tier, expr: value });
+                }
+            }
+            (_, Some(name)) => {
+                return Err(fail(format!(
+                    "has no trailing ReturnStatement to bind to '{}'",
+                    name
+                )));
+            }
+            (last, None) => body.extend(last),
+        }
+
+        nodes.extend(body);
+        Ok((file, component, nodes))
+    }
+}
+
+/// Replace `$input.<name>` placeholders in a serialized component body.
+///
+/// An `Identifier` placeholder becomes the caller's AST node. Inside the raw
+/// JSON of `DbQuery`/`DbMutate` `where`, `orderBy` and `include` it is lowered to
+/// the plain JSON those fields expect instead. A bare string placeholder (such
+/// as `"model": "$input.model"`) takes the value of a string literal input.
+fn substitute_inputs(
+    value: &mut serde_json::Value,
+    component: &ComponentSpec,
+    given: &IndexMap<&str, serde_json::Value>,
+    raw: bool,
+) -> Result<(), String> {
+    let input_of = |placeholder: &str| -> Result<Option<String>, String> {
+        match placeholder.strip_prefix("$input.") {
+            Some(name) if component.inputs.contains_key(name) => Ok(Some(name.to_string())),
+            Some(name) => Err(format!("references unknown input '{}'", name)),
+            None => Ok(None),
+        }
+    };
+
+    match value {
+        serde_json::Value::Object(map) => {
+            let node_type = map.get("type").and_then(|t| t.as_str()).map(str::to_string);
+            if node_type.as_deref() == Some("Identifier") {
+                if let Some(name) = map.get("name").and_then(|n| n.as_str()) {
+                    if let Some(input) = input_of(name)? {
+                        *value = match (given.get(input.as_str()), raw) {
+                            (Some(node), true) => lower_to_raw(node),
+                            (Some(node), false) => node.clone(),
+                            (None, true) => serde_json::Value::Null,
+                            (None, false) => {
+                                serde_json::json!({ "type": "Literal", "tier": 0, "value": null })
+                            }
+                        };
+                        return Ok(());
+                    }
+                }
+            }
+            let db_node = matches!(node_type.as_deref(), Some("DbQuery" | "DbMutate"));
+            for (key, child) in map.iter_mut() {
+                let child_raw =
+                    raw || (db_node && matches!(key.as_str(), "where" | "orderBy" | "include"));
+                substitute_inputs(child, component, given, child_raw)?;
+            }
+        }
+        serde_json::Value::Array(arr) => {
+            for child in arr {
+                substitute_inputs(child, component, given, raw)?;
+            }
+        }
+        serde_json::Value::String(s) => {
+            if let Some(input) = input_of(s)? {
+                let literal = given
+                    .get(input.as_str())
+                    .filter(|node| node["type"] == "Literal" && node["value"].is_string())
+                    .ok_or_else(|| format!("input '{}' must be a string literal here", input))?;
+                *value = literal["value"].clone();
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Lower an AST input to the plain JSON used by filter/order/include objects.
+/// Literals and object/array literals unwrap; anything else stays an AST node.
+fn lower_to_raw(node: &serde_json::Value) -> serde_json::Value {
+    match node.get("type").and_then(|t| t.as_str()) {
+        Some("Literal") => node["value"].clone(),
+        Some("ObjectExpr") => serde_json::Value::Object(
+            node["properties"]
+                .as_object()
+                .map(|props| {
+                    props
+                        .iter()
+                        .map(|(k, v)| (k.clone(), lower_to_raw(v)))
+                        .collect()
+                })
+                .unwrap_or_default(),
+        ),
+        Some("ArrayExpr") => serde_json::Value::Array(
+            node["elements"]
+                .as_array()
+                .map(|elements| elements.iter().map(lower_to_raw).collect())
+                .unwrap_or_default(),
+        ),
+        _ => node.clone(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use pretty_assertions::assert_eq;
     use std::path::PathBuf;
 
+    use rash_spec::types::ast::IsolationLevel;
     use rash_spec::types::common::{Framework, Runtime};
     use rash_spec::types::config::{RashConfig, ServerConfig, TargetConfig};
 
@@ -813,6 +1673,8 @@ mod tests {
             models: vec![],
             middleware: vec![],
             handlers: vec![],
+            functions: vec![],
+            components: vec![],
         }
     }
 
@@ -948,6 +1810,500 @@ mod tests {
         assert!(h.bridge_languages.is_empty());
     }
 
+    #[test]
+    fn test_convert_db_filter() {
+        let project = minimal_project();
+        let (index, _) = build_index(&project);
+        let mut components = ComponentExpander::new(&project, &index);
+        let mut tier = Tier::Domain;
+        let mut langs = HashSet::new();
+        let filter = convert_filter(
+            &serde_json::json!({
+                "id": { "type": "Identifier", "tier": 0, "name": "userId" },
+                "age": { "gte": 18, "lt": { "type": "Identifier", "tier": 0, "name": "maxAge" } },
+                "OR": [{ "role": "admin" }, { "NOT": { "email": null } }]
+            }),
+            &mut tier,
+            &mut langs,
+            &mut components,
+        );
+
+        // serde_json objects iterate in key order: OR, age, id.
+        let FilterIR::And { filters } = filter else {
+            panic!("expected sibling keys to combine with AND");
+        };
+        assert_eq!(filters.len(), 4);
+        let FilterIR::Or { filters: any } = &filters[0] else {
+            panic!("expected OR");
+        };
+        assert!(matches!(&any[1], FilterIR::Not { .. }));
+        assert!(matches!(
+            &filters[1],
+            FilterIR::Condition {
+                op: FilterOp::Gte,
+                ..
+            }
+        ));
+        assert!(matches!(
+            &filters[2],
+            FilterIR::Condition { op: FilterOp::Lt, value, .. }
+                if matches!(value.as_ref(), ExprIR::Identifier { .. })
+        ));
+        assert!(matches!(
+            &filters[3],
+            FilterIR::Condition { field, op: FilterOp::Eq, value }
+                if field == "id" && matches!(value.as_ref(), ExprIR::Identifier { name } if name == "userId")
+        ));
+
+        let order = convert_order_by(
+            "User",
+            "findMany",
+            &serde_json::json!([{ "role": "asc" }, { "createdAt": "desc" }]),
+            &mut components,
+        );
+        assert_eq!(order.len(), 2);
+        assert_eq!(order[1].direction, SortDirection::Desc);
+        assert!(components.take_error().is_ok());
+
+        let include = convert_include(
+            &serde_json::json!({ "posts": true, "profile": { "include": { "user": true } } }),
+        );
+        assert_eq!(include.len(), 2);
+        assert_eq!(include[1].include[0].relation, "user");
+    }
+
+    #[test]
+    fn test_convert_db_filter_combinators_are_case_sensitive() {
+        let project = minimal_project();
+        let (index, _) = build_index(&project);
+        let mut components = ComponentExpander::new(&project, &index);
+        let filter = convert_filter(
+            &serde_json::json!({ "or": [{ "role": "admin" }] }),
+            &mut Tier::Domain,
+            &mut HashSet::new(),
+            &mut components,
+        );
+
+        assert!(matches!(
+            filter,
+            FilterIR::Condition { ref field, op: FilterOp::Eq, .. } if field == "or"
+        ));
+    }
+
+    #[test]
+    fn test_convert_db_not_takes_a_list_of_filters() {
+        let project = minimal_project();
+        let (index, _) = build_index(&project);
+        let mut components = ComponentExpander::new(&project, &index);
+        let filter = convert_filter(
+            &serde_json::json!({ "NOT": [{ "role": "admin" }, { "email": null }] }),
+            &mut Tier::Domain,
+            &mut HashSet::new(),
+            &mut components,
+        );
+
+        let FilterIR::Not { filter } = filter else {
+            panic!("expected NOT");
+        };
+        let FilterIR::Or { filters } = *filter else {
+            panic!("expected NOT over the listed filters");
+        };
+        assert!(matches!(
+            &filters[..],
+            [
+                FilterIR::Condition { field: role, .. },
+                FilterIR::Condition { field: email, .. },
+            ] if role == "role" && email == "email"
+        ));
+
+        let empty = convert_filter(
+            &serde_json::json!({ "NOT": [] }),
+            &mut Tier::Domain,
+            &mut HashSet::new(),
+            &mut components,
+        );
+        assert!(matches!(empty, FilterIR::And { ref filters } if filters.is_empty()));
+    }
+
+    #[test]
+    fn test_convert_db_unknown_sort_direction_is_rejected() {
+        let project = minimal_project();
+        let (index, _) = build_index(&project);
+        let mut components = ComponentExpander::new(&project, &index);
+        let order = convert_order_by(
+            "User",
+            "findMany",
+            &serde_json::json!([{ "name": "asc" }, { "createdAt": "descending" }]),
+            &mut components,
+        );
+
+        assert_eq!(order.len(), 1);
+        assert_eq!(order[0].field, "name");
+        match components.take_error() {
+            Err(ConvertError::UnknownSortDirection {
+                field, direction, ..
+            }) => {
+                assert_eq!(field, "createdAt");
+                assert_eq!(direction, "\"descending\"");
+            }
+            other => panic!("expected UnknownSortDirection, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_convert_db_empty_where_is_no_filter() {
+        let project = minimal_project();
+        let (index, _) = build_index(&project);
+        let mut components = ComponentExpander::new(&project, &index);
+        let node: AstNode = serde_json::from_value(serde_json::json!({
+            "type": "DbMutate",
+            "tier": 1,
+            "model": "User",
+            "operation": "delete",
+            "where": {}
+        }))
+        .unwrap();
+
+        let expr =
+            convert_ast_to_expr(&node, &mut Tier::Domain, &mut HashSet::new(), &mut components);
+        let ExprIR::DbMutate(m) = expr else {
+            panic!("expected DbMutate");
+        };
+        assert!(m.r#where.is_none());
+        assert!(components.take_error().is_ok());
+    }
+
+    #[test]
+    fn test_convert_db_expression_where_is_rejected() {
+        let project = minimal_project();
+        let (index, _) = build_index(&project);
+        let mut components = ComponentExpander::new(&project, &index);
+        let node: AstNode = serde_json::from_value(serde_json::json!({
+            "type": "DbQuery",
+            "tier": 1,
+            "model": "User",
+            "operation": "findMany",
+            "where": { "type": "Identifier", "tier": 0, "name": "filter" }
+        }))
+        .unwrap();
+
+        let expr =
+            convert_ast_to_expr(&node, &mut Tier::Domain, &mut HashSet::new(), &mut components);
+        let ExprIR::DbQuery(q) = expr else {
+            panic!("expected DbQuery");
+        };
+        assert!(q.r#where.is_none());
+        match components.take_error() {
+            Err(ConvertError::DynamicFilter { model, operation, kind }) => {
+                assert_eq!(model, "User");
+                assert_eq!(operation, "findMany");
+                assert_eq!(kind, "Identifier");
+            }
+            other => panic!("expected DynamicFilter, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_convert_db_transaction() {
+        let node: AstNode = serde_json::from_value(serde_json::json!({
+            "type": "DbTransaction",
+            "tier": 1,
+            "isolationLevel": "repeatableRead",
+            "body": [{
+                "type": "DbMutate",
+                "tier": 1,
+                "model": "Product",
+                "operation": "update",
+                "where": { "id": { "type": "Identifier", "tier": 0, "name": "productId" } }
+            }]
+        }))
+        .unwrap();
+
+        let project = minimal_project();
+        let (index, _) = build_index(&project);
+        let mut components = ComponentExpander::new(&project, &index);
+        let mut tier = Tier::Universal;
+        let mut langs = HashSet::new();
+        match convert_ast_to_statement(&node, &mut tier, &mut langs, &mut components) {
+            StatementIR::Transaction { body, isolation } => {
+                assert_eq!(isolation, Some(IsolationLevel::RepeatableRead));
+                assert!(matches!(
+                    &body[0],
+                    StatementIR::Expression {
+                        expr: ExprIR::DbMutate(_)
+                    }
+                ));
+            }
+            other => panic!("Expected Transaction, got {other:?}"),
+        }
+        assert_eq!(tier, Tier::Domain);
+    }
+
+    #[test]
+    fn test_convert_project_with_function() {
+        let mut project = minimal_project();
+
+        let function: FunctionSpec = serde_json::from_value(serde_json::json!({
+            "name": "formatCurrency",
+            "params": [
+                { "name": "amount", "type": "number" },
+                { "name": "currency", "type": "string", "default": "KRW" }
+            ],
+            "returnType": "string",
+            "body": [{
+                "type": "ReturnStatement",
+                "tier": 0,
+                "value": { "type": "Identifier", "tier": 0, "name": "amount" }
+            }]
+        }))
+        .unwrap();
+        project
+            .functions
+            .push(("functions/currency.function.json".into(), function));
+
+        let handler: HandlerSpec = serde_json::from_value(serde_json::json!({
+            "name": "showPrice",
+            "body": [{
+                "type": "ReturnStatement",
+                "tier": 0,
+                "value": {
+                    "type": "CallFunction",
+                    "tier": 0,
+                    "function": "formatCurrency",
+                    "args": [{ "type": "Literal", "tier": 0, "value": 1000 }]
+                }
+            }]
+        }))
+        .unwrap();
+        project
+            .handlers
+            .push(("handlers/price.handler.json".into(), handler));
+
+        let ir = convert_project(&project).unwrap();
+        let function = &ir.functions[0];
+        assert_eq!(function.name, "formatCurrency");
+        assert_eq!(function.params[0].type_ir, TypeIR::Number);
+        assert_eq!(function.params[1].default, Some(serde_json::json!("KRW")));
+        assert_eq!(function.return_type, TypeIR::String);
+
+        match &ir.handlers[0].body[0] {
+            StatementIR::Return {
+                value: Some(ExprIR::CallFunction { function, args }),
+            } => {
+                assert_eq!(function, "formatCurrency");
+                assert_eq!(args.len(), 1);
+            }
+            other => panic!("Expected CallFunction return, got {other:?}"),
+        }
+    }
+
+    fn paginate_component() -> ComponentSpec {
+        serde_json::from_value(serde_json::json!({
+            "name": "paginate",
+            "typeParams": ["T"],
+            "inputs": {
+                "model": { "type": "string" },
+                "where": { "type": "object", "optional": true },
+                "orderBy": { "type": "object", "optional": true }
+            },
+            "contextInputs": {
+                "page": { "source": "query.page", "type": "integer", "default": 1 }
+            },
+            "body": [
+                {
+                    "type": "LetStatement",
+                    "tier": 0,
+                    "name": "data",
+                    "value": {
+                        "type": "AwaitExpr",
+                        "tier": 1,
+                        "expr": {
+                            "type": "DbQuery",
+                            "tier": 1,
+                            "model": "$input.model",
+                            "operation": "findMany",
+                            "where": { "type": "Identifier", "tier": 0, "name": "$input.where" },
+                            "orderBy": { "type": "Identifier", "tier": 0, "name": "$input.orderBy" }
+                        }
+                    }
+                },
+                {
+                    "type": "ReturnStatement",
+                    "tier": 0,
+                    "value": {
+                        "type": "ObjectExpr",
+                        "tier": 0,
+                        "properties": {
+                            "data": { "type": "Identifier", "tier": 0, "name": "data" },
+                            "page": { "type": "Identifier", "tier": 0, "name": "page" }
+                        }
+                    }
+                }
+            ]
+        }))
+        .unwrap()
+    }
+
+    fn handler_using(component: &str, inputs: serde_json::Value) -> HandlerSpec {
+        serde_json::from_value(serde_json::json!({
+            "name": "listUsers",
+            "body": [
+                {
+                    "type": "UseComponent",
+                    "tier": 0,
+                    "ref": component,
+                    "typeArgs": ["User"],
+                    "inputs": inputs,
+                    "bind": "result"
+                },
+                {
+                    "type": "ReturnStatement",
+                    "tier": 0,
+                    "value": { "type": "Identifier", "tier": 0, "name": "result" }
+                }
+            ]
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_convert_use_component_expands_inline() {
+        let mut project = minimal_project();
+        project.components.push((
+            "components/pagination.component.json".into(),
+            paginate_component(),
+        ));
+        project.handlers.push((
+            "handlers/users.handler.json".into(),
+            handler_using(
+                "paginate",
+                serde_json::json!({
+                    "model": { "type": "Literal", "tier": 0, "value": "User" },
+                    "where": {
+                        "type": "ObjectExpr",
+                        "tier": 0,
+                        "properties": {
+                            "role": { "type": "Identifier", "tier": 0, "name": "roleFilter" }
+                        }
+                    }
+                }),
+            ),
+        ));
+
+        let ir = convert_project(&project).unwrap();
+        let handler = &ir.handlers[0];
+        assert_eq!(handler.max_tier, Tier::Domain);
+
+        let (name, source, body) = match &handler.body[0] {
+            StatementIR::Component { name, source, body } => (name, source, body),
+            other => panic!("Expected Component, got {other:?}"),
+        };
+        assert_eq!(name, "paginate");
+        assert_eq!(source, "components/pagination.component.json");
+        assert_eq!(body.len(), 3);
+
+        match &body[0] {
+            StatementIR::Let {
+                name,
+                value: ExprIR::Binary { op, left, .. },
+                ..
+            } => {
+                assert_eq!(name, "page");
+                assert_eq!(op, "||");
+                assert!(matches!(left.as_ref(), ExprIR::CtxGet { path } if path == "query.page"));
+            }
+            other => panic!("Expected context input let, got {other:?}"),
+        }
+
+        match &body[1] {
+            StatementIR::Let {
+                value: ExprIR::Await { expr },
+                ..
+            } => match expr.as_ref() {
+                ExprIR::DbQuery(q) => {
+                    assert_eq!(q.model, "User");
+                    assert!(q.order_by.is_empty());
+                    let filter = serde_json::to_value(q.r#where.as_ref().unwrap()).unwrap();
+                    assert_eq!(filter["field"], "role");
+                    assert_eq!(filter["value"]["name"], "roleFilter");
+                }
+                other => panic!("Expected DbQuery, got {other:?}"),
+            },
+            other => panic!("Expected query let, got {other:?}"),
+        }
+
+        assert!(matches!(
+            &body[2],
+            StatementIR::Let { name, value: ExprIR::Object { .. }, .. } if name == "result"
+        ));
+    }
+
+    #[test]
+    fn test_convert_use_component_errors_point_at_component_file() {
+        let mut project = minimal_project();
+        project.components.push((
+            "components/pagination.component.json".into(),
+            paginate_component(),
+        ));
+        project.handlers.push((
+            "handlers/users.handler.json".into(),
+            handler_using("paginate", serde_json::json!({})),
+        ));
+
+        let err = convert_project(&project).unwrap_err();
+        match &err {
+            ConvertError::ComponentExpansion { name, file, reason } => {
+                assert_eq!(name, "paginate");
+                assert_eq!(file, "components/pagination.component.json");
+                assert_eq!(reason, "is missing required input 'model'");
+            }
+            other => panic!("Expected ComponentExpansion, got {other:?}"),
+        }
+        assert!(err
+            .to_string()
+            .contains("components/pagination.component.json"));
+    }
+
+    #[test]
+    fn test_convert_use_component_unknown_and_recursive() {
+        let mut project = minimal_project();
+        project.handlers.push((
+            "handlers/users.handler.json".into(),
+            handler_using("missing", serde_json::json!({})),
+        ));
+        assert!(matches!(
+            convert_project(&project),
+            Err(ConvertError::UnknownComponent { name }) if name == "missing"
+        ));
+
+        let looping: ComponentSpec = serde_json::from_value(serde_json::json!({
+            "name": "loop",
+            "body": [
+                { "type": "UseComponent", "tier": 0, "ref": "loop" },
+                {
+                    "type": "ReturnStatement",
+                    "tier": 0,
+                    "value": { "type": "Literal", "tier": 0, "value": 1 }
+                }
+            ]
+        }))
+        .unwrap();
+        project
+            .components
+            .push(("components/loop.component.json".into(), looping));
+        project.handlers[0] = (
+            "handlers/users.handler.json".into(),
+            handler_using("loop", serde_json::json!({})),
+        );
+        match convert_project(&project) {
+            Err(ConvertError::RecursiveComponent { file, chain, .. }) => {
+                assert_eq!(file, "components/loop.component.json");
+                assert_eq!(chain, "loop -> loop");
+            }
+            other => panic!("Expected RecursiveComponent, got {other:?}"),
+        }
+    }
+
     #[test]
     fn test_convert_handler_with_native_bridge() {
         let mut project = minimal_project();
@@ -1044,7 +2400,8 @@ mod tests {
         let mw_json = serde_json::json!({
             "name": "auth",
             "type": "request",
-            "handler": { "ref": "auth.verifyToken" }
+            "handler": { "ref": "auth.verifyToken" },
+            "provides": { "user": { "type": "object" } }
         });
         let mw: MiddlewareSpec = serde_json::from_value(mw_json).unwrap();
         project
@@ -1059,6 +2416,81 @@ mod tests {
             ir.middleware[0].handler_ref.as_deref(),
             Some("auth.verifyToken")
         );
+        assert!(ir.middleware[0].provides.contains_key("user"));
+    }
+
+    #[test]
+    fn test_convert_endpoint_provides_from_middleware_chain() {
+        let mut project = minimal_project();
+        project.config.middleware = Some(
+            serde_json::from_value(serde_json::json!({
+                "global": [{ "ref": "requestId" }]
+            }))
+            .unwrap(),
+        );
+
+        let middleware = [
+            serde_json::json!({
+                "name": "requestId",
+                "type": "request",
+                "provides": { "requestId": { "type": "string" } }
+            }),
+            serde_json::json!({
+                "name": "auth",
+                "type": "request",
+                "provides": {
+                    "user": {
+                        "type": "object",
+                        "properties": { "id": { "type": "string" } },
+                        "required": ["id"]
+                    }
+                }
+            }),
+            serde_json::json!({
+                "name": "tenant",
+                "type": "request",
+                "provides": { "tenantId": { "type": "integer" } }
+            }),
+            // Composition cycles back to itself; expansion stops there
+            serde_json::json!({
+                "name": "secured",
+                "type": "composed",
+                "compose": [{ "ref": "auth" }, { "ref": "tenant" }, { "ref": "secured" }]
+            }),
+        ];
+        for mw in middleware {
+            let mw: MiddlewareSpec = serde_json::from_value(mw).unwrap();
+            let file = format!("middleware/{}.middleware.json", mw.name);
+            project.middleware.push((file, mw));
+        }
+
+        let route: RouteSpec = serde_json::from_value(serde_json::json!({
+            "path": "/v1/me",
+            "methods": {
+                "GET": {
+                    "handler": { "ref": "users.me" },
+                    "middleware": [{ "ref": "secured" }]
+                }
+            }
+        }))
+        .unwrap();
+        project.routes.push(("routes/me.route.json".into(), route));
+
+        let ir = convert_project(&project).unwrap();
+        let endpoint = &ir.routes[0].methods[&rash_spec::types::common::HttpMethod::Get];
+        assert_eq!(endpoint.middleware, vec!["secured"]);
+        assert_eq!(
+            endpoint.provides.keys().collect::<Vec<_>>(),
+            vec!["requestId", "user", "tenantId"]
+        );
+        assert_eq!(endpoint.provides["requestId"], TypeIR::String);
+        assert_eq!(endpoint.provides["tenantId"], TypeIR::Number);
+        assert_eq!(
+            endpoint.provides["user"],
+            TypeIR::Object {
+                fields: vec![("id".to_string(), TypeIR::String)]
+            }
+        );
     }
 
     #[test]
@@ -31,6 +31,9 @@ pub enum ExprIR {
         args: Vec<ExprIR>,
     },
 
+    /// Call to a user-defined function in the shared utility module
+    CallFunction { function: String, args: Vec<ExprIR> },
+
     /// Member access: `object.property`
     Member {
         object: Box<ExprIR>,
@@ -130,12 +133,12 @@ pub struct DbQueryIR {
     pub model: String,
     /// Query operation (e.g., "findUnique", "findMany", "count")
     pub operation: String,
-    /// WHERE clause (opaque JSON for now)
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub r#where: Option<serde_json::Value>,
-    /// ORDER BY clause
+    /// WHERE clause
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub order_by: Option<serde_json::Value>,
+    pub r#where: Option<FilterIR>,
+    /// ORDER BY clause, in priority order
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub order_by: Vec<OrderByIR>,
     /// Skip count expression
     #[serde(skip_serializing_if = "Option::is_none")]
     pub skip: Option<Box<ExprIR>>,
@@ -146,8 +149,8 @@ pub struct DbQueryIR {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub select: Option<Vec<String>>,
     /// Relations to include
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub include: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub include: Vec<IncludeIR>,
 }
 
 /// Database mutation expression (Tier 1).
@@ -160,9 +163,82 @@ pub struct DbMutateIR {
     /// Data payload expression
     #[serde(skip_serializing_if = "Option::is_none")]
     pub data: Option<Box<ExprIR>>,
-    /// WHERE clause (opaque JSON)
+    /// WHERE clause
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub r#where: Option<serde_json::Value>,
+    pub r#where: Option<FilterIR>,
+}
+
+/// Typed `where` filter shared by database queries and mutations.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind")]
+pub enum FilterIR {
+    /// Compare a model field against a value: `field op value`
+    Condition {
+        field: String,
+        op: FilterOp,
+        value: Box<ExprIR>,
+    },
+    /// All sub-filters must match
+    And { filters: Vec<FilterIR> },
+    /// Any sub-filter must match
+    Or { filters: Vec<FilterIR> },
+    /// Negated sub-filter
+    Not { filter: Box<FilterIR> },
+}
+
+impl FilterIR {
+    /// Create an equality condition on `field`.
+    pub fn eq(field: impl Into<String>, value: ExprIR) -> Self {
+        FilterIR::Condition {
+            field: field.into(),
+            op: FilterOp::Eq,
+            value: Box::new(value),
+        }
+    }
+}
+
+/// Comparison operator in a filter condition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum FilterOp {
+    Eq,
+    Ne,
+    Gt,
+    Gte,
+    Lt,
+    Lte,
+    In,
+    NotIn,
+    Contains,
+    StartsWith,
+    EndsWith,
+}
+
+/// A single ORDER BY key.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderByIR {
+    /// Model field to sort by
+    pub field: String,
+    /// Sort direction
+    pub direction: SortDirection,
+}
+
+/// Sort direction for an ORDER BY key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SortDirection {
+    Asc,
+    Desc,
+}
+
+/// A relation to eager-load, with nested relations of its own.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IncludeIR {
+    /// Relation name on the queried model
+    pub relation: String,
+    /// Relations to load on the related model
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub include: Vec<IncludeIR>,
 }
 
 /// HTTP response construction expression (Tier 1).
@@ -325,17 +401,27 @@ mod tests {
         let expr = ExprIR::DbQuery(DbQueryIR {
             model: "User".to_string(),
             operation: "findUnique".to_string(),
-            r#where: Some(serde_json::json!({ "id": "123" })),
-            order_by: None,
+            r#where: Some(FilterIR::eq(
+                "id",
+                ExprIR::literal(serde_json::json!("123")),
+            )),
+            order_by: vec![OrderByIR {
+                field: "createdAt".to_string(),
+                direction: SortDirection::Desc,
+            }],
             skip: None,
             take: None,
             select: Some(vec!["id".to_string(), "email".to_string()]),
-            include: None,
+            include: vec![],
         });
 
         let json = serde_json::to_value(&expr).unwrap();
         assert_eq!(json["kind"], "DbQuery");
         assert_eq!(json["model"], "User");
+        assert_eq!(json["where"]["kind"], "Condition");
+        assert_eq!(json["where"]["op"], "eq");
+        assert_eq!(json["order_by"][0]["direction"], "desc");
+        assert!(json.get("include").is_none());
 
         let deserialized: ExprIR = serde_json::from_value(json).unwrap();
         match deserialized {
@@ -343,6 +429,13 @@ mod tests {
                 assert_eq!(q.model, "User");
                 assert_eq!(q.operation, "findUnique");
                 assert_eq!(q.select.unwrap().len(), 2);
+                assert!(matches!(
+                    q.r#where,
+                    Some(FilterIR::Condition {
+                        op: FilterOp::Eq,
+                        ..
+                    })
+                ));
             }
             _ => panic!("Expected DbQuery"),
         }
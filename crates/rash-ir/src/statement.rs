@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use rash_spec::types::ast::IsolationLevel;
+
 use crate::expr::{ExprIR, TypeIR};
 
 /// IR-level statement — a single instruction in a handler body.
@@ -60,6 +62,21 @@ pub enum StatementIR {
     /// Throw expression as statement
     Throw { value: ExprIR },
 
+    /// Database transaction: commits when the body completes, rolls back on throw
+    Transaction {
+        body: Vec<StatementIR>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        isolation: Option<IsolationLevel>,
+    },
+
+    /// Inline-expanded component body, tagged with the component it came from
+    Component {
+        name: String,
+        /// Spec file of the component, for error reporting
+        source: String,
+        body: Vec<StatementIR>,
+    },
+
     /// Expression used as statement
     Expression { expr: ExprIR },
 }
@@ -23,6 +23,9 @@ pub struct ProjectIR {
     pub middleware: Vec<MiddlewareIR>,
     /// All handler definitions
     pub handlers: Vec<HandlerIR>,
+    /// All user-defined functions, emitted into a shared utility module
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub functions: Vec<FunctionIR>,
 }
 
 /// A single route and its HTTP method endpoints.
@@ -50,6 +53,9 @@ pub struct EndpointIR {
     /// Ordered middleware chain applied before the handler
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub middleware: Vec<String>,
+    /// Context values the middleware chain provides, read as `state.<name>`
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub provides: IndexMap<String, TypeIR>,
     /// Request shape
     pub request: RequestIR,
     /// Response shapes keyed by HTTP status code
@@ -118,6 +124,15 @@ pub struct MiddlewareIR {
     /// Reference to the handler implementing this middleware
     #[serde(skip_serializing_if = "Option::is_none")]
     pub handler_ref: Option<String>,
+    /// Values this middleware provides to downstream handlers, keyed by name
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub provides: IndexMap<String, serde_json::Value>,
+    /// Middleware refs a composed middleware runs, in order
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub compose: Vec<String>,
+    /// Whether a composed middleware stops at the first step that responds
+    #[serde(default)]
+    pub short_circuit: bool,
 }
 
 /// A handler function definition with its full AST body.
@@ -140,6 +155,33 @@ pub struct HandlerIR {
     pub bridge_languages: HashSet<Language>,
 }
 
+/// A user-defined pure function shared by handlers.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FunctionIR {
+    /// Function name (e.g., "formatCurrency")
+    pub name: String,
+    /// Ordered parameters
+    pub params: Vec<FunctionParamIR>,
+    /// Return type
+    pub return_type: TypeIR,
+    /// Function body as a sequence of IR statements
+    pub body: Vec<StatementIR>,
+    /// Maximum tier of any node in this function
+    pub max_tier: Tier,
+}
+
+/// A function parameter with an optional literal default.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FunctionParamIR {
+    /// Parameter name
+    pub name: String,
+    /// Parameter type
+    pub type_ir: TypeIR,
+    /// Value used when a call omits this argument
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<serde_json::Value>,
+}
+
 /// A named, typed parameter.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ParamIR {
@@ -162,6 +204,7 @@ mod tests {
             models: vec![],
             middleware: vec![],
             handlers: vec![],
+            functions: vec![],
         };
 
         let json = serde_json::to_value(&project).unwrap();
@@ -182,6 +225,7 @@ mod tests {
                         summary: Some("List all users".to_string()),
                         handler_ref: "users.listUsers".to_string(),
                         middleware: vec!["auth".to_string()],
+                        provides: IndexMap::new(),
                         request: RequestIR {
                             query_schema: Some("ListUsersQuery".to_string()),
                             body_schema: None,
@@ -289,6 +333,9 @@ mod tests {
             name: "auth".to_string(),
             middleware_type: "request".to_string(),
             handler_ref: Some("auth.verifyToken".to_string()),
+            provides: IndexMap::new(),
+            compose: vec![],
+            short_circuit: false,
         };
 
         let json = serde_json::to_value(&mw).unwrap();
@@ -5,6 +5,8 @@ use sha2::{Digest, Sha256};
 use tokio::io::{AsyncBufReadExt, AsyncWriteExt};
 use tokio::time::timeout;
 
+use crate::hmu_transport::{HmuChannel, HmuSocket};
+use crate::process_manager::HmuPipes;
 use crate::hmu_types::*;
 
 /// Configuration for the HMU engine.
@@ -14,6 +16,9 @@ pub struct HmuConfig {
     pub ack_timeout: Duration,
     /// Max consecutive failures before requiring restart (default: 3).
     pub max_consecutive_failures: u32,
+    /// How long the socket may go without a heartbeat before the server is
+    /// considered unreachable (default: 6 seconds).
+    pub heartbeat_timeout: Duration,
 }
 
 impl Default for HmuConfig {
@@ -21,6 +26,7 @@ impl Default for HmuConfig {
         Self {
             ack_timeout: Duration::from_secs(5),
             max_consecutive_failures: 3,
+            heartbeat_timeout: Duration::from_secs(6),
         }
     }
 }
@@ -43,11 +49,14 @@ pub enum HmuError {
     Json(#[from] serde_json::Error),
     #[error("Server requires restart")]
     RequiresRestart,
+    #[error("HMU socket silent: no heartbeat within {0:?}")]
+    HeartbeatLost(Duration),
 }
 
 /// The HMU Engine manages hot module updates to a running server process.
 ///
-/// Communication happens via stdin/stdout JSON messages (newline-delimited IPC).
+/// Updates travel over the socket transport when the server connected to it,
+/// otherwise as newline-delimited JSON on its stdin/stdout.
 pub struct HmuEngine {
     config: HmuConfig,
     next_id: u64,
@@ -118,46 +127,93 @@ impl HmuEngine {
         W: AsyncWriteExt + Unpin,
         R: AsyncBufReadExt + Unpin,
     {
-        // Serialize and write
-        let mut json = serde_json::to_string(update)?;
-        json.push('\n');
-        stdin.write_all(json.as_bytes()).await?;
-        stdin.flush().await?;
+        write_update(stdin, update).await?;
 
-        // Read ACK with timeout
-        let mut line = String::new();
-        let read_result = timeout(self.config.ack_timeout, stdout.read_line(&mut line)).await;
-
-        match read_result {
-            Ok(Ok(0)) => {
-                return Err(HmuError::Io(std::io::Error::new(
-                    std::io::ErrorKind::UnexpectedEof,
-                    "child process closed stdout",
-                )));
-            }
-            Ok(Ok(_)) => {}
-            Ok(Err(e)) => return Err(HmuError::Io(e)),
+        // Read ACK with timeout, skipping late ACKs of earlier updates
+        let ack = match timeout(self.config.ack_timeout, read_ack(stdout, &update.id)).await {
+            Ok(ack) => ack?,
             Err(_) => return Err(HmuError::Timeout(self.config.ack_timeout)),
+        };
+
+        Ok(self.record_ack(ack))
+    }
+
+    /// Send an HMU update as a frame on the socket transport and wait for its
+    /// ACK. Fails fast if the server's heartbeat has gone quiet.
+    pub async fn send_update_framed(
+        &mut self,
+        socket: &mut HmuSocket,
+        update: &HmuUpdate,
+    ) -> Result<HmuResult, HmuError> {
+        let ack = exchange_framed(&self.config, socket, update).await?;
+        Ok(self.record_ack(ack))
+    }
+
+    /// Send an HMU update on whichever channel the server is reachable by.
+    pub async fn send(
+        &mut self,
+        channel: HmuChannel<'_>,
+        update: &HmuUpdate,
+    ) -> Result<HmuResult, HmuError> {
+        let ack = Self::exchange(&self.config, channel, update).await?;
+        Ok(self.record_ack(ack))
+    }
+
+    /// Send an HMU update and wait for its ACK without borrowing the engine,
+    /// so a caller can release it while the server applies the update. Pass
+    /// the ACK to [`HmuEngine::record_ack`] afterwards.
+    pub async fn exchange(
+        config: &HmuConfig,
+        channel: HmuChannel<'_>,
+        update: &HmuUpdate,
+    ) -> Result<HmuAck, HmuError> {
+        match channel {
+            HmuChannel::Socket(socket) => exchange_framed(config, socket, update).await,
+            HmuChannel::Stdio(pipes) => exchange_piped(config, pipes, update).await,
         }
+    }
 
-        let ack: HmuAck = serde_json::from_str(line.trim())?;
+    /// Configuration the engine was created with.
+    pub fn config(&self) -> &HmuConfig {
+        &self.config
+    }
 
+    /// Update failure counts from an ACK and decide whether to restart.
+    pub fn record_ack(&mut self, ack: HmuAck) -> HmuResult {
         // Update failure counts
         for path in &ack.applied {
             self.reset_failure_count(path);
         }
-        for path in &ack.failed {
-            let count = self.failure_counts.entry(path.clone()).or_insert(0);
-            *count += 1;
-        }
+        self.count_failures(&ack.failed);
 
         let requires_restart =
             ack.requires_restart || self.check_escalation(&ack.failed);
 
-        Ok(HmuResult {
+        HmuResult {
             ack,
             requires_restart,
-        })
+        }
+    }
+
+    /// Count an update that never got an ACK as a failure of each of its
+    /// modules and decide whether to restart.
+    ///
+    /// A lost or late ACK alone doesn't restart the server; only a server
+    /// without any HMU channel, or modules that keep failing, do.
+    pub fn record_failure(&mut self, update: &HmuUpdate, error: &HmuError) -> bool {
+        if matches!(error, HmuError::RequiresRestart) {
+            return true;
+        }
+        let paths: Vec<String> = update.modules.iter().map(|m| m.path.clone()).collect();
+        self.count_failures(&paths);
+        self.check_escalation(&paths)
+    }
+
+    fn count_failures(&mut self, paths: &[String]) {
+        for path in paths {
+            let count = self.failure_counts.entry(path.clone()).or_insert(0);
+            *count += 1;
+        }
     }
 
     /// Reset the failure count for a module path (e.g. after a successful update).
@@ -170,11 +226,104 @@ impl HmuEngine {
         failed_paths.iter().any(|path| {
             self.failure_counts
                 .get(path)
-                .map_or(false, |&count| count >= self.config.max_consecutive_failures)
+                .is_some_and(|&count| count >= self.config.max_consecutive_failures)
         })
     }
 }
 
+/// Write an update as one line of JSON.
+async fn write_update<W>(stdin: &mut W, update: &HmuUpdate) -> Result<(), HmuError>
+where
+    W: AsyncWriteExt + Unpin,
+{
+    let mut json = serde_json::to_string(update)?;
+    json.push('\n');
+    stdin.write_all(json.as_bytes()).await?;
+    stdin.flush().await?;
+    Ok(())
+}
+
+/// Send an update on the child's stdin and wait for the ACK its stdout
+/// reader forwards.
+async fn exchange_piped(
+    config: &HmuConfig,
+    pipes: &mut HmuPipes,
+    update: &HmuUpdate,
+) -> Result<HmuAck, HmuError> {
+    write_update(&mut pipes.stdin, update).await?;
+
+    let recv = async {
+        loop {
+            let ack = pipes.acks.recv().await.ok_or_else(|| {
+                HmuError::Io(std::io::Error::new(
+                    std::io::ErrorKind::UnexpectedEof,
+                    "child process closed stdout",
+                ))
+            })?;
+            if ack.id == update.id {
+                return Ok(ack);
+            }
+        }
+    };
+    match timeout(config.ack_timeout, recv).await {
+        Ok(ack) => ack,
+        Err(_) => Err(HmuError::Timeout(config.ack_timeout)),
+    }
+}
+
+/// Send an update as a frame on the socket and wait for its ACK. Fails fast
+/// if the server's heartbeat has gone quiet.
+async fn exchange_framed(
+    config: &HmuConfig,
+    socket: &mut HmuSocket,
+    update: &HmuUpdate,
+) -> Result<HmuAck, HmuError> {
+    if !socket.is_alive(config.heartbeat_timeout) {
+        return Err(HmuError::HeartbeatLost(config.heartbeat_timeout));
+    }
+    socket.send(&serde_json::to_vec(update)?).await?;
+
+    match timeout(config.ack_timeout, recv_ack(socket, &update.id)).await {
+        Ok(ack) => ack,
+        Err(_) => Err(HmuError::Timeout(config.ack_timeout)),
+    }
+}
+
+/// Read ACK lines until the one answering update `id`.
+async fn read_ack<R>(stdout: &mut R, id: &str) -> Result<HmuAck, HmuError>
+where
+    R: AsyncBufReadExt + Unpin,
+{
+    loop {
+        let mut line = String::new();
+        if stdout.read_line(&mut line).await? == 0 {
+            return Err(HmuError::Io(std::io::Error::new(
+                std::io::ErrorKind::UnexpectedEof,
+                "child process closed stdout",
+            )));
+        }
+        let ack: HmuAck = serde_json::from_str(line.trim())?;
+        if ack.id == id {
+            return Ok(ack);
+        }
+    }
+}
+
+/// Wait for the socket ACK answering update `id`.
+async fn recv_ack(socket: &mut HmuSocket, id: &str) -> Result<HmuAck, HmuError> {
+    loop {
+        let ack = socket.recv_ack().await.ok_or_else(|| {
+            HmuError::Io(std::io::Error::new(
+                std::io::ErrorKind::UnexpectedEof,
+                "server closed the HMU socket",
+            ))
+        })?;
+        if ack.id == id {
+            return Ok(ack);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -271,6 +420,7 @@ mod tests {
         let config = HmuConfig::default();
         assert_eq!(config.ack_timeout, Duration::from_secs(5));
         assert_eq!(config.max_consecutive_failures, 3);
+        assert_eq!(config.heartbeat_timeout, Duration::from_secs(6));
     }
 
     // ── send_update ───────────────────────────────────────────────
@@ -335,6 +485,46 @@ mod tests {
         assert!(!result.requires_restart);
     }
 
+    #[tokio::test]
+    async fn send_update_skips_acks_of_earlier_updates() {
+        let mut engine = HmuEngine::new(HmuConfig::default());
+        engine.create_update(vec![]);
+        let update = engine.create_update(vec![(
+            "a.ts".into(),
+            HmuAction::Replace,
+            "new code".into(),
+        )]);
+
+        let ack = |id: &str, status: &str| {
+            serde_json::json!({
+                "type": "HMU_ACK",
+                "id": id,
+                "status": status,
+                "applied": [],
+                "failed": ["a.ts"],
+                "requiresRestart": false
+            })
+        };
+        // hmu_001 timed out earlier and its ACK arrives late
+        let lines = format!("{}\n{}\n", ack("hmu_001", "success"), ack("hmu_002", "failed"));
+
+        let (mut stdin_w, mut stdout_r, mut srv_stdin_r, mut srv_stdout_w) = make_ipc_pair();
+        tokio::spawn(async move {
+            let mut line = String::new();
+            srv_stdin_r.read_line(&mut line).await.unwrap();
+            srv_stdout_w.write_all(lines.as_bytes()).await.unwrap();
+            srv_stdout_w.flush().await.unwrap();
+        });
+
+        let result = engine
+            .send_update(&mut stdin_w, &mut stdout_r, &update)
+            .await
+            .unwrap();
+
+        assert_eq!(result.ack.id, "hmu_002");
+        assert_eq!(result.ack.status, HmuStatus::Failed);
+    }
+
     #[tokio::test]
     async fn send_update_timeout() {
         let config = HmuConfig {
@@ -402,6 +592,70 @@ mod tests {
         assert!(!result.requires_restart);
     }
 
+    // ── send_update_framed ────────────────────────────────────────
+
+    #[tokio::test]
+    async fn send_update_framed_round_trip() {
+        use crate::hmu_transport::{read_frame, write_frame};
+
+        let mut engine = HmuEngine::new(HmuConfig::default());
+        let update = engine.create_update(vec![(
+            "a.ts".into(),
+            HmuAction::Replace,
+            "new code".into(),
+        )]);
+
+        let mut socket = HmuSocket::listen_tcp().await.unwrap();
+        let addr = socket.endpoint().trim_start_matches("tcp://").to_string();
+        let hello = serde_json::json!({ "type": "HMU_HELLO", "token": socket.token() });
+
+        // Simulate server: hello, then answer the update it receives
+        tokio::spawn(async move {
+            let mut stream = tokio::net::TcpStream::connect(addr).await.unwrap();
+            write_frame(&mut stream, hello.to_string().as_bytes())
+                .await
+                .unwrap();
+            let frame = read_frame(&mut stream).await.unwrap().unwrap();
+            let received: HmuUpdate = serde_json::from_slice(&frame).unwrap();
+            let ack = serde_json::json!({
+                "type": "HMU_ACK",
+                "id": received.id,
+                "status": "success",
+                "applied": ["a.ts"],
+                "failed": [],
+                "requiresRestart": false
+            });
+            write_frame(&mut stream, ack.to_string().as_bytes())
+                .await
+                .unwrap();
+            tokio::time::sleep(Duration::from_secs(1)).await;
+        });
+
+        while !socket.is_connected() || !socket.is_alive(Duration::from_secs(5)) {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+
+        let result = engine
+            .send(HmuChannel::Socket(&mut socket), &update)
+            .await
+            .unwrap();
+
+        assert_eq!(result.ack.id, "hmu_001");
+        assert_eq!(result.ack.status, HmuStatus::Success);
+        assert!(!result.requires_restart);
+    }
+
+    #[tokio::test]
+    async fn send_update_framed_requires_heartbeat() {
+        let mut engine = HmuEngine::new(HmuConfig::default());
+        let update = engine.create_update(vec![]);
+        let mut socket = HmuSocket::listen_tcp().await.unwrap();
+
+        let result = engine.send_update_framed(&mut socket, &update).await;
+
+        assert!(matches!(result, Err(HmuError::HeartbeatLost(_))));
+    }
+
     // ── failure escalation ────────────────────────────────────────
 
     #[test]
@@ -451,6 +705,34 @@ mod tests {
         engine.reset_failure_count("unknown.ts"); // should not panic
     }
 
+    // ── record_failure ────────────────────────────────────────────
+
+    #[test]
+    fn record_failure_restarts_only_on_escalation() {
+        let config = HmuConfig {
+            max_consecutive_failures: 2,
+            ..Default::default()
+        };
+        let mut engine = HmuEngine::new(config);
+        let update = engine.create_update(vec![(
+            "x.ts".into(),
+            HmuAction::Replace,
+            "code".into(),
+        )]);
+        let timeout = HmuError::Timeout(Duration::from_secs(5));
+
+        assert!(!engine.record_failure(&update, &timeout));
+        assert!(engine.record_failure(&update, &timeout));
+    }
+
+    #[test]
+    fn record_failure_restarts_without_channel() {
+        let mut engine = HmuEngine::new(HmuConfig::default());
+        let update = engine.create_update(vec![]);
+
+        assert!(engine.record_failure(&update, &HmuError::RequiresRestart));
+    }
+
     // ── escalation via send_update ────────────────────────────────
 
     #[tokio::test]
@@ -0,0 +1,440 @@
+use std::collections::hash_map::RandomState;
+use std::future::Future;
+use std::hash::{BuildHasher, Hasher};
+use std::io;
+use std::path::PathBuf;
+#[cfg(unix)]
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio::sync::{mpsc, oneshot};
+use tokio::task::JoinHandle;
+
+use crate::hmu_types::HmuAck;
+use crate::process_manager::HmuPipes;
+
+/// Environment variable that tells the child where to connect: a Unix socket
+/// path, or `tcp://127.0.0.1:{port}` for the loopback transport.
+pub const HMU_SOCKET_ENV: &str = "RASH_HMU_SOCKET";
+
+/// Environment variable holding the secret the child must present when it
+/// connects to [`HMU_SOCKET_ENV`].
+pub const HMU_TOKEN_ENV: &str = "RASH_HMU_TOKEN";
+
+/// Type tag of the keep-alive frame the child sends on the socket.
+pub const HEARTBEAT_TYPE: &str = "HMU_HEARTBEAT";
+
+/// Type tag of the first frame on a connection, carrying the token.
+pub const HELLO_TYPE: &str = "HMU_HELLO";
+
+/// How long a new connection may take to send its hello frame.
+const HELLO_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Largest frame accepted in either direction.
+pub const MAX_FRAME_BYTES: usize = 16 * 1024 * 1024;
+
+/// Distinguishes the socket files of servers started by the same Rash process.
+#[cfg(unix)]
+static NEXT_SOCKET: AtomicU64 = AtomicU64::new(1);
+
+type FrameWriter = Box<dyn AsyncWrite + Send + Unpin>;
+
+/// Write one frame: a 4-byte big-endian length followed by the payload.
+pub async fn write_frame<W>(writer: &mut W, payload: &[u8]) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin + ?Sized,
+{
+    let len = u32::try_from(payload.len())
+        .ok()
+        .filter(|&len| len as usize <= MAX_FRAME_BYTES)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "HMU frame too large"))?;
+    writer.write_all(&len.to_be_bytes()).await?;
+    writer.write_all(payload).await?;
+    writer.flush().await
+}
+
+/// Read one frame. Returns `None` when the peer closed the stream between
+/// frames.
+pub async fn read_frame<R>(reader: &mut R) -> io::Result<Option<Vec<u8>>>
+where
+    R: AsyncRead + Unpin + ?Sized,
+{
+    let mut header = [0u8; 4];
+    match reader.read_exact(&mut header).await {
+        Ok(_) => {}
+        Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(e) => return Err(e),
+    }
+    let len = u32::from_be_bytes(header) as usize;
+    if len > MAX_FRAME_BYTES {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("HMU frame of {len} bytes exceeds the limit"),
+        ));
+    }
+    let mut payload = vec![0; len];
+    reader.read_exact(&mut payload).await?;
+    Ok(Some(payload))
+}
+
+/// Rash's end of the socket transport.
+///
+/// Listens for a single connection from the child, the first whose hello
+/// frame carries [`HmuSocket::token`]; other local connections are dropped.
+/// Once connected, incoming frames are read in the background: heartbeats
+/// refresh `last_seen` and `HMU_ACK` frames are queued for
+/// [`HmuSocket::recv_ack`].
+pub struct HmuSocket {
+    endpoint: String,
+    token: String,
+    socket_path: Option<PathBuf>,
+    connected: oneshot::Receiver<FrameWriter>,
+    writer: Option<FrameWriter>,
+    acks: mpsc::UnboundedReceiver<HmuAck>,
+    last_seen: Arc<Mutex<Option<Instant>>>,
+    task: JoinHandle<()>,
+}
+
+impl HmuSocket {
+    /// Listen on a fresh Unix socket in the temp directory.
+    #[cfg(unix)]
+    pub async fn listen() -> io::Result<Self> {
+        let path = std::env::temp_dir().join(format!(
+            "rash-hmu-{}-{}.sock",
+            std::process::id(),
+            NEXT_SOCKET.fetch_add(1, Ordering::Relaxed)
+        ));
+        let _ = std::fs::remove_file(&path);
+        let listener = tokio::net::UnixListener::bind(&path)?;
+        let endpoint = path.to_string_lossy().into_owned();
+        let token = new_token();
+        let expected = token.clone();
+        Ok(Self::serve(endpoint, token, Some(path), async move {
+            loop {
+                let (stream, _) = listener.accept().await?;
+                if let Some(stream) = authenticate(stream, &expected).await {
+                    return Ok(stream);
+                }
+            }
+        }))
+    }
+
+    /// Listen on loopback TCP where Unix sockets are unavailable.
+    #[cfg(not(unix))]
+    pub async fn listen() -> io::Result<Self> {
+        Self::listen_tcp().await
+    }
+
+    /// Listen on an ephemeral loopback TCP port.
+    pub async fn listen_tcp() -> io::Result<Self> {
+        let listener = tokio::net::TcpListener::bind(("127.0.0.1", 0)).await?;
+        let endpoint = format!("tcp://{}", listener.local_addr()?);
+        let token = new_token();
+        let expected = token.clone();
+        Ok(Self::serve(endpoint, token, None, async move {
+            loop {
+                let (stream, _) = listener.accept().await?;
+                if let Some(stream) = authenticate(stream, &expected).await {
+                    return Ok(stream);
+                }
+            }
+        }))
+    }
+
+    fn serve<F, S>(
+        endpoint: String,
+        token: String,
+        socket_path: Option<PathBuf>,
+        accept: F,
+    ) -> Self
+    where
+        F: Future<Output = io::Result<S>> + Send + 'static,
+        S: AsyncRead + AsyncWrite + Send + 'static,
+    {
+        let (conn_tx, connected) = oneshot::channel();
+        let (ack_tx, acks) = mpsc::unbounded_channel();
+        let last_seen = Arc::new(Mutex::new(None));
+
+        let seen = Arc::clone(&last_seen);
+        let task = tokio::spawn(async move {
+            let Ok(stream) = accept.await else {
+                return;
+            };
+            // The hello frame was the first sign of life
+            *seen.lock().unwrap() = Some(Instant::now());
+            let (mut reader, writer) = tokio::io::split(stream);
+            if conn_tx.send(Box::new(writer) as FrameWriter).is_err() {
+                return;
+            }
+            while let Ok(Some(frame)) = read_frame(&mut reader).await {
+                *seen.lock().unwrap() = Some(Instant::now());
+                let Ok(message) = serde_json::from_slice::<serde_json::Value>(&frame) else {
+                    continue;
+                };
+                if message["type"] == HEARTBEAT_TYPE {
+                    continue;
+                }
+                if let Ok(ack) = serde_json::from_value::<HmuAck>(message) {
+                    if ack_tx.send(ack).is_err() {
+                        break;
+                    }
+                }
+            }
+            *seen.lock().unwrap() = None;
+        });
+
+        Self {
+            endpoint,
+            token,
+            socket_path,
+            connected,
+            writer: None,
+            acks,
+            last_seen,
+            task,
+        }
+    }
+
+    /// Value for [`HMU_SOCKET_ENV`] in the child's environment.
+    pub fn endpoint(&self) -> &str {
+        &self.endpoint
+    }
+
+    /// Value for [`HMU_TOKEN_ENV`] in the child's environment.
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+
+    /// Whether the child has connected.
+    pub fn is_connected(&mut self) -> bool {
+        if self.writer.is_none() {
+            self.writer = self.connected.try_recv().ok();
+        }
+        self.writer.is_some()
+    }
+
+    /// Whether a frame arrived from the child within `timeout`.
+    pub fn is_alive(&self, timeout: Duration) -> bool {
+        self.last_seen
+            .lock()
+            .unwrap()
+            .is_some_and(|seen| seen.elapsed() < timeout)
+    }
+
+    /// Send one frame to the child.
+    pub async fn send(&mut self, payload: &[u8]) -> io::Result<()> {
+        if !self.is_connected() {
+            return Err(io::Error::new(
+                io::ErrorKind::NotConnected,
+                "server has not connected to the HMU socket",
+            ));
+        }
+        let writer = self.writer.as_mut().expect("checked above");
+        write_frame(writer, payload).await
+    }
+
+    /// Wait for the next `HMU_ACK`. Returns `None` once the connection closed.
+    pub async fn recv_ack(&mut self) -> Option<HmuAck> {
+        self.acks.recv().await
+    }
+}
+
+impl Drop for HmuSocket {
+    fn drop(&mut self) {
+        self.task.abort();
+        if let Some(path) = &self.socket_path {
+            let _ = std::fs::remove_file(path);
+        }
+    }
+}
+
+/// A fresh secret for one spawned server, drawn from the hasher keys the
+/// standard library seeds from the OS.
+fn new_token() -> String {
+    (0..2)
+        .map(|_| format!("{:016x}", RandomState::new().build_hasher().finish()))
+        .collect()
+}
+
+/// Accept `stream` if its first frame is a hello carrying `token`.
+async fn authenticate<S>(mut stream: S, token: &str) -> Option<S>
+where
+    S: AsyncRead + Unpin,
+{
+    let frame = tokio::time::timeout(HELLO_TIMEOUT, read_frame(&mut stream))
+        .await
+        .ok()?
+        .ok()??;
+    let hello: serde_json::Value = serde_json::from_slice(&frame).ok()?;
+    (hello["type"] == HELLO_TYPE && hello["token"] == token).then_some(stream)
+}
+
+/// The channel an HMU update travels on.
+pub enum HmuChannel<'a> {
+    /// Length-prefixed frames over [`HMU_SOCKET_ENV`], with heartbeats.
+    Socket(&'a mut HmuSocket),
+    /// Newline-delimited JSON over the child's stdin/stdout, used until (or
+    /// unless) the child connects to the socket.
+    Stdio(&'a mut HmuPipes),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn ack_frame(id: &str) -> Vec<u8> {
+        serde_json::to_vec(&serde_json::json!({
+            "type": "HMU_ACK",
+            "id": id,
+            "status": "success",
+            "applied": ["src/handlers/a.ts"],
+            "failed": [],
+            "requiresRestart": false
+        }))
+        .unwrap()
+    }
+
+    fn hello_frame(token: &str) -> Vec<u8> {
+        serde_json::to_vec(&serde_json::json!({ "type": HELLO_TYPE, "token": token })).unwrap()
+    }
+
+    async fn wait_connected(socket: &mut HmuSocket) {
+        for _ in 0..100 {
+            if socket.is_connected() {
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        panic!("child never connected");
+    }
+
+    #[tokio::test]
+    async fn frames_round_trip() {
+        let (mut a, mut b) = tokio::io::duplex(1024);
+        write_frame(&mut a, b"{\"type\":\"HMU_HEARTBEAT\"}")
+            .await
+            .unwrap();
+        write_frame(&mut a, b"").await.unwrap();
+        drop(a);
+
+        assert_eq!(
+            read_frame(&mut b).await.unwrap().unwrap(),
+            b"{\"type\":\"HMU_HEARTBEAT\"}"
+        );
+        assert_eq!(read_frame(&mut b).await.unwrap().unwrap(), b"");
+        assert!(read_frame(&mut b).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn read_frame_rejects_oversized_length() {
+        let (mut a, mut b) = tokio::io::duplex(64);
+        a.write_all(&u32::MAX.to_be_bytes()).await.unwrap();
+        let err = read_frame(&mut b).await.unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn unix_socket_carries_heartbeats_and_acks() {
+        let mut socket = HmuSocket::listen().await.unwrap();
+        let path = PathBuf::from(socket.endpoint());
+        assert!(!socket.is_connected());
+
+        let mut child = tokio::net::UnixStream::connect(&path).await.unwrap();
+        write_frame(&mut child, &hello_frame(socket.token()))
+            .await
+            .unwrap();
+        write_frame(&mut child, b"{\"type\":\"HMU_HEARTBEAT\"}")
+            .await
+            .unwrap();
+        wait_connected(&mut socket).await;
+
+        socket.send(b"{\"type\":\"HMU_UPDATE\"}").await.unwrap();
+        assert_eq!(
+            read_frame(&mut child).await.unwrap().unwrap(),
+            b"{\"type\":\"HMU_UPDATE\"}"
+        );
+
+        write_frame(&mut child, &ack_frame("hmu_001"))
+            .await
+            .unwrap();
+        assert_eq!(socket.recv_ack().await.unwrap().id, "hmu_001");
+        assert!(socket.is_alive(Duration::from_secs(5)));
+
+        drop(child);
+        assert!(socket.recv_ack().await.is_none());
+        assert!(!socket.is_alive(Duration::from_secs(5)));
+
+        drop(socket);
+        assert!(!path.exists());
+    }
+
+    #[tokio::test]
+    async fn tcp_socket_uses_loopback_endpoint() {
+        let mut socket = HmuSocket::listen_tcp().await.unwrap();
+        let addr = socket
+            .endpoint()
+            .strip_prefix("tcp://")
+            .unwrap()
+            .to_string();
+        assert!(addr.starts_with("127.0.0.1:"));
+
+        let mut child = tokio::net::TcpStream::connect(&addr).await.unwrap();
+        write_frame(&mut child, &hello_frame(socket.token()))
+            .await
+            .unwrap();
+        write_frame(&mut child, &ack_frame("hmu_007"))
+            .await
+            .unwrap();
+        wait_connected(&mut socket).await;
+        assert_eq!(socket.recv_ack().await.unwrap().id, "hmu_007");
+    }
+
+    #[tokio::test]
+    async fn connections_without_the_token_are_rejected() {
+        let mut socket = HmuSocket::listen_tcp().await.unwrap();
+        let addr = socket.endpoint().trim_start_matches("tcp://").to_string();
+
+        // A stray local process: no hello, then a hello with the wrong token
+        let mut stray = tokio::net::TcpStream::connect(&addr).await.unwrap();
+        write_frame(&mut stray, b"{\"type\":\"HMU_HEARTBEAT\"}")
+            .await
+            .unwrap();
+        assert!(read_frame(&mut stray).await.unwrap().is_none());
+        let mut wrong = tokio::net::TcpStream::connect(&addr).await.unwrap();
+        write_frame(&mut wrong, &hello_frame("not-the-token"))
+            .await
+            .unwrap();
+        assert!(read_frame(&mut wrong).await.unwrap().is_none());
+        assert!(!socket.is_connected());
+
+        let mut child = tokio::net::TcpStream::connect(&addr).await.unwrap();
+        write_frame(&mut child, &hello_frame(socket.token()))
+            .await
+            .unwrap();
+        wait_connected(&mut socket).await;
+        socket.send(b"{\"type\":\"HMU_UPDATE\"}").await.unwrap();
+        assert_eq!(
+            read_frame(&mut child).await.unwrap().unwrap(),
+            b"{\"type\":\"HMU_UPDATE\"}"
+        );
+    }
+
+    #[tokio::test]
+    async fn each_socket_gets_its_own_token() {
+        let a = HmuSocket::listen_tcp().await.unwrap();
+        let b = HmuSocket::listen_tcp().await.unwrap();
+        assert_eq!(a.token().len(), 32);
+        assert_ne!(a.token(), b.token());
+    }
+
+    #[tokio::test]
+    async fn send_before_connect_is_an_error() {
+        let mut socket = HmuSocket::listen_tcp().await.unwrap();
+        let err = socket.send(b"{}").await.unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::NotConnected);
+    }
+}
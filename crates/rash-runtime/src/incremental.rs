@@ -4,6 +4,7 @@ use std::path::PathBuf;
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 
+use rash_codegen::CodeGenerator;
 use rash_ir::dep_graph::{FileChangePlan, NodeId, SpecDependencyGraph};
 use rash_ir::types::ProjectIR;
 
@@ -16,6 +17,7 @@ pub enum SpecChange {
     SchemaModified(String),
     HandlerModified(String),
     MiddlewareModified(String),
+    ModelModified(String),
     ConfigModified,
 }
 
@@ -27,6 +29,7 @@ impl SpecChange {
             SpecChange::SchemaModified(name) => Some(NodeId::Schema(name.clone())),
             SpecChange::HandlerModified(name) => Some(NodeId::Handler(name.clone())),
             SpecChange::MiddlewareModified(name) => Some(NodeId::Middleware(name.clone())),
+            SpecChange::ModelModified(name) => Some(NodeId::Model(name.clone())),
             SpecChange::ConfigModified => None,
         }
     }
@@ -102,6 +105,32 @@ impl CodegenCache {
     }
 }
 
+/// Collect the models referenced by `DbQuery`/`DbMutate` expressions in
+/// serialized IR.
+fn collect_db_models(value: &serde_json::Value, out: &mut Vec<String>) {
+    match value {
+        serde_json::Value::Object(map) => {
+            if matches!(
+                map.get("kind").and_then(|k| k.as_str()),
+                Some("DbQuery" | "DbMutate")
+            ) {
+                if let Some(model) = map.get("model").and_then(|m| m.as_str()) {
+                    out.push(model.to_string());
+                }
+            }
+            for v in map.values() {
+                collect_db_models(v, out);
+            }
+        }
+        serde_json::Value::Array(arr) => {
+            for v in arr {
+                collect_db_models(v, out);
+            }
+        }
+        _ => {}
+    }
+}
+
 /// Compute SHA-256 hash of content, returning "sha256:{hex}".
 pub fn compute_hash(content: &str) -> String {
     let mut hasher = Sha256::new();
@@ -114,13 +143,35 @@ pub fn compute_hash(content: &str) -> String {
 pub struct IncrementalCodegen {
     cache: CodegenCache,
     dep_graph: SpecDependencyGraph,
+    /// Extension of generated source files (e.g. "ts")
+    extension: String,
+    /// Path of the generated route registration file
+    routes_file: String,
+    /// Generated files built from every model (e.g. "prisma/schema.prisma")
+    orm_schema_files: Vec<String>,
 }
 
 impl IncrementalCodegen {
+    /// Incremental codegen for a TypeScript project.
     pub fn new() -> Self {
         Self {
             cache: CodegenCache::new(),
             dep_graph: SpecDependencyGraph::new(),
+            extension: "ts".into(),
+            routes_file: "src/routes/index.ts".into(),
+            orm_schema_files: vec!["prisma/schema.prisma".into()],
+        }
+    }
+
+    /// Incremental codegen for the files `generator` emits, so the
+    /// dependency graph points at the target's real file paths.
+    pub fn for_generator(generator: &CodeGenerator) -> Self {
+        let extension = generator.emitter().file_extension().to_string();
+        Self {
+            routes_file: generator.adapter().route_file_path(&extension),
+            orm_schema_files: generator.adapter().orm_schema_files(),
+            extension,
+            ..Self::new()
         }
     }
 
@@ -136,7 +187,7 @@ impl IncrementalCodegen {
 
     /// Build dependency graph from a ProjectIR.
     ///
-    /// Walks all routes, schemas, handlers, and middleware to create edges
+    /// Walks all routes, schemas, models, handlers, and middleware to create edges
     /// reflecting how changes propagate through the codebase.
     pub fn build_dep_graph(&mut self, project: &ProjectIR) {
         self.dep_graph = SpecDependencyGraph::new();
@@ -145,21 +196,54 @@ impl IncrementalCodegen {
         for schema in &project.schemas {
             let schema_node = NodeId::Schema(schema.name.clone());
             let file_node = NodeId::GeneratedFile(PathBuf::from(format!(
-                "src/schemas/{}.ts",
-                schema.name.to_lowercase()
+                "src/schemas/{}.{}",
+                schema.name.to_lowercase(),
+                self.extension
             )));
             self.dep_graph.add_edge(schema_node, file_node);
         }
 
+        // Model -> generated model file and ORM schema files
+        for model in &project.models {
+            let model_node = NodeId::Model(model.name.clone());
+            let file_node = NodeId::GeneratedFile(PathBuf::from(format!(
+                "src/models/{}.{}",
+                model.name.to_lowercase(),
+                self.extension
+            )));
+            self.dep_graph.add_edge(model_node.clone(), file_node);
+            for orm_file in &self.orm_schema_files {
+                let file_node = NodeId::GeneratedFile(PathBuf::from(orm_file));
+                self.dep_graph.add_edge(model_node.clone(), file_node);
+            }
+
+            // Relation target -> model (the relation field is typed by its target)
+            for relation in model.relations.values() {
+                if let Some(target) = relation.get("target").and_then(|t| t.as_str()) {
+                    self.dep_graph
+                        .add_edge(NodeId::Model(target.to_string()), model_node.clone());
+                }
+            }
+        }
+
         // Handler -> generated handler file
         for handler in &project.handlers {
             let handler_node = NodeId::Handler(handler.name.clone());
             let filename = handler.name.replace('.', "_");
             let file_node = NodeId::GeneratedFile(PathBuf::from(format!(
-                "src/handlers/{}.ts",
-                filename
+                "src/handlers/{}.{}",
+                filename, self.extension
             )));
             self.dep_graph.add_edge(handler_node.clone(), file_node);
+
+            // Models queried or mutated by the handler body -> handler
+            let body = serde_json::to_value(&handler.body).unwrap_or_default();
+            let mut models = Vec::new();
+            collect_db_models(&body, &mut models);
+            for model in models {
+                self.dep_graph
+                    .add_edge(NodeId::Model(model), handler_node.clone());
+            }
         }
 
         // Middleware -> generated middleware file
@@ -167,14 +251,14 @@ impl IncrementalCodegen {
             let mw_node = NodeId::Middleware(mw.name.clone());
             let filename = mw.name.replace('.', "_");
             let file_node = NodeId::GeneratedFile(PathBuf::from(format!(
-                "src/middleware/{}.ts",
-                filename
+                "src/middleware/{}.{}",
+                filename, self.extension
             )));
             self.dep_graph.add_edge(mw_node, file_node);
         }
 
         // Route -> generated routes index file
-        let routes_file = NodeId::GeneratedFile(PathBuf::from("src/routes/index.ts"));
+        let routes_file = NodeId::GeneratedFile(PathBuf::from(&self.routes_file));
         for route in &project.routes {
             let route_node = NodeId::Route(route.path.clone());
             self.dep_graph
@@ -594,6 +678,10 @@ mod tests {
             SpecChange::MiddlewareModified("auth".into()).to_node_id(),
             Some(NodeId::Middleware("auth".into()))
         );
+        assert_eq!(
+            SpecChange::ModelModified("User".into()).to_node_id(),
+            Some(NodeId::Model("User".into()))
+        );
         assert_eq!(SpecChange::ConfigModified.to_node_id(), None);
     }
 
@@ -758,6 +846,7 @@ mod tests {
             models: vec![],
             middleware: vec![],
             handlers: vec![],
+            functions: vec![],
         };
 
         codegen.build_dep_graph(&project);
@@ -779,6 +868,7 @@ mod tests {
             models: vec![],
             middleware: vec![],
             handlers: vec![],
+            functions: vec![],
         };
 
         codegen.build_dep_graph(&project);
@@ -792,6 +882,37 @@ mod tests {
         ))));
     }
 
+    #[test]
+    fn build_dep_graph_follows_generator_target() {
+        use rash_ir::types::SchemaIR;
+        use rash_spec::types::common::{Framework, Language};
+
+        let generator = CodeGenerator::new(Language::Python, Framework::Django).unwrap();
+        let mut codegen = IncrementalCodegen::for_generator(&generator);
+        let project = ProjectIR {
+            config: serde_json::json!({}),
+            routes: vec![],
+            schemas: vec![SchemaIR {
+                name: "User".into(),
+                definitions: Default::default(),
+            }],
+            models: vec![],
+            middleware: vec![],
+            handlers: vec![],
+            functions: vec![],
+        };
+
+        codegen.build_dep_graph(&project);
+
+        let affected = codegen
+            .dep_graph()
+            .affected_nodes(&[NodeId::Schema("User".into())]);
+        assert!(affected.contains(&NodeId::GeneratedFile(PathBuf::from(
+            "src/schemas/user.py"
+        ))));
+        assert_eq!(codegen.routes_file, "src/api/urls.py");
+    }
+
     #[test]
     fn build_dep_graph_creates_handler_edges() {
         use rash_ir::types::HandlerIR;
@@ -813,6 +934,7 @@ mod tests {
                 max_tier: rash_spec::types::common::Tier::Universal,
                 bridge_languages: Default::default(),
             }],
+            functions: vec![],
         };
 
         codegen.build_dep_graph(&project);
@@ -824,4 +946,84 @@ mod tests {
             "src/handlers/users_getUser.ts"
         ))));
     }
+
+    #[test]
+    fn build_dep_graph_creates_model_edges() {
+        use rash_ir::expr::{DbQueryIR, ExprIR, TypeIR};
+        use rash_ir::statement::StatementIR;
+        use rash_ir::types::{HandlerIR, ModelIR};
+
+        let model = |name: &str, relations: serde_json::Value| ModelIR {
+            name: name.into(),
+            table_name: format!("{}s", name.to_lowercase()),
+            columns: Default::default(),
+            relations: serde_json::from_value(relations).unwrap(),
+            indexes: vec![],
+        };
+        let handler = |name: &str, body: Vec<StatementIR>| HandlerIR {
+            name: name.into(),
+            is_async: true,
+            params: vec![],
+            return_type: TypeIR::Void,
+            body,
+            max_tier: rash_spec::types::common::Tier::Domain,
+            bridge_languages: Default::default(),
+        };
+
+        let mut codegen = IncrementalCodegen::new();
+        let project = ProjectIR {
+            config: serde_json::json!({}),
+            routes: vec![],
+            schemas: vec![],
+            models: vec![
+                model("User", serde_json::json!({})),
+                model(
+                    "Post",
+                    serde_json::json!({ "author": { "type": "belongsTo", "target": "User" } }),
+                ),
+                model("Tag", serde_json::json!({})),
+            ],
+            middleware: vec![],
+            handlers: vec![
+                handler(
+                    "posts.list",
+                    vec![StatementIR::Return {
+                        value: Some(ExprIR::DbQuery(DbQueryIR {
+                            model: "Post".into(),
+                            operation: "findMany".into(),
+                            r#where: None,
+                            order_by: vec![],
+                            skip: None,
+                            take: None,
+                            select: None,
+                            include: vec![],
+                        })),
+                    }],
+                ),
+                handler("health.check", vec![]),
+            ],
+            functions: vec![],
+        };
+
+        codegen.build_dep_graph(&project);
+
+        let plan = codegen.compute_change_plan(&[SpecChange::ModelModified("User".into())]);
+        assert!(!plan.requires_full_regen);
+        assert_eq!(
+            plan.affected_files,
+            vec![
+                PathBuf::from("prisma/schema.prisma"),
+                PathBuf::from("src/handlers/posts_list.ts"),
+                PathBuf::from("src/models/post.ts"),
+                PathBuf::from("src/models/user.ts"),
+            ]
+        );
+        assert!(plan
+            .affected_specs
+            .contains(&NodeId::Handler("posts.list".into())));
+        assert!(!plan
+            .affected_specs
+            .contains(&NodeId::Handler("health.check".into())));
+        assert!(!plan.affected_specs.contains(&NodeId::Model("Tag".into())));
+    }
 }
@@ -1,4 +1,5 @@
 pub mod hmu_engine;
+pub mod hmu_transport;
 pub mod hmu_types;
 pub mod incremental;
 pub mod log_types;
@@ -6,3 +7,4 @@ pub mod preflight;
 pub mod preflight_checker;
 pub mod process_manager;
 pub mod runtime_detect;
+pub mod watch;
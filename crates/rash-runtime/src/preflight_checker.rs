@@ -12,11 +12,11 @@ pub struct PreflightChecker;
 impl PreflightChecker {
     /// Run all preflight checks for the given project configuration.
     pub fn run(config: &RashConfig, project_dir: &Path) -> PreflightReport {
-        let mut checks = Vec::new();
-
-        checks.push(Self::check_runtime_exists(config.target.runtime));
-        checks.push(Self::check_port_available(config.server.port));
-        checks.push(Self::check_output_dir(config, project_dir));
+        let checks = vec![
+            Self::check_runtime_exists(config.target.runtime),
+            Self::check_port_available(config.server.port),
+            Self::check_output_dir(config, project_dir),
+        ];
 
         let ok = !checks.iter().any(|c| c.status == CheckStatus::Fail);
         PreflightReport { ok, checks }
@@ -147,7 +147,7 @@ fn is_writable(path: &Path) -> bool {
             Err(_) => false,
         }
     } else {
-        path.parent().map_or(false, |p| is_writable(p))
+        path.parent().is_some_and(is_writable)
     }
 }
 
@@ -181,6 +181,7 @@ mod tests {
                 out_dir: d.to_string(),
                 source_map: false,
                 strict: false,
+                hmu: false,
             }),
             middleware: None,
             plugins: vec![],
@@ -1,13 +1,20 @@
 use std::collections::HashMap;
 use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use tokio::io::{AsyncBufReadExt, BufReader};
-use tokio::sync::{mpsc, watch};
+use tokio::process::ChildStdin;
+use tokio::sync::mpsc::error::TrySendError;
+use tokio::sync::{mpsc, watch, Mutex};
 
 use rash_spec::types::common::{Framework, Language, Runtime};
+use rash_spec::types::config::RashConfig;
 
+use crate::hmu_transport::{HmuChannel, HmuSocket, HMU_SOCKET_ENV, HMU_TOKEN_ENV};
+use crate::hmu_types::HmuAck;
 use crate::log_types::{LogEntry, LogLevel, LogSource};
 
 /// Error types for process management operations.
@@ -53,11 +60,60 @@ pub struct ServerConfig {
     pub env_vars: HashMap<String, String>,
 }
 
+impl ServerConfig {
+    /// Config for serving `project` from its generated `output_dir`.
+    pub fn for_project(project: &RashConfig, output_dir: PathBuf) -> Self {
+        Self {
+            language: project.target.language,
+            framework: project.target.framework,
+            runtime: project.target.runtime,
+            port: project.server.port,
+            host: project.server.host.clone(),
+            output_dir,
+            env_vars: HashMap::new(),
+        }
+    }
+}
+
 struct RunningProcess {
     child: tokio::process::Child,
     pid: u32,
     port: u16,
     started_at: DateTime<Utc>,
+    hmu: Arc<Mutex<HmuLink>>,
+}
+
+/// The child's side of the HMU channel: updates are written to its stdin and
+/// `HMU_ACK` lines are split off its stdout into `acks`.
+pub struct HmuPipes {
+    pub stdin: ChildStdin,
+    pub acks: mpsc::Receiver<HmuAck>,
+}
+
+/// Both HMU transports of a running process.
+///
+/// Shared behind its own lock (see [`ProcessManager::hmu_link`]), so waiting
+/// for an ACK doesn't keep the manager borrowed and concurrent updates queue
+/// up instead of interleaving.
+pub struct HmuLink {
+    socket: Option<HmuSocket>,
+    pipes: Option<HmuPipes>,
+}
+
+impl HmuLink {
+    /// The channel to send HMU updates on: the socket while the child is
+    /// connected and has sent a frame within `heartbeat_timeout`, otherwise
+    /// its stdio pipes.
+    pub fn channel(&mut self, heartbeat_timeout: Duration) -> Option<HmuChannel<'_>> {
+        let live = self
+            .socket
+            .as_mut()
+            .is_some_and(|s| s.is_connected() && s.is_alive(heartbeat_timeout));
+        if live {
+            return self.socket.as_mut().map(HmuChannel::Socket);
+        }
+        self.pipes.as_mut().map(HmuChannel::Stdio)
+    }
 }
 
 /// Manages the lifecycle of a child server process.
@@ -73,6 +129,8 @@ pub struct ProcessManager {
 
 const PORT_DETECT_TIMEOUT_SECS: u64 = 10;
 const GRACEFUL_SHUTDOWN_SECS: u64 = 3;
+/// ACKs queued for the HMU engine; later ones are dropped while it is full.
+const HMU_ACK_QUEUE: usize = 16;
 
 impl ProcessManager {
     /// Create a new ProcessManager and return the log/status receivers.
@@ -101,15 +159,36 @@ impl ProcessManager {
 
         let (cmd, args, cwd) = Self::resolve_command(config);
 
+        // Offer the socket transport; the child falls back to stdio if it
+        // cannot connect, and so do we if it never does
+        let hmu_socket = match HmuSocket::listen().await {
+            Ok(socket) => Some(socket),
+            Err(e) => {
+                let _ = self.log_tx.send(LogEntry {
+                    timestamp: Utc::now(),
+                    level: LogLevel::Warn,
+                    message: format!("[rash] HMU socket unavailable, using stdio: {e}"),
+                    source: LogSource::Stderr,
+                });
+                None
+            }
+        };
+
         let mut command = tokio::process::Command::new(&cmd);
         command
             .args(&args)
             .current_dir(&cwd)
+            .stdin(std::process::Stdio::piped())
             .stdout(std::process::Stdio::piped())
             .stderr(std::process::Stdio::piped())
             .envs(&config.env_vars)
             .env("PORT", config.port.to_string())
             .env("HOST", &config.host);
+        if let Some(socket) = &hmu_socket {
+            command
+                .env(HMU_SOCKET_ENV, socket.endpoint())
+                .env(HMU_TOKEN_ENV, socket.token());
+        }
 
         let mut child = command.spawn().map_err(|e| {
             self.set_status(ServerStatus::Errored);
@@ -125,6 +204,14 @@ impl ProcessManager {
         let stdout = child.stdout.take();
         let stderr = child.stderr.take();
 
+        // HMU acks arrive interleaved with stdout logs; the stdout reader
+        // forwards them through a bounded queue
+        let (ack_tx, ack_rx) = mpsc::channel(HMU_ACK_QUEUE);
+        let pipes = child.stdin.take().map(|stdin| HmuPipes {
+            stdin,
+            acks: ack_rx,
+        });
+
         let expected_port = config.port;
 
         // Channel to detect the port from stdout
@@ -140,6 +227,7 @@ impl ProcessManager {
                 LogLevel::Info,
                 log_tx,
                 port_tx,
+                Some(ack_tx),
                 expected_port,
             ));
         }
@@ -153,6 +241,7 @@ impl ProcessManager {
                 LogLevel::Error,
                 log_tx,
                 None,
+                None,
                 expected_port,
             ));
         }
@@ -178,6 +267,10 @@ impl ProcessManager {
             pid,
             port,
             started_at: now,
+            hmu: Arc::new(Mutex::new(HmuLink {
+                socket: hmu_socket,
+                pipes,
+            })),
         });
         self.set_status(ServerStatus::Running);
 
@@ -242,6 +335,12 @@ impl ProcessManager {
         self.process.as_ref().map(|p| p.started_at)
     }
 
+    /// Get the HMU transports of the running process, if any. Lock the
+    /// link for the whole update, from sending it to reading its ACK.
+    pub fn hmu_link(&self) -> Option<Arc<Mutex<HmuLink>>> {
+        self.process.as_ref().map(|p| Arc::clone(&p.hmu))
+    }
+
     /// Determine the command, arguments, and working directory for a given config.
     pub fn resolve_command(config: &ServerConfig) -> (String, Vec<String>, PathBuf) {
         let dir = config.output_dir.clone();
@@ -295,18 +394,31 @@ impl ProcessManager {
 }
 
 /// Stream lines from an async reader, sending LogEntry messages and optionally detecting port.
+/// `HMU_ACK` lines are forwarded to `ack_tx` instead of being logged.
 async fn stream_output<R: tokio::io::AsyncRead + Unpin>(
     reader: R,
     source: LogSource,
     level: LogLevel,
     log_tx: mpsc::UnboundedSender<LogEntry>,
     mut port_tx: Option<tokio::sync::oneshot::Sender<u16>>,
+    mut ack_tx: Option<mpsc::Sender<HmuAck>>,
     expected_port: u16,
 ) {
     let buf = BufReader::new(reader);
     let mut lines = buf.lines();
 
     while let Ok(Some(line)) = lines.next_line().await {
+        if let Some(tx) = ack_tx.as_ref() {
+            if let Some(ack) = parse_hmu_ack(&line) {
+                // Never wait on the engine: logs keep flowing, and an ACK
+                // nobody is reading answers an update that already timed out
+                if let Err(TrySendError::Closed(_)) = tx.try_send(ack) {
+                    ack_tx = None; // Pipes dropped
+                }
+                continue;
+            }
+        }
+
         // Try to detect port from line
         if port_tx.is_some() {
             if let Some(port) = detect_port(&line, expected_port) {
@@ -329,6 +441,14 @@ async fn stream_output<R: tokio::io::AsyncRead + Unpin>(
     }
 }
 
+fn parse_hmu_ack(line: &str) -> Option<HmuAck> {
+    let line = line.trim();
+    if !line.starts_with('{') {
+        return None;
+    }
+    serde_json::from_str(line).ok()
+}
+
 /// Try to detect a port number from a log line.
 ///
 /// Looks for common patterns like:
@@ -362,10 +482,10 @@ fn detect_port(line: &str, expected_port: u16) -> Option<u16> {
 
     // If line contains the expected port as a standalone number
     let port_str = expected_port.to_string();
-    if lower.contains("listen") || lower.contains("start") || lower.contains("running") {
-        if line.contains(&port_str) {
-            return Some(expected_port);
-        }
+    if (lower.contains("listen") || lower.contains("start") || lower.contains("running"))
+        && line.contains(&port_str)
+    {
+        return Some(expected_port);
     }
 
     None
@@ -511,6 +631,155 @@ mod tests {
         assert_eq!(detect_port("port 80", 80), None);
     }
 
+    // --- HMU ack forwarding tests ---
+
+    #[tokio::test]
+    async fn stream_output_splits_hmu_acks_from_logs() {
+        let output = concat!(
+            "Listening on port 3000\n",
+            r#"{"type":"HMU_ACK","id":"hmu_001","status":"success","applied":["a.ts"],"failed":[],"requiresRestart":false}"#,
+            "\n",
+            "{\"not\": \"an ack\"}\n",
+        );
+        let (log_tx, mut log_rx) = mpsc::unbounded_channel();
+        let (ack_tx, mut ack_rx) = mpsc::channel(HMU_ACK_QUEUE);
+
+        stream_output(
+            output.as_bytes(),
+            LogSource::Stdout,
+            LogLevel::Info,
+            log_tx,
+            None,
+            Some(ack_tx),
+            3000,
+        )
+        .await;
+
+        let mut logs = Vec::new();
+        while let Ok(entry) = log_rx.try_recv() {
+            logs.push(entry.message);
+        }
+        assert_eq!(logs, vec!["Listening on port 3000", "{\"not\": \"an ack\"}"]);
+
+        assert_eq!(ack_rx.recv().await.unwrap().id, "hmu_001");
+        assert!(ack_rx.recv().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn stream_output_drops_acks_when_queue_is_full() {
+        let mut output = String::new();
+        for i in 0..HMU_ACK_QUEUE + 4 {
+            output.push_str(&format!(
+                r#"{{"type":"HMU_ACK","id":"hmu_{i:03}","status":"success","applied":[],"failed":[],"requiresRestart":false}}"#
+            ));
+            output.push('\n');
+        }
+        output.push_str("still logging\n");
+        let (log_tx, mut log_rx) = mpsc::unbounded_channel();
+        let (ack_tx, mut ack_rx) = mpsc::channel(HMU_ACK_QUEUE);
+
+        // Nobody reads the ACKs while the output streams
+        stream_output(
+            output.as_bytes(),
+            LogSource::Stdout,
+            LogLevel::Info,
+            log_tx,
+            None,
+            Some(ack_tx),
+            3000,
+        )
+        .await;
+
+        assert_eq!(log_rx.try_recv().unwrap().message, "still logging");
+        let mut queued = 0;
+        while ack_rx.recv().await.is_some() {
+            queued += 1;
+        }
+        assert_eq!(queued, HMU_ACK_QUEUE);
+    }
+
+    // --- HMU channel selection tests ---
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn hmu_link_falls_back_to_stdio_once_the_socket_drops() {
+        use crate::hmu_engine::{HmuConfig, HmuEngine};
+        use crate::hmu_transport::write_frame;
+        use crate::hmu_types::{HmuAckType, HmuAction, HmuStatus, HmuUpdate};
+
+        let config = HmuConfig::default();
+        let mut socket = HmuSocket::listen_tcp().await.unwrap();
+        let addr = socket.endpoint().trim_start_matches("tcp://").to_string();
+        let hello = serde_json::json!({ "type": "HMU_HELLO", "token": socket.token() });
+        let mut conn = tokio::net::TcpStream::connect(addr).await.unwrap();
+        write_frame(&mut conn, hello.to_string().as_bytes())
+            .await
+            .unwrap();
+        while !socket.is_connected() || !socket.is_alive(config.heartbeat_timeout) {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+
+        // `cat` echoes each update on stdin back, and is answered with an ACK
+        let mut child = tokio::process::Command::new("cat")
+            .stdin(std::process::Stdio::piped())
+            .stdout(std::process::Stdio::piped())
+            .kill_on_drop(true)
+            .spawn()
+            .unwrap();
+        let (ack_tx, acks) = mpsc::channel(HMU_ACK_QUEUE);
+        let mut echoed = BufReader::new(child.stdout.take().unwrap()).lines();
+        tokio::spawn(async move {
+            while let Ok(Some(line)) = echoed.next_line().await {
+                let update: HmuUpdate = serde_json::from_str(&line).unwrap();
+                let ack = HmuAck {
+                    kind: HmuAckType::HmuAck,
+                    id: update.id,
+                    status: HmuStatus::Success,
+                    applied: update.modules.into_iter().map(|m| m.path).collect(),
+                    failed: vec![],
+                    rolled_back: None,
+                    requires_restart: false,
+                };
+                let _ = ack_tx.send(ack).await;
+            }
+        });
+
+        let mut link = HmuLink {
+            socket: Some(socket),
+            pipes: Some(HmuPipes {
+                stdin: child.stdin.take().unwrap(),
+                acks,
+            }),
+        };
+        assert!(matches!(
+            link.channel(config.heartbeat_timeout),
+            Some(HmuChannel::Socket(_))
+        ));
+
+        drop(conn);
+        for _ in 0..100 {
+            if matches!(
+                link.channel(config.heartbeat_timeout),
+                Some(HmuChannel::Stdio(_))
+            ) {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+
+        let mut engine = HmuEngine::new(config.clone());
+        let update = engine.create_update(vec![(
+            "src/handlers/a.ts".into(),
+            HmuAction::Replace,
+            "new code".into(),
+        )]);
+        let channel = link.channel(config.heartbeat_timeout).unwrap();
+        assert!(matches!(channel, HmuChannel::Stdio(_)));
+        let ack = HmuEngine::exchange(&config, channel, &update).await.unwrap();
+        assert_eq!(ack.id, update.id);
+        assert_eq!(ack.applied, vec!["src/handlers/a.ts"]);
+    }
+
     // --- ProcessManager unit tests ---
 
     #[test]
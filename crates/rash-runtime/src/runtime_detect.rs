@@ -28,7 +28,7 @@ impl RuntimeDetector {
     pub fn check_runtime(name: &str) -> Option<DetectedRuntime> {
         let (cmd, args) = Self::runtime_command(name)?;
         let version = Self::run_version_command(cmd, args, name)?;
-        let path = which::which(cmd).ok().map(PathBuf::from);
+        let path = which::which(cmd).ok();
 
         Some(DetectedRuntime {
             name: name.to_string(),
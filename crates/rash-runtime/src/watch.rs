@@ -0,0 +1,1138 @@
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use serde::Serialize;
+use tokio::sync::{mpsc, Mutex};
+use tokio::time::timeout;
+
+use rash_codegen::{CodeGenerator, CodegenError};
+use rash_ir::convert::convert_project;
+use rash_ir::dep_graph::FileChangePlan;
+use rash_ir::types::ProjectIR;
+use rash_spec::loader::{self, LoadedProject, SpecFile};
+use rash_spec::parser;
+use rash_spec::types::common::Severity;
+use rash_spec::types::error::{ErrorEntry, ValidationReport};
+
+use crate::hmu_engine::{HmuConfig, HmuEngine, HmuError};
+use crate::hmu_types::{HmuAck, HmuStatus, HmuUpdate};
+use crate::incremental::{FileChange, FileChangeAction, IncrementalCodegen, SpecChange};
+use crate::process_manager::{HmuLink, ProcessError, ProcessManager, ServerConfig};
+
+/// How long the project must stay quiet before a burst of edits is applied.
+pub const DEFAULT_DEBOUNCE: Duration = Duration::from_millis(200);
+
+const CONFIG_FILE: &str = "rash.config.json";
+
+/// Top-level directories that never hold specs. Dot directories (`.git`,
+/// `.rash`) and the output directory are skipped as well.
+const IGNORED_DIRS: &[&str] = &["node_modules", "target"];
+
+/// Errors from watching a project and regenerating it.
+#[derive(Debug, thiserror::Error)]
+pub enum WatchError {
+    #[error("failed to watch project: {0}")]
+    Notify(#[from] notify::Error),
+    #[error("failed to load project: {0}")]
+    Load(String),
+    #[error("invalid spec: {}", describe_errors(.0))]
+    InvalidSpec(Vec<ErrorEntry>),
+    #[error("IR conversion failed: {0}")]
+    Convert(String),
+    #[error("codegen failed: {0}")]
+    Codegen(#[from] CodegenError),
+    #[error("I/O error on {0}: {1}")]
+    Io(String, std::io::Error),
+}
+
+fn describe_errors(errors: &[ErrorEntry]) -> String {
+    errors
+        .iter()
+        .map(|e| format!("{}: {}", e.file, e.message))
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+/// Debounced filesystem watcher over a project's spec files.
+pub struct SpecWatcher {
+    root: PathBuf,
+    ignored: Vec<PathBuf>,
+    debounce: Duration,
+    watcher: RecommendedWatcher,
+    watched: HashSet<PathBuf>,
+    events: mpsc::UnboundedReceiver<PathBuf>,
+}
+
+impl SpecWatcher {
+    /// Watch the project at `root`, skipping the `ignored` directories (e.g.
+    /// the generated output).
+    pub fn new(root: &Path, ignored: &[PathBuf], debounce: Duration) -> Result<Self, WatchError> {
+        let root = root
+            .canonicalize()
+            .map_err(|e| WatchError::Io(root.display().to_string(), e))?;
+        let ignored = ignored
+            .iter()
+            .filter_map(|dir| dir.canonicalize().ok())
+            .collect();
+
+        let (tx, events) = mpsc::unbounded_channel();
+        let watcher = notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+            let Ok(event) = res else {
+                return;
+            };
+            if matches!(event.kind, EventKind::Access(_)) {
+                return;
+            }
+            for path in event.paths {
+                let _ = tx.send(path);
+            }
+        })?;
+
+        let mut this = Self {
+            root,
+            ignored,
+            debounce,
+            watcher,
+            watched: HashSet::new(),
+            events,
+        };
+
+        // Watch the root itself shallowly and each spec directory under it
+        // recursively, so large build directories are never traversed
+        let root = this.root.clone();
+        this.watcher.watch(&root, RecursiveMode::NonRecursive)?;
+        let entries =
+            std::fs::read_dir(&root).map_err(|e| WatchError::Io(root.display().to_string(), e))?;
+        for entry in entries.filter_map(|e| e.ok()) {
+            this.watch_dir(&entry.path())?;
+        }
+
+        Ok(this)
+    }
+
+    /// The watched project root.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    fn is_ignored(&self, dir: &Path) -> bool {
+        let name = dir.file_name().and_then(|n| n.to_str()).unwrap_or("");
+        name.starts_with('.')
+            || IGNORED_DIRS.contains(&name)
+            || self.ignored.iter().any(|i| i == dir)
+    }
+
+    /// Start watching a top-level directory, unless it is ignored.
+    fn watch_dir(&mut self, dir: &Path) -> Result<(), WatchError> {
+        if !dir.is_dir() || self.is_ignored(dir) || self.watched.contains(dir) {
+            return Ok(());
+        }
+        self.watcher.watch(dir, RecursiveMode::Recursive)?;
+        self.watched.insert(dir.to_path_buf());
+        Ok(())
+    }
+
+    /// Map an event path to a spec file path relative to the root.
+    fn spec_path(&mut self, path: &Path) -> Option<String> {
+        let rel = path.strip_prefix(&self.root).ok()?;
+        // A directory created at the top level may hold specs later on
+        if rel.components().count() == 1 && path.is_dir() {
+            let _ = self.watch_dir(path);
+            return None;
+        }
+        parser::detect_spec_type(path)?;
+        Some(rel.to_string_lossy().into_owned())
+    }
+
+    /// Wait for the next burst of spec edits and return the changed files,
+    /// relative to the root, once no event arrived for the debounce period.
+    ///
+    /// Returns `None` when the watcher stopped.
+    pub async fn next_batch(&mut self) -> Option<Vec<String>> {
+        let mut batch = BTreeSet::new();
+        loop {
+            let path = if batch.is_empty() {
+                self.events.recv().await?
+            } else {
+                match timeout(self.debounce, self.events.recv()).await {
+                    Ok(Some(path)) => path,
+                    Ok(None) | Err(_) => return Some(batch.into_iter().collect()),
+                }
+            };
+            if let Some(rel) = self.spec_path(&path) {
+                batch.insert(rel);
+            }
+        }
+    }
+}
+
+/// Result of applying one burst of spec edits.
+#[derive(Debug, Clone)]
+pub struct DevUpdate {
+    /// Spec files that changed, relative to the project root
+    pub specs: Vec<String>,
+    /// Spec changes derived from those files
+    pub spec_changes: Vec<SpecChange>,
+    /// Generated files the changes reach
+    pub plan: FileChangePlan,
+    /// Generated files that were written or removed
+    pub changes: Vec<FileChange>,
+    /// Whether the server's HMU client can swap all of `changes` in place;
+    /// otherwise pushing them restarts the server
+    pub hot_swappable: bool,
+}
+
+/// A project kept in sync with its generated output while it is edited.
+///
+/// Holds the loaded specs and the output last written, so an edit only
+/// reloads the specs that changed and only writes the files that differ.
+pub struct DevSession {
+    project: LoadedProject,
+    output_dir: PathBuf,
+    incremental: IncrementalCodegen,
+    files: BTreeMap<String, String>,
+}
+
+impl DevSession {
+    /// Load the project at `root`, generate it and write it to `output_dir`
+    /// (by default [`default_output_dir`]).
+    pub fn open(root: &Path, output_dir: Option<&Path>) -> Result<Self, WatchError> {
+        let project = load_valid_project(root)?;
+        let output_dir = output_dir
+            .map(Path::to_path_buf)
+            .unwrap_or_else(|| default_output_dir(&project));
+        let generator = new_generator(&project)?;
+        let ir = convert(&project)?;
+        let generated = generator.generate(&ir)?;
+        generated
+            .write_to_disk(&output_dir)
+            .map_err(|e| WatchError::Io(output_dir.display().to_string(), e))?;
+
+        let mut incremental = IncrementalCodegen::for_generator(&generator);
+        incremental.build_dep_graph(&ir);
+        let files = generated.files().clone();
+        incremental.update_cache(&incremental.diff_files(&BTreeMap::new(), &files));
+
+        Ok(Self {
+            project,
+            output_dir,
+            incremental,
+            files,
+        })
+    }
+
+    /// The project as last loaded.
+    pub fn project(&self) -> &LoadedProject {
+        &self.project
+    }
+
+    /// Where the generated project is written.
+    pub fn output_dir(&self) -> &Path {
+        &self.output_dir
+    }
+
+    /// Config for serving the generated project.
+    pub fn server_config(&self) -> ServerConfig {
+        ServerConfig::for_project(&self.project.config, self.output_dir.clone())
+    }
+
+    /// Whether the generated server accepts hot module updates.
+    pub fn hmu_enabled(&self) -> bool {
+        self.project.config.codegen.as_ref().is_some_and(|c| c.hmu)
+    }
+
+    /// Bring the running server up to date with changes written by
+    /// [`DevSession::apply`].
+    ///
+    /// The files are already on disk, so when HMU is off, the changes aren't
+    /// [hot-swappable](DevUpdate::hot_swappable), the server has no HMU
+    /// channel or a module keeps failing to apply, the server is restarted
+    /// to load them instead.
+    ///
+    /// Holds `process_manager` and `hmu_engine` for the whole push; callers
+    /// that share them with other commands use [`DevSession::begin_push`],
+    /// [`PendingPush::exchange`] and [`DevSession::finish_push`] instead.
+    pub async fn push(
+        &self,
+        process_manager: &mut ProcessManager,
+        hmu_engine: &mut HmuEngine,
+        update: &DevUpdate,
+    ) -> Result<WatchEvent, ProcessError> {
+        let sent = self
+            .begin_push(process_manager, hmu_engine, update)
+            .exchange()
+            .await;
+        self.finish_push(process_manager, hmu_engine, sent).await
+    }
+
+    /// Create the HMU update for `update`'s changes and take a handle to the
+    /// server's HMU channel. Nothing is sent yet, so the manager and engine
+    /// can be released before [`PendingPush::exchange`].
+    pub fn begin_push(
+        &self,
+        process_manager: &ProcessManager,
+        hmu_engine: &mut HmuEngine,
+        update: &DevUpdate,
+    ) -> PendingPush {
+        let update = (self.hmu_enabled() && update.hot_swappable).then(|| {
+            hmu_engine.create_update(
+                IncrementalCodegen::to_hmu_modules(&update.changes)
+                    .into_iter()
+                    .map(|m| (m.path, m.action, m.content))
+                    .collect(),
+            )
+        });
+        PendingPush {
+            update,
+            link: process_manager.hmu_link(),
+            config: hmu_engine.config().clone(),
+        }
+    }
+
+    /// Record how the server took a pushed update, restarting it when the
+    /// update couldn't be applied.
+    pub async fn finish_push(
+        &self,
+        process_manager: &mut ProcessManager,
+        hmu_engine: &mut HmuEngine,
+        sent: SentPush,
+    ) -> Result<WatchEvent, ProcessError> {
+        let server_config = self.server_config();
+        let Some(update) = sent.update else {
+            let port = process_manager.restart(&server_config).await?;
+            return Ok(WatchEvent::Restarted { port });
+        };
+
+        let (mut push, requires_restart) = match sent.result {
+            Ok(ack) => {
+                let result = hmu_engine.record_ack(ack);
+                (
+                    HmuPush {
+                        id: update.id.clone(),
+                        status: result.ack.status,
+                        applied: result.ack.applied,
+                        failed: result.ack.failed,
+                        error: None,
+                        restarted_on: None,
+                    },
+                    result.requires_restart,
+                )
+            }
+            Err(e) => (
+                HmuPush {
+                    id: update.id.clone(),
+                    status: HmuStatus::Failed,
+                    applied: vec![],
+                    failed: update.modules.iter().map(|m| m.path.clone()).collect(),
+                    error: Some(e.to_string()),
+                    restarted_on: None,
+                },
+                hmu_engine.record_failure(&update, &e),
+            ),
+        };
+
+        if requires_restart {
+            push.restarted_on = Some(process_manager.restart(&server_config).await?);
+            // The restarted server loaded every module from disk
+            for path in &push.failed {
+                hmu_engine.reset_failure_count(path);
+            }
+        }
+
+        Ok(WatchEvent::Pushed(push))
+    }
+
+    /// Reload the changed spec files, regenerate the files they affect and
+    /// write those that differ from the previous output.
+    ///
+    /// A spec that fails to load or generate leaves the output and the
+    /// session untouched, so a half-saved file never breaks the server.
+    pub fn apply(&mut self, specs: &[String]) -> Result<DevUpdate, WatchError> {
+        let mut project = self.project.clone();
+        let mut spec_changes = Vec::new();
+        let mut full_regen = false;
+
+        if specs.iter().any(|path| path == CONFIG_FILE) {
+            // The config can change the target, so everything is reloaded
+            project = load_valid_project(&project.root)?;
+            spec_changes.push(SpecChange::ConfigModified);
+        } else {
+            for rel_path in specs {
+                let old = project.spec(rel_path);
+                let new = if project.root.join(rel_path).is_file() {
+                    let spec = loader::load_spec_file(&project.root, rel_path)
+                        .map_err(WatchError::InvalidSpec)?;
+                    project.insert_spec(rel_path, spec.clone());
+                    Some(spec)
+                } else {
+                    project.remove_spec(rel_path);
+                    None
+                };
+                if old.is_none() && new.is_none() {
+                    // A file that never loaded went away again
+                    continue;
+                }
+                match spec_edit(old.as_ref(), new.as_ref()) {
+                    Some(change) => spec_changes.push(change),
+                    None => full_regen = true,
+                }
+            }
+        }
+
+        let generator = new_generator(&project)?;
+        let ir = convert(&project)?;
+        let generated = generator.generate(&ir)?;
+
+        let mut incremental = if spec_changes.contains(&SpecChange::ConfigModified) {
+            IncrementalCodegen::for_generator(&generator)
+        } else {
+            std::mem::take(&mut self.incremental)
+        };
+        incremental.build_dep_graph(&ir);
+        let mut plan = incremental.compute_change_plan(&spec_changes);
+        plan.requires_full_regen |= full_regen;
+
+        let changes = if plan.requires_full_regen {
+            incremental.diff_files(&self.files, generated.files())
+        } else {
+            let scope: BTreeSet<String> = plan
+                .affected_files
+                .iter()
+                .map(|path| path.to_string_lossy().replace('\\', "/"))
+                .collect();
+            let in_scope = |files: &BTreeMap<String, String>| -> BTreeMap<String, String> {
+                files
+                    .iter()
+                    .filter(|(path, _)| scope.contains(*path))
+                    .map(|(path, content)| (path.clone(), content.clone()))
+                    .collect()
+            };
+            incremental.diff_files(&in_scope(&self.files), &in_scope(generated.files()))
+        };
+
+        let written = write_changes(&self.output_dir, &changes);
+        incremental.update_cache(&changes);
+        self.incremental = incremental;
+        written?;
+
+        // Track exactly what is on disk, so files a plan missed are still
+        // diffed on the next full regeneration
+        for change in &changes {
+            match change.action {
+                FileChangeAction::Create | FileChangeAction::Update => {
+                    self.files
+                        .insert(change.path.clone(), change.content.clone());
+                }
+                FileChangeAction::Delete => {
+                    self.files.remove(&change.path);
+                }
+            }
+        }
+        self.project = project;
+
+        Ok(DevUpdate {
+            specs: specs.to_vec(),
+            spec_changes,
+            plan,
+            hot_swappable: all_hot_swappable(&generator, &changes),
+            changes,
+        })
+    }
+}
+
+/// Directory the project is generated into: `codegen.outDir`, relative to the
+/// project root.
+pub fn default_output_dir(project: &LoadedProject) -> PathBuf {
+    project.root.join(
+        project
+            .config
+            .codegen
+            .as_ref()
+            .map(|c| c.out_dir.as_str())
+            .unwrap_or("./dist"),
+    )
+}
+
+/// The `SpecChange` for an edited spec file, or `None` when the edit needs a
+/// full regeneration: the spec was added, removed or renamed (which touches
+/// module lists and the entrypoint), or its kind has no dependency graph node.
+fn spec_edit(old: Option<&SpecFile>, new: Option<&SpecFile>) -> Option<SpecChange> {
+    fn spec_change(spec: &SpecFile) -> Option<SpecChange> {
+        match spec {
+            SpecFile::Route(route) => Some(SpecChange::RouteModified(route.path.clone())),
+            SpecFile::Handler(handler) => Some(SpecChange::HandlerModified(handler.name.clone())),
+            SpecFile::Schema(schema) => Some(SpecChange::SchemaModified(schema.name.clone())),
+            SpecFile::Middleware(mw) => Some(SpecChange::MiddlewareModified(mw.name.clone())),
+            SpecFile::Model(model) => Some(SpecChange::ModelModified(model.name.clone())),
+            SpecFile::Function(_) | SpecFile::Component(_) => None,
+        }
+    }
+
+    let old = spec_change(old?)?;
+    let new = spec_change(new?)?;
+    (old == new).then_some(new)
+}
+
+fn load_valid_project(root: &Path) -> Result<LoadedProject, WatchError> {
+    let (project, report) =
+        loader::load_project(root).map_err(|e| WatchError::Load(e.to_string()))?;
+    ensure_valid(report)?;
+    Ok(project)
+}
+
+fn ensure_valid(report: ValidationReport) -> Result<(), WatchError> {
+    let errors: Vec<ErrorEntry> = report
+        .errors
+        .into_iter()
+        .filter(|e| e.severity == Severity::Error)
+        .collect();
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(WatchError::InvalidSpec(errors))
+    }
+}
+
+fn new_generator(project: &LoadedProject) -> Result<CodeGenerator, WatchError> {
+    let target = &project.config.target;
+    Ok(CodeGenerator::new(target.language, target.framework)?)
+}
+
+/// Whether the server generated for `project` swaps all of `changes` in
+/// place over HMU. Anything else is loaded by restarting the server.
+pub fn hot_swappable(project: &LoadedProject, changes: &[FileChange]) -> bool {
+    new_generator(project).is_ok_and(|generator| all_hot_swappable(&generator, changes))
+}
+
+fn all_hot_swappable(generator: &CodeGenerator, changes: &[FileChange]) -> bool {
+    changes.iter().all(|change| {
+        change.action == FileChangeAction::Update
+            && generator.adapter().hot_swappable(&change.path)
+    })
+}
+
+fn convert(project: &LoadedProject) -> Result<ProjectIR, WatchError> {
+    convert_project(project).map_err(|e| WatchError::Convert(e.to_string()))
+}
+
+fn write_changes(output_dir: &Path, changes: &[FileChange]) -> Result<(), WatchError> {
+    for change in changes {
+        let path = output_dir.join(&change.path);
+        let io_err = |e| WatchError::Io(path.display().to_string(), e);
+        match change.action {
+            FileChangeAction::Create | FileChangeAction::Update => {
+                if let Some(parent) = path.parent() {
+                    std::fs::create_dir_all(parent).map_err(io_err)?;
+                }
+                std::fs::write(&path, &change.content).map_err(io_err)?;
+            }
+            FileChangeAction::Delete => match std::fs::remove_file(&path) {
+                Err(e) if e.kind() != std::io::ErrorKind::NotFound => return Err(io_err(e)),
+                _ => {}
+            },
+        }
+    }
+    Ok(())
+}
+
+/// An HMU update created by [`DevSession::begin_push`], not yet sent.
+pub struct PendingPush {
+    /// `None` when HMU is off or the changes aren't hot-swappable, and the
+    /// server is restarted instead
+    update: Option<HmuUpdate>,
+    link: Option<Arc<Mutex<HmuLink>>>,
+    config: HmuConfig,
+}
+
+impl PendingPush {
+    /// Send the update and wait for the server's ACK. Only the server's HMU
+    /// channel is locked meanwhile, so other commands can use the process
+    /// manager and HMU engine while the server applies the update.
+    pub async fn exchange(self) -> SentPush {
+        let Some(update) = self.update else {
+            return SentPush {
+                update: None,
+                result: Err(HmuError::RequiresRestart),
+            };
+        };
+        let mut link = match self.link {
+            Some(link) => Some(link.lock_owned().await),
+            None => None,
+        };
+        let result = match link.as_mut().and_then(|l| l.channel(self.config.heartbeat_timeout)) {
+            Some(channel) => HmuEngine::exchange(&self.config, channel, &update).await,
+            None => Err(HmuError::RequiresRestart),
+        };
+        SentPush {
+            update: Some(update),
+            result,
+        }
+    }
+}
+
+/// An update sent by [`PendingPush::exchange`], to be passed to
+/// [`DevSession::finish_push`].
+pub struct SentPush {
+    update: Option<HmuUpdate>,
+    result: Result<HmuAck, HmuError>,
+}
+
+/// How the running server took an update.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HmuPush {
+    pub id: String,
+    pub status: HmuStatus,
+    pub applied: Vec<String>,
+    pub failed: Vec<String>,
+    /// Why the update could not be delivered, if it was not
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Port of the restarted server, when HMU gave way to a restart
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub restarted_on: Option<u16>,
+}
+
+/// Progress of a watch session, as streamed to the CLI and the GUI.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum WatchEvent {
+    /// Generated files were rewritten after spec edits.
+    #[serde(rename_all = "camelCase")]
+    Regenerated {
+        specs: Vec<String>,
+        files: Vec<String>,
+        full_regen: bool,
+    },
+    /// The running server received the regenerated files over HMU.
+    Pushed(HmuPush),
+    /// The server was restarted to load the regenerated files.
+    Restarted { port: u16 },
+    /// Spec edits could not be applied; the previous output stays in place.
+    Failed { specs: Vec<String>, message: String },
+}
+
+impl WatchEvent {
+    /// Event for a successfully applied `DevUpdate`.
+    pub fn regenerated(update: &DevUpdate) -> Self {
+        WatchEvent::Regenerated {
+            specs: update.specs.clone(),
+            files: update.changes.iter().map(|c| c.path.clone()).collect(),
+            full_regen: update.plan.requires_full_regen,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use tempfile::TempDir;
+
+    fn write(dir: &Path, rel_path: &str, content: &str) {
+        let path = dir.join(rel_path);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, content).unwrap();
+    }
+
+    fn handler_json(name: &str, value: &str) -> String {
+        format!(
+            r#"{{
+                "name": "{name}",
+                "async": false,
+                "body": [
+                    {{ "type": "ReturnStatement", "tier": 0, "value": {{ "type": "Literal", "tier": 0, "value": "{value}" }} }}
+                ]
+            }}"#
+        )
+    }
+
+    fn route_json(path: &str, handler: &str) -> String {
+        format!(
+            r#"{{ "path": "{path}", "methods": {{ "GET": {{ "handler": {{ "ref": "{handler}" }} }} }} }}"#
+        )
+    }
+
+    fn create_project(dir: &Path) {
+        write(
+            dir,
+            "rash.config.json",
+            r#"{
+                "version": "1.0.0",
+                "name": "watched",
+                "target": { "language": "typescript", "framework": "express", "runtime": "bun" },
+                "server": { "port": 3000, "host": "0.0.0.0" }
+            }"#,
+        );
+        write(
+            dir,
+            "routes/users.route.json",
+            &route_json("/users", "users.list"),
+        );
+        write(
+            dir,
+            "handlers/users.handler.json",
+            &handler_json("users.list", "v1"),
+        );
+        write(
+            dir,
+            "routes/health.route.json",
+            &route_json("/health", "health.check"),
+        );
+        write(
+            dir,
+            "handlers/health.handler.json",
+            &handler_json("health.check", "ok"),
+        );
+    }
+
+    fn paths(update: &DevUpdate) -> Vec<&str> {
+        update.changes.iter().map(|c| c.path.as_str()).collect()
+    }
+
+    #[test]
+    fn open_writes_the_generated_project() {
+        let tmp = TempDir::new().unwrap();
+        create_project(tmp.path());
+
+        // Without an explicit directory the output goes to codegen.outDir
+        let session = DevSession::open(tmp.path(), None).unwrap();
+
+        let out = tmp.path().join("dist");
+        assert!(out.join("src/index.ts").exists());
+        assert!(out.join("src/handlers/users_list.ts").exists());
+        assert!(!session.hmu_enabled());
+        assert_eq!(session.server_config().port, 3000);
+        assert_eq!(session.server_config().output_dir, session.output_dir());
+    }
+
+    #[test]
+    fn handler_edit_rewrites_only_its_file() {
+        let tmp = TempDir::new().unwrap();
+        create_project(tmp.path());
+        let out = tmp.path().join("dist");
+        let mut session = DevSession::open(tmp.path(), Some(&out)).unwrap();
+
+        write(
+            tmp.path(),
+            "handlers/users.handler.json",
+            &handler_json("users.list", "v2"),
+        );
+        let update = session
+            .apply(&["handlers/users.handler.json".into()])
+            .unwrap();
+
+        assert_eq!(
+            update.spec_changes,
+            vec![SpecChange::HandlerModified("users.list".into())]
+        );
+        assert!(!update.plan.requires_full_regen);
+        assert_eq!(paths(&update), vec!["src/handlers/users_list.ts"]);
+        assert_eq!(update.changes[0].action, FileChangeAction::Update);
+        assert!(update.hot_swappable);
+        let written = std::fs::read_to_string(out.join("src/handlers/users_list.ts")).unwrap();
+        assert!(written.contains("v2"));
+
+        // Saving the same content again changes nothing
+        let update = session
+            .apply(&["handlers/users.handler.json".into()])
+            .unwrap();
+        assert!(update.changes.is_empty());
+    }
+
+    #[test]
+    fn route_edit_rewrites_the_route_file() {
+        let tmp = TempDir::new().unwrap();
+        create_project(tmp.path());
+        let out = tmp.path().join("dist");
+        let mut session = DevSession::open(tmp.path(), Some(&out)).unwrap();
+
+        write(
+            tmp.path(),
+            "routes/users.route.json",
+            r#"{ "path": "/users", "methods": { "POST": { "handler": { "ref": "users.list" } } } }"#,
+        );
+        let update = session.apply(&["routes/users.route.json".into()]).unwrap();
+
+        assert_eq!(
+            update.spec_changes,
+            vec![SpecChange::RouteModified("/users".into())]
+        );
+        assert_eq!(paths(&update), vec!["src/routes/index.ts"]);
+        // Routes are registered once at startup
+        assert!(!update.hot_swappable);
+    }
+
+    #[test]
+    fn model_edit_rewrites_the_model_and_orm_schema() {
+        let tmp = TempDir::new().unwrap();
+        create_project(tmp.path());
+        let model_json = |columns: &str| {
+            format!(r#"{{ "name": "User", "tableName": "users", "columns": {{ {columns} }} }}"#)
+        };
+        write(
+            tmp.path(),
+            "models/user.model.json",
+            &model_json(r#""id": { "type": "uuid", "primaryKey": true }"#),
+        );
+        write(
+            tmp.path(),
+            "handlers/users.handler.json",
+            r#"{
+                "name": "users.list",
+                "async": true,
+                "body": [
+                    { "type": "ReturnStatement", "tier": 0, "value": { "type": "DbQuery", "tier": 1, "model": "User", "operation": "findMany" } }
+                ]
+            }"#,
+        );
+        let out = tmp.path().join("dist");
+        let mut session = DevSession::open(tmp.path(), Some(&out)).unwrap();
+
+        write(
+            tmp.path(),
+            "models/user.model.json",
+            &model_json(
+                r#""id": { "type": "uuid", "primaryKey": true }, "email": { "type": "varchar(255)" }"#,
+            ),
+        );
+        let update = session.apply(&["models/user.model.json".into()]).unwrap();
+
+        assert_eq!(
+            update.spec_changes,
+            vec![SpecChange::ModelModified("User".into())]
+        );
+        assert!(!update.plan.requires_full_regen);
+        assert!(update
+            .plan
+            .affected_files
+            .contains(&PathBuf::from("src/handlers/users_list.ts")));
+        assert!(!update
+            .plan
+            .affected_files
+            .contains(&PathBuf::from("src/handlers/health_check.ts")));
+        assert_eq!(
+            paths(&update),
+            vec!["prisma/schema.prisma", "src/models/user.ts"]
+        );
+        let schema = std::fs::read_to_string(out.join("prisma/schema.prisma")).unwrap();
+        assert!(schema.contains("email"));
+        assert!(!update.hot_swappable);
+    }
+
+    #[test]
+    fn push_restarts_for_changes_the_client_cant_swap() {
+        let tmp = TempDir::new().unwrap();
+        create_project(tmp.path());
+        let config = |language: &str, framework: &str| {
+            format!(
+                r#"{{
+                    "version": "1.0.0",
+                    "name": "watched",
+                    "target": {{ "language": "{language}", "framework": "{framework}", "runtime": "bun" }},
+                    "server": {{ "port": 3000, "host": "0.0.0.0" }},
+                    "codegen": {{ "hmu": true }}
+                }}"#
+            )
+        };
+        write(tmp.path(), "rash.config.json", &config("typescript", "express"));
+        let out = tmp.path().join("dist");
+        let mut session = DevSession::open(tmp.path(), Some(&out)).unwrap();
+        let (process_manager, _log_rx, _status_rx) = ProcessManager::new();
+        let mut hmu_engine = HmuEngine::new(HmuConfig::default());
+
+        // A handler edit goes out as an update
+        write(
+            tmp.path(),
+            "handlers/users.handler.json",
+            &handler_json("users.list", "v2"),
+        );
+        let update = session
+            .apply(&["handlers/users.handler.json".into()])
+            .unwrap();
+        let pending = session.begin_push(&process_manager, &mut hmu_engine, &update);
+        assert_eq!(
+            pending.update.map(|u| u.modules.len()),
+            Some(1),
+            "a handler edit is hot-swapped"
+        );
+
+        // A route edit restarts the server
+        write(
+            tmp.path(),
+            "routes/users.route.json",
+            r#"{ "path": "/users", "methods": { "POST": { "handler": { "ref": "users.list" } } } }"#,
+        );
+        let update = session.apply(&["routes/users.route.json".into()]).unwrap();
+        let pending = session.begin_push(&process_manager, &mut hmu_engine, &update);
+        assert!(pending.update.is_none(), "a route edit restarts");
+
+        // Compiled targets have no HMU client, so even handlers restart
+        write(tmp.path(), "rash.config.json", &config("rust", "axum"));
+        session.apply(&[CONFIG_FILE.into()]).unwrap();
+        write(
+            tmp.path(),
+            "handlers/users.handler.json",
+            &handler_json("users.list", "v3"),
+        );
+        let update = session
+            .apply(&["handlers/users.handler.json".into()])
+            .unwrap();
+        assert_eq!(paths(&update), vec!["src/handlers/users_list.rs"]);
+        assert!(!update.hot_swappable);
+        assert!(!hot_swappable(session.project(), &update.changes));
+        let pending = session.begin_push(&process_manager, &mut hmu_engine, &update);
+        assert!(pending.update.is_none(), "a Rust handler edit restarts");
+    }
+
+    #[test]
+    fn new_and_deleted_specs_regenerate_everything() {
+        let tmp = TempDir::new().unwrap();
+        create_project(tmp.path());
+        let out = tmp.path().join("dist");
+        let mut session = DevSession::open(tmp.path(), Some(&out)).unwrap();
+
+        std::fs::remove_file(tmp.path().join("routes/health.route.json")).unwrap();
+        std::fs::remove_file(tmp.path().join("handlers/health.handler.json")).unwrap();
+        let update = session
+            .apply(&[
+                "handlers/health.handler.json".into(),
+                "routes/health.route.json".into(),
+            ])
+            .unwrap();
+
+        assert!(update.plan.requires_full_regen);
+        let removed = update
+            .changes
+            .iter()
+            .find(|c| c.path == "src/handlers/health_check.ts")
+            .unwrap();
+        assert_eq!(removed.action, FileChangeAction::Delete);
+        assert!(!out.join("src/handlers/health_check.ts").exists());
+        assert!(paths(&update).contains(&"src/routes/index.ts"));
+    }
+
+    #[test]
+    fn invalid_spec_keeps_previous_output() {
+        let tmp = TempDir::new().unwrap();
+        create_project(tmp.path());
+        let out = tmp.path().join("dist");
+        let mut session = DevSession::open(tmp.path(), Some(&out)).unwrap();
+        let before = std::fs::read_to_string(out.join("src/handlers/users_list.ts")).unwrap();
+
+        write(tmp.path(), "handlers/users.handler.json", "{ \"name\": ");
+        let err = session
+            .apply(&["handlers/users.handler.json".into()])
+            .unwrap_err();
+        assert!(matches!(err, WatchError::InvalidSpec(_)));
+        assert_eq!(
+            std::fs::read_to_string(out.join("src/handlers/users_list.ts")).unwrap(),
+            before
+        );
+
+        // Fixing the spec picks up where the session left off
+        write(
+            tmp.path(),
+            "handlers/users.handler.json",
+            &handler_json("users.list", "v3"),
+        );
+        let update = session
+            .apply(&["handlers/users.handler.json".into()])
+            .unwrap();
+        assert_eq!(paths(&update), vec!["src/handlers/users_list.ts"]);
+    }
+
+    #[test]
+    fn config_edit_retargets_the_generator() {
+        let tmp = TempDir::new().unwrap();
+        create_project(tmp.path());
+        let out = tmp.path().join("dist");
+        let mut session = DevSession::open(tmp.path(), Some(&out)).unwrap();
+
+        write(
+            tmp.path(),
+            "rash.config.json",
+            r#"{
+                "version": "1.0.0",
+                "name": "watched",
+                "target": { "language": "python", "framework": "fastapi", "runtime": "python" },
+                "server": { "port": 8000, "host": "0.0.0.0" }
+            }"#,
+        );
+        let update = session.apply(&["rash.config.json".into()]).unwrap();
+
+        assert_eq!(update.spec_changes, vec![SpecChange::ConfigModified]);
+        assert!(out.join("src/handlers/users_list.py").exists());
+        assert!(!out.join("src/handlers/users_list.ts").exists());
+        assert_eq!(session.server_config().port, 8000);
+    }
+
+    #[test]
+    fn spec_edit_detects_renames() {
+        let tmp = TempDir::new().unwrap();
+        create_project(tmp.path());
+        let load = |rel| loader::load_spec_file(tmp.path(), rel).unwrap();
+
+        let users = load("handlers/users.handler.json");
+        let health = load("handlers/health.handler.json");
+        assert_eq!(
+            spec_edit(Some(&users), Some(&users)),
+            Some(SpecChange::HandlerModified("users.list".into()))
+        );
+        assert_eq!(spec_edit(Some(&users), Some(&health)), None);
+        assert_eq!(spec_edit(None, Some(&users)), None);
+        assert_eq!(spec_edit(Some(&users), None), None);
+    }
+
+    #[tokio::test]
+    async fn watcher_batches_spec_edits() {
+        let tmp = TempDir::new().unwrap();
+        create_project(tmp.path());
+        let mut watcher = SpecWatcher::new(tmp.path(), &[], Duration::from_millis(100)).unwrap();
+
+        write(
+            tmp.path(),
+            "handlers/users.handler.json",
+            &handler_json("users.list", "v2"),
+        );
+        write(tmp.path(), "handlers/notes.txt", "not a spec");
+        write(tmp.path(), "dist/src/index.ts", "generated");
+        write(
+            tmp.path(),
+            "routes/users.route.json",
+            &route_json("/users", "users.list"),
+        );
+
+        let batch = timeout(Duration::from_secs(5), watcher.next_batch())
+            .await
+            .expect("no batch within 5s")
+            .unwrap();
+        assert_eq!(
+            batch,
+            vec![
+                "handlers/users.handler.json".to_string(),
+                "routes/users.route.json".to_string()
+            ]
+        );
+    }
+
+    /// Stands in for uvicorn: answers each stdio update after a delay.
+    const SLOW_HMU_SERVER: &str = r#"
+import json, sys, time
+print("Listening on port 8000", flush=True)
+for line in sys.stdin:
+    update = json.loads(line)
+    time.sleep(1)
+    print(json.dumps({
+        "type": "HMU_ACK",
+        "id": update["id"],
+        "status": "success",
+        "applied": [m["path"] for m in update["modules"]],
+        "failed": [],
+        "requiresRestart": False,
+    }), flush=True)
+"#;
+
+    #[tokio::test]
+    async fn push_leaves_the_runtime_free_while_the_server_applies() {
+        let tmp = TempDir::new().unwrap();
+        create_project(tmp.path());
+        write(
+            tmp.path(),
+            "rash.config.json",
+            r#"{
+                "version": "1.0.0",
+                "name": "watched",
+                "target": { "language": "python", "framework": "fastapi", "runtime": "python" },
+                "server": { "port": 8000, "host": "0.0.0.0" },
+                "codegen": { "hmu": true }
+            }"#,
+        );
+        let out = tmp.path().join("dist");
+        let mut session = DevSession::open(tmp.path(), Some(&out)).unwrap();
+        write(&out, "uvicorn.py", SLOW_HMU_SERVER);
+
+        let (mut process_manager, _log_rx, _status_rx) = ProcessManager::new();
+        process_manager.start(&session.server_config()).await.unwrap();
+        let runtime = Arc::new(Mutex::new((
+            process_manager,
+            HmuEngine::new(HmuConfig::default()),
+        )));
+
+        write(
+            tmp.path(),
+            "handlers/users.handler.json",
+            &handler_json("users.list", "v2"),
+        );
+        let update = session
+            .apply(&["handlers/users.handler.json".into()])
+            .unwrap();
+
+        let pending = {
+            let mut rt = runtime.lock().await;
+            let (process_manager, hmu_engine) = &mut *rt;
+            session.begin_push(process_manager, hmu_engine, &update)
+        };
+        let exchange = tokio::spawn(pending.exchange());
+
+        // Another command gets the runtime while the update is in flight
+        let rt = timeout(Duration::from_millis(500), runtime.lock())
+            .await
+            .expect("runtime is locked during the push");
+        assert!(rt.0.pid().is_some());
+        assert!(!exchange.is_finished());
+        drop(rt);
+
+        let sent = exchange.await.unwrap();
+        let mut rt = runtime.lock().await;
+        let (process_manager, hmu_engine) = &mut *rt;
+        let event = session
+            .finish_push(process_manager, hmu_engine, sent)
+            .await
+            .unwrap();
+        process_manager.stop().await.unwrap();
+
+        match event {
+            WatchEvent::Pushed(push) => {
+                assert_eq!(push.status, HmuStatus::Success);
+                assert_eq!(push.applied, vec!["src/handlers/users_list.py"]);
+                assert_eq!(push.restarted_on, None);
+            }
+            other => panic!("expected a push, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn watch_event_serialization() {
+        let event = WatchEvent::Regenerated {
+            specs: vec!["handlers/users.handler.json".into()],
+            files: vec!["src/handlers/users_list.ts".into()],
+            full_regen: false,
+        };
+        assert_eq!(
+            serde_json::to_value(&event).unwrap(),
+            serde_json::json!({
+                "type": "regenerated",
+                "specs": ["handlers/users.handler.json"],
+                "files": ["src/handlers/users_list.ts"],
+                "fullRegen": false
+            })
+        );
+
+        let event = WatchEvent::Pushed(HmuPush {
+            id: "hmu_001".into(),
+            status: HmuStatus::Success,
+            applied: vec!["src/handlers/users_list.ts".into()],
+            failed: vec![],
+            error: None,
+            restarted_on: None,
+        });
+        let json = serde_json::to_value(&event).unwrap();
+        assert_eq!(json["type"], "pushed");
+        assert_eq!(json["status"], "success");
+        assert!(json.get("restartedOn").is_none());
+    }
+}
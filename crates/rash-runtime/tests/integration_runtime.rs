@@ -39,6 +39,7 @@ fn make_rash_config(runtime: Runtime, port: u16, out_dir: Option<&str>) -> RashC
             out_dir: d.to_string(),
             source_map: false,
             strict: false,
+            hmu: false,
         }),
         middleware: None,
         plugins: vec![],
@@ -269,6 +270,7 @@ fn test_hmu_engine_failure_escalation() {
     let config = HmuConfig {
         ack_timeout: Duration::from_secs(5),
         max_consecutive_failures: 2,
+        ..Default::default()
     };
     let mut engine = HmuEngine::new(config);
 
@@ -297,6 +299,7 @@ async fn test_hmu_engine_send_update_escalation_after_max_failures() {
     let config = HmuConfig {
         ack_timeout: Duration::from_secs(5),
         max_consecutive_failures: 2,
+        ..Default::default()
     };
     let mut engine = HmuEngine::new(config);
 
@@ -10,6 +10,8 @@ pub enum SymbolKind {
     Middleware,
     Model,
     Route,
+    Function,
+    Component,
 }
 
 impl std::fmt::Display for SymbolKind {
@@ -20,6 +22,8 @@ impl std::fmt::Display for SymbolKind {
             SymbolKind::Middleware => write!(f, "middleware"),
             SymbolKind::Model => write!(f, "model"),
             SymbolKind::Route => write!(f, "route"),
+            SymbolKind::Function => write!(f, "function"),
+            SymbolKind::Component => write!(f, "component"),
         }
     }
 }
@@ -182,6 +186,20 @@ pub fn build_index(project: &crate::loader::LoadedProject) -> (SpecIndex, Vec<Er
         }
     }
 
+    // Register user functions
+    for (file, function) in &project.functions {
+        if let Some(err) = index.register(&function.name, SymbolKind::Function, file, "$.name") {
+            errors.push(err);
+        }
+    }
+
+    // Register components
+    for (file, component) in &project.components {
+        if let Some(err) = index.register(&component.name, SymbolKind::Component, file, "$.name") {
+            errors.push(err);
+        }
+    }
+
     // Register routes
     for (file, route) in &project.routes {
         if let Some(err) = index.register(&route.path, SymbolKind::Route, file, "$.path") {
@@ -197,9 +215,12 @@ pub fn build_index(project: &crate::loader::LoadedProject) -> (SpecIndex, Vec<Er
 fn canonicalize_for_kind(name: &str, kind: SymbolKind) -> String {
     match kind {
         SymbolKind::Route => canonicalize_route(name),
-        SymbolKind::Schema | SymbolKind::Handler | SymbolKind::Middleware | SymbolKind::Model => {
-            canonicalize_ident(name)
-        }
+        SymbolKind::Schema
+        | SymbolKind::Handler
+        | SymbolKind::Middleware
+        | SymbolKind::Model
+        | SymbolKind::Function
+        | SymbolKind::Component => canonicalize_ident(name),
     }
 }
 
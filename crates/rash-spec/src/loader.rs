@@ -4,8 +4,10 @@ use walkdir::WalkDir;
 
 use crate::parser::{self, SpecFileType};
 use crate::schema_def;
+use crate::types::component::ComponentSpec;
 use crate::types::config::RashConfig;
 use crate::types::error::{ErrorEntry, ValidationReport, E_PARSE_ERROR};
+use crate::types::function::FunctionSpec;
 use crate::types::handler::HandlerSpec;
 use crate::types::middleware::MiddlewareSpec;
 use crate::types::model::ModelSpec;
@@ -29,6 +31,81 @@ pub struct LoadedProject {
     pub middleware: Vec<(String, MiddlewareSpec)>,
     /// All parsed handlers with their relative file paths
     pub handlers: Vec<(String, HandlerSpec)>,
+    /// All parsed user functions with their relative file paths
+    pub functions: Vec<(String, FunctionSpec)>,
+    /// All parsed components with their relative file paths
+    pub components: Vec<(String, ComponentSpec)>,
+}
+
+/// A single parsed spec file (anything but `rash.config.json`)
+#[derive(Debug, Clone)]
+pub enum SpecFile {
+    Route(RouteSpec),
+    Schema(SchemaSpec),
+    Model(ModelSpec),
+    Middleware(MiddlewareSpec),
+    Handler(HandlerSpec),
+    Function(FunctionSpec),
+    Component(ComponentSpec),
+}
+
+impl LoadedProject {
+    /// Get the spec loaded from `rel_path`.
+    pub fn spec(&self, rel_path: &str) -> Option<SpecFile> {
+        fn find<T: Clone>(entries: &[(String, T)], rel_path: &str) -> Option<T> {
+            entries
+                .iter()
+                .find(|(path, _)| path == rel_path)
+                .map(|(_, spec)| spec.clone())
+        }
+
+        find(&self.routes, rel_path)
+            .map(SpecFile::Route)
+            .or_else(|| find(&self.schemas, rel_path).map(SpecFile::Schema))
+            .or_else(|| find(&self.models, rel_path).map(SpecFile::Model))
+            .or_else(|| find(&self.middleware, rel_path).map(SpecFile::Middleware))
+            .or_else(|| find(&self.handlers, rel_path).map(SpecFile::Handler))
+            .or_else(|| find(&self.functions, rel_path).map(SpecFile::Function))
+            .or_else(|| find(&self.components, rel_path).map(SpecFile::Component))
+    }
+
+    /// Store the spec parsed from `rel_path`, replacing the one previously
+    /// loaded from that file in place so generated output keeps its order.
+    pub fn insert_spec(&mut self, rel_path: &str, spec: SpecFile) {
+        fn upsert<T>(entries: &mut Vec<(String, T)>, rel_path: &str, spec: T) {
+            match entries.iter_mut().find(|(path, _)| path == rel_path) {
+                Some(entry) => entry.1 = spec,
+                None => entries.push((rel_path.to_string(), spec)),
+            }
+        }
+
+        match spec {
+            SpecFile::Route(spec) => upsert(&mut self.routes, rel_path, spec),
+            SpecFile::Schema(spec) => upsert(&mut self.schemas, rel_path, spec),
+            SpecFile::Model(spec) => upsert(&mut self.models, rel_path, spec),
+            SpecFile::Middleware(spec) => upsert(&mut self.middleware, rel_path, spec),
+            SpecFile::Handler(spec) => upsert(&mut self.handlers, rel_path, spec),
+            SpecFile::Function(spec) => upsert(&mut self.functions, rel_path, spec),
+            SpecFile::Component(spec) => upsert(&mut self.components, rel_path, spec),
+        }
+    }
+
+    /// Drop the spec loaded from `rel_path`, e.g. after the file was deleted.
+    pub fn remove_spec(&mut self, rel_path: &str) -> Option<SpecFile> {
+        fn take<T>(entries: &mut Vec<(String, T)>, rel_path: &str) -> Option<T> {
+            let pos = entries.iter().position(|(path, _)| path == rel_path)?;
+            Some(entries.remove(pos).1)
+        }
+
+        take(&mut self.routes, rel_path)
+            .map(SpecFile::Route)
+            .or_else(|| take(&mut self.schemas, rel_path).map(SpecFile::Schema))
+            .or_else(|| take(&mut self.models, rel_path).map(SpecFile::Model))
+            .or_else(|| take(&mut self.middleware, rel_path).map(SpecFile::Middleware))
+            .or_else(|| take(&mut self.handlers, rel_path).map(SpecFile::Handler))
+            .or_else(|| take(&mut self.functions, rel_path).map(SpecFile::Function))
+            .or_else(|| take(&mut self.components, rel_path).map(SpecFile::Component))
+    }
 }
 
 fn json_parse_error_entry(err: serde_json::Error, file_path: &str) -> ErrorEntry {
@@ -56,6 +133,8 @@ pub fn load_project(project_dir: &Path) -> Result<(LoadedProject, ValidationRepo
     let model_schema = schema_def::generate_model_schema();
     let middleware_schema = schema_def::generate_middleware_schema();
     let handler_schema = schema_def::generate_handler_schema();
+    let function_schema = schema_def::generate_function_schema();
+    let component_schema = schema_def::generate_component_schema();
 
     // Check directory exists
     if !project_dir.is_dir() {
@@ -90,11 +169,17 @@ pub fn load_project(project_dir: &Path) -> Result<(LoadedProject, ValidationRepo
         }
     };
 
-    let mut routes = Vec::new();
-    let mut schemas = Vec::new();
-    let mut models = Vec::new();
-    let mut middleware = Vec::new();
-    let mut handlers = Vec::new();
+    let mut project = LoadedProject {
+        root: project_dir.to_path_buf(),
+        config,
+        routes: Vec::new(),
+        schemas: Vec::new(),
+        models: Vec::new(),
+        middleware: Vec::new(),
+        handlers: Vec::new(),
+        functions: Vec::new(),
+        components: Vec::new(),
+    };
 
     // Walk directory and collect spec files
     for entry in WalkDir::new(project_dir)
@@ -118,91 +203,107 @@ pub fn load_project(project_dir: &Path) -> Result<(LoadedProject, ValidationRepo
             continue;
         };
 
-        let content = match std::fs::read_to_string(path) {
-            Ok(c) => c,
-            Err(e) => {
-                report.push(ErrorEntry::error(
-                    E_PARSE_ERROR,
-                    format!("Failed to read file: {e}"),
-                    &rel_path,
-                    "$",
-                ));
-                continue;
-            }
+        let schema = match spec_type {
+            SpecFileType::Config => continue,
+            SpecFileType::Route => &route_schema,
+            SpecFileType::Schema => &schema_schema,
+            SpecFileType::Model => &model_schema,
+            SpecFileType::Middleware => &middleware_schema,
+            SpecFileType::Handler => &handler_schema,
+            SpecFileType::Function => &function_schema,
+            SpecFileType::Component => &component_schema,
         };
 
-        let json_value = match serde_json::from_str::<serde_json::Value>(&content) {
-            Ok(v) => v,
-            Err(e) => {
-                report.push(json_parse_error_entry(e, &rel_path));
-                continue;
+        let parsed = read_spec_content(path, &rel_path)
+            .and_then(|content| parse_spec_file(spec_type, &content, &rel_path, schema));
+        match parsed {
+            Ok(spec) => {
+                project.insert_spec(&rel_path, spec);
             }
-        };
-
-        let schema_errors = match spec_type {
-            SpecFileType::Config => Vec::new(),
-            SpecFileType::Route => {
-                schema_def::validate_against_schema(&json_value, &route_schema, &rel_path)
-            }
-            SpecFileType::Schema => {
-                schema_def::validate_against_schema(&json_value, &schema_schema, &rel_path)
-            }
-            SpecFileType::Model => {
-                schema_def::validate_against_schema(&json_value, &model_schema, &rel_path)
-            }
-            SpecFileType::Middleware => {
-                schema_def::validate_against_schema(&json_value, &middleware_schema, &rel_path)
-            }
-            SpecFileType::Handler => {
-                schema_def::validate_against_schema(&json_value, &handler_schema, &rel_path)
-            }
-        };
-
-        if !schema_errors.is_empty() {
-            for entry in schema_errors {
-                report.push(entry);
-            }
-            continue;
-        }
-
-        match spec_type {
-            SpecFileType::Config => {
-                // Already loaded above
+            Err(entries) => {
+                for entry in entries {
+                    report.push(entry);
+                }
             }
-            SpecFileType::Route => match parser::parse_route(&content, &rel_path) {
-                Ok(route) => routes.push((rel_path, route)),
-                Err(entry) => report.push(entry),
-            },
-            SpecFileType::Schema => match parser::parse_schema(&content, &rel_path) {
-                Ok(schema) => schemas.push((rel_path, schema)),
-                Err(entry) => report.push(entry),
-            },
-            SpecFileType::Model => match parser::parse_model(&content, &rel_path) {
-                Ok(model) => models.push((rel_path, model)),
-                Err(entry) => report.push(entry),
-            },
-            SpecFileType::Middleware => match parser::parse_middleware(&content, &rel_path) {
-                Ok(mw) => middleware.push((rel_path, mw)),
-                Err(entry) => report.push(entry),
-            },
-            SpecFileType::Handler => match parser::parse_handler(&content, &rel_path) {
-                Ok(handler) => handlers.push((rel_path, handler)),
-                Err(entry) => report.push(entry),
-            },
         }
     }
 
-    let project = LoadedProject {
-        root: project_dir.to_path_buf(),
-        config,
-        routes,
-        schemas,
-        models,
-        middleware,
-        handlers,
+    Ok((project, report))
+}
+
+/// Load and parse a single spec file, e.g. after it changed on disk.
+///
+/// `rel_path` is relative to `project_dir`, as in [`LoadedProject`].
+/// `rash.config.json` is not a spec file; reload the project for it.
+pub fn load_spec_file(project_dir: &Path, rel_path: &str) -> Result<SpecFile, Vec<ErrorEntry>> {
+    let path = project_dir.join(rel_path);
+    let spec_type = parser::detect_spec_type(&path)
+        .filter(|t| *t != SpecFileType::Config)
+        .ok_or_else(|| {
+            vec![ErrorEntry::error(
+                E_PARSE_ERROR,
+                "Not a spec file",
+                rel_path,
+                "$",
+            )]
+        })?;
+
+    let schema = match spec_type {
+        SpecFileType::Config => unreachable!("filtered above"),
+        SpecFileType::Route => schema_def::generate_route_schema(),
+        SpecFileType::Schema => schema_def::generate_schema_schema(),
+        SpecFileType::Model => schema_def::generate_model_schema(),
+        SpecFileType::Middleware => schema_def::generate_middleware_schema(),
+        SpecFileType::Handler => schema_def::generate_handler_schema(),
+        SpecFileType::Function => schema_def::generate_function_schema(),
+        SpecFileType::Component => schema_def::generate_component_schema(),
     };
 
-    Ok((project, report))
+    let content = read_spec_content(&path, rel_path)?;
+    parse_spec_file(spec_type, &content, rel_path, &schema)
+}
+
+fn read_spec_content(path: &Path, rel_path: &str) -> Result<String, Vec<ErrorEntry>> {
+    std::fs::read_to_string(path).map_err(|e| {
+        vec![ErrorEntry::error(
+            E_PARSE_ERROR,
+            format!("Failed to read file: {e}"),
+            rel_path,
+            "$",
+        )]
+    })
+}
+
+/// Validate a spec file against its JSON Schema, then parse it.
+fn parse_spec_file(
+    spec_type: SpecFileType,
+    content: &str,
+    rel_path: &str,
+    schema: &serde_json::Value,
+) -> Result<SpecFile, Vec<ErrorEntry>> {
+    let json_value = serde_json::from_str::<serde_json::Value>(content)
+        .map_err(|e| vec![json_parse_error_entry(e, rel_path)])?;
+
+    let schema_errors = schema_def::validate_against_schema(&json_value, schema, rel_path);
+    if !schema_errors.is_empty() {
+        return Err(schema_errors);
+    }
+
+    let parsed = match spec_type {
+        SpecFileType::Config => unreachable!("rash.config.json is loaded separately"),
+        SpecFileType::Route => parser::parse_route(content, rel_path).map(SpecFile::Route),
+        SpecFileType::Schema => parser::parse_schema(content, rel_path).map(SpecFile::Schema),
+        SpecFileType::Model => parser::parse_model(content, rel_path).map(SpecFile::Model),
+        SpecFileType::Middleware => {
+            parser::parse_middleware(content, rel_path).map(SpecFile::Middleware)
+        }
+        SpecFileType::Handler => parser::parse_handler(content, rel_path).map(SpecFile::Handler),
+        SpecFileType::Function => parser::parse_function(content, rel_path).map(SpecFile::Function),
+        SpecFileType::Component => {
+            parser::parse_component(content, rel_path).map(SpecFile::Component)
+        }
+    };
+    parsed.map_err(|entry| vec![entry])
 }
 
 /// Errors that prevent project loading entirely
@@ -307,6 +408,56 @@ mod tests {
         assert_eq!(project.routes.len(), 1);
     }
 
+    #[test]
+    fn test_reload_single_spec_file() {
+        let tmp = TempDir::new().unwrap();
+        create_minimal_project(tmp.path());
+
+        let routes_dir = tmp.path().join("routes");
+        std::fs::create_dir_all(&routes_dir).unwrap();
+        for name in ["a", "b"] {
+            std::fs::write(
+                routes_dir.join(format!("{name}.route.json")),
+                format!(r#"{{ "path": "/{name}", "methods": {{ "GET": {{ "handler": {{ "ref": "{name}.get" }} }} }} }}"#),
+            )
+            .unwrap();
+        }
+        let (mut project, _) = load_project(tmp.path()).unwrap();
+        let first = project.routes[0].0.clone();
+
+        // Edit in place keeps the route's position
+        std::fs::write(
+            tmp.path().join(&first),
+            r#"{ "path": "/renamed", "methods": { "GET": { "handler": { "ref": "x.get" } } } }"#,
+        )
+        .unwrap();
+        let spec = load_spec_file(tmp.path(), &first).unwrap();
+        project.insert_spec(&first, spec);
+        assert_eq!(project.routes.len(), 2);
+        assert_eq!(project.routes[0].1.path, "/renamed");
+        assert!(matches!(project.spec(&first), Some(SpecFile::Route(r)) if r.path == "/renamed"));
+
+        assert!(matches!(
+            project.remove_spec(&first),
+            Some(SpecFile::Route(_))
+        ));
+        assert_eq!(project.routes.len(), 1);
+        assert!(project.spec(&first).is_none());
+    }
+
+    #[test]
+    fn test_reload_invalid_spec_file_reports_errors() {
+        let tmp = TempDir::new().unwrap();
+        create_minimal_project(tmp.path());
+        std::fs::write(tmp.path().join("bad.route.json"), "{ invalid json }").unwrap();
+
+        let errors = load_spec_file(tmp.path(), "bad.route.json").unwrap_err();
+        assert_eq!(errors[0].code, E_PARSE_ERROR);
+
+        let errors = load_spec_file(tmp.path(), "rash.config.json").unwrap_err();
+        assert_eq!(errors.len(), 1);
+    }
+
     #[test]
     fn test_load_nonexistent_directory() {
         let err = load_project(Path::new("/nonexistent/path")).unwrap_err();
@@ -371,11 +522,42 @@ mod tests {
         )
         .unwrap();
 
+        let functions_dir = tmp.path().join("functions");
+        std::fs::create_dir_all(&functions_dir).unwrap();
+        std::fs::write(
+            functions_dir.join("currency.function.json"),
+            r#"{
+                "name": "formatCurrency",
+                "params": [{ "name": "amount", "type": "number" }],
+                "returnType": "string",
+                "body": [
+                    { "type": "ReturnStatement", "tier": 0, "value": { "type": "Identifier", "tier": 0, "name": "amount" } }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let components_dir = tmp.path().join("components");
+        std::fs::create_dir_all(&components_dir).unwrap();
+        std::fs::write(
+            components_dir.join("pagination.component.json"),
+            r#"{
+                "name": "paginate",
+                "inputs": { "model": { "type": "string" } },
+                "body": [
+                    { "type": "ReturnStatement", "tier": 0, "value": { "type": "Identifier", "tier": 0, "name": "$input.model" } }
+                ]
+            }"#,
+        )
+        .unwrap();
+
         let (project, report) = load_project(tmp.path()).unwrap();
         assert!(report.ok);
         assert_eq!(project.schemas.len(), 1);
         assert_eq!(project.models.len(), 1);
         assert_eq!(project.middleware.len(), 1);
         assert_eq!(project.handlers.len(), 1);
+        assert_eq!(project.functions.len(), 1);
+        assert_eq!(project.components.len(), 1);
     }
 }
@@ -1,7 +1,9 @@
 use std::path::Path;
 
+use crate::types::component::ComponentSpec;
 use crate::types::config::RashConfig;
 use crate::types::error::{ErrorEntry, E_PARSE_ERROR};
+use crate::types::function::FunctionSpec;
 use crate::types::handler::HandlerSpec;
 use crate::types::middleware::MiddlewareSpec;
 use crate::types::model::ModelSpec;
@@ -44,6 +46,18 @@ pub fn parse_handler(content: &str, file_path: &str) -> Result<HandlerSpec, Erro
     serde_json::from_str(content).map_err(|e| serde_error_to_entry(e, file_path))
 }
 
+/// Parse a *.function.json file
+#[allow(clippy::result_large_err)]
+pub fn parse_function(content: &str, file_path: &str) -> Result<FunctionSpec, ErrorEntry> {
+    serde_json::from_str(content).map_err(|e| serde_error_to_entry(e, file_path))
+}
+
+/// Parse a *.component.json file
+#[allow(clippy::result_large_err)]
+pub fn parse_component(content: &str, file_path: &str) -> Result<ComponentSpec, ErrorEntry> {
+    serde_json::from_str(content).map_err(|e| serde_error_to_entry(e, file_path))
+}
+
 /// Detect spec file type from file name
 pub fn detect_spec_type(file_path: &Path) -> Option<SpecFileType> {
     let name = file_path.file_name()?.to_str()?;
@@ -59,6 +73,10 @@ pub fn detect_spec_type(file_path: &Path) -> Option<SpecFileType> {
         Some(SpecFileType::Middleware)
     } else if name.ends_with(".handler.json") {
         Some(SpecFileType::Handler)
+    } else if name.ends_with(".function.json") {
+        Some(SpecFileType::Function)
+    } else if name.ends_with(".component.json") {
+        Some(SpecFileType::Component)
     } else {
         None
     }
@@ -73,6 +91,8 @@ pub enum SpecFileType {
     Model,
     Middleware,
     Handler,
+    Function,
+    Component,
 }
 
 /// Convert a serde_json error into a structured ErrorEntry
@@ -203,6 +223,14 @@ mod tests {
             detect_spec_type(Path::new("users.handler.json")),
             Some(SpecFileType::Handler)
         );
+        assert_eq!(
+            detect_spec_type(Path::new("currency.function.json")),
+            Some(SpecFileType::Function)
+        );
+        assert_eq!(
+            detect_spec_type(Path::new("pagination.component.json")),
+            Some(SpecFileType::Component)
+        );
         assert_eq!(detect_spec_type(Path::new("README.md")), None);
     }
 
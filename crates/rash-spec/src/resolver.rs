@@ -13,6 +13,10 @@ pub enum RefContext {
     Middleware,
     /// Reference in model field → expects Model
     Model,
+    /// Reference in `CallFunction` node → expects Function
+    Function,
+    /// Reference in `UseComponent` node → expects Component
+    Component,
 }
 
 impl RefContext {
@@ -23,6 +27,8 @@ impl RefContext {
             RefContext::Schema => SymbolKind::Schema,
             RefContext::Middleware => SymbolKind::Middleware,
             RefContext::Model => SymbolKind::Model,
+            RefContext::Function => SymbolKind::Function,
+            RefContext::Component => SymbolKind::Component,
         }
     }
 }
@@ -211,6 +217,18 @@ mod tests {
             "models/user.model.json",
             "$.name",
         );
+        index.register(
+            "formatCurrency",
+            SymbolKind::Function,
+            "functions/currency.function.json",
+            "$.name",
+        );
+        index.register(
+            "paginate",
+            SymbolKind::Component,
+            "components/pagination.component.json",
+            "$.name",
+        );
         index
     }
 
@@ -241,6 +259,42 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_resolve_function_ref() {
+        let index = setup_index();
+        let resolver = Resolver::new(&index);
+
+        match resolver.resolve("format_currency", RefContext::Function) {
+            ResolveResult::Found(entry) => {
+                assert_eq!(entry.original_name, "formatCurrency");
+                assert_eq!(entry.kind, SymbolKind::Function);
+            }
+            other => panic!("Expected Found, got {:?}", other),
+        }
+
+        match resolver.resolve("getUser", RefContext::Function) {
+            ResolveResult::TypeMismatch { expected, found } => {
+                assert_eq!(expected, SymbolKind::Function);
+                assert_eq!(found.kind, SymbolKind::Handler);
+            }
+            other => panic!("Expected TypeMismatch, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_resolve_component_ref() {
+        let index = setup_index();
+        let resolver = Resolver::new(&index);
+
+        match resolver.resolve("Paginate", RefContext::Component) {
+            ResolveResult::Found(entry) => {
+                assert_eq!(entry.original_name, "paginate");
+                assert_eq!(entry.file, "components/pagination.component.json");
+            }
+            other => panic!("Expected Found, got {:?}", other),
+        }
+    }
+
     #[test]
     fn test_resolve_not_found() {
         let index = setup_index();
@@ -1,8 +1,10 @@
 use jsonschema::Validator;
 use schemars::schema_for;
 
+use crate::types::component::ComponentSpec;
 use crate::types::config::RashConfig;
 use crate::types::error::{ErrorEntry, E_SCHEMA_VIOLATION};
+use crate::types::function::FunctionSpec;
 use crate::types::handler::HandlerSpec;
 use crate::types::middleware::MiddlewareSpec;
 use crate::types::model::ModelSpec;
@@ -34,6 +36,14 @@ pub fn generate_handler_schema() -> serde_json::Value {
     serde_json::to_value(schema_for!(HandlerSpec)).expect("schema serialization should not fail")
 }
 
+pub fn generate_function_schema() -> serde_json::Value {
+    serde_json::to_value(schema_for!(FunctionSpec)).expect("schema serialization should not fail")
+}
+
+pub fn generate_component_schema() -> serde_json::Value {
+    serde_json::to_value(schema_for!(ComponentSpec)).expect("schema serialization should not fail")
+}
+
 /// Validate a JSON value against a JSON Schema, returning errors in `ErrorEntry` format.
 pub fn validate_against_schema(
     value: &serde_json::Value,
@@ -77,6 +77,19 @@ pub enum AstNode {
     /// Expression used as statement
     ExpressionStatement { tier: Tier, expr: Box<AstNode> },
 
+    /// Inline use of a component (*.component.json), optionally binding its result
+    UseComponent {
+        tier: Tier,
+        #[serde(rename = "ref")]
+        reference: String,
+        #[serde(rename = "typeArgs", default, skip_serializing_if = "Vec::is_empty")]
+        type_args: Vec<String>,
+        #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+        inputs: IndexMap<String, AstNode>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        bind: Option<String>,
+    },
+
     // ── Expressions (Tier 0) ──
     /// Literal value (string, number, boolean, null)
     Literal {
@@ -109,6 +122,13 @@ pub enum AstNode {
         args: Vec<AstNode>,
     },
 
+    /// Call to a user-defined function (*.function.json)
+    CallFunction {
+        tier: Tier,
+        function: String,
+        args: Vec<AstNode>,
+    },
+
     /// Member access: `object.property`
     MemberExpr {
         tier: Tier,
@@ -152,7 +172,7 @@ pub enum AstNode {
     },
 
     // ── Domain Nodes (Tier 1) ──
-    /// Database query (findOne, findMany, count, etc.)
+    /// Database query (findUnique, findFirst, findMany, count)
     DbQuery {
         tier: Tier,
         model: String,
@@ -171,7 +191,7 @@ pub enum AstNode {
         include: Option<serde_json::Value>,
     },
 
-    /// Database mutation (insert, update, delete)
+    /// Database mutation (create, update, delete)
     DbMutate {
         tier: Tier,
         model: String,
@@ -182,6 +202,14 @@ pub enum AstNode {
         r#where: Option<serde_json::Value>,
     },
 
+    /// Database transaction: the body commits atomically, a throw rolls it back
+    DbTransaction {
+        tier: Tier,
+        body: Vec<AstNode>,
+        #[serde(rename = "isolationLevel", skip_serializing_if = "Option::is_none")]
+        isolation_level: Option<IsolationLevel>,
+    },
+
     /// HTTP response
     HttpRespond {
         tier: Tier,
@@ -289,6 +317,16 @@ pub struct CatchClause {
     pub body: Vec<AstNode>,
 }
 
+/// Isolation level for DbTransaction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum IsolationLevel {
+    ReadUncommitted,
+    ReadCommitted,
+    RepeatableRead,
+    Serializable,
+}
+
 /// Template string part
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(tag = "kind")]
@@ -424,6 +462,90 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_db_transaction_roundtrip() {
+        let json = serde_json::json!({
+            "type": "DbTransaction",
+            "tier": 1,
+            "isolationLevel": "serializable",
+            "body": [
+                {
+                    "type": "DbMutate",
+                    "tier": 1,
+                    "model": "Order",
+                    "operation": "create",
+                    "data": { "type": "Identifier", "tier": 0, "name": "order" }
+                }
+            ]
+        });
+
+        let node: AstNode = serde_json::from_value(json.clone()).unwrap();
+        match &node {
+            AstNode::DbTransaction {
+                body,
+                isolation_level,
+                ..
+            } => {
+                assert_eq!(body.len(), 1);
+                assert_eq!(*isolation_level, Some(IsolationLevel::Serializable));
+            }
+            _ => panic!("Expected DbTransaction"),
+        }
+        assert_eq!(serde_json::to_value(&node).unwrap(), json);
+    }
+
+    #[test]
+    fn test_call_function_roundtrip() {
+        let json = serde_json::json!({
+            "type": "CallFunction",
+            "tier": 0,
+            "function": "formatCurrency",
+            "args": [{ "type": "Identifier", "tier": 0, "name": "total" }]
+        });
+
+        let node: AstNode = serde_json::from_value(json.clone()).unwrap();
+        match &node {
+            AstNode::CallFunction { function, args, .. } => {
+                assert_eq!(function, "formatCurrency");
+                assert_eq!(args.len(), 1);
+            }
+            _ => panic!("Expected CallFunction"),
+        }
+        assert_eq!(serde_json::to_value(&node).unwrap(), json);
+    }
+
+    #[test]
+    fn test_use_component_roundtrip() {
+        let json = serde_json::json!({
+            "type": "UseComponent",
+            "tier": 0,
+            "ref": "paginate",
+            "typeArgs": ["User"],
+            "inputs": {
+                "model": { "type": "Literal", "tier": 0, "value": "User" }
+            },
+            "bind": "result"
+        });
+
+        let node: AstNode = serde_json::from_value(json.clone()).unwrap();
+        match &node {
+            AstNode::UseComponent {
+                reference,
+                type_args,
+                inputs,
+                bind,
+                ..
+            } => {
+                assert_eq!(reference, "paginate");
+                assert_eq!(type_args, &vec!["User".to_string()]);
+                assert!(inputs.contains_key("model"));
+                assert_eq!(bind.as_deref(), Some("result"));
+            }
+            _ => panic!("Expected UseComponent"),
+        }
+        assert_eq!(serde_json::to_value(&node).unwrap(), json);
+    }
+
     #[test]
     fn test_native_bridge_deserialization() {
         let json = serde_json::json!({
@@ -0,0 +1,136 @@
+use indexmap::IndexMap;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::ast::AstNode;
+use super::common::TypeRef;
+use super::handler::HandlerMeta;
+
+/// Component specification (*.component.json)
+///
+/// A parameterized handler fragment. `UseComponent` expands the body inline at
+/// IR conversion time, substituting `$input.<name>` placeholders with the
+/// caller's inputs.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct ComponentSpec {
+    /// JSON Schema reference
+    #[serde(rename = "$schema", skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
+
+    /// Component name (e.g., "paginate")
+    pub name: String,
+
+    /// Description
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Informational version string
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+
+    /// Palette category (e.g., "query")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+
+    /// Generic type parameters bound by `UseComponent.typeArgs`
+    #[serde(rename = "typeParams", default, skip_serializing_if = "Vec::is_empty")]
+    pub type_params: Vec<String>,
+
+    /// Inputs supplied by the caller
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub inputs: IndexMap<String, ComponentInput>,
+
+    /// Inputs read from the request context
+    #[serde(
+        rename = "contextInputs",
+        default,
+        skip_serializing_if = "IndexMap::is_empty"
+    )]
+    pub context_inputs: IndexMap<String, ContextInput>,
+
+    /// Shape of the value the body returns
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub outputs: IndexMap<String, serde_json::Value>,
+
+    /// Component body (array of AST statements)
+    pub body: Vec<AstNode>,
+
+    /// Component metadata
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub meta: Option<HandlerMeta>,
+}
+
+/// Caller-supplied component input
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct ComponentInput {
+    #[serde(rename = "type")]
+    pub input_type: TypeRef,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub optional: bool,
+}
+
+fn is_false(v: &bool) -> bool {
+    !(*v)
+}
+
+/// Component input bound from the request context
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct ContextInput {
+    /// Context path (e.g., "query.page")
+    pub source: String,
+    #[serde(rename = "type")]
+    pub input_type: TypeRef,
+    /// Literal value used when the context value is missing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<serde_json::Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_component_deserialization_from_docs() {
+        let json = serde_json::json!({
+            "$schema": "https://rash.dev/schemas/component.json",
+            "name": "paginate",
+            "version": "1.0.0",
+            "category": "query",
+            "typeParams": ["T"],
+            "inputs": {
+                "model": { "type": "string", "description": "대상 모델명" },
+                "where": { "type": "object", "optional": true }
+            },
+            "contextInputs": {
+                "page": { "source": "query.page", "type": "integer", "default": 1 }
+            },
+            "outputs": {
+                "data": { "type": "array", "items": { "typeParam": "T" } },
+                "total": { "type": "integer" }
+            },
+            "body": [
+                {
+                    "type": "ReturnStatement",
+                    "tier": 0,
+                    "value": { "type": "Identifier", "tier": 0, "name": "page" }
+                }
+            ],
+            "meta": { "maxTier": 1 }
+        });
+
+        let component: ComponentSpec = serde_json::from_value(json).unwrap();
+        assert_eq!(component.name, "paginate");
+        assert_eq!(component.type_params, vec!["T".to_string()]);
+        assert!(!component.inputs["model"].optional);
+        assert!(component.inputs["where"].optional);
+        assert_eq!(component.context_inputs["page"].source, "query.page");
+        assert_eq!(
+            component.context_inputs["page"].default,
+            Some(serde_json::json!(1))
+        );
+        assert_eq!(component.outputs.len(), 2);
+        assert_eq!(component.body.len(), 1);
+    }
+}
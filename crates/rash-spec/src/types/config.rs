@@ -102,6 +102,10 @@ pub struct CodegenConfig {
 
     #[serde(default)]
     pub strict: bool,
+
+    /// Emit an HMU client so the running server accepts hot module updates
+    #[serde(default)]
+    pub hmu: bool,
 }
 
 fn default_out_dir() -> String {
@@ -18,6 +18,21 @@ pub const E_VERSION_MISMATCH: &str = "E_VERSION_MISMATCH";
 pub const E_MIGRATION_FAILED: &str = "E_MIGRATION_FAILED";
 pub const E_SCHEMA_VIOLATION: &str = "E_SCHEMA_VIOLATION";
 pub const E_INCOMPATIBLE_TARGET: &str = "E_INCOMPATIBLE_TARGET";
+pub const E_UNKNOWN_FIELD: &str = "E_UNKNOWN_FIELD";
+pub const E_TYPE_MISMATCH: &str = "E_TYPE_MISMATCH";
+pub const E_RESPONSE_MISMATCH: &str = "E_RESPONSE_MISMATCH";
+pub const E_UNDEFINED_VARIABLE: &str = "E_UNDEFINED_VARIABLE";
+pub const E_SHADOWED_VARIABLE: &str = "E_SHADOWED_VARIABLE";
+pub const E_UNUSED_VARIABLE: &str = "E_UNUSED_VARIABLE";
+pub const E_UNKNOWN_ROUTE_PARAM: &str = "E_UNKNOWN_ROUTE_PARAM";
+pub const E_UNEXPECTED_REQUEST_BODY: &str = "E_UNEXPECTED_REQUEST_BODY";
+pub const E_UNDECLARED_RESPONSE: &str = "E_UNDECLARED_RESPONSE";
+pub const E_UNUSED_RESPONSE: &str = "E_UNUSED_RESPONSE";
+pub const E_UNPROVIDED_CONTEXT: &str = "E_UNPROVIDED_CONTEXT";
+pub const E_INVALID_RELATION: &str = "E_INVALID_RELATION";
+pub const E_UNKNOWN_COLUMN: &str = "E_UNKNOWN_COLUMN";
+pub const E_INVALID_COLUMN_TYPE: &str = "E_INVALID_COLUMN_TYPE";
+pub const E_UNKNOWN_OPERATION: &str = "E_UNKNOWN_OPERATION";
 
 /// A single validation/parsing error entry
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
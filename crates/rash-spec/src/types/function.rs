@@ -0,0 +1,95 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::ast::AstNode;
+use super::common::TypeRef;
+use super::handler::HandlerMeta;
+
+/// User-defined function specification (*.function.json)
+///
+/// A pure, reusable unit of logic. Handlers call it through `CallFunction`;
+/// codegen extracts every function into a shared utility module.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct FunctionSpec {
+    /// JSON Schema reference
+    #[serde(rename = "$schema", skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
+
+    /// Function name (e.g., "formatCurrency")
+    pub name: String,
+
+    /// Description
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Whether this function is free of side effects
+    #[serde(default = "default_pure")]
+    pub pure: bool,
+
+    /// Ordered parameters
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub params: Vec<FunctionParam>,
+
+    /// Return type
+    #[serde(rename = "returnType", skip_serializing_if = "Option::is_none")]
+    pub return_type: Option<TypeRef>,
+
+    /// Function body (array of AST statements)
+    pub body: Vec<AstNode>,
+
+    /// Function metadata
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub meta: Option<HandlerMeta>,
+}
+
+fn default_pure() -> bool {
+    true
+}
+
+/// Function parameter definition
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct FunctionParam {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub param_type: TypeRef,
+    /// Literal value used when the caller omits this argument
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<serde_json::Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_function_deserialization_from_docs() {
+        let json = serde_json::json!({
+            "$schema": "https://rash.dev/schemas/function.json",
+            "name": "formatCurrency",
+            "description": "숫자를 통화 형식 문자열로 변환",
+            "pure": true,
+            "params": [
+                { "name": "amount", "type": "number" },
+                { "name": "currency", "type": "string", "default": "KRW" }
+            ],
+            "returnType": "string",
+            "body": [
+                {
+                    "type": "ReturnStatement",
+                    "tier": 0,
+                    "value": { "type": "Identifier", "tier": 0, "name": "amount" }
+                }
+            ],
+            "meta": { "maxTier": 2 }
+        });
+
+        let func: FunctionSpec = serde_json::from_value(json).unwrap();
+        assert_eq!(func.name, "formatCurrency");
+        assert!(func.pure);
+        assert_eq!(func.params.len(), 2);
+        assert_eq!(func.params[0].default, None);
+        assert_eq!(func.params[1].default, Some(serde_json::json!("KRW")));
+        assert_eq!(func.return_type, Some(TypeRef::Simple("string".into())));
+        assert_eq!(func.body.len(), 1);
+    }
+}
@@ -1,7 +1,9 @@
 pub mod ast;
 pub mod common;
+pub mod component;
 pub mod config;
 pub mod error;
+pub mod function;
 pub mod handler;
 pub mod middleware;
 pub mod model;
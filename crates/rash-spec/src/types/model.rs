@@ -97,6 +97,10 @@ pub struct RelationSpec {
 
     /// Foreign key column name
     pub foreign_key: String,
+
+    /// Join model for `manyToMany` (synthesized when omitted)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub through: Option<String>,
 }
 
 /// Types of model relations
@@ -1,2 +1,3 @@
 pub mod rules;
+mod suggest;
 pub mod validator;
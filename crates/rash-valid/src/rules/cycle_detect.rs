@@ -1,11 +1,12 @@
 use std::collections::{HashMap, HashSet};
 
-use rash_spec::index::SpecIndex;
+use rash_spec::index::{SpecIndex, SymbolKind};
 use rash_spec::loader::LoadedProject;
 use rash_spec::types::error::{ErrorEntry, ValidationReport, E_REF_CYCLE};
 
-/// Detect circular references between schemas using DFS.
-pub fn check(project: &LoadedProject, _index: &SpecIndex, report: &mut ValidationReport) {
+/// Detect circular references between schemas, between components and
+/// between composed middleware using DFS.
+pub fn check(project: &LoadedProject, index: &SpecIndex, report: &mut ValidationReport) {
     // Build adjacency list for schema references
     let mut graph: HashMap<String, Vec<(String, String)>> = HashMap::new(); // name -> [(target, file)]
 
@@ -53,6 +54,141 @@ pub fn check(project: &LoadedProject, _index: &SpecIndex, report: &mut Validatio
             }
         }
     }
+
+    check_component_cycles(project, report);
+    check_middleware_cycles(project, index, report);
+}
+
+/// Detect components that (transitively) use themselves, which would never
+/// finish expanding.
+fn check_component_cycles(project: &LoadedProject, report: &mut ValidationReport) {
+    let mut graph: HashMap<String, Vec<(String, String)>> = HashMap::new();
+    let mut files: HashMap<String, String> = HashMap::new();
+
+    for (file, component) in &project.components {
+        files.insert(component.name.clone(), file.clone());
+        let body = serde_json::to_value(&component.body).unwrap_or_default();
+        let mut uses = Vec::new();
+        collect_component_uses(&body, &mut uses);
+        graph
+            .entry(component.name.clone())
+            .or_default()
+            .extend(uses.into_iter().map(|target| (target, file.clone())));
+    }
+
+    let mut visited = HashSet::new();
+    let mut in_stack = HashSet::new();
+    let mut names: Vec<&String> = graph.keys().collect();
+    names.sort();
+
+    for node in names {
+        if visited.contains(node) {
+            continue;
+        }
+        let mut path = Vec::new();
+        if let Some(cycle) = dfs_find_cycle(node, &graph, &mut visited, &mut in_stack, &mut path) {
+            let file = files
+                .get(&cycle[0])
+                .map(|f| f.as_str())
+                .unwrap_or("unknown");
+            report.push(
+                ErrorEntry::error(
+                    E_REF_CYCLE,
+                    format!("Circular component use detected: {}", cycle.join(" -> ")),
+                    file,
+                    "$.body",
+                )
+                .with_suggestion("Remove the UseComponent node that closes the cycle"),
+            );
+        }
+    }
+}
+
+/// Detect composed middleware that (transitively) composes itself, which
+/// would never finish expanding into a chain.
+fn check_middleware_cycles(
+    project: &LoadedProject,
+    index: &SpecIndex,
+    report: &mut ValidationReport,
+) {
+    let mut graph: HashMap<String, Vec<(String, String)>> = HashMap::new();
+    let mut files: HashMap<String, String> = HashMap::new();
+
+    for (file, mw) in &project.middleware {
+        files.insert(mw.name.clone(), file.clone());
+        // Unresolved refs are reported by ref_integrity
+        let targets = mw
+            .compose
+            .iter()
+            .flatten()
+            .filter_map(|r| index.lookup(&r.reference, SymbolKind::Middleware))
+            .map(|entry| (entry.original_name.clone(), file.clone()));
+        graph.entry(mw.name.clone()).or_default().extend(targets);
+    }
+
+    let mut visited = HashSet::new();
+    let mut in_stack = HashSet::new();
+    let mut names: Vec<&String> = graph.keys().collect();
+    names.sort();
+
+    for node in names {
+        if visited.contains(node) {
+            continue;
+        }
+        let mut path = Vec::new();
+        if let Some(cycle) = dfs_find_cycle(node, &graph, &mut visited, &mut in_stack, &mut path) {
+            let file = files
+                .get(&cycle[0])
+                .map(|f| f.as_str())
+                .unwrap_or("unknown");
+            let position = project
+                .middleware
+                .iter()
+                .find(|(_, mw)| mw.name == cycle[0])
+                .and_then(|(_, mw)| {
+                    mw.compose.iter().flatten().position(|r| {
+                        index
+                            .lookup(&r.reference, SymbolKind::Middleware)
+                            .is_some_and(|entry| entry.original_name == cycle[1])
+                    })
+                })
+                .unwrap_or(0);
+            report.push(
+                ErrorEntry::error(
+                    E_REF_CYCLE,
+                    format!(
+                        "Circular middleware composition detected: {}",
+                        cycle.join(" -> ")
+                    ),
+                    file,
+                    &format!("$.compose[{}]", position),
+                )
+                .with_suggestion("Remove the compose ref that closes the cycle"),
+            );
+        }
+    }
+}
+
+/// Collect the `ref` of every `UseComponent` node in a serialized AST subtree.
+fn collect_component_uses(value: &serde_json::Value, uses: &mut Vec<String>) {
+    match value {
+        serde_json::Value::Object(map) => {
+            if map.get("type").and_then(|t| t.as_str()) == Some("UseComponent") {
+                if let Some(serde_json::Value::String(r)) = map.get("ref") {
+                    uses.push(r.clone());
+                }
+            }
+            for v in map.values() {
+                collect_component_uses(v, uses);
+            }
+        }
+        serde_json::Value::Array(arr) => {
+            for v in arr {
+                collect_component_uses(v, uses);
+            }
+        }
+        _ => {}
+    }
 }
 
 /// DFS to find cycles, returns the cycle path if found
@@ -200,6 +336,66 @@ mod tests {
         assert!(refs.contains(&"Tag".to_string()));
     }
 
+    #[test]
+    fn test_collect_component_uses_nested() {
+        let body = serde_json::json!([
+            { "type": "UseComponent", "tier": 0, "ref": "paginate" },
+            {
+                "type": "IfStatement",
+                "tier": 0,
+                "condition": { "type": "Literal", "tier": 0, "value": true },
+                "then": [{ "type": "UseComponent", "tier": 0, "ref": "auditLog" }]
+            }
+        ]);
+
+        let mut uses = Vec::new();
+        collect_component_uses(&body, &mut uses);
+        assert_eq!(uses, vec!["paginate".to_string(), "auditLog".to_string()]);
+    }
+
+    #[test]
+    fn test_middleware_composition_cycle() {
+        let dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("../../fixtures/golden-user-crud");
+        let (mut project, _) = rash_spec::loader::load_project(&dir).unwrap();
+        let secured = serde_json::from_value(serde_json::json!({
+            "name": "secured",
+            "type": "composed",
+            "compose": [{ "ref": "validate" }, { "ref": "auth" }],
+            "shortCircuit": true
+        }))
+        .unwrap();
+        project
+            .middleware
+            .push(("middleware/secured.middleware.json".to_string(), secured));
+        let (index, _) = rash_spec::index::build_index(&project);
+
+        let mut report = ValidationReport::success();
+        check_middleware_cycles(&project, &index, &mut report);
+        assert!(report.errors.is_empty(), "{:?}", report.errors);
+
+        // auth composing secured closes auth -> secured -> auth
+        let (_, auth) = project
+            .middleware
+            .iter_mut()
+            .find(|(_, mw)| mw.name == "auth")
+            .unwrap();
+        auth.compose = Some(vec![
+            serde_json::from_value(serde_json::json!({ "ref": "secured" })).unwrap(),
+        ]);
+        let (index, _) = rash_spec::index::build_index(&project);
+        let mut report = ValidationReport::success();
+        check_middleware_cycles(&project, &index, &mut report);
+        assert_eq!(report.errors.len(), 1);
+        assert_eq!(report.errors[0].code, E_REF_CYCLE);
+        assert_eq!(
+            report.errors[0].message,
+            "Circular middleware composition detected: auth -> secured -> auth"
+        );
+        assert_eq!(report.errors[0].file, "middleware/auth.middleware.json");
+        assert_eq!(report.errors[0].path, "$.compose[0]");
+    }
+
     #[test]
     fn test_dfs_no_cycle() {
         let mut graph = HashMap::new();
@@ -0,0 +1,598 @@
+use rash_spec::loader::LoadedProject;
+use rash_spec::resolver::{RefContext, ResolveResult, Resolver};
+use rash_spec::types::error::{
+    ErrorEntry, ValidationReport, E_REF_NOT_FOUND, E_UNKNOWN_FIELD, E_UNKNOWN_OPERATION,
+};
+use rash_spec::types::model::ModelSpec;
+
+use crate::suggest::did_you_mean;
+
+/// What a database operation evaluates to, relative to the model's row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DbResult {
+    /// One row
+    Row,
+    /// One row, or none when nothing matched
+    OptionalRow,
+    /// Every matching row
+    Rows,
+    /// Number of matching rows
+    Count,
+}
+
+/// Operations `DbQuery` lowers to in every emitter, with what each returns.
+pub(crate) const QUERY_OPERATIONS: &[(&str, DbResult)] = &[
+    ("findUnique", DbResult::OptionalRow),
+    ("findFirst", DbResult::OptionalRow),
+    ("findMany", DbResult::Rows),
+    ("count", DbResult::Count),
+];
+
+/// Operations `DbMutate` lowers to in every emitter, with what each returns.
+pub(crate) const MUTATE_OPERATIONS: &[(&str, DbResult)] = &[
+    ("create", DbResult::Row),
+    ("update", DbResult::Row),
+    ("delete", DbResult::Row),
+];
+
+/// Result of `operation`, if it is one of `operations`.
+pub(crate) fn operation_result(
+    operations: &[(&str, DbResult)],
+    operation: &str,
+) -> Option<DbResult> {
+    operations
+        .iter()
+        .find(|(name, _)| *name == operation)
+        .map(|(_, result)| *result)
+}
+
+/// Names from other ORMs that map to one of our operations.
+const OPERATION_ALIASES: &[(&str, &str)] = &[
+    ("findOne", "findFirst"),
+    ("findById", "findUnique"),
+    ("findAll", "findMany"),
+    ("insert", "create"),
+    ("remove", "delete"),
+    ("destroy", "delete"),
+];
+
+/// Check the model, operation and every field named by `DbQuery`/`DbMutate`
+/// nodes in handler, function and component bodies.
+pub fn check(project: &LoadedProject, resolver: &Resolver, report: &mut ValidationReport) {
+    let bodies = project
+        .handlers
+        .iter()
+        .map(|(file, h)| (file, serde_json::to_value(&h.body)))
+        .chain(
+            project
+                .functions
+                .iter()
+                .map(|(file, f)| (file, serde_json::to_value(&f.body))),
+        )
+        .chain(
+            project
+                .components
+                .iter()
+                .map(|(file, c)| (file, serde_json::to_value(&c.body))),
+        );
+    for (file, body) in bodies {
+        let body = body.unwrap_or_default();
+        check_body(&body, "$.body", project, resolver, file, report);
+    }
+}
+
+fn check_body(
+    value: &serde_json::Value,
+    path: &str,
+    project: &LoadedProject,
+    resolver: &Resolver,
+    file: &str,
+    report: &mut ValidationReport,
+) {
+    match value {
+        serde_json::Value::Object(map) => {
+            let operations = match map.get("type").and_then(|t| t.as_str()) {
+                Some("DbQuery") => Some(QUERY_OPERATIONS),
+                Some("DbMutate") => Some(MUTATE_OPERATIONS),
+                _ => None,
+            };
+            if let Some(operations) = operations {
+                check_db_node(map, operations, path, project, resolver, file, report);
+            }
+            for (key, v) in map {
+                check_body(
+                    v,
+                    &format!("{}.{}", path, key),
+                    project,
+                    resolver,
+                    file,
+                    report,
+                );
+            }
+        }
+        serde_json::Value::Array(arr) => {
+            for (i, v) in arr.iter().enumerate() {
+                check_body(
+                    v,
+                    &format!("{}[{}]", path, i),
+                    project,
+                    resolver,
+                    file,
+                    report,
+                );
+            }
+        }
+        _ => {}
+    }
+}
+
+fn check_db_node(
+    node: &serde_json::Map<String, serde_json::Value>,
+    operations: &[(&str, DbResult)],
+    path: &str,
+    project: &LoadedProject,
+    resolver: &Resolver,
+    file: &str,
+    report: &mut ValidationReport,
+) {
+    if let Some(operation) = node.get("operation").and_then(|o| o.as_str()) {
+        if operation_result(operations, operation).is_none() {
+            report.push(
+                ErrorEntry::error(
+                    E_UNKNOWN_OPERATION,
+                    format!("Unknown database operation '{}'", operation),
+                    file,
+                    &format!("{}.operation", path),
+                )
+                .with_suggestion(operation_suggestion(operation, operations)),
+            );
+        }
+    }
+
+    let Some(model_name) = node.get("model").and_then(|m| m.as_str()) else {
+        return;
+    };
+    // Component placeholders are checked once substituted
+    if model_name.starts_with("$input.") {
+        return;
+    }
+    let model_path = format!("{}.model", path);
+    let Some(model) = resolve_model(project, resolver, model_name, file, &model_path, report)
+    else {
+        return;
+    };
+
+    let checker = FieldChecker { file, model };
+    if let Some(select) = node.get("select").and_then(|s| s.as_array()) {
+        for (i, field) in select.iter().enumerate() {
+            if let Some(field) = field.as_str() {
+                checker.field(field, &format!("{}.select[{}]", path, i), report);
+            }
+        }
+    }
+    if let Some(filter) = node.get("where") {
+        checker.filter(filter, &format!("{}.where", path), report);
+    }
+    if let Some(order_by) = node.get("orderBy") {
+        let order_path = format!("{}.orderBy", path);
+        match order_by {
+            serde_json::Value::Array(entries) => {
+                for (i, entry) in entries.iter().enumerate() {
+                    checker.keys(entry, &format!("{}[{}]", order_path, i), report);
+                }
+            }
+            other => checker.keys(other, &order_path, report),
+        }
+    }
+    if let Some(include) = node.get("include") {
+        check_include(
+            project,
+            resolver,
+            model,
+            include,
+            &format!("{}.include", path),
+            file,
+            report,
+        );
+    }
+    if let Some(data) = node.get("data") {
+        // Only literal objects have statically known keys
+        if data.get("type").and_then(|t| t.as_str()) == Some("ObjectExpr") {
+            if let Some(properties) = data.get("properties") {
+                checker.keys(properties, &format!("{}.data.properties", path), report);
+            }
+        }
+    }
+}
+
+fn operation_suggestion(operation: &str, operations: &[(&str, DbResult)]) -> String {
+    OPERATION_ALIASES
+        .iter()
+        .find(|(alias, op)| *alias == operation && operation_result(operations, op).is_some())
+        .map(|(_, op)| format!("Did you mean '{}'?", op))
+        .unwrap_or_else(|| did_you_mean(operation, operations.iter().map(|(name, _)| *name)))
+}
+
+/// Resolve a model reference, reporting it with a did-you-mean suggestion
+/// when it doesn't exist.
+fn resolve_model<'a>(
+    project: &'a LoadedProject,
+    resolver: &Resolver,
+    name: &str,
+    file: &str,
+    path: &str,
+    report: &mut ValidationReport,
+) -> Option<&'a ModelSpec> {
+    match resolver.resolve(name, RefContext::Model) {
+        ResolveResult::Found(entry) => project
+            .models
+            .iter()
+            .find(|(f, model)| *f == entry.file && model.name == entry.original_name)
+            .map(|(_, model)| model),
+        ResolveResult::NotFound => {
+            let models = project.models.iter().map(|(_, m)| m.name.as_str());
+            report.push(
+                ErrorEntry::error(
+                    E_REF_NOT_FOUND,
+                    format!("Model '{}' not found", name),
+                    file,
+                    path,
+                )
+                .with_suggestion(did_you_mean(name, models)),
+            );
+            None
+        }
+        _ => {
+            if let Err(err) = resolver.resolve_or_error(name, RefContext::Model, file, path) {
+                report.push(err);
+            }
+            None
+        }
+    }
+}
+
+/// Validate `include` (`["posts"]` or `{ "posts": { "include": { ... } } }`),
+/// following nested includes into the related model.
+fn check_include(
+    project: &LoadedProject,
+    resolver: &Resolver,
+    model: &ModelSpec,
+    include: &serde_json::Value,
+    path: &str,
+    file: &str,
+    report: &mut ValidationReport,
+) {
+    let checker = FieldChecker { file, model };
+    match include {
+        serde_json::Value::Array(relations) => {
+            for (i, relation) in relations.iter().enumerate() {
+                if let Some(relation) = relation.as_str() {
+                    checker.relation(relation, &format!("{}[{}]", path, i), report);
+                }
+            }
+        }
+        serde_json::Value::Object(relations) => {
+            for (relation, nested) in relations {
+                let relation_path = format!("{}.{}", path, relation);
+                if !checker.relation(relation, &relation_path, report) {
+                    continue;
+                }
+                let Some(nested) = nested.get("include") else {
+                    continue;
+                };
+                let target = &model.relations[relation].target;
+                if let ResolveResult::Found(entry) = resolver.resolve(target, RefContext::Model) {
+                    let target = project
+                        .models
+                        .iter()
+                        .find(|(f, m)| *f == entry.file && m.name == entry.original_name);
+                    if let Some((_, target)) = target {
+                        check_include(
+                            project,
+                            resolver,
+                            target,
+                            nested,
+                            &format!("{}.include", relation_path),
+                            file,
+                            report,
+                        );
+                    }
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Field lookups against one model's columns and relations.
+struct FieldChecker<'a> {
+    file: &'a str,
+    model: &'a ModelSpec,
+}
+
+impl FieldChecker<'_> {
+    fn field_names(&self) -> impl Iterator<Item = &str> + Clone {
+        self.model
+            .columns
+            .keys()
+            .chain(self.model.relations.keys())
+            .map(String::as_str)
+    }
+
+    fn field(&self, name: &str, path: &str, report: &mut ValidationReport) {
+        if self.model.columns.contains_key(name) || self.model.relations.contains_key(name) {
+            return;
+        }
+        report.push(
+            ErrorEntry::error(
+                E_UNKNOWN_FIELD,
+                format!("Model '{}' has no field '{}'", self.model.name, name),
+                self.file,
+                path,
+            )
+            .with_suggestion(did_you_mean(name, self.field_names())),
+        );
+    }
+
+    /// Whether `name` is a relation of the model, reporting it if not.
+    fn relation(&self, name: &str, path: &str, report: &mut ValidationReport) -> bool {
+        if self.model.relations.contains_key(name) {
+            return true;
+        }
+        report.push(
+            ErrorEntry::error(
+                E_UNKNOWN_FIELD,
+                format!("Model '{}' has no relation '{}'", self.model.name, name),
+                self.file,
+                path,
+            )
+            .with_suggestion(did_you_mean(
+                name,
+                self.model.relations.keys().map(String::as_str),
+            )),
+        );
+        false
+    }
+
+    /// Check every key of an object as a field name.
+    fn keys(&self, value: &serde_json::Value, path: &str, report: &mut ValidationReport) {
+        if let Some(map) = value.as_object() {
+            for key in map.keys() {
+                self.field(key, &format!("{}.{}", path, key), report);
+            }
+        }
+    }
+
+    /// Check the field keys of a `where` filter, descending into `AND`/`OR`/`NOT`.
+    fn filter(&self, value: &serde_json::Value, path: &str, report: &mut ValidationReport) {
+        let Some(map) = value.as_object() else {
+            return;
+        };
+        // A whole filter supplied as an expression (e.g. a component input)
+        if map.contains_key("type") {
+            return;
+        }
+        for (key, v) in map {
+            let key_path = format!("{}.{}", path, key);
+            if matches!(key.as_str(), "AND" | "OR" | "NOT") {
+                match v {
+                    serde_json::Value::Array(filters) => {
+                        for (i, f) in filters.iter().enumerate() {
+                            self.filter(f, &format!("{}[{}]", key_path, i), report);
+                        }
+                    }
+                    other => self.filter(other, &key_path, report),
+                }
+            } else {
+                self.field(key, &key_path, report);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rash_spec::index::build_index;
+    use std::path::PathBuf;
+
+    fn golden_project() -> LoadedProject {
+        let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .parent()
+            .unwrap()
+            .parent()
+            .unwrap()
+            .join("fixtures/golden-user-crud");
+        let (project, _) = rash_spec::loader::load_project(&dir).unwrap();
+        project
+    }
+
+    fn run_with_body(body: serde_json::Value) -> Vec<ErrorEntry> {
+        let mut project = golden_project();
+        project
+            .handlers
+            .iter_mut()
+            .find(|(_, h)| h.name == "users.listUsers")
+            .unwrap()
+            .1
+            .body = serde_json::from_value(body).unwrap();
+        let (index, _) = build_index(&project);
+        let resolver = Resolver::new(&index);
+        let mut report = ValidationReport::success();
+        check(&project, &resolver, &mut report);
+        report.errors
+    }
+
+    fn found(errors: &[ErrorEntry]) -> Vec<(&str, &str, &str)> {
+        errors
+            .iter()
+            .map(|e| {
+                (
+                    e.code.as_str(),
+                    e.path.as_str(),
+                    e.suggestion.as_deref().unwrap_or_default(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_golden_queries_are_valid() {
+        let project = golden_project();
+        let (index, _) = build_index(&project);
+        let resolver = Resolver::new(&index);
+        let mut report = ValidationReport::success();
+        check(&project, &resolver, &mut report);
+        assert!(report.errors.is_empty(), "{:?}", report.errors);
+    }
+
+    #[test]
+    fn test_unknown_model_and_operation() {
+        let errors = run_with_body(serde_json::json!([
+            {
+                "type": "ExpressionStatement", "tier": 0,
+                "expr": { "type": "DbQuery", "tier": 1, "model": "Users", "operation": "findMany" }
+            },
+            {
+                "type": "ExpressionStatement", "tier": 0,
+                "expr": { "type": "DbMutate", "tier": 1, "model": "Post", "operation": "insert" }
+            }
+        ]));
+
+        assert_eq!(
+            found(&errors),
+            vec![
+                (
+                    E_REF_NOT_FOUND,
+                    "$.body[0].expr.model",
+                    "Did you mean 'User'?"
+                ),
+                (
+                    E_UNKNOWN_OPERATION,
+                    "$.body[1].expr.operation",
+                    "Did you mean 'create'?"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_operations_no_emitter_lowers_are_unknown() {
+        let errors = run_with_body(serde_json::json!([
+            {
+                "type": "ExpressionStatement", "tier": 0,
+                "expr": { "type": "DbQuery", "tier": 1, "model": "User", "operation": "aggregate" }
+            },
+            {
+                "type": "ExpressionStatement", "tier": 0,
+                "expr": { "type": "DbMutate", "tier": 1, "model": "User", "operation": "upsert" }
+            },
+            {
+                "type": "ExpressionStatement", "tier": 0,
+                "expr": { "type": "DbMutate", "tier": 1, "model": "User", "operation": "deleteMany" }
+            }
+        ]));
+
+        let codes: Vec<(&str, &str)> = found(&errors)
+            .into_iter()
+            .map(|(code, path, _)| (code, path))
+            .collect();
+        assert_eq!(
+            codes,
+            vec![
+                (E_UNKNOWN_OPERATION, "$.body[0].expr.operation"),
+                (E_UNKNOWN_OPERATION, "$.body[1].expr.operation"),
+                (E_UNKNOWN_OPERATION, "$.body[2].expr.operation"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unknown_fields_in_select_where_order_and_data() {
+        let errors = run_with_body(serde_json::json!([
+            {
+                "type": "LetStatement", "tier": 0, "name": "users",
+                "value": {
+                    "type": "DbQuery", "tier": 1, "model": "User", "operation": "findMany",
+                    "select": ["id", "emial"],
+                    "where": {
+                        "role": "admin",
+                        "OR": [{ "nmae": { "contains": "a" } }, { "deletedAt": null }]
+                    },
+                    "orderBy": [{ "createdAt": "desc" }, { "created": "asc" }]
+                }
+            },
+            {
+                "type": "ExpressionStatement", "tier": 0,
+                "expr": {
+                    "type": "DbMutate", "tier": 1, "model": "Post", "operation": "create",
+                    "data": {
+                        "type": "ObjectExpr", "tier": 0,
+                        "properties": {
+                            "title": { "type": "Literal", "tier": 0, "value": "Hi" },
+                            "authorID": { "type": "Identifier", "tier": 0, "name": "users" }
+                        }
+                    }
+                }
+            }
+        ]));
+
+        assert_eq!(
+            found(&errors),
+            vec![
+                (
+                    E_UNKNOWN_FIELD,
+                    "$.body[0].value.select[1]",
+                    "Did you mean 'email'?"
+                ),
+                (
+                    E_UNKNOWN_FIELD,
+                    "$.body[0].value.where.OR[0].nmae",
+                    "Did you mean 'name'?"
+                ),
+                (
+                    E_UNKNOWN_FIELD,
+                    "$.body[0].value.orderBy[1].created",
+                    "Did you mean 'createdAt'?"
+                ),
+                (
+                    E_UNKNOWN_FIELD,
+                    "$.body[1].expr.data.properties.authorID",
+                    "Did you mean 'authorId'?"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_nested_include_follows_relation_target() {
+        let errors = run_with_body(serde_json::json!([
+            {
+                "type": "ExpressionStatement", "tier": 0,
+                "expr": {
+                    "type": "DbQuery", "tier": 1, "model": "User", "operation": "findMany",
+                    "include": {
+                        "email": true,
+                        "posts": { "include": { "autor": true } }
+                    }
+                }
+            }
+        ]));
+
+        assert_eq!(
+            found(&errors),
+            vec![
+                (
+                    E_UNKNOWN_FIELD,
+                    "$.body[0].expr.include.email",
+                    "Expected one of: posts, profile"
+                ),
+                (
+                    E_UNKNOWN_FIELD,
+                    "$.body[0].expr.include.posts.include.autor",
+                    "Did you mean 'author'?"
+                ),
+            ]
+        );
+    }
+}
@@ -1,5 +1,9 @@
 pub mod cycle_detect;
+pub mod db_check;
+pub mod model_check;
 pub mod ref_integrity;
 pub mod required_fields;
+pub mod route_contract;
+pub mod scope_check;
 pub mod type_consistency;
 pub mod version_check;
@@ -0,0 +1,426 @@
+use rash_spec::loader::LoadedProject;
+use rash_spec::resolver::{RefContext, ResolveResult, Resolver};
+use rash_spec::types::common::DatabaseType;
+use rash_spec::types::error::{
+    ErrorEntry, ValidationReport, E_INVALID_COLUMN_TYPE, E_INVALID_RELATION, E_UNKNOWN_COLUMN,
+};
+use rash_spec::types::model::{ModelSpec, RelationType};
+
+/// Column types every supported database (or its ORM) can represent.
+const PORTABLE_COLUMN_TYPES: &[&str] = &[
+    "string",
+    "text",
+    "varchar",
+    "char",
+    "uuid",
+    "integer",
+    "int",
+    "smallint",
+    "bigint",
+    "float",
+    "double",
+    "real",
+    "decimal",
+    "numeric",
+    "boolean",
+    "bool",
+    "date",
+    "time",
+    "datetime",
+    "timestamp",
+    "json",
+    "enum",
+];
+
+/// Check model relations, indexes and column types.
+pub fn check(project: &LoadedProject, resolver: &Resolver, report: &mut ValidationReport) {
+    let db_type = project.config.database.as_ref().map(|db| db.db_type);
+
+    for (file, model) in &project.models {
+        for (rel_name, rel) in &model.relations {
+            let rel_path = format!("$.relations.{}", rel_name);
+            // Unresolved targets are reported by ref_integrity
+            let Some(target) = resolve_model(project, resolver, &rel.target) else {
+                continue;
+            };
+
+            match rel.relation_type {
+                RelationType::BelongsTo => {
+                    check_foreign_key(model, model, &rel.foreign_key, file, &rel_path, report);
+                }
+                RelationType::HasOne | RelationType::HasMany => {
+                    check_foreign_key(model, target, &rel.foreign_key, file, &rel_path, report);
+                }
+                RelationType::ManyToMany => match &rel.through {
+                    Some(through) => match resolve_model(project, resolver, through) {
+                        Some(join) => check_foreign_key(
+                            model,
+                            join,
+                            &rel.foreign_key,
+                            file,
+                            &rel_path,
+                            report,
+                        ),
+                        None => report.push(
+                            ErrorEntry::error(
+                                E_INVALID_RELATION,
+                                format!("Join model '{}' not found", through),
+                                file,
+                                &format!("{}.through", rel_path),
+                            )
+                            .with_suggestion(format!(
+                                "Create model '{}' or omit 'through' to synthesize one",
+                                through
+                            )),
+                        ),
+                    },
+                    None => {
+                        // A join table is synthesized from both primary keys
+                        for side in [model, target] {
+                            if primary_keys(side).len() != 1 {
+                                report.push(
+                                    ErrorEntry::error(
+                                        E_INVALID_RELATION,
+                                        format!(
+                                            "Cannot synthesize a join model for '{}': '{}' needs exactly one primary key column",
+                                            rel_name, side.name
+                                        ),
+                                        file,
+                                        &format!("{}.type", rel_path),
+                                    )
+                                    .with_suggestion(
+                                        "Add a 'through' join model with both foreign keys",
+                                    ),
+                                );
+                            }
+                        }
+                    }
+                },
+            }
+        }
+
+        for (i, index) in model.indexes.iter().enumerate() {
+            for (j, column) in index.columns.iter().enumerate() {
+                if !model.columns.contains_key(column) {
+                    report.push(
+                        ErrorEntry::error(
+                            E_UNKNOWN_COLUMN,
+                            format!(
+                                "Index column '{}' does not exist on '{}'",
+                                column, model.name
+                            ),
+                            file,
+                            &format!("$.indexes[{}].columns[{}]", i, j),
+                        )
+                        .with_suggestion(column_suggestion(model)),
+                    );
+                }
+            }
+        }
+
+        if let Some(db_type) = db_type {
+            for (name, column) in &model.columns {
+                if !is_valid_column_type(&column.col_type, db_type) {
+                    report.push(
+                        ErrorEntry::error(
+                            E_INVALID_COLUMN_TYPE,
+                            format!(
+                                "Column type '{}' is not supported by {:?}",
+                                column.col_type, db_type
+                            ),
+                            file,
+                            &format!("$.columns.{}.type", name),
+                        )
+                        .with_suggestion(format!(
+                            "Use one of: {}",
+                            PORTABLE_COLUMN_TYPES
+                                .iter()
+                                .chain(specific_column_types(db_type))
+                                .copied()
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        )),
+                    );
+                }
+            }
+        }
+    }
+}
+
+fn resolve_model<'a>(
+    project: &'a LoadedProject,
+    resolver: &Resolver,
+    name: &str,
+) -> Option<&'a ModelSpec> {
+    let ResolveResult::Found(entry) = resolver.resolve(name, RefContext::Model) else {
+        return None;
+    };
+    project
+        .models
+        .iter()
+        .find(|(file, model)| *file == entry.file && model.name == entry.original_name)
+        .map(|(_, model)| model)
+}
+
+/// Report a relation whose foreign key isn't a column of `owner`, the side
+/// that stores it.
+fn check_foreign_key(
+    model: &ModelSpec,
+    owner: &ModelSpec,
+    foreign_key: &str,
+    file: &str,
+    rel_path: &str,
+    report: &mut ValidationReport,
+) {
+    if owner.columns.contains_key(foreign_key) {
+        return;
+    }
+    let location = if owner.name == model.name {
+        "this model".to_string()
+    } else {
+        format!("'{}'", owner.name)
+    };
+    report.push(
+        ErrorEntry::error(
+            E_INVALID_RELATION,
+            format!(
+                "Foreign key '{}' must be a column of {}",
+                foreign_key, location
+            ),
+            file,
+            &format!("{}.foreignKey", rel_path),
+        )
+        .with_suggestion(column_suggestion(owner)),
+    );
+}
+
+fn primary_keys(model: &ModelSpec) -> Vec<&str> {
+    model
+        .columns
+        .iter()
+        .filter(|(_, col)| col.primary_key)
+        .map(|(name, _)| name.as_str())
+        .collect()
+}
+
+fn column_suggestion(model: &ModelSpec) -> String {
+    let columns: Vec<&str> = model.columns.keys().map(String::as_str).collect();
+    format!("Columns of '{}': {}", model.name, columns.join(", "))
+}
+
+/// Column types only available on a particular database.
+fn specific_column_types(db_type: DatabaseType) -> &'static [&'static str] {
+    match db_type {
+        DatabaseType::PostgreSQL => &[
+            "serial",
+            "bigserial",
+            "timestamptz",
+            "jsonb",
+            "bytea",
+            "inet",
+            "citext",
+        ],
+        DatabaseType::MySQL => &[
+            "serial",
+            "tinyint",
+            "mediumint",
+            "tinytext",
+            "mediumtext",
+            "longtext",
+            "blob",
+            "longblob",
+            "year",
+        ],
+        DatabaseType::SQLite => &["blob"],
+        DatabaseType::MongoDB => &["objectid", "object", "array"],
+    }
+}
+
+/// Whether `col_type` (e.g. `varchar(255)`, `decimal(10, 2)`) names a type
+/// the database supports, with numeric size arguments if any.
+fn is_valid_column_type(col_type: &str, db_type: DatabaseType) -> bool {
+    let (base, args) = match col_type.split_once('(') {
+        Some((base, rest)) => match rest.strip_suffix(')') {
+            Some(args) => (base, Some(args)),
+            None => return false,
+        },
+        None => (col_type, None),
+    };
+    let args_ok = args.map_or(true, |args| {
+        args.split(',').all(|arg| arg.trim().parse::<u32>().is_ok())
+    });
+    let base = base.trim().to_ascii_lowercase();
+    args_ok
+        && PORTABLE_COLUMN_TYPES
+            .iter()
+            .chain(specific_column_types(db_type))
+            .any(|t| *t == base)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rash_spec::index::build_index;
+    use std::path::PathBuf;
+
+    fn golden_project() -> LoadedProject {
+        let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .parent()
+            .unwrap()
+            .parent()
+            .unwrap()
+            .join("fixtures/golden-user-crud");
+        let (project, _) = rash_spec::loader::load_project(&dir).unwrap();
+        project
+    }
+
+    fn run(project: &LoadedProject) -> Vec<ErrorEntry> {
+        let (index, _) = build_index(project);
+        let resolver = Resolver::new(&index);
+        let mut report = ValidationReport::success();
+        check(project, &resolver, &mut report);
+        report.errors
+    }
+
+    fn model_mut<'a>(project: &'a mut LoadedProject, name: &str) -> &'a mut ModelSpec {
+        project
+            .models
+            .iter_mut()
+            .map(|(_, m)| m)
+            .find(|m| m.name == name)
+            .unwrap()
+    }
+
+    fn found(errors: &[ErrorEntry]) -> Vec<(&str, &str, &str)> {
+        errors
+            .iter()
+            .map(|e| (e.code.as_str(), e.file.as_str(), e.path.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn test_golden_models_are_valid() {
+        let errors = run(&golden_project());
+        assert!(errors.is_empty(), "{:?}", errors);
+    }
+
+    #[test]
+    fn test_foreign_key_side_per_relation_type() {
+        let mut project = golden_project();
+        // User.posts (hasMany) expects `authorId` on Post, Post.author
+        // (belongsTo) expects it on Post itself
+        let post = model_mut(&mut project, "Post");
+        let author_id = post.columns.shift_remove("authorId").unwrap();
+        post.columns.insert("writerId".to_string(), author_id);
+        // Profile.userId lives on Profile, not User
+        model_mut(&mut project, "User").relations["profile"].relation_type =
+            RelationType::BelongsTo;
+
+        let errors = run(&project);
+        assert_eq!(
+            found(&errors),
+            vec![
+                (
+                    E_INVALID_RELATION,
+                    "models/post.model.json",
+                    "$.relations.author.foreignKey"
+                ),
+                (
+                    E_INVALID_RELATION,
+                    "models/user.model.json",
+                    "$.relations.posts.foreignKey"
+                ),
+                (
+                    E_INVALID_RELATION,
+                    "models/user.model.json",
+                    "$.relations.profile.foreignKey"
+                ),
+            ]
+        );
+        assert!(errors[1].message.contains("'Post'"));
+        assert!(errors[2].message.contains("this model"));
+    }
+
+    #[test]
+    fn test_many_to_many_join_model() {
+        let mut project = golden_project();
+        let relation = |through: Option<&str>| {
+            serde_json::from_value(serde_json::json!({
+                "type": "manyToMany",
+                "target": "Post",
+                "foreignKey": "userId",
+                "through": through
+            }))
+            .unwrap()
+        };
+        let user = model_mut(&mut project, "User");
+        user.relations
+            .insert("liked".to_string(), relation(Some("Profile")));
+        user.relations
+            .insert("saved".to_string(), relation(Some("Bookmark")));
+        user.relations.insert("tagged".to_string(), relation(None));
+        // A composite key on Post rules out synthesizing the join table
+        model_mut(&mut project, "Post").columns["authorId"].primary_key = true;
+
+        assert_eq!(
+            found(&run(&project)),
+            vec![
+                (
+                    E_INVALID_RELATION,
+                    "models/user.model.json",
+                    "$.relations.saved.through"
+                ),
+                (
+                    E_INVALID_RELATION,
+                    "models/user.model.json",
+                    "$.relations.tagged.type"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_index_columns_and_column_types() {
+        let mut project = golden_project();
+        let user = model_mut(&mut project, "User");
+        user.indexes[1].columns[1] = "created_at".to_string();
+        user.columns["name"].col_type = "longtext".to_string();
+        user.columns["email"].col_type = "varchar(max)".to_string();
+        user.columns["passwordHash"].col_type = "jsonb".to_string();
+
+        let errors = run(&project);
+        assert_eq!(
+            found(&errors),
+            vec![
+                (
+                    E_UNKNOWN_COLUMN,
+                    "models/user.model.json",
+                    "$.indexes[1].columns[1]"
+                ),
+                (
+                    E_INVALID_COLUMN_TYPE,
+                    "models/user.model.json",
+                    "$.columns.email.type"
+                ),
+                (
+                    E_INVALID_COLUMN_TYPE,
+                    "models/user.model.json",
+                    "$.columns.name.type"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_column_types_per_database() {
+        assert!(is_valid_column_type("decimal(10, 2)", DatabaseType::MySQL));
+        assert!(is_valid_column_type("longtext", DatabaseType::MySQL));
+        assert!(!is_valid_column_type("jsonb", DatabaseType::MySQL));
+        assert!(is_valid_column_type(
+            "TIMESTAMPTZ",
+            DatabaseType::PostgreSQL
+        ));
+        assert!(!is_valid_column_type("varchar(255", DatabaseType::SQLite));
+        assert!(is_valid_column_type("objectId", DatabaseType::MongoDB));
+    }
+}
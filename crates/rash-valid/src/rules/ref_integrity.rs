@@ -118,4 +118,53 @@ pub fn check(project: &LoadedProject, resolver: &Resolver, report: &mut Validati
             }
         }
     }
+    // Check function calls and component uses in handler, function and component bodies
+    for (file, handler) in &project.handlers {
+        let body = serde_json::to_value(&handler.body).unwrap_or_default();
+        check_body_refs(&body, "$.body", resolver, file, report);
+    }
+    for (file, function) in &project.functions {
+        let body = serde_json::to_value(&function.body).unwrap_or_default();
+        check_body_refs(&body, "$.body", resolver, file, report);
+    }
+    for (file, component) in &project.components {
+        let body = serde_json::to_value(&component.body).unwrap_or_default();
+        check_body_refs(&body, "$.body", resolver, file, report);
+    }
+}
+
+/// Resolve every `CallFunction` and `UseComponent` node in a serialized AST subtree.
+fn check_body_refs(
+    value: &serde_json::Value,
+    path: &str,
+    resolver: &Resolver,
+    file: &str,
+    report: &mut ValidationReport,
+) {
+    match value {
+        serde_json::Value::Object(map) => {
+            let target = match map.get("type").and_then(|t| t.as_str()) {
+                Some("CallFunction") => Some(("function", RefContext::Function)),
+                Some("UseComponent") => Some(("ref", RefContext::Component)),
+                _ => None,
+            };
+            if let Some((key, context)) = target {
+                if let Some(name) = map.get(key).and_then(|f| f.as_str()) {
+                    let ref_path = format!("{}.{}", path, key);
+                    if let Err(err) = resolver.resolve_or_error(name, context, file, &ref_path) {
+                        report.push(err);
+                    }
+                }
+            }
+            for (key, v) in map {
+                check_body_refs(v, &format!("{}.{}", path, key), resolver, file, report);
+            }
+        }
+        serde_json::Value::Array(arr) => {
+            for (i, v) in arr.iter().enumerate() {
+                check_body_refs(v, &format!("{}[{}]", path, i), resolver, file, report);
+            }
+        }
+        _ => {}
+    }
 }
@@ -0,0 +1,517 @@
+use std::collections::HashSet;
+
+use rash_ir::convert::endpoint_middleware;
+use rash_spec::index::{SpecIndex, SymbolKind};
+use rash_spec::loader::LoadedProject;
+use rash_spec::types::common::HttpMethod;
+use rash_spec::types::error::{
+    ErrorEntry, ValidationReport, E_UNDECLARED_RESPONSE, E_UNEXPECTED_REQUEST_BODY,
+    E_UNKNOWN_ROUTE_PARAM, E_UNPROVIDED_CONTEXT, E_UNUSED_RESPONSE,
+};
+use rash_spec::types::handler::HandlerSpec;
+
+use crate::suggest::closest;
+
+/// Cross-check each endpoint against the body of the handler it references:
+/// route params, request body access, middleware-provided state and response
+/// status codes.
+pub fn check(project: &LoadedProject, index: &SpecIndex, report: &mut ValidationReport) {
+    for (route_file, route) in &project.routes {
+        let route_params: Vec<&str> = route
+            .path
+            .split('/')
+            .filter_map(|segment| segment.strip_prefix(':'))
+            .collect();
+
+        for (method, endpoint) in &route.methods {
+            let Some((handler_file, handler)) =
+                find_handler(project, index, &endpoint.handler.reference)
+            else {
+                // Unresolved handler refs are reported by ref_integrity
+                continue;
+            };
+            let method_name = format!("{:?}", method).to_uppercase();
+            let endpoint_name = format!("{} {}", method_name, route.path);
+
+            let mut usage = HandlerUsage::default();
+            let body = serde_json::to_value(&handler.body).unwrap_or_default();
+            collect_usage(&body, "$.body", None, project, &mut Vec::new(), &mut usage);
+
+            let chain = endpoint_middleware(project, index, endpoint);
+            let provided: Vec<&str> = chain
+                .iter()
+                .flat_map(|mw| mw.provides.iter().flatten().map(|(name, _)| name.as_str()))
+                .collect();
+
+            for (ctx_path, path) in &usage.ctx_reads {
+                let mut segments = ctx_path.split('.');
+                match (segments.next(), segments.next()) {
+                    (Some("params"), Some(param)) if !route_params.contains(&param) => {
+                        report.push(
+                            ErrorEntry::error(
+                                E_UNKNOWN_ROUTE_PARAM,
+                                format!(
+                                    "Handler reads 'params.{}' but {} has no ':{}' segment",
+                                    param, endpoint_name, param
+                                ),
+                                handler_file,
+                                path,
+                            )
+                            .with_suggestion(format!(
+                                "Add ':{}' to the route path or read one of: {}",
+                                param,
+                                route_params.join(", ")
+                            )),
+                        );
+                    }
+                    (Some("body"), _)
+                        if matches!(method, HttpMethod::Get | HttpMethod::Head)
+                            && endpoint
+                                .request
+                                .as_ref()
+                                .and_then(|r| r.body.as_ref())
+                                .is_none() =>
+                    {
+                        report.push(
+                            ErrorEntry::error(
+                                E_UNEXPECTED_REQUEST_BODY,
+                                format!(
+                                    "Handler reads the request body but {} has no body",
+                                    endpoint_name
+                                ),
+                                handler_file,
+                                path,
+                            )
+                            .with_suggestion(
+                                "Read 'query' or 'params' instead, or use POST/PUT/PATCH",
+                            ),
+                        );
+                    }
+                    (Some("state"), Some(name)) if !provided.contains(&name) => {
+                        let suggestion = match closest(name, provided.iter().copied()) {
+                            Some(candidate) => format!("Did you mean 'state.{}'?", candidate),
+                            None => format!(
+                                "Add middleware that provides '{}' to {}",
+                                name, endpoint_name
+                            ),
+                        };
+                        report.push(
+                            ErrorEntry::error(
+                                E_UNPROVIDED_CONTEXT,
+                                format!(
+                                    "Handler reads 'state.{}' but no middleware on {} provides it",
+                                    name, endpoint_name
+                                ),
+                                handler_file,
+                                path,
+                            )
+                            .with_suggestion(suggestion),
+                        );
+                    }
+                    _ => {}
+                }
+            }
+
+            let Some(responses) = &endpoint.response else {
+                continue;
+            };
+
+            if !responses.contains_key("default") {
+                for (status, path) in &usage.responses {
+                    if !responses.contains_key(&status.to_string()) {
+                        let declared: Vec<&str> = responses.keys().map(String::as_str).collect();
+                        report.push(
+                            ErrorEntry::error(
+                                E_UNDECLARED_RESPONSE,
+                                format!(
+                                    "Handler responds with {} but {} doesn't declare it",
+                                    status, endpoint_name
+                                ),
+                                handler_file,
+                                path,
+                            )
+                            .with_suggestion(format!(
+                                "Declare response '{}' on the route or use one of: {}",
+                                status,
+                                declared.join(", ")
+                            )),
+                        );
+                    }
+                }
+            }
+
+            // Statuses the middleware chain can short-circuit with
+            let middleware_statuses: HashSet<u16> = chain
+                .iter()
+                .flat_map(|mw| mw.errors.iter().flatten().map(|(_, err)| err.status))
+                .collect();
+            let returned: HashSet<u16> =
+                usage.responses.iter().map(|(status, _)| *status).collect();
+
+            for code in responses.keys() {
+                let Ok(status) = code.parse::<u16>() else {
+                    continue;
+                };
+                // A throw can surface as any error status
+                if returned.contains(&status)
+                    || middleware_statuses.contains(&status)
+                    || (status >= 400 && usage.throws)
+                {
+                    continue;
+                }
+                report.push(
+                    ErrorEntry::warning(
+                        E_UNUSED_RESPONSE,
+                        format!(
+                            "{} declares response {} but no code path returns it",
+                            endpoint_name, status
+                        ),
+                        route_file,
+                        &format!("$.methods.{}.response.{}", method_name, code),
+                    )
+                    .with_suggestion(format!(
+                        "Return {} from '{}' or remove the declaration",
+                        status, handler.name
+                    )),
+                );
+            }
+        }
+    }
+}
+
+fn find_handler<'a>(
+    project: &'a LoadedProject,
+    index: &SpecIndex,
+    reference: &str,
+) -> Option<(&'a str, &'a HandlerSpec)> {
+    let entry = index.lookup(reference, SymbolKind::Handler)?;
+    project
+        .handlers
+        .iter()
+        .find(|(file, handler)| *file == entry.file && handler.name == entry.original_name)
+        .map(|(file, handler)| (file.as_str(), handler))
+}
+
+/// Request context reads and responses reachable from a handler body.
+#[derive(Default)]
+struct HandlerUsage {
+    /// `(ctx path, JSONPath)` of every `CtxGet`
+    ctx_reads: Vec<(String, String)>,
+    /// `(status, JSONPath)` of every `HttpRespond`
+    responses: Vec<(u16, String)>,
+    throws: bool,
+}
+
+/// Walk a serialized AST subtree. Components are followed into their bodies,
+/// with findings reported at the `UseComponent` node (`at`).
+fn collect_usage(
+    value: &serde_json::Value,
+    path: &str,
+    at: Option<&str>,
+    project: &LoadedProject,
+    expanding: &mut Vec<String>,
+    usage: &mut HandlerUsage,
+) {
+    match value {
+        serde_json::Value::Object(map) => {
+            // Inside a component everything is reported at the UseComponent node
+            let field = |name: &str| match at {
+                Some(at) => at.to_string(),
+                None => format!("{}.{}", path, name),
+            };
+            match map.get("type").and_then(|t| t.as_str()) {
+                Some("CtxGet") => {
+                    if let Some(ctx_path) = map.get("path").and_then(|p| p.as_str()) {
+                        usage.ctx_reads.push((ctx_path.to_string(), field("path")));
+                    }
+                }
+                Some("HttpRespond") => {
+                    if let Some(status) = map.get("status").and_then(|s| s.as_u64()) {
+                        usage.responses.push((status as u16, field("status")));
+                    }
+                }
+                Some("ThrowStatement") => usage.throws = true,
+                Some("UseComponent") => {
+                    let reference = map.get("ref").and_then(|r| r.as_str()).unwrap_or_default();
+                    let component = project
+                        .components
+                        .iter()
+                        .find(|(_, c)| c.name == reference)
+                        .map(|(_, c)| c);
+                    // Recursive components are reported by cycle_detect
+                    let component = component.filter(|_| !expanding.iter().any(|n| n == reference));
+                    if let Some(component) = component {
+                        let use_path = field("ref");
+                        for input in component.context_inputs.values() {
+                            usage
+                                .ctx_reads
+                                .push((input.source.clone(), use_path.clone()));
+                        }
+                        expanding.push(reference.to_string());
+                        let body = serde_json::to_value(&component.body).unwrap_or_default();
+                        collect_usage(&body, "$.body", Some(&use_path), project, expanding, usage);
+                        expanding.pop();
+                    }
+                }
+                _ => {}
+            }
+            for (key, v) in map {
+                collect_usage(
+                    v,
+                    &format!("{}.{}", path, key),
+                    at,
+                    project,
+                    expanding,
+                    usage,
+                );
+            }
+        }
+        serde_json::Value::Array(arr) => {
+            for (i, v) in arr.iter().enumerate() {
+                collect_usage(
+                    v,
+                    &format!("{}[{}]", path, i),
+                    at,
+                    project,
+                    expanding,
+                    usage,
+                );
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rash_spec::index::build_index;
+    use rash_spec::types::common::Severity;
+    use std::path::PathBuf;
+
+    fn load_fixture(name: &str) -> LoadedProject {
+        let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .parent()
+            .unwrap()
+            .parent()
+            .unwrap()
+            .join("fixtures")
+            .join(name);
+        let (project, _) = rash_spec::loader::load_project(&dir).unwrap();
+        project
+    }
+
+    fn golden_project() -> LoadedProject {
+        load_fixture("golden-user-crud")
+    }
+
+    fn check_all(project: &LoadedProject) -> Vec<ErrorEntry> {
+        let (index, _) = build_index(project);
+        let mut report = ValidationReport::success();
+        check(project, &index, &mut report);
+        report.errors
+    }
+
+    /// Entries beyond the ones the unmodified golden project already reports.
+    fn run(project: &LoadedProject) -> Vec<ErrorEntry> {
+        let baseline = check_all(&golden_project());
+        check_all(project)
+            .into_iter()
+            .filter(|e| !baseline.contains(e))
+            .collect()
+    }
+
+    fn codes(errors: &[ErrorEntry]) -> Vec<(&str, &str)> {
+        errors
+            .iter()
+            .map(|e| (e.code.as_str(), e.path.as_str()))
+            .collect()
+    }
+
+    fn handler_mut<'a>(project: &'a mut LoadedProject, name: &str) -> &'a mut HandlerSpec {
+        project
+            .handlers
+            .iter_mut()
+            .map(|(_, h)| h)
+            .find(|h| h.name == name)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_golden_project_honours_its_routes() {
+        // Declared error responses the golden handlers never return only warn
+        let errors = check_all(&golden_project());
+        assert_eq!(
+            codes(&errors),
+            vec![
+                (E_UNUSED_RESPONSE, "$.methods.PUT.response.404"),
+                (E_UNUSED_RESPONSE, "$.methods.DELETE.response.404"),
+                (E_UNUSED_RESPONSE, "$.methods.POST.response.400"),
+            ]
+        );
+        assert!(errors.iter().all(|e| e.severity == Severity::Warning));
+    }
+
+    #[test]
+    fn test_route_responses_fixture() {
+        // v1 endpoints declare responses nothing returns; v2 fixes them
+        let errors = check_all(&load_fixture("contracts/route-responses"));
+        let found: Vec<(&str, &str)> = errors
+            .iter()
+            .map(|e| (e.file.as_str(), e.path.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (
+                    "routes/v1/users/[id].route.json",
+                    "$.methods.DELETE.response.404"
+                ),
+                ("routes/v1/users.route.json", "$.methods.POST.response.400"),
+            ]
+        );
+        assert!(errors.iter().all(|e| e.code == E_UNUSED_RESPONSE));
+    }
+
+    #[test]
+    fn test_unknown_param_and_body_on_get() {
+        let mut project = golden_project();
+        handler_mut(&mut project, "users.getUser").body =
+            serde_json::from_value(serde_json::json!([
+                {
+                    "type": "LetStatement", "tier": 0, "name": "slug",
+                    "value": { "type": "CtxGet", "tier": 1, "path": "params.slug" }
+                },
+                {
+                    "type": "LetStatement", "tier": 0, "name": "filter",
+                    "value": { "type": "CtxGet", "tier": 1, "path": "body.filter" }
+                },
+                {
+                    "type": "ReturnStatement", "tier": 0,
+                    "value": { "type": "HttpRespond", "tier": 1, "status": 200 }
+                }
+            ]))
+            .unwrap();
+
+        let errors = run(&project);
+        assert_eq!(
+            codes(&errors),
+            vec![
+                (E_UNKNOWN_ROUTE_PARAM, "$.body[0].value.path"),
+                (E_UNEXPECTED_REQUEST_BODY, "$.body[1].value.path"),
+                (E_UNUSED_RESPONSE, "$.methods.GET.response.404"),
+            ]
+        );
+        assert_eq!(errors[0].file, "handlers/users.handler.json");
+        assert_eq!(errors[2].file, "routes/api/v1/users/[id].route.json");
+        assert_eq!(errors[2].severity, Severity::Warning);
+    }
+
+    #[test]
+    fn test_undeclared_response_status() {
+        let mut project = golden_project();
+        let handler = handler_mut(&mut project, "users.createUser");
+        let last = handler.body.len() - 1;
+        handler.body[last] = serde_json::from_value(serde_json::json!({
+            "type": "ReturnStatement", "tier": 0,
+            "value": { "type": "HttpRespond", "tier": 1, "status": 200 }
+        }))
+        .unwrap();
+
+        let found: Vec<(String, String)> = run(&project)
+            .into_iter()
+            .map(|e| (e.code, e.path))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (
+                    E_UNDECLARED_RESPONSE.to_string(),
+                    format!("$.body[{}].value.status", last)
+                ),
+                (
+                    E_UNUSED_RESPONSE.to_string(),
+                    "$.methods.POST.response.201".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_component_responses_count_for_the_handler() {
+        let mut project = golden_project();
+        let component: rash_spec::types::component::ComponentSpec =
+            serde_json::from_value(serde_json::json!({
+                "name": "notFound",
+                "body": [{
+                    "type": "ReturnStatement", "tier": 0,
+                    "value": { "type": "HttpRespond", "tier": 1, "status": 410 }
+                }]
+            }))
+            .unwrap();
+        project
+            .components
+            .push(("components/not-found.component.json".to_string(), component));
+        handler_mut(&mut project, "users.getUser").body.insert(
+            0,
+            serde_json::from_value(serde_json::json!({
+                "type": "UseComponent", "tier": 0, "ref": "notFound"
+            }))
+            .unwrap(),
+        );
+
+        let errors = run(&project);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, E_UNDECLARED_RESPONSE);
+        assert_eq!(errors[0].path, "$.body[0].ref");
+    }
+
+    #[test]
+    fn test_state_must_be_provided_by_middleware_chain() {
+        let mut project = golden_project();
+        let reads = ["state.user", "state.usr", "state.tenant"];
+        let handler = handler_mut(&mut project, "users.getUser");
+        for (i, ctx_path) in reads.iter().enumerate() {
+            handler.body.insert(
+                i,
+                serde_json::from_value(serde_json::json!({
+                    "type": "LetStatement", "tier": 0, "name": format!("v{}", i),
+                    "value": { "type": "CtxGet", "tier": 1, "path": ctx_path }
+                }))
+                .unwrap(),
+            );
+        }
+
+        let errors = run(&project);
+        assert_eq!(
+            codes(&errors),
+            vec![
+                (E_UNPROVIDED_CONTEXT, "$.body[1].value.path"),
+                (E_UNPROVIDED_CONTEXT, "$.body[2].value.path"),
+            ]
+        );
+        assert_eq!(
+            errors[0].suggestion.as_deref(),
+            Some("Did you mean 'state.user'?")
+        );
+        assert!(errors[1]
+            .suggestion
+            .as_deref()
+            .unwrap()
+            .contains("'tenant'"));
+
+        // A value provided by global middleware reaches every endpoint
+        let (_, cors) = project
+            .middleware
+            .iter_mut()
+            .find(|(_, mw)| mw.name == "cors")
+            .unwrap();
+        cors.provides.get_or_insert_with(Default::default).insert(
+            "tenant".to_string(),
+            serde_json::json!({ "type": "string" }),
+        );
+        let errors = run(&project);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].path, "$.body[1].value.path");
+    }
+}
@@ -0,0 +1,684 @@
+use rash_spec::loader::LoadedProject;
+use rash_spec::types::ast::{AstNode, TemplatePart};
+use rash_spec::types::component::ComponentSpec;
+use rash_spec::types::error::{
+    ErrorEntry, ValidationReport, E_SHADOWED_VARIABLE, E_UNDEFINED_VARIABLE, E_UNUSED_VARIABLE,
+};
+
+/// Check lexical scoping in handler, function and component bodies: every
+/// `Identifier` must be bound earlier, and bindings should neither shadow an
+/// outer name nor go unused.
+pub fn check(project: &LoadedProject, report: &mut ValidationReport) {
+    for (file, handler) in &project.handlers {
+        let mut checker = ScopeChecker::new(file, None);
+        checker.push_scope();
+        for name in handler.params.iter().flat_map(|p| p.keys()) {
+            checker.declare(name, &format!("$.params.{}", name), false);
+        }
+        checker.check_block(&handler.body, "$.body");
+        checker.pop_scope();
+        for err in checker.errors {
+            report.push(err);
+        }
+    }
+
+    for (file, function) in &project.functions {
+        let mut checker = ScopeChecker::new(file, None);
+        checker.push_scope();
+        for (i, param) in function.params.iter().enumerate() {
+            checker.declare(&param.name, &format!("$.params[{}].name", i), false);
+        }
+        checker.check_block(&function.body, "$.body");
+        checker.pop_scope();
+        for err in checker.errors {
+            report.push(err);
+        }
+    }
+
+    for (file, component) in &project.components {
+        let mut checker = ScopeChecker::new(file, Some(component));
+        checker.push_scope();
+        for name in component.context_inputs.keys() {
+            checker.declare(name, &format!("$.contextInputs.{}", name), false);
+        }
+        checker.check_block(&component.body, "$.body");
+        checker.pop_scope();
+        for err in checker.errors {
+            report.push(err);
+        }
+    }
+}
+
+/// Globals a body can read or call without declaring them.
+const GLOBALS: &[&str] = &[
+    "Array",
+    "Boolean",
+    "Date",
+    "JSON",
+    "Math",
+    "Number",
+    "Object",
+    "Promise",
+    "String",
+    "console",
+    "decodeURIComponent",
+    "encodeURIComponent",
+    "isFinite",
+    "isNaN",
+    "parseFloat",
+    "parseInt",
+];
+
+/// A name introduced by a statement, parameter or binding clause.
+struct Binding {
+    name: String,
+    /// JSONPath of the declaration
+    path: String,
+    used: bool,
+    /// Parameters and catch bindings are part of a signature, so leaving
+    /// them unused isn't reported
+    report_unused: bool,
+}
+
+struct ScopeChecker<'a> {
+    file: &'a str,
+    /// Set when checking a component body, whose inputs are `$input.<name>`
+    component: Option<&'a ComponentSpec>,
+    scopes: Vec<Vec<Binding>>,
+    errors: Vec<ErrorEntry>,
+}
+
+impl<'a> ScopeChecker<'a> {
+    fn new(file: &'a str, component: Option<&'a ComponentSpec>) -> Self {
+        Self {
+            file,
+            component,
+            scopes: Vec::new(),
+            errors: Vec::new(),
+        }
+    }
+
+    fn push_scope(&mut self) {
+        self.scopes.push(Vec::new());
+    }
+
+    fn pop_scope(&mut self) {
+        let Some(scope) = self.scopes.pop() else {
+            return;
+        };
+        for binding in scope {
+            if binding.report_unused && !binding.used && !binding.name.starts_with('_') {
+                self.errors.push(
+                    ErrorEntry::warning(
+                        E_UNUSED_VARIABLE,
+                        format!("'{}' is never used", binding.name),
+                        self.file,
+                        &binding.path,
+                    )
+                    .with_suggestion(format!(
+                        "Remove the binding or rename it to '_{}'",
+                        binding.name
+                    )),
+                );
+            }
+        }
+    }
+
+    fn lookup(&mut self, name: &str) -> Option<&mut Binding> {
+        self.scopes
+            .iter_mut()
+            .rev()
+            .find_map(|scope| scope.iter_mut().rev().find(|b| b.name == name))
+    }
+
+    fn declare(&mut self, name: &str, path: &str, report_unused: bool) {
+        if let Some(previous) = self.lookup(name) {
+            let previous_path = previous.path.clone();
+            self.errors.push(
+                ErrorEntry::warning(
+                    E_SHADOWED_VARIABLE,
+                    format!(
+                        "'{}' shadows the binding declared at {}",
+                        name, previous_path
+                    ),
+                    self.file,
+                    path,
+                )
+                .with_suggestion("Rename one of the bindings"),
+            );
+        }
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.push(Binding {
+                name: name.to_string(),
+                path: path.to_string(),
+                used: false,
+                report_unused,
+            });
+        }
+    }
+
+    fn reference(&mut self, name: &str, path: &str) {
+        if let Some(input) = name.strip_prefix("$input.") {
+            if let Some(component) = self.component {
+                if !component.inputs.contains_key(input) {
+                    let declared: Vec<&str> = component.inputs.keys().map(String::as_str).collect();
+                    self.errors.push(
+                        ErrorEntry::error(
+                            E_UNDEFINED_VARIABLE,
+                            format!("Component has no input '{}'", input),
+                            self.file,
+                            path,
+                        )
+                        .with_suggestion(format!("Declared inputs: {}", declared.join(", "))),
+                    );
+                }
+                return;
+            }
+        }
+
+        // A dotted name (`user.email`, `Math.ceil`) is bound through its root
+        let root = name.split('.').next().unwrap_or(name);
+        match self.lookup(root) {
+            Some(binding) => binding.used = true,
+            None if GLOBALS.contains(&root) || name == "_" => {}
+            None => self.errors.push(
+                ErrorEntry::error(
+                    E_UNDEFINED_VARIABLE,
+                    format!("'{}' is not defined", root),
+                    self.file,
+                    path,
+                )
+                .with_suggestion(format!(
+                    "Declare '{}' with a LetStatement before using it",
+                    root
+                )),
+            ),
+        }
+    }
+
+    fn check_block(&mut self, stmts: &[AstNode], path: &str) {
+        for (i, stmt) in stmts.iter().enumerate() {
+            self.check_statement(stmt, &format!("{}[{}]", path, i));
+        }
+    }
+
+    /// Check a nested block in its own scope, with `bindings` declared first.
+    fn check_scoped(&mut self, stmts: &[AstNode], path: &str, bindings: &[(&str, String, bool)]) {
+        self.push_scope();
+        for (name, binding_path, report_unused) in bindings {
+            self.declare(name, binding_path, *report_unused);
+        }
+        self.check_block(stmts, path);
+        self.pop_scope();
+    }
+
+    fn check_statement(&mut self, node: &AstNode, path: &str) {
+        match node {
+            AstNode::LetStatement { name, value, .. } => {
+                // The value is checked first: `let x = x` reads an outer `x`
+                self.check_expr(value, &format!("{}.value", path));
+                self.declare(name, &format!("{}.name", path), true);
+            }
+            AstNode::AssignStatement { target, value, .. } => {
+                self.check_expr(target, &format!("{}.target", path));
+                self.check_expr(value, &format!("{}.value", path));
+            }
+            AstNode::ReturnStatement { value, .. } | AstNode::ThrowStatement { value, .. } => {
+                self.check_expr(value, &format!("{}.value", path));
+            }
+            AstNode::ExpressionStatement { expr, .. } => {
+                self.check_expr(expr, &format!("{}.expr", path));
+            }
+            AstNode::IfStatement {
+                condition,
+                then,
+                else_branch,
+                ..
+            } => {
+                self.check_expr(condition, &format!("{}.condition", path));
+                self.check_scoped(then, &format!("{}.then", path), &[]);
+                if let Some(else_branch) = else_branch {
+                    self.check_scoped(else_branch, &format!("{}.else", path), &[]);
+                }
+            }
+            AstNode::ForStatement {
+                binding,
+                iterable,
+                body,
+                ..
+            } => {
+                self.check_expr(iterable, &format!("{}.iterable", path));
+                self.check_scoped(
+                    body,
+                    &format!("{}.body", path),
+                    &[(binding, format!("{}.binding", path), true)],
+                );
+            }
+            AstNode::WhileStatement {
+                condition, body, ..
+            } => {
+                self.check_expr(condition, &format!("{}.condition", path));
+                self.check_scoped(body, &format!("{}.body", path), &[]);
+            }
+            AstNode::MatchStatement { expr, arms, .. } => {
+                self.check_expr(expr, &format!("{}.expr", path));
+                for (i, arm) in arms.iter().enumerate() {
+                    let arm_path = format!("{}.arms[{}]", path, i);
+                    self.check_expr(&arm.pattern, &format!("{}.pattern", arm_path));
+                    self.check_scoped(&arm.body, &format!("{}.body", arm_path), &[]);
+                }
+            }
+            AstNode::TryCatchStatement {
+                try_block,
+                catch_block,
+                finally_block,
+                ..
+            } => {
+                self.check_scoped(try_block, &format!("{}.try", path), &[]);
+                self.check_scoped(
+                    &catch_block.body,
+                    &format!("{}.catch.body", path),
+                    &[(
+                        &catch_block.binding,
+                        format!("{}.catch.binding", path),
+                        false,
+                    )],
+                );
+                if let Some(finally_block) = finally_block {
+                    self.check_scoped(finally_block, &format!("{}.finally", path), &[]);
+                }
+            }
+            AstNode::DbTransaction { body, .. } => {
+                self.check_scoped(body, &format!("{}.body", path), &[]);
+            }
+            AstNode::UseComponent { inputs, bind, .. } => {
+                for (name, input) in inputs {
+                    self.check_expr(input, &format!("{}.inputs.{}", path, name));
+                }
+                if let Some(bind) = bind {
+                    self.declare(bind, &format!("{}.bind", path), true);
+                }
+            }
+            other => self.check_expr(other, path),
+        }
+    }
+
+    fn check_expr(&mut self, node: &AstNode, path: &str) {
+        match node {
+            AstNode::Identifier { name, .. } => self.reference(name, path),
+            AstNode::BinaryExpr { left, right, .. } => {
+                self.check_expr(left, &format!("{}.left", path));
+                self.check_expr(right, &format!("{}.right", path));
+            }
+            AstNode::UnaryExpr { operand, .. } => {
+                self.check_expr(operand, &format!("{}.operand", path));
+            }
+            AstNode::CallExpr { callee, args, .. } => {
+                self.check_expr(callee, &format!("{}.callee", path));
+                self.check_all(args, &format!("{}.args", path));
+            }
+            AstNode::CallFunction { args, .. } => {
+                self.check_all(args, &format!("{}.args", path));
+            }
+            AstNode::MemberExpr { object, .. } => {
+                self.check_expr(object, &format!("{}.object", path));
+            }
+            AstNode::IndexExpr { object, index, .. } => {
+                self.check_expr(object, &format!("{}.object", path));
+                self.check_expr(index, &format!("{}.index", path));
+            }
+            AstNode::ObjectExpr { properties, .. } => {
+                for (key, value) in properties {
+                    self.check_expr(value, &format!("{}.properties.{}", path, key));
+                }
+            }
+            AstNode::ArrayExpr { elements, .. } => {
+                self.check_all(elements, &format!("{}.elements", path));
+            }
+            AstNode::ArrowFn { params, body, .. } => {
+                let bindings: Vec<(&str, String, bool)> = params
+                    .iter()
+                    .enumerate()
+                    .map(|(i, p)| (p.as_str(), format!("{}.params[{}]", path, i), false))
+                    .collect();
+                self.check_scoped(body, &format!("{}.body", path), &bindings);
+            }
+            AstNode::AwaitExpr { expr, .. } => self.check_expr(expr, &format!("{}.expr", path)),
+            AstNode::PipeExpr { stages, .. } => {
+                self.check_all(stages, &format!("{}.stages", path));
+            }
+            AstNode::TemplateString { parts, .. } => {
+                for (i, part) in parts.iter().enumerate() {
+                    if let TemplatePart::Expr { value } = part {
+                        self.check_expr(value, &format!("{}.parts[{}].value", path, i));
+                    }
+                }
+            }
+            AstNode::DbQuery {
+                r#where,
+                order_by,
+                skip,
+                take,
+                include,
+                ..
+            } => {
+                for (key, value) in [
+                    ("where", r#where),
+                    ("orderBy", order_by),
+                    ("include", include),
+                ] {
+                    if let Some(value) = value {
+                        self.check_json(value, &format!("{}.{}", path, key));
+                    }
+                }
+                for (key, node) in [("skip", skip), ("take", take)] {
+                    if let Some(node) = node {
+                        self.check_expr(node, &format!("{}.{}", path, key));
+                    }
+                }
+            }
+            AstNode::DbMutate { data, r#where, .. } => {
+                if let Some(data) = data {
+                    self.check_expr(data, &format!("{}.data", path));
+                }
+                if let Some(value) = r#where {
+                    self.check_json(value, &format!("{}.where", path));
+                }
+            }
+            AstNode::HttpRespond { headers, body, .. } => {
+                for (name, value) in headers.iter().flatten() {
+                    self.check_expr(value, &format!("{}.headers.{}", path, name));
+                }
+                if let Some(body) = body {
+                    self.check_expr(body, &format!("{}.body", path));
+                }
+            }
+            AstNode::Validate { data, .. } => self.check_expr(data, &format!("{}.data", path)),
+            AstNode::HashPassword { input, .. } => {
+                self.check_expr(input, &format!("{}.input", path));
+            }
+            AstNode::VerifyPassword { password, hash, .. } => {
+                self.check_expr(password, &format!("{}.password", path));
+                self.check_expr(hash, &format!("{}.hash", path));
+            }
+            AstNode::SignToken {
+                payload, secret, ..
+            } => {
+                self.check_expr(payload, &format!("{}.payload", path));
+                if let Some(secret) = secret {
+                    self.check_expr(secret, &format!("{}.secret", path));
+                }
+            }
+            AstNode::VerifyToken { token, secret, .. } => {
+                self.check_expr(token, &format!("{}.token", path));
+                if let Some(secret) = secret {
+                    self.check_expr(secret, &format!("{}.secret", path));
+                }
+            }
+            AstNode::SendEmail {
+                to, subject, body, ..
+            } => {
+                self.check_expr(to, &format!("{}.to", path));
+                self.check_expr(subject, &format!("{}.subject", path));
+                self.check_expr(body, &format!("{}.body", path));
+            }
+            AstNode::EmitEvent { data, .. } => {
+                if let Some(data) = data {
+                    self.check_expr(data, &format!("{}.data", path));
+                }
+            }
+            AstNode::LogMessage { message, .. } => {
+                self.check_expr(message, &format!("{}.message", path));
+            }
+            AstNode::NativeBridge { call, fallback, .. } => {
+                self.check_all(&call.args, &format!("{}.call.args", path));
+                if let Some(fallback) = fallback {
+                    self.check_expr(&fallback.node, &format!("{}.fallback.node", path));
+                }
+            }
+            // Statements in expression position (e.g. a bare `HttpRespond` body)
+            AstNode::LetStatement { .. }
+            | AstNode::AssignStatement { .. }
+            | AstNode::ReturnStatement { .. }
+            | AstNode::IfStatement { .. }
+            | AstNode::ForStatement { .. }
+            | AstNode::WhileStatement { .. }
+            | AstNode::MatchStatement { .. }
+            | AstNode::TryCatchStatement { .. }
+            | AstNode::ThrowStatement { .. }
+            | AstNode::ExpressionStatement { .. }
+            | AstNode::UseComponent { .. }
+            | AstNode::DbTransaction { .. } => self.check_statement(node, path),
+            AstNode::Literal { .. } | AstNode::CtxGet { .. } => {}
+        }
+    }
+
+    fn check_all(&mut self, nodes: &[AstNode], path: &str) {
+        for (i, node) in nodes.iter().enumerate() {
+            self.check_expr(node, &format!("{}[{}]", path, i));
+        }
+    }
+
+    /// Check AST nodes embedded in `where`/`orderBy`/`include` JSON.
+    fn check_json(&mut self, value: &serde_json::Value, path: &str) {
+        match value {
+            serde_json::Value::Object(map) => {
+                if map.contains_key("type") {
+                    if let Ok(node) = serde_json::from_value::<AstNode>(value.clone()) {
+                        self.check_expr(&node, path);
+                        return;
+                    }
+                }
+                for (key, v) in map {
+                    self.check_json(v, &format!("{}.{}", path, key));
+                }
+            }
+            serde_json::Value::Array(arr) => {
+                for (i, v) in arr.iter().enumerate() {
+                    self.check_json(v, &format!("{}[{}]", path, i));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rash_spec::types::common::Severity;
+    use std::path::PathBuf;
+
+    fn check_body(body: serde_json::Value) -> Vec<ErrorEntry> {
+        let body: Vec<AstNode> = serde_json::from_value(body).unwrap();
+        let mut checker = ScopeChecker::new("handlers/test.handler.json", None);
+        checker.push_scope();
+        checker.check_block(&body, "$.body");
+        checker.pop_scope();
+        checker.errors
+    }
+
+    fn ident(name: &str) -> serde_json::Value {
+        serde_json::json!({ "type": "Identifier", "tier": 0, "name": name })
+    }
+
+    fn let_stmt(name: &str, value: serde_json::Value) -> serde_json::Value {
+        serde_json::json!({ "type": "LetStatement", "tier": 0, "name": name, "value": value })
+    }
+
+    fn ret(value: serde_json::Value) -> serde_json::Value {
+        serde_json::json!({ "type": "ReturnStatement", "tier": 0, "value": value })
+    }
+
+    #[test]
+    fn test_golden_project_has_no_scope_errors() {
+        let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .parent()
+            .unwrap()
+            .parent()
+            .unwrap()
+            .join("fixtures/golden-user-crud");
+        let (project, _) = rash_spec::loader::load_project(&dir).unwrap();
+        let mut report = ValidationReport::success();
+        check(&project, &mut report);
+        assert!(report.errors.is_empty(), "{:?}", report.errors);
+    }
+
+    #[test]
+    fn test_undefined_variable_in_nested_block() {
+        let errors = check_body(serde_json::json!([
+            let_stmt("user", serde_json::json!({ "type": "CtxGet", "tier": 1, "path": "body" })),
+            let_stmt("ok", serde_json::json!({ "type": "Literal", "tier": 0, "value": true })),
+            {
+                "type": "IfStatement",
+                "tier": 0,
+                "condition": ident("ok"),
+                "then": [ret(ident("usr"))]
+            },
+            ret(ident("user"))
+        ]));
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, E_UNDEFINED_VARIABLE);
+        assert_eq!(errors[0].severity, Severity::Error);
+        assert_eq!(errors[0].path, "$.body[2].then[0].value");
+    }
+
+    #[test]
+    fn test_block_bindings_do_not_escape() {
+        let errors = check_body(serde_json::json!([
+            {
+                "type": "ForStatement",
+                "tier": 0,
+                "binding": "item",
+                "iterable": { "type": "ArrayExpr", "tier": 0, "elements": [] },
+                "body": [let_stmt("last", ident("item")), ret(ident("last"))]
+            },
+            ret(ident("item"))
+        ]));
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, E_UNDEFINED_VARIABLE);
+        assert_eq!(errors[0].path, "$.body[1].value");
+    }
+
+    #[test]
+    fn test_let_value_is_checked_before_binding() {
+        let errors = check_body(serde_json::json!([
+            let_stmt("x", ident("x")),
+            ret(ident("x"))
+        ]));
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, E_UNDEFINED_VARIABLE);
+        assert_eq!(errors[0].path, "$.body[0].value");
+    }
+
+    #[test]
+    fn test_shadowing_and_unused_are_warnings() {
+        let errors = check_body(serde_json::json!([
+            let_stmt("id", serde_json::json!({ "type": "CtxGet", "tier": 1, "path": "params.id" })),
+            let_stmt("_ignored", serde_json::json!({ "type": "Literal", "tier": 0, "value": 1 })),
+            {
+                "type": "TryCatchStatement",
+                "tier": 0,
+                "try": [
+                    let_stmt("id", ident("id")),
+                    ret(ident("id"))
+                ],
+                "catch": { "binding": "err", "body": [] }
+            },
+            let_stmt("unused", serde_json::json!({ "type": "Literal", "tier": 0, "value": 2 }))
+        ]));
+
+        let found: Vec<(&str, &str)> = errors
+            .iter()
+            .map(|e| (e.code.as_str(), e.path.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (E_SHADOWED_VARIABLE, "$.body[2].try[0].name"),
+                (E_UNUSED_VARIABLE, "$.body[3].name"),
+            ]
+        );
+        assert!(errors.iter().all(|e| e.severity == Severity::Warning));
+    }
+
+    #[test]
+    fn test_arrow_params_filters_and_globals() {
+        let errors = check_body(serde_json::json!([
+            let_stmt(
+                "minAge",
+                serde_json::json!({ "type": "Literal", "tier": 0, "value": 18 })
+            ),
+            let_stmt(
+                "users",
+                serde_json::json!({
+                    "type": "DbQuery",
+                    "tier": 1,
+                    "model": "User",
+                    "operation": "findMany",
+                    "where": { "age": { "gte": ident("minAge") }, "name": ident("nme") }
+                })
+            ),
+            ret(serde_json::json!({
+                "type": "CallExpr",
+                "tier": 0,
+                "callee": ident("Math.max"),
+                "args": [{
+                    "type": "ArrowFn",
+                    "tier": 0,
+                    "params": ["u"],
+                    "body": [ret(ident("u")), ret(ident("users"))]
+                }]
+            }))
+        ]));
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, E_UNDEFINED_VARIABLE);
+        assert_eq!(errors[0].path, "$.body[1].value.where.name");
+    }
+
+    #[test]
+    fn test_dotted_name_with_unbound_root() {
+        let errors = check_body(serde_json::json!([
+            let_stmt("user", serde_json::json!({ "type": "CtxGet", "tier": 1, "path": "body" })),
+            let_stmt("email", ident("user.email")),
+            let_stmt("now", ident("Date.now")),
+            ret(serde_json::json!({
+                "type": "ArrayExpr",
+                "tier": 0,
+                "elements": [ident("email"), ident("now"), ident("foo.bar")]
+            }))
+        ]));
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, E_UNDEFINED_VARIABLE);
+        assert_eq!(errors[0].message, "'foo' is not defined");
+        assert_eq!(errors[0].path, "$.body[3].value.elements[2]");
+    }
+
+    #[test]
+    fn test_bare_callee_must_be_bound_or_global() {
+        let call = |name: &str| {
+            serde_json::json!({ "type": "CallExpr", "tier": 0, "callee": ident(name), "args": [] })
+        };
+        let errors = check_body(serde_json::json!([
+            let_stmt("count", call("parseInt")),
+            let_stmt("format", serde_json::json!({
+                "type": "ArrowFn", "tier": 0, "params": [], "body": [ret(ident("count"))]
+            })),
+            let_stmt("label", call("format")),
+            ret(serde_json::json!({
+                "type": "ArrayExpr",
+                "tier": 0,
+                "elements": [ident("label"), call("doThing")]
+            }))
+        ]));
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, E_UNDEFINED_VARIABLE);
+        assert_eq!(errors[0].message, "'doThing' is not defined");
+        assert_eq!(errors[0].path, "$.body[3].value.elements[1].callee");
+    }
+}
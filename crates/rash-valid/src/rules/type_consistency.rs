@@ -1,10 +1,26 @@
-use rash_spec::index::SpecIndex;
+use std::collections::{HashMap, HashSet};
+
+use rash_ir::convert::{convert_json_schema, provided_context};
+use rash_ir::expr::TypeIR;
+use rash_spec::index::{SpecIndex, SymbolKind};
 use rash_spec::loader::LoadedProject;
-use rash_spec::types::error::{ErrorEntry, ValidationReport, E_INCOMPATIBLE_TARGET};
+use rash_spec::types::ast::{AstNode, TemplatePart};
+use rash_spec::types::common::TypeRef;
+use rash_spec::types::error::{
+    ErrorEntry, ValidationReport, E_INCOMPATIBLE_TARGET, E_RESPONSE_MISMATCH, E_TYPE_MISMATCH,
+    E_UNKNOWN_FIELD,
+};
+use rash_spec::types::route::EndpointSpec;
+
+use super::db_check::{operation_result, DbResult, MUTATE_OPERATIONS, QUERY_OPERATIONS};
+
+/// How deep `compatible` follows schema refs before giving up (and accepting).
+const MAX_REF_DEPTH: usize = 8;
 
 /// Check type consistency across the project.
-pub fn check(project: &LoadedProject, _index: &SpecIndex, report: &mut ValidationReport) {
+pub fn check(project: &LoadedProject, index: &SpecIndex, report: &mut ValidationReport) {
     check_language_framework_compatibility(project, report);
+    check_handler_types(project, index, report);
 }
 
 /// Verify that the target language and framework are compatible
@@ -60,3 +76,928 @@ fn check_language_framework_compatibility(project: &LoadedProject, report: &mut
         );
     }
 }
+
+/// Infer a type for every binding in each handler body and check member access
+/// and `HttpRespond` bodies against the schemas of the endpoints that route to it.
+fn check_handler_types(project: &LoadedProject, index: &SpecIndex, report: &mut ValidationReport) {
+    let mut endpoints: HashMap<&str, Vec<&EndpointSpec>> = HashMap::new();
+    for (_, route) in &project.routes {
+        for endpoint in route.methods.values() {
+            if let Some(entry) = index.lookup(&endpoint.handler.reference, SymbolKind::Handler) {
+                endpoints
+                    .entry(entry.original_name.as_str())
+                    .or_default()
+                    .push(endpoint);
+            }
+        }
+    }
+
+    for (file, handler) in &project.handlers {
+        // A handler shared by several endpoints is checked once per endpoint;
+        // findings that don't depend on the endpoint are reported once.
+        let bound: Vec<Option<&EndpointSpec>> = match endpoints.get(handler.name.as_str()) {
+            Some(eps) => eps.iter().map(|ep| Some(*ep)).collect(),
+            None => vec![None],
+        };
+        let mut seen = HashSet::new();
+        for endpoint in bound {
+            let mut checker = TypeChecker {
+                project,
+                index,
+                endpoint,
+                file,
+                errors: Vec::new(),
+            };
+            let mut env = HashMap::new();
+            checker.check_block(&handler.body, "$.body", &mut env);
+            for err in checker.errors {
+                if seen.insert((err.code.clone(), err.path.clone(), err.message.clone())) {
+                    report.push(err);
+                }
+            }
+        }
+    }
+}
+
+/// Flow-insensitive type inference over a single handler body.
+struct TypeChecker<'a> {
+    project: &'a LoadedProject,
+    index: &'a SpecIndex,
+    endpoint: Option<&'a EndpointSpec>,
+    file: &'a str,
+    errors: Vec<ErrorEntry>,
+}
+
+impl TypeChecker<'_> {
+    fn check_block(&mut self, stmts: &[AstNode], path: &str, env: &mut HashMap<String, TypeIR>) {
+        for (i, stmt) in stmts.iter().enumerate() {
+            self.check_statement(stmt, &format!("{}[{}]", path, i), env);
+        }
+    }
+
+    /// Check a nested block whose bindings don't outlive it.
+    fn check_scoped(
+        &mut self,
+        stmts: &[AstNode],
+        path: &str,
+        env: &HashMap<String, TypeIR>,
+        bindings: &[(&str, TypeIR)],
+    ) {
+        let mut inner = env.clone();
+        for (name, ty) in bindings {
+            inner.insert(name.to_string(), ty.clone());
+        }
+        self.check_block(stmts, path, &mut inner);
+    }
+
+    fn check_statement(&mut self, node: &AstNode, path: &str, env: &mut HashMap<String, TypeIR>) {
+        match node {
+            AstNode::LetStatement {
+                name,
+                value_type,
+                value,
+                ..
+            } => {
+                let value_path = format!("{}.value", path);
+                let inferred = self.infer(value, &value_path, env);
+                let ty = match value_type {
+                    Some(declared) => {
+                        let declared = type_ref_to_ir(declared);
+                        if !self.compatible(&inferred, &declared, 0) {
+                            self.errors.push(
+                                ErrorEntry::error(
+                                    E_TYPE_MISMATCH,
+                                    format!(
+                                        "'{}' is declared as {} but its value is {}",
+                                        name,
+                                        describe(&declared),
+                                        describe(&inferred)
+                                    ),
+                                    self.file,
+                                    &value_path,
+                                )
+                                .with_suggestion("Fix the valueType or the assigned expression"),
+                            );
+                        }
+                        declared
+                    }
+                    None => inferred,
+                };
+                env.insert(name.clone(), ty);
+            }
+            AstNode::AssignStatement { target, value, .. } => {
+                self.infer(target, &format!("{}.target", path), env);
+                self.infer(value, &format!("{}.value", path), env);
+            }
+            AstNode::ReturnStatement { value, .. } | AstNode::ThrowStatement { value, .. } => {
+                self.infer(value, &format!("{}.value", path), env);
+            }
+            AstNode::ExpressionStatement { expr, .. } => {
+                self.infer(expr, &format!("{}.expr", path), env);
+            }
+            AstNode::IfStatement {
+                condition,
+                then,
+                else_branch,
+                ..
+            } => {
+                self.infer(condition, &format!("{}.condition", path), env);
+                self.check_scoped(then, &format!("{}.then", path), env, &[]);
+                if let Some(else_branch) = else_branch {
+                    self.check_scoped(else_branch, &format!("{}.else", path), env, &[]);
+                }
+            }
+            AstNode::ForStatement {
+                binding,
+                iterable,
+                body,
+                ..
+            } => {
+                let iterable_ty = self.infer(iterable, &format!("{}.iterable", path), env);
+                let item = match self.resolve(&iterable_ty) {
+                    TypeIR::Array { inner } => *inner,
+                    _ => TypeIR::Any,
+                };
+                self.check_scoped(body, &format!("{}.body", path), env, &[(binding, item)]);
+            }
+            AstNode::WhileStatement {
+                condition, body, ..
+            } => {
+                self.infer(condition, &format!("{}.condition", path), env);
+                self.check_scoped(body, &format!("{}.body", path), env, &[]);
+            }
+            AstNode::MatchStatement { expr, arms, .. } => {
+                self.infer(expr, &format!("{}.expr", path), env);
+                for (i, arm) in arms.iter().enumerate() {
+                    let arm_path = format!("{}.arms[{}]", path, i);
+                    self.infer(&arm.pattern, &format!("{}.pattern", arm_path), env);
+                    self.check_scoped(&arm.body, &format!("{}.body", arm_path), env, &[]);
+                }
+            }
+            AstNode::TryCatchStatement {
+                try_block,
+                catch_block,
+                finally_block,
+                ..
+            } => {
+                self.check_scoped(try_block, &format!("{}.try", path), env, &[]);
+                self.check_scoped(
+                    &catch_block.body,
+                    &format!("{}.catch.body", path),
+                    env,
+                    &[(&catch_block.binding, TypeIR::Any)],
+                );
+                if let Some(finally_block) = finally_block {
+                    self.check_scoped(finally_block, &format!("{}.finally", path), env, &[]);
+                }
+            }
+            AstNode::DbTransaction { body, .. } => {
+                self.check_scoped(body, &format!("{}.body", path), env, &[]);
+            }
+            AstNode::UseComponent { inputs, bind, .. } => {
+                for (name, input) in inputs {
+                    self.infer(input, &format!("{}.inputs.{}", path, name), env);
+                }
+                if let Some(bind) = bind {
+                    env.insert(bind.clone(), TypeIR::Any);
+                }
+            }
+            other => {
+                self.infer(other, path, env);
+            }
+        }
+    }
+
+    fn infer(&mut self, node: &AstNode, path: &str, env: &HashMap<String, TypeIR>) -> TypeIR {
+        match node {
+            AstNode::Literal { value, .. } => match value {
+                serde_json::Value::String(_) => TypeIR::String,
+                serde_json::Value::Number(_) => TypeIR::Number,
+                serde_json::Value::Bool(_) => TypeIR::Boolean,
+                serde_json::Value::Null => TypeIR::Null,
+                _ => TypeIR::Any,
+            },
+            AstNode::Identifier { name, .. } => env.get(name).cloned().unwrap_or(TypeIR::Any),
+            AstNode::BinaryExpr {
+                operator,
+                left,
+                right,
+                ..
+            } => {
+                let l = self.infer(left, &format!("{}.left", path), env);
+                let r = self.infer(right, &format!("{}.right", path), env);
+                match operator.as_str() {
+                    "==" | "!=" | "===" | "!==" | "<" | "<=" | ">" | ">=" | "in" => TypeIR::Boolean,
+                    "+" if l == TypeIR::String || r == TypeIR::String => TypeIR::String,
+                    "+" | "-" | "*" | "/" | "%" | "**" => TypeIR::Number,
+                    _ if l == r => l,
+                    _ => TypeIR::Any,
+                }
+            }
+            AstNode::UnaryExpr {
+                operator, operand, ..
+            } => {
+                self.infer(operand, &format!("{}.operand", path), env);
+                match operator.as_str() {
+                    "!" | "not" => TypeIR::Boolean,
+                    "-" | "+" => TypeIR::Number,
+                    _ => TypeIR::Any,
+                }
+            }
+            AstNode::CallExpr { callee, args, .. } => {
+                self.infer(callee, &format!("{}.callee", path), env);
+                self.infer_all(args, &format!("{}.args", path), env);
+                TypeIR::Any
+            }
+            AstNode::CallFunction { function, args, .. } => {
+                self.infer_all(args, &format!("{}.args", path), env);
+                self.project
+                    .functions
+                    .iter()
+                    .find(|(_, f)| f.name == *function)
+                    .and_then(|(_, f)| f.return_type.as_ref())
+                    .map(type_ref_to_ir)
+                    .unwrap_or(TypeIR::Any)
+            }
+            AstNode::MemberExpr {
+                object, property, ..
+            } => {
+                let object_ty = self.infer(object, &format!("{}.object", path), env);
+                self.field_type(&object_ty, property, &format!("{}.property", path))
+            }
+            AstNode::IndexExpr { object, index, .. } => {
+                let object_ty = self.infer(object, &format!("{}.object", path), env);
+                self.infer(index, &format!("{}.index", path), env);
+                match self.resolve(&object_ty) {
+                    TypeIR::Array { inner } => *inner,
+                    _ => TypeIR::Any,
+                }
+            }
+            AstNode::ObjectExpr { properties, .. } => TypeIR::Object {
+                fields: properties
+                    .iter()
+                    .map(|(key, value)| {
+                        let ty = self.infer(value, &format!("{}.properties.{}", path, key), env);
+                        (key.clone(), ty)
+                    })
+                    .collect(),
+            },
+            AstNode::ArrayExpr { elements, .. } => {
+                let types = self.infer_all(elements, &format!("{}.elements", path), env);
+                match types.first() {
+                    Some(first) if types.iter().all(|t| t == first) => TypeIR::array(first.clone()),
+                    _ => TypeIR::array(TypeIR::Any),
+                }
+            }
+            AstNode::ArrowFn { params, body, .. } => {
+                let bindings: Vec<(&str, TypeIR)> =
+                    params.iter().map(|p| (p.as_str(), TypeIR::Any)).collect();
+                self.check_scoped(body, &format!("{}.body", path), env, &bindings);
+                TypeIR::Any
+            }
+            AstNode::AwaitExpr { expr, .. } => self.infer(expr, &format!("{}.expr", path), env),
+            AstNode::PipeExpr { stages, .. } => {
+                self.infer_all(stages, &format!("{}.stages", path), env);
+                TypeIR::Any
+            }
+            AstNode::TemplateString { parts, .. } => {
+                for (i, part) in parts.iter().enumerate() {
+                    if let TemplatePart::Expr { value } = part {
+                        self.infer(value, &format!("{}.parts[{}].value", path, i), env);
+                    }
+                }
+                TypeIR::String
+            }
+            AstNode::DbQuery {
+                model,
+                operation,
+                skip,
+                take,
+                select,
+                ..
+            } => {
+                for (key, node) in [("skip", skip), ("take", take)] {
+                    if let Some(node) = node {
+                        self.infer(node, &format!("{}.{}", path, key), env);
+                    }
+                }
+                let Some(row) = self.model_type(model, select.as_deref()) else {
+                    return TypeIR::Any;
+                };
+                db_result_type(QUERY_OPERATIONS, operation, row)
+            }
+            AstNode::DbMutate {
+                model,
+                operation,
+                data,
+                ..
+            } => {
+                if let Some(data) = data {
+                    self.infer(data, &format!("{}.data", path), env);
+                }
+                let Some(row) = self.model_type(model, None) else {
+                    return TypeIR::Any;
+                };
+                db_result_type(MUTATE_OPERATIONS, operation, row)
+            }
+            AstNode::HttpRespond { status, body, .. } => {
+                if let Some(body) = body {
+                    let body_path = format!("{}.body", path);
+                    let body_ty = self.infer(body, &body_path, env);
+                    self.check_response(*status, &body_ty, &body_path);
+                }
+                TypeIR::Any
+            }
+            AstNode::CtxGet { path: ctx_path, .. } => self.ctx_type(ctx_path, path),
+            AstNode::Validate { schema, data, .. } => {
+                self.infer(data, &format!("{}.data", path), env);
+                TypeIR::Ref(ref_name(schema))
+            }
+            AstNode::HashPassword { input, .. } => {
+                self.infer(input, &format!("{}.input", path), env);
+                TypeIR::String
+            }
+            AstNode::VerifyPassword { password, hash, .. } => {
+                self.infer(password, &format!("{}.password", path), env);
+                self.infer(hash, &format!("{}.hash", path), env);
+                TypeIR::Boolean
+            }
+            AstNode::SignToken { payload, .. } => {
+                self.infer(payload, &format!("{}.payload", path), env);
+                TypeIR::String
+            }
+            AstNode::VerifyToken { token, .. } => {
+                self.infer(token, &format!("{}.token", path), env);
+                TypeIR::Any
+            }
+            AstNode::SendEmail {
+                to, subject, body, ..
+            } => {
+                self.infer(to, &format!("{}.to", path), env);
+                self.infer(subject, &format!("{}.subject", path), env);
+                self.infer(body, &format!("{}.body", path), env);
+                TypeIR::Void
+            }
+            AstNode::EmitEvent { data, .. } => {
+                if let Some(data) = data {
+                    self.infer(data, &format!("{}.data", path), env);
+                }
+                TypeIR::Void
+            }
+            AstNode::LogMessage { message, .. } => {
+                self.infer(message, &format!("{}.message", path), env);
+                TypeIR::Void
+            }
+            // Statements in expression position and bridge calls carry no usable type
+            _ => TypeIR::Any,
+        }
+    }
+
+    fn infer_all(
+        &mut self,
+        nodes: &[AstNode],
+        path: &str,
+        env: &HashMap<String, TypeIR>,
+    ) -> Vec<TypeIR> {
+        nodes
+            .iter()
+            .enumerate()
+            .map(|(i, node)| self.infer(node, &format!("{}[{}]", path, i), env))
+            .collect()
+    }
+
+    /// Type of `property` on `object_ty`, reporting fields that a known object
+    /// type doesn't have.
+    fn field_type(&mut self, object_ty: &TypeIR, property: &str, path: &str) -> TypeIR {
+        match self.resolve(object_ty) {
+            TypeIR::Optional { inner } => self.field_type(&inner, property, path),
+            TypeIR::Object { fields } => match fields.iter().find(|(name, _)| name == property) {
+                Some((_, ty)) => ty.clone(),
+                None => {
+                    let available: Vec<&str> =
+                        fields.iter().map(|(name, _)| name.as_str()).collect();
+                    self.errors.push(
+                        ErrorEntry::error(
+                            E_UNKNOWN_FIELD,
+                            format!("{} has no field '{}'", describe(object_ty), property),
+                            self.file,
+                            path,
+                        )
+                        .with_suggestion(format!("Available fields: {}", available.join(", "))),
+                    );
+                    TypeIR::Any
+                }
+            },
+            TypeIR::Array { .. } if property == "length" => TypeIR::Number,
+            TypeIR::String if property == "length" => TypeIR::Number,
+            _ => TypeIR::Any,
+        }
+    }
+
+    /// Type of a `CtxGet` path, resolved against the endpoint's request schemas
+    /// and the values its middleware chain provides.
+    fn ctx_type(&mut self, ctx_path: &str, path: &str) -> TypeIR {
+        let mut segments = ctx_path.split('.');
+        let root = segments.next().unwrap_or_default();
+        let request = self.endpoint.and_then(|ep| ep.request.as_ref());
+        let mut ty = match root {
+            "body" => match request.and_then(|r| r.body.as_ref()) {
+                Some(body) => TypeIR::Ref(ref_name(&body.reference)),
+                None => return TypeIR::Any,
+            },
+            "query" => match request.and_then(|r| r.query.as_ref()) {
+                Some(query) => TypeIR::Ref(ref_name(&query.reference)),
+                None => return TypeIR::Any,
+            },
+            "params" | "headers" if ctx_path.contains('.') => return TypeIR::String,
+            "state" => {
+                let provided = match (self.endpoint, segments.next()) {
+                    (Some(endpoint), Some(name)) => {
+                        provided_context(self.project, self.index, endpoint).shift_remove(name)
+                    }
+                    _ => None,
+                };
+                // Values no middleware provides are reported by route_contract
+                match provided {
+                    Some(ty) => ty,
+                    None => return TypeIR::Any,
+                }
+            }
+            _ => return TypeIR::Any,
+        };
+        let field_path = format!("{}.path", path);
+        for segment in segments {
+            ty = self.field_type(&ty, segment, &field_path);
+        }
+        ty
+    }
+
+    /// Check an `HttpRespond` body against the schema declared for its status.
+    /// Inferred bodies are approximate, so a mismatch warns rather than blocking codegen.
+    fn check_response(&mut self, status: u16, body_ty: &TypeIR, path: &str) {
+        let Some(schema_ref) = self
+            .endpoint
+            .and_then(|ep| ep.response.as_ref())
+            .and_then(|responses| responses.get(&status.to_string()))
+            .and_then(|resp| resp.schema.as_ref())
+        else {
+            return;
+        };
+        let expected = TypeIR::Ref(ref_name(&schema_ref.reference));
+        if !self.compatible(body_ty, &expected, 0) {
+            self.errors.push(
+                ErrorEntry::warning(
+                    E_RESPONSE_MISMATCH,
+                    format!(
+                        "Response body for status {} is {} but schema '{}' expects {}",
+                        status,
+                        describe(body_ty),
+                        schema_ref.reference,
+                        describe(&self.resolve(&expected))
+                    ),
+                    self.file,
+                    path,
+                )
+                .with_suggestion(format!(
+                    "Return a value matching '{}' or change the response schema",
+                    schema_ref.reference
+                )),
+            );
+        }
+    }
+
+    /// Whether a value of type `actual` can stand in for `expected`. Unknown
+    /// types (`Any`, unresolvable refs) are accepted.
+    fn compatible(&self, actual: &TypeIR, expected: &TypeIR, depth: usize) -> bool {
+        if depth > MAX_REF_DEPTH || actual == expected {
+            return true;
+        }
+        let actual = self.resolve(actual);
+        let expected = self.resolve(expected);
+        match (&actual, &expected) {
+            (TypeIR::Any, _)
+            | (_, TypeIR::Any)
+            | (TypeIR::Ref { .. }, _)
+            | (_, TypeIR::Ref { .. }) => true,
+            (TypeIR::Null, TypeIR::Optional { .. }) => true,
+            // Null checks aren't tracked, so an optional value is taken as present
+            (TypeIR::Optional { inner: a }, TypeIR::Optional { inner: e }) => {
+                self.compatible(a, e, depth + 1)
+            }
+            (TypeIR::Optional { inner: a }, e) => self.compatible(a, e, depth + 1),
+            (a, TypeIR::Optional { inner: e }) => self.compatible(a, e, depth + 1),
+            (TypeIR::Array { inner: a }, TypeIR::Array { inner: e }) => {
+                self.compatible(a, e, depth + 1)
+            }
+            (
+                TypeIR::Object {
+                    fields: actual_fields,
+                },
+                TypeIR::Object {
+                    fields: expected_fields,
+                },
+            ) => expected_fields.iter().all(|(name, e)| {
+                match actual_fields.iter().find(|(n, _)| n == name) {
+                    Some((_, a)) => self.compatible(a, e, depth + 1),
+                    None => matches!(e, TypeIR::Optional { .. }),
+                }
+            }),
+            (TypeIR::Union { variants }, e) => {
+                variants.iter().all(|a| self.compatible(a, e, depth + 1))
+            }
+            (a, TypeIR::Union { variants }) => {
+                variants.iter().any(|e| self.compatible(a, e, depth + 1))
+            }
+            _ => false,
+        }
+    }
+
+    /// Expand a schema ref one level; other types are returned as-is.
+    fn resolve(&self, ty: &TypeIR) -> TypeIR {
+        match ty {
+            TypeIR::Ref { name } => self.schema_type(name).unwrap_or_else(|| ty.clone()),
+            other => other.clone(),
+        }
+    }
+
+    fn schema_type(&self, name: &str) -> Option<TypeIR> {
+        let entry = self.index.lookup(name, SymbolKind::Schema)?;
+        self.project
+            .schemas
+            .iter()
+            .filter(|(file, _)| *file == entry.file)
+            .find_map(|(_, schema)| schema.definitions.get(&entry.original_name))
+            .map(convert_json_schema)
+    }
+
+    /// Row type of a model, narrowed to `select` when given. Relations are only
+    /// present when included, so they are typed loosely.
+    fn model_type(&self, name: &str, select: Option<&[String]>) -> Option<TypeIR> {
+        let entry = self.index.lookup(name, SymbolKind::Model)?;
+        let (_, model) = self
+            .project
+            .models
+            .iter()
+            .find(|(file, model)| *file == entry.file && model.name == entry.original_name)?;
+        let columns = model.columns.iter().map(|(name, col)| {
+            let ty = column_type(&col.col_type);
+            let ty = if col.nullable == Some(true) {
+                TypeIR::optional(ty)
+            } else {
+                ty
+            };
+            (name.clone(), ty)
+        });
+        let relations = model
+            .relations
+            .keys()
+            .map(|name| (name.clone(), TypeIR::Any));
+        let fields = columns
+            .chain(relations)
+            .filter(|(name, _)| select.map_or(true, |s| s.contains(name)))
+            .collect();
+        Some(TypeIR::Object { fields })
+    }
+}
+
+/// Strip file and JSON-pointer prefixes from a schema ref
+/// (`"user.schema#UserResponse"` or `"#/definitions/UserResponse"`).
+fn ref_name(reference: &str) -> String {
+    reference
+        .rsplit(['#', '/'])
+        .next()
+        .unwrap_or(reference)
+        .to_string()
+}
+
+fn type_ref_to_ir(type_ref: &TypeRef) -> TypeIR {
+    match type_ref {
+        TypeRef::Simple(s) => match s.as_str() {
+            "string" | "String" => TypeIR::String,
+            "number" | "Number" | "integer" | "int" | "float" | "i32" | "i64" | "f32" | "f64" => {
+                TypeIR::Number
+            }
+            "boolean" | "Boolean" | "bool" => TypeIR::Boolean,
+            "null" | "None" | "nil" => TypeIR::Null,
+            "void" | "unit" | "()" => TypeIR::Void,
+            "any" | "Any" => TypeIR::Any,
+            other => TypeIR::Ref(ref_name(other)),
+        },
+        TypeRef::Reference(r) => TypeIR::Ref(ref_name(&r.reference)),
+        TypeRef::Complex {
+            reference,
+            nullable,
+        } => {
+            let ty = TypeIR::Ref(ref_name(reference));
+            if *nullable == Some(true) {
+                TypeIR::optional(ty)
+            } else {
+                ty
+            }
+        }
+    }
+}
+
+/// Type of a database operation on rows of type `row`; `Any` for operations
+/// the `db_check` rule reports.
+fn db_result_type(operations: &[(&str, DbResult)], operation: &str, row: TypeIR) -> TypeIR {
+    match operation_result(operations, operation) {
+        Some(DbResult::Row) => row,
+        Some(DbResult::OptionalRow) => TypeIR::optional(row),
+        Some(DbResult::Rows) => TypeIR::array(row),
+        Some(DbResult::Count) => TypeIR::Number,
+        None => TypeIR::Any,
+    }
+}
+
+/// Map a model column type (e.g. `varchar(255)`) to a type.
+fn column_type(col_type: &str) -> TypeIR {
+    let base = col_type
+        .split('(')
+        .next()
+        .unwrap_or(col_type)
+        .trim()
+        .to_lowercase();
+    match base.as_str() {
+        "uuid" | "varchar" | "char" | "text" | "string" | "enum" | "timestamp" | "timestamptz"
+        | "date" | "time" | "datetime" => TypeIR::String,
+        "int" | "integer" | "smallint" | "bigint" | "serial" | "bigserial" | "decimal"
+        | "numeric" | "float" | "double" | "real" => TypeIR::Number,
+        "boolean" | "bool" => TypeIR::Boolean,
+        _ => TypeIR::Any,
+    }
+}
+
+/// Short human-readable rendering of a type for error messages.
+fn describe(ty: &TypeIR) -> String {
+    match ty {
+        TypeIR::String => "string".to_string(),
+        TypeIR::Number => "number".to_string(),
+        TypeIR::Boolean => "boolean".to_string(),
+        TypeIR::Null => "null".to_string(),
+        TypeIR::Void => "void".to_string(),
+        TypeIR::Any => "any".to_string(),
+        TypeIR::Array { inner } => format!("{}[]", describe(inner)),
+        TypeIR::Optional { inner } => format!("{} | null", describe(inner)),
+        TypeIR::Ref { name } => format!("'{}'", name),
+        TypeIR::Object { fields } => {
+            let names: Vec<&str> = fields.iter().map(|(name, _)| name.as_str()).collect();
+            format!("{{ {} }}", names.join(", "))
+        }
+        TypeIR::Union { variants } => variants
+            .iter()
+            .map(describe)
+            .collect::<Vec<_>>()
+            .join(" | "),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rash_spec::index::build_index;
+    use rash_spec::types::common::Severity;
+    use rash_spec::types::handler::HandlerSpec;
+    use std::path::PathBuf;
+
+    fn load_fixture(name: &str) -> LoadedProject {
+        let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .parent()
+            .unwrap()
+            .parent()
+            .unwrap()
+            .join("fixtures")
+            .join(name);
+        let (project, _) = rash_spec::loader::load_project(&dir).unwrap();
+        project
+    }
+
+    fn golden_project() -> LoadedProject {
+        load_fixture("golden-user-crud")
+    }
+
+    fn replace_body(project: &mut LoadedProject, handler: &str, body: serde_json::Value) {
+        let (_, spec): &mut (String, HandlerSpec) = project
+            .handlers
+            .iter_mut()
+            .find(|(_, h)| h.name == handler)
+            .unwrap();
+        spec.body = serde_json::from_value(body).unwrap();
+    }
+
+    fn run(project: &LoadedProject) -> Vec<ErrorEntry> {
+        let (index, _) = build_index(project);
+        let mut report = ValidationReport::success();
+        check_handler_types(project, &index, &mut report);
+        report.errors
+    }
+
+    /// Entries reported against one handler file.
+    fn run_on(project: &LoadedProject, file: &str) -> Vec<ErrorEntry> {
+        run(project).into_iter().filter(|e| e.file == file).collect()
+    }
+
+    fn codes(errors: &[ErrorEntry]) -> Vec<(&str, &str)> {
+        errors
+            .iter()
+            .map(|e| (e.code.as_str(), e.path.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn test_golden_project_is_well_typed() {
+        // listUsers responds with bare rows; see fixtures/contracts/list-response
+        let errors = run(&golden_project());
+        assert_eq!(
+            codes(&errors),
+            vec![(E_RESPONSE_MISMATCH, "$.body[1].value.body")]
+        );
+        assert_eq!(errors[0].file, "handlers/listUsers.handler.json");
+        assert_eq!(errors[0].severity, Severity::Warning);
+    }
+
+    #[test]
+    fn test_list_response_fixture() {
+        let project = load_fixture("contracts/list-response");
+        let errors = run(&project);
+        assert_eq!(
+            codes(&errors),
+            vec![(E_RESPONSE_MISMATCH, "$.body[1].value.body")]
+        );
+        assert_eq!(errors[0].file, "handlers/listUsers.handler.json");
+        assert!(errors[0].message.contains("'UserListResponse'"));
+        assert!(run_on(&project, "handlers/listUsersPaged.handler.json").is_empty());
+    }
+
+    #[test]
+    fn test_unknown_field_on_request_body() {
+        let mut project = golden_project();
+        replace_body(
+            &mut project,
+            "users.createUser",
+            serde_json::json!([
+                {
+                    "type": "LetStatement", "tier": 0, "name": "data",
+                    "value": { "type": "CtxGet", "tier": 1, "path": "body" }
+                },
+                {
+                    "type": "LetStatement", "tier": 0, "name": "email",
+                    "value": {
+                        "type": "MemberExpr", "tier": 0,
+                        "object": { "type": "Identifier", "tier": 0, "name": "data" },
+                        "property": "emial"
+                    }
+                },
+                {
+                    "type": "LetStatement", "tier": 0, "name": "name",
+                    "value": { "type": "CtxGet", "tier": 1, "path": "body.nmae" }
+                }
+            ]),
+        );
+
+        let errors = run_on(&project, "handlers/createUser.handler.json");
+        assert_eq!(
+            codes(&errors),
+            vec![
+                (E_UNKNOWN_FIELD, "$.body[1].value.property"),
+                (E_UNKNOWN_FIELD, "$.body[2].value.path"),
+            ]
+        );
+        assert_eq!(errors[0].file, "handlers/createUser.handler.json");
+        assert!(errors[0].suggestion.as_deref().unwrap().contains("email"));
+    }
+
+    #[test]
+    fn test_middleware_state_is_typed() {
+        let mut project = golden_project();
+        replace_body(
+            &mut project,
+            "users.createUser",
+            serde_json::json!([
+                {
+                    "type": "LetStatement", "tier": 0, "name": "role",
+                    "valueType": "string",
+                    "value": { "type": "CtxGet", "tier": 1, "path": "state.user.role" }
+                },
+                {
+                    "type": "LetStatement", "tier": 0, "name": "admin",
+                    "valueType": "boolean",
+                    "value": { "type": "CtxGet", "tier": 1, "path": "state.user.isAdmin" }
+                },
+                {
+                    "type": "LetStatement", "tier": 0, "name": "id",
+                    "valueType": "number",
+                    "value": { "type": "CtxGet", "tier": 1, "path": "state.user.id" }
+                }
+            ]),
+        );
+
+        let errors = run_on(&project, "handlers/createUser.handler.json");
+        assert_eq!(
+            codes(&errors),
+            vec![
+                (E_UNKNOWN_FIELD, "$.body[1].value.path"),
+                (E_TYPE_MISMATCH, "$.body[2].value"),
+            ]
+        );
+        assert!(errors[0].message.contains("isAdmin"));
+    }
+
+    #[test]
+    fn test_unknown_field_on_model_row() {
+        let mut project = golden_project();
+        replace_body(
+            &mut project,
+            "users.listUsers",
+            serde_json::json!([
+                {
+                    "type": "LetStatement", "tier": 0, "name": "user",
+                    "value": {
+                        "type": "DbQuery", "tier": 1, "model": "User",
+                        "operation": "findFirst", "select": ["id", "email"]
+                    }
+                },
+                {
+                    "type": "ExpressionStatement", "tier": 0,
+                    "expr": {
+                        "type": "MemberExpr", "tier": 0,
+                        "object": { "type": "Identifier", "tier": 0, "name": "user" },
+                        "property": "name"
+                    }
+                }
+            ]),
+        );
+
+        assert_eq!(
+            codes(&run(&project)),
+            vec![(E_UNKNOWN_FIELD, "$.body[1].expr.property")]
+        );
+    }
+
+    #[test]
+    fn test_declared_type_mismatch() {
+        let mut project = golden_project();
+        replace_body(
+            &mut project,
+            "users.listUsers",
+            serde_json::json!([
+                {
+                    "type": "LetStatement", "tier": 0, "name": "total",
+                    "valueType": "number",
+                    "value": {
+                        "type": "DbQuery", "tier": 1, "model": "User", "operation": "findMany"
+                    }
+                }
+            ]),
+        );
+
+        assert_eq!(
+            codes(&run(&project)),
+            vec![(E_TYPE_MISMATCH, "$.body[0].value")]
+        );
+    }
+
+    #[test]
+    fn test_respond_body_checked_against_status_schema() {
+        let mut project = golden_project();
+        replace_body(
+            &mut project,
+            "users.createUser",
+            serde_json::json!([
+                {
+                    "type": "IfStatement", "tier": 0,
+                    "condition": { "type": "Literal", "tier": 0, "value": true },
+                    "then": [{
+                        "type": "ReturnStatement", "tier": 0,
+                        "value": {
+                            "type": "HttpRespond", "tier": 1, "status": 400,
+                            "body": {
+                                "type": "ObjectExpr", "tier": 0,
+                                "properties": {
+                                    "message": { "type": "Literal", "tier": 0, "value": "bad" }
+                                }
+                            }
+                        }
+                    }]
+                },
+                {
+                    "type": "ReturnStatement", "tier": 0,
+                    "value": {
+                        "type": "HttpRespond", "tier": 1, "status": 201,
+                        "body": { "type": "Literal", "tier": 0, "value": "created" }
+                    }
+                }
+            ]),
+        );
+
+        let errors = run_on(&project, "handlers/createUser.handler.json");
+        // ErrorResponse requires `code`; UserResponse is an object, not a string
+        assert_eq!(
+            codes(&errors),
+            vec![
+                (E_RESPONSE_MISMATCH, "$.body[0].then[0].value.body"),
+                (E_RESPONSE_MISMATCH, "$.body[1].value.body"),
+            ]
+        );
+    }
+}
@@ -81,6 +81,8 @@ mod tests {
             models: vec![],
             middleware: vec![],
             handlers: vec![],
+            functions: vec![],
+            components: vec![],
         }
     }
 
@@ -0,0 +1,76 @@
+/// Pick the candidate closest to `name` by edit distance, if any is close
+/// enough to be a plausible typo (case differences count as one edit).
+pub(crate) fn closest<'a>(
+    name: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Option<&'a str> {
+    let max = (name.chars().count() / 3).max(1);
+    candidates
+        .into_iter()
+        .map(|candidate| (edit_distance(name, candidate), candidate))
+        .filter(|(distance, _)| *distance <= max)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// `"Did you mean 'x'?"` when a close candidate exists, otherwise the list of
+/// valid names.
+pub(crate) fn did_you_mean<'a>(
+    name: &str,
+    candidates: impl IntoIterator<Item = &'a str> + Clone,
+) -> String {
+    match closest(name, candidates.clone()) {
+        Some(candidate) => format!("Did you mean '{}'?", candidate),
+        None => format!(
+            "Expected one of: {}",
+            candidates.into_iter().collect::<Vec<_>>().join(", ")
+        ),
+    }
+}
+
+/// Edit distance counting insertions, deletions, substitutions and adjacent
+/// transpositions (`emial` → `email`) as one edit each. Comparison is
+/// case-insensitive, but a case-only difference still costs one edit.
+fn edit_distance(a: &str, b: &str) -> usize {
+    if a.eq_ignore_ascii_case(b) {
+        return usize::from(a != b);
+    }
+    let a: Vec<char> = a.to_lowercase().chars().collect();
+    let b: Vec<char> = b.to_lowercase().chars().collect();
+    let mut d = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in d.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    d[0] = (0..=b.len()).collect();
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            d[i][j] = (d[i - 1][j] + 1)
+                .min(d[i][j - 1] + 1)
+                .min(d[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                d[i][j] = d[i][j].min(d[i - 2][j - 2] + 1);
+            }
+        }
+    }
+    d[a.len()][b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_closest_candidate() {
+        let models = ["User", "Post", "Profile"];
+        assert_eq!(closest("Users", models), Some("User"));
+        assert_eq!(closest("user", models), Some("User"));
+        assert_eq!(closest("Comment", models), None);
+        assert_eq!(closest("Porfile", models), Some("Profile"));
+        assert_eq!(
+            did_you_mean("fndMany", ["findMany", "findFirst"]),
+            "Did you mean 'findMany'?"
+        );
+        assert_eq!(did_you_mean("xyz", ["a", "b"]), "Expected one of: a, b");
+    }
+}
@@ -24,6 +24,10 @@ pub fn validate(project: &LoadedProject) -> ValidationReport {
 
     rules::ref_integrity::check(project, &resolver, &mut report);
     rules::required_fields::check(project, &mut report);
+    rules::model_check::check(project, &resolver, &mut report);
+    rules::db_check::check(project, &resolver, &mut report);
+    rules::route_contract::check(project, &index, &mut report);
+    rules::scope_check::check(project, &mut report);
     rules::type_consistency::check(project, &index, &mut report);
     rules::cycle_detect::check(project, &index, &mut report);
 
@@ -4,6 +4,10 @@ use std::process::ExitCode;
 use anyhow::{Context, Result};
 use clap::{Parser, Subcommand};
 use colored::Colorize;
+use rash_runtime::hmu_engine::{HmuConfig, HmuEngine};
+use rash_runtime::log_types::LogSource;
+use rash_runtime::process_manager::ProcessManager;
+use rash_runtime::watch::{self, DevSession, SpecWatcher, WatchEvent};
 
 #[derive(Parser)]
 #[command(name = "rash", about = "Rash – visual server application builder")]
@@ -53,6 +57,18 @@ enum Command {
         #[arg(short, long)]
         output: Option<PathBuf>,
     },
+    /// Regenerate code and hot-update the server whenever specs change
+    Dev {
+        /// Project directory (defaults to current directory)
+        #[arg(default_value = ".")]
+        path: PathBuf,
+        /// Output directory (defaults to codegen.outDir)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+        /// Only regenerate code, without running the server
+        #[arg(long)]
+        no_server: bool,
+    },
 }
 
 fn main() -> ExitCode {
@@ -69,6 +85,11 @@ fn main() -> ExitCode {
         Command::Validate { path } => cmd_validate(&path),
         Command::Check { path } => cmd_check(&path),
         Command::Codegen { path, output } => cmd_codegen(&path, output.as_deref()),
+        Command::Dev {
+            path,
+            output,
+            no_server,
+        } => cmd_dev(&path, output.as_deref(), no_server),
     };
 
     match result {
@@ -469,6 +490,158 @@ fn cmd_codegen(path: &Path, output: Option<&Path>) -> Result<bool> {
     Ok(true)
 }
 
+fn cmd_dev(path: &Path, output: Option<&Path>, no_server: bool) -> Result<bool> {
+    let runtime = tokio::runtime::Runtime::new().context("Failed to start the async runtime")?;
+    runtime.block_on(dev(path, output, no_server))
+}
+
+async fn dev(path: &Path, output: Option<&Path>, no_server: bool) -> Result<bool> {
+    println!(
+        "{} {}",
+        "Starting dev session for".bold(),
+        path.canonicalize()
+            .unwrap_or_else(|_| path.to_path_buf())
+            .display()
+    );
+
+    // 1. Load, generate & write the whole project once
+    let mut session = DevSession::open(path, output)
+        .with_context(|| format!("Failed to generate project at '{}'", path.display()))?;
+    println!(
+        "{} Generated → {}",
+        "✓".green().bold(),
+        session.output_dir().display()
+    );
+
+    // 2. Start the server, forwarding its output
+    let mut server = None;
+    if !no_server {
+        let (mut process_manager, mut log_rx, _status_rx) = ProcessManager::new();
+        let port = process_manager
+            .start(&session.server_config())
+            .await
+            .context("Failed to start the server")?;
+        tokio::spawn(async move {
+            while let Some(log) = log_rx.recv().await {
+                match log.source {
+                    LogSource::Stdout => println!("  {} {}", "│".dimmed(), log.message),
+                    LogSource::Stderr => eprintln!("  {} {}", "│".red(), log.message),
+                }
+            }
+        });
+        println!("{} Server running on port {}", "✓".green().bold(), port);
+        if !session.hmu_enabled() {
+            println!(
+                "{} codegen.hmu is off — the server restarts on every change",
+                "⚠".yellow().bold()
+            );
+        }
+        server = Some((process_manager, HmuEngine::new(HmuConfig::default())));
+    }
+
+    // 3. Apply spec edits as they happen
+    let mut watcher = SpecWatcher::new(
+        path,
+        &[session.output_dir().to_path_buf()],
+        watch::DEFAULT_DEBOUNCE,
+    )?;
+    println!("{}", "Watching for spec changes (Ctrl+C to stop)".dimmed());
+
+    loop {
+        let batch = tokio::select! {
+            batch = watcher.next_batch() => batch,
+            _ = tokio::signal::ctrl_c() => None,
+        };
+        let Some(batch) = batch else {
+            break;
+        };
+
+        let update = match session.apply(&batch) {
+            Ok(update) => update,
+            Err(e) => {
+                print_watch_event(&WatchEvent::Failed {
+                    specs: batch,
+                    message: e.to_string(),
+                });
+                continue;
+            }
+        };
+        print_watch_event(&WatchEvent::regenerated(&update));
+
+        if let Some((process_manager, hmu_engine)) = server.as_mut() {
+            if update.changes.is_empty() {
+                continue;
+            }
+            match session
+                .push(process_manager, hmu_engine, &update)
+                .await
+            {
+                Ok(event) => print_watch_event(&event),
+                Err(e) => eprintln!(
+                    "{} failed to restart the server: {e}",
+                    "error:".red().bold()
+                ),
+            }
+        }
+    }
+
+    if let Some((mut process_manager, _)) = server {
+        process_manager
+            .stop()
+            .await
+            .context("Failed to stop the server")?;
+    }
+    Ok(true)
+}
+
+fn print_watch_event(event: &WatchEvent) {
+    match event {
+        WatchEvent::Regenerated {
+            specs,
+            files,
+            full_regen,
+        } => {
+            let scope = if *full_regen { " (full)" } else { "" };
+            println!(
+                "{} {} → {} file(s){}",
+                "↻".cyan().bold(),
+                specs.join(", "),
+                files.len(),
+                scope.dimmed()
+            );
+            for file_path in files {
+                println!("  {} {}", "→".dimmed(), file_path);
+            }
+        }
+        WatchEvent::Pushed(push) => {
+            if push.failed.is_empty() {
+                println!(
+                    "{} {} applied {} module(s)",
+                    "✓".green().bold(),
+                    push.id,
+                    push.applied.len()
+                );
+            } else {
+                println!(
+                    "{} {} failed: {}",
+                    "⚠".yellow().bold(),
+                    push.id,
+                    push.error.as_deref().unwrap_or(&push.failed.join(", "))
+                );
+            }
+            if let Some(port) = push.restarted_on {
+                println!("{} Server restarted on port {}", "✓".green().bold(), port);
+            }
+        }
+        WatchEvent::Restarted { port } => {
+            println!("{} Server restarted on port {}", "✓".green().bold(), port);
+        }
+        WatchEvent::Failed { specs, message } => {
+            println!("{} {}: {}", "✗".red().bold(), specs.join(", "), message);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -3,3 +3,4 @@ pub mod openapi;
 pub mod project;
 pub mod runtime;
 pub mod spec;
+pub mod watch;
@@ -99,6 +99,11 @@ pub fn open_project(
 pub fn close_project(state: State<'_, AppState>) -> Result<(), AppError> {
     let mut guard = state.project.lock().map_err(|e| AppError::IoError(e.to_string()))?;
     *guard = None;
+
+    // Stop watching the closed project's specs
+    if let Some(task) = state.watch.lock().map_err(|e| AppError::IoError(e.to_string()))?.take() {
+        task.abort();
+    }
     Ok(())
 }
 
@@ -177,6 +182,32 @@ fn build_project_tree(project: &loader::LoadedProject, root: &Path) -> ProjectTr
         .collect();
     handler_children.sort_by(|a, b| a.label.cmp(&b.label));
 
+    let mut function_children: Vec<TreeNode> = project
+        .functions
+        .iter()
+        .map(|(file, function)| TreeNode {
+            id: format!("function:{}", function.name),
+            label: function.name.clone(),
+            kind: "function".to_string(),
+            path: Some(file.clone()),
+            children: vec![],
+        })
+        .collect();
+    function_children.sort_by(|a, b| a.label.cmp(&b.label));
+
+    let mut component_children: Vec<TreeNode> = project
+        .components
+        .iter()
+        .map(|(file, component)| TreeNode {
+            id: format!("component:{}", component.name),
+            label: component.name.clone(),
+            kind: "component".to_string(),
+            path: Some(file.clone()),
+            children: vec![],
+        })
+        .collect();
+    component_children.sort_by(|a, b| a.label.cmp(&b.label));
+
     let nodes = vec![
         TreeNode {
             id: "section:routes".to_string(),
@@ -213,6 +244,20 @@ fn build_project_tree(project: &loader::LoadedProject, root: &Path) -> ProjectTr
             path: None,
             children: handler_children,
         },
+        TreeNode {
+            id: "section:functions".to_string(),
+            label: "Functions".to_string(),
+            kind: "section".to_string(),
+            path: None,
+            children: function_children,
+        },
+        TreeNode {
+            id: "section:components".to_string(),
+            label: "Components".to_string(),
+            kind: "section".to_string(),
+            path: None,
+            children: component_children,
+        },
     ];
 
     ProjectTree {
@@ -1,15 +1,15 @@
-use std::collections::HashMap;
-
 use tauri::{AppHandle, Emitter, State};
 
 use serde::Serialize;
 
-use rash_runtime::hmu_engine::{HmuConfig, HmuEngine};
+use rash_runtime::hmu_engine::{HmuConfig, HmuEngine, HmuError};
+use rash_runtime::hmu_types::HmuStatus;
 use rash_runtime::incremental::{FileChange, IncrementalCodegen};
 use rash_runtime::preflight::PreflightReport;
 use rash_runtime::preflight_checker::PreflightChecker;
 use rash_runtime::process_manager::{ProcessManager, ServerConfig, ServerStatus};
 use rash_runtime::runtime_detect::{DetectedRuntime, RuntimeDetector};
+use rash_runtime::watch::{default_output_dir, hot_swappable};
 
 use crate::error::AppError;
 use crate::state::{AppState, RuntimeState};
@@ -32,27 +32,7 @@ pub async fn start_server(
     state: State<'_, AppState>,
 ) -> Result<u16, AppError> {
     // 1. Extract project config while holding the sync lock briefly
-    let server_config = {
-        let guard = state.project.lock().unwrap();
-        let open = guard.as_ref().ok_or(AppError::NoProject)?;
-        let config = &open.project.config;
-
-        ServerConfig {
-            language: config.target.language,
-            framework: config.target.framework,
-            runtime: config.target.runtime,
-            port: config.server.port,
-            host: config.server.host.clone(),
-            output_dir: open.root.join(
-                config
-                    .codegen
-                    .as_ref()
-                    .map(|c| c.out_dir.as_str())
-                    .unwrap_or("./dist"),
-            ),
-            env_vars: HashMap::new(),
-        }
-    };
+    let server_config = server_config(&state)?;
 
     // 2. Stop existing process if any
     {
@@ -109,6 +89,17 @@ pub async fn start_server(
     Ok(port)
 }
 
+/// Server configuration of the open project.
+fn server_config(state: &AppState) -> Result<ServerConfig, AppError> {
+    let guard = state.project.lock().unwrap();
+    let open = guard.as_ref().ok_or(AppError::NoProject)?;
+
+    Ok(ServerConfig::for_project(
+        &open.project.config,
+        default_output_dir(&open.project),
+    ))
+}
+
 #[tauri::command]
 pub async fn stop_server(state: State<'_, AppState>) -> Result<(), AppError> {
     let mut rt_guard = state.runtime.lock().await;
@@ -171,23 +162,41 @@ pub async fn apply_hmu(
     state: State<'_, AppState>,
     changes: Vec<FileChange>,
 ) -> Result<HmuResultPayload, AppError> {
-    let mut rt_guard = state.runtime.lock().await;
-    let rt_state = rt_guard
-        .as_mut()
-        .ok_or(AppError::RuntimeError("no server is running".into()))?;
+    // Needed if the server has to be restarted; read before the runtime lock
+    let server_config = server_config(&state)?;
+    // Only changes the server's HMU client can swap are sent; the rest are
+    // loaded by restarting it
+    let swappable = {
+        let guard = state.project.lock().unwrap();
+        let open = guard.as_ref().ok_or(AppError::NoProject)?;
+        hot_swappable(&open.project, &changes)
+    };
 
-    // Convert FileChanges to HMU modules
-    let modules = IncrementalCodegen::to_hmu_modules(&changes);
+    // Create the update and take a handle to the HMU channel, so the runtime
+    // lock isn't held while the server applies the update
+    let (update, link, hmu_config) = {
+        let mut rt_guard = state.runtime.lock().await;
+        let rt_state = rt_guard
+            .as_mut()
+            .ok_or(AppError::RuntimeError("no server is running".into()))?;
 
-    // Create HMU update
-    let update = rt_state.hmu_engine.create_update(
-        modules
-            .into_iter()
-            .map(|m| (m.path, m.action, m.content))
-            .collect(),
-    );
+        // Convert FileChanges to HMU modules
+        let modules = IncrementalCodegen::to_hmu_modules(&changes);
 
-    let payload = HmuResultPayload {
+        let update = rt_state.hmu_engine.create_update(
+            modules
+                .into_iter()
+                .map(|m| (m.path, m.action, m.content))
+                .collect(),
+        );
+        (
+            update,
+            rt_state.process_manager.hmu_link(),
+            rt_state.hmu_engine.config().clone(),
+        )
+    };
+
+    let pending = HmuResultPayload {
         id: update.id.clone(),
         status: "pending".into(),
         applied: vec![],
@@ -196,12 +205,75 @@ pub async fn apply_hmu(
     };
 
     // Emit the update event for the frontend to track
-    if let Err(e) = app.emit("hmu:result", &payload) {
+    if let Err(e) = app.emit("hmu:result", &pending) {
         eprintln!("[rash] warning: failed to emit hmu result: {e}");
     }
 
+    // Send the update and wait for the server's ACK
+    let mut link = match link {
+        Some(link) => Some(link.lock_owned().await),
+        None => None,
+    };
+    let sent = match link.as_mut().and_then(|l| l.channel(hmu_config.heartbeat_timeout)) {
+        Some(channel) if swappable => HmuEngine::exchange(&hmu_config, channel, &update).await,
+        _ => Err(HmuError::RequiresRestart),
+    };
+    drop(link);
+
+    let mut rt_guard = state.runtime.lock().await;
+    let rt_state = rt_guard
+        .as_mut()
+        .ok_or(AppError::RuntimeError("server stopped during hmu update".into()))?;
+
+    let payload = match sent {
+        Ok(ack) => {
+            let result = rt_state.hmu_engine.record_ack(ack);
+            HmuResultPayload {
+                id: update.id.clone(),
+                status: match result.ack.status {
+                    HmuStatus::Success => "success",
+                    HmuStatus::Partial => "partial",
+                    HmuStatus::Failed => "failed",
+                }
+                .into(),
+                applied: result.ack.applied,
+                failed: result.ack.failed,
+                requires_restart: result.requires_restart,
+            }
+        }
+        Err(e) => {
+            // The files are on disk either way; restart only once the server
+            // can't take updates or the modules keep failing
+            eprintln!("[rash] warning: hmu update {} failed: {e}", update.id);
+            HmuResultPayload {
+                id: update.id.clone(),
+                status: "failed".into(),
+                applied: vec![],
+                failed: update.modules.iter().map(|m| m.path.clone()).collect(),
+                requires_restart: rt_state.hmu_engine.record_failure(&update, &e),
+            }
+        }
+    };
+
     // Update the incremental cache
     rt_state.incremental.update_cache(&changes);
 
+    if payload.requires_restart {
+        rt_state
+            .process_manager
+            .restart(&server_config)
+            .await
+            .map_err(|e| AppError::RuntimeError(e.to_string()))?;
+        // The restarted server loaded every module from disk
+        for path in &payload.failed {
+            rt_state.hmu_engine.reset_failure_count(path);
+        }
+    }
+    drop(rt_guard);
+
+    if let Err(e) = app.emit("hmu:result", &payload) {
+        eprintln!("[rash] warning: failed to emit hmu result: {e}");
+    }
+
     Ok(payload)
 }
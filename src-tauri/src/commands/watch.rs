@@ -0,0 +1,110 @@
+use tauri::{AppHandle, Emitter, Manager, State};
+
+use rash_runtime::watch::{DevSession, SpecWatcher, WatchEvent, DEFAULT_DEBOUNCE};
+use rash_spec::index::build_index;
+
+use crate::error::AppError;
+use crate::state::AppState;
+
+/// Watch the open project's spec files, regenerating code on every edit and
+/// pushing it to the running server. Progress is emitted as `watch:event`.
+///
+/// Returns the directory the project is generated into.
+#[tauri::command]
+pub async fn start_watch(app: AppHandle, state: State<'_, AppState>) -> Result<String, AppError> {
+    let root = {
+        let guard = state.project.lock().unwrap();
+        guard.as_ref().ok_or(AppError::NoProject)?.root.clone()
+    };
+
+    if let Some(task) = state.watch.lock().unwrap().take() {
+        task.abort();
+    }
+
+    let mut session =
+        DevSession::open(&root, None).map_err(|e| AppError::CodegenError(e.to_string()))?;
+    let output_dir = session.output_dir().to_path_buf();
+    let mut watcher = SpecWatcher::new(&root, &[output_dir.clone()], DEFAULT_DEBOUNCE)
+        .map_err(|e| AppError::IoError(e.to_string()))?;
+
+    let task = tokio::spawn(async move {
+        while let Some(batch) = watcher.next_batch().await {
+            let update = match session.apply(&batch) {
+                Ok(update) => update,
+                Err(e) => {
+                    emit_watch_event(
+                        &app,
+                        &WatchEvent::Failed {
+                            specs: batch,
+                            message: e.to_string(),
+                        },
+                    );
+                    continue;
+                }
+            };
+            emit_watch_event(&app, &WatchEvent::regenerated(&update));
+
+            let state = app.state::<AppState>();
+
+            // Keep the editor's view of the project in step with the disk
+            if let Some(open) = state.project.lock().unwrap().as_mut() {
+                if open.root == root {
+                    open.project = session.project().clone();
+                    open.index = build_index(&open.project).0;
+                }
+            }
+
+            if update.changes.is_empty() {
+                continue;
+            }
+            // Create the update under the runtime lock, but release it while
+            // the server applies the update so other commands aren't blocked
+            let pending = {
+                let mut rt_guard = state.runtime.lock().await;
+                let Some(rt_state) = rt_guard.as_mut() else {
+                    continue;
+                };
+                session.begin_push(
+                    &rt_state.process_manager,
+                    &mut rt_state.hmu_engine,
+                    &update,
+                )
+            };
+            let sent = pending.exchange().await;
+
+            let mut rt_guard = state.runtime.lock().await;
+            let Some(rt_state) = rt_guard.as_mut() else {
+                continue;
+            };
+            match session
+                .finish_push(
+                    &mut rt_state.process_manager,
+                    &mut rt_state.hmu_engine,
+                    sent,
+                )
+                .await
+            {
+                Ok(event) => emit_watch_event(&app, &event),
+                Err(e) => eprintln!("[rash] warning: failed to restart server: {e}"),
+            }
+            rt_state.incremental.update_cache(&update.changes);
+        }
+    });
+    *state.watch.lock().unwrap() = Some(task);
+
+    Ok(output_dir.to_string_lossy().into_owned())
+}
+
+#[tauri::command]
+pub async fn stop_watch(state: State<'_, AppState>) -> Result<(), AppError> {
+    if let Some(task) = state.watch.lock().unwrap().take() {
+        task.abort();
+    }
+    Ok(())
+}
+
+fn emit_watch_event(app: &AppHandle, event: &WatchEvent) {
+    if let Err(e) = app.emit("watch:event", event) {
+        eprintln!("[rash] warning: failed to emit watch event: {e}");
+    }
+}
@@ -45,6 +45,8 @@ pub fn run() {
             commands::runtime::restart_server,
             commands::runtime::get_server_status,
             commands::runtime::apply_hmu,
+            commands::watch::start_watch,
+            commands::watch::stop_watch,
             commands::openapi::export_openapi,
             commands::openapi::import_openapi,
             commands::openapi::import_from_code,
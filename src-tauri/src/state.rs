@@ -4,6 +4,7 @@ use std::sync::Mutex;
 use rash_spec::index::SpecIndex;
 use rash_spec::loader::LoadedProject;
 use tokio::sync::Mutex as TokioMutex;
+use tokio::task::JoinHandle;
 
 use rash_runtime::hmu_engine::HmuEngine;
 use rash_runtime::incremental::IncrementalCodegen;
@@ -27,6 +28,8 @@ pub struct RuntimeState {
 pub struct AppState {
     pub project: Mutex<Option<OpenProject>>,
     pub runtime: TokioMutex<Option<RuntimeState>>,
+    /// Spec watcher task started by `start_watch`
+    pub watch: Mutex<Option<JoinHandle<()>>>,
 }
 
 impl Default for AppState {
@@ -34,6 +37,7 @@ impl Default for AppState {
         Self {
             project: Mutex::new(None),
             runtime: TokioMutex::new(None),
+            watch: Mutex::new(None),
         }
     }
 }
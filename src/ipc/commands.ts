@@ -238,7 +238,7 @@ export interface FileChange {
 
 export interface HmuResultPayload {
   id: string;
-  status: "success" | "partial" | "failed";
+  status: "pending" | "success" | "partial" | "failed";
   applied: string[];
   failed: string[];
   requiresRestart: boolean;
@@ -247,3 +247,31 @@ export interface HmuResultPayload {
 export function applyHmu(changes: FileChange[]): Promise<HmuResultPayload> {
   return invoke<HmuResultPayload>("apply_hmu", { changes });
 }
+
+// ---------------------------------------------------------------------------
+// Spec watcher commands
+// ---------------------------------------------------------------------------
+
+export interface HmuPush {
+  id: string;
+  status: "success" | "partial" | "failed";
+  applied: string[];
+  failed: string[];
+  error?: string;
+  restartedOn?: number;
+}
+
+export type WatchEvent =
+  | { type: "regenerated"; specs: string[]; files: string[]; fullRegen: boolean }
+  | ({ type: "pushed" } & HmuPush)
+  | { type: "restarted"; port: number }
+  | { type: "failed"; specs: string[]; message: string };
+
+/** Start regenerating on spec edits; resolves to the output directory. */
+export function startWatch(): Promise<string> {
+  return invoke<string>("start_watch");
+}
+
+export function stopWatch(): Promise<void> {
+  return invoke<void>("stop_watch");
+}
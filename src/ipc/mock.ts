@@ -266,6 +266,10 @@ export async function mockInvoke<T>(cmd: string, args?: Record<string, unknown>)
       return { filesCreated: [], warnings: [] } as T;
     case "apply_hmu":
       return { id: "mock", status: "success", applied: [], failed: [], requiresRestart: false } as T;
+    case "start_watch":
+      return "/mock/output" as T;
+    case "stop_watch":
+      return undefined as T;
 
     default:
       console.warn(`[mock] unhandled command: ${cmd}`);
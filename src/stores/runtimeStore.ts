@@ -2,7 +2,13 @@ import { createSignal } from "solid-js";
 import type { Language, Framework } from "../ipc/types";
 import { onEvent } from "../ipc/events";
 import * as cmd from "../ipc/commands";
-import type { ServerStatus, LogEntry, PreflightReport, HmuResultPayload } from "../ipc/commands";
+import type {
+  ServerStatus,
+  LogEntry,
+  PreflightReport,
+  HmuResultPayload,
+  WatchEvent,
+} from "../ipc/commands";
 
 function createRuntimeStore() {
   const [serverStatus, setServerStatus] = createSignal<ServerStatus>("stopped");
@@ -10,11 +16,13 @@ function createRuntimeStore() {
   const [port, setPort] = createSignal<number | null>(null);
   const [preflight, setPreflight] = createSignal<PreflightReport | null>(null);
   const [building, setBuilding] = createSignal(false);
+  const [lastWatchEvent, setLastWatchEvent] = createSignal<WatchEvent | null>(null);
 
   // Event subscriptions
   let unlistenLog: (() => void) | undefined;
   let unlistenStatus: (() => void) | undefined;
   let unlistenHmu: (() => void) | undefined;
+  let unlistenWatch: (() => void) | undefined;
 
   function subscribe() {
     onEvent<LogEntry>("server:log", (entry) => {
@@ -28,12 +36,17 @@ function createRuntimeStore() {
     onEvent<HmuResultPayload>("hmu:result", (_payload) => {
       // HMU result tracking — future UI can display this
     }).then((fn) => { unlistenHmu = fn; });
+
+    onEvent<WatchEvent>("watch:event", (event) => {
+      setLastWatchEvent(event);
+    }).then((fn) => { unlistenWatch = fn; });
   }
 
   function unsubscribe() {
     unlistenLog?.();
     unlistenStatus?.();
     unlistenHmu?.();
+    unlistenWatch?.();
   }
 
   // Auto-subscribe on creation
@@ -66,6 +79,9 @@ function createRuntimeStore() {
       const p = await cmd.startServer();
       setPort(p);
       setServerStatus("running");
+
+      // 4. Keep the server in sync with spec edits
+      await cmd.startWatch();
     } catch (err) {
       console.error("Build failed:", err);
       setServerStatus("errored");
@@ -76,6 +92,7 @@ function createRuntimeStore() {
 
   async function stop(): Promise<void> {
     try {
+      await cmd.stopWatch();
       await cmd.stopServer();
       setServerStatus("stopped");
       setPort(null);
@@ -94,6 +111,7 @@ function createRuntimeStore() {
     port,
     preflight,
     building,
+    lastWatchEvent,
     build,
     stop,
     clearLogs,